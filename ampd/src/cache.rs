@@ -0,0 +1,382 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+use tokio::time::Instant;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct Config {
+    pub capacity: usize,
+    #[serde(with = "humantime_serde")]
+    pub ttl: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            capacity: 10000,
+            ttl: Duration::from_secs(5),
+        }
+    }
+}
+
+/// A bounded cache for RPC responses that can be cloned and shared between handlers.
+/// Entries expire after the configured time-to-live, and the oldest entry is evicted once the capacity is reached.
+/// Concurrent lookups of the same key only fetch the value once.
+#[derive(Clone)]
+pub struct Cache<K, V> {
+    config: Config,
+    entries: Arc<Mutex<Entries<K, V>>>,
+    counters: Arc<Counters>,
+}
+
+impl<K, V> Cache<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Caches with the same label share their hit and miss counters in the given metrics
+    pub fn new(label: impl Into<String>, config: Config, metrics: &Metrics) -> Self {
+        Self {
+            config,
+            entries: Arc::new(Mutex::new(Entries::default())),
+            counters: metrics.counters(label.into()),
+        }
+    }
+
+    /// Returns the cached value for the given key, or fetches and caches it if it is missing or expired.
+    /// Failed fetches are not cached, the entry is evicted so the next lookup fetches the value again.
+    pub async fn get_or_try_fetch<F, Fut, E>(&self, key: K, fetch: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        let cell = self.cell(key.clone());
+
+        let mut fetched = false;
+        let value = cell
+            .get_or_try_init(|| {
+                fetched = true;
+                fetch()
+            })
+            .await
+            .cloned();
+        self.counters.record(!fetched);
+
+        if value.is_err() {
+            self.entries
+                .lock()
+                .expect("cache lock must not be poisoned")
+                .remove_uninitialized(&key, &cell);
+        }
+
+        value
+    }
+
+    /// Returns the cached value for the given key if it has been fetched and has not expired yet
+    pub fn get(&self, key: &K) -> Option<V> {
+        let entries = self
+            .entries
+            .lock()
+            .expect("cache lock must not be poisoned");
+
+        let value = entries
+            .get(key)
+            .filter(|(inserted_at, _)| inserted_at.elapsed() < self.config.ttl)
+            .and_then(|(_, cell)| cell.get().cloned());
+        self.counters.record(value.is_some());
+
+        value
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let _ = self
+            .entries
+            .lock()
+            .expect("cache lock must not be poisoned")
+            .insert(key, self.config.capacity)
+            .set(value);
+    }
+
+    fn cell(&self, key: K) -> Arc<OnceCell<V>> {
+        let mut entries = self
+            .entries
+            .lock()
+            .expect("cache lock must not be poisoned");
+
+        match entries.get(&key) {
+            Some((inserted_at, cell)) if inserted_at.elapsed() < self.config.ttl => cell.clone(),
+            _ => entries.insert(key, self.config.capacity),
+        }
+    }
+}
+
+struct Entries<K, V> {
+    values: HashMap<K, (Instant, Arc<OnceCell<V>>)>,
+    insertion_order: VecDeque<K>,
+}
+
+impl<K, V> Default for Entries<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            insertion_order: VecDeque::new(),
+        }
+    }
+}
+
+impl<K, V> Entries<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    fn get(&self, key: &K) -> Option<&(Instant, Arc<OnceCell<V>>)> {
+        self.values.get(key)
+    }
+
+    fn insert(&mut self, key: K, capacity: usize) -> Arc<OnceCell<V>> {
+        let cell = Arc::new(OnceCell::new());
+
+        // expired entries are overwritten in place, so every key is in the insertion order at most once
+        if self
+            .values
+            .insert(key.clone(), (Instant::now(), cell.clone()))
+            .is_none()
+        {
+            self.insertion_order.push_back(key);
+        }
+
+        while self.values.len() > capacity {
+            let Some(oldest) = self.insertion_order.pop_front() else {
+                break;
+            };
+            self.values.remove(&oldest);
+        }
+
+        cell
+    }
+
+    /// Removes the entry of a failed fetch, unless it has been replaced or filled in the meantime
+    fn remove_uninitialized(&mut self, key: &K, cell: &Arc<OnceCell<V>>) {
+        let is_same_empty_cell = self
+            .values
+            .get(key)
+            .is_some_and(|(_, current)| Arc::ptr_eq(current, cell) && !current.initialized());
+
+        if is_same_empty_cell {
+            self.values.remove(key);
+            self.insertion_order.retain(|k| k != key);
+        }
+    }
+}
+
+/// Hit and miss counters of all RPC caches, grouped by cache label
+#[derive(Clone, Default)]
+pub struct Metrics {
+    counters: Arc<Mutex<BTreeMap<String, Arc<Counters>>>>,
+}
+
+impl Metrics {
+    fn counters(&self, label: String) -> Arc<Counters> {
+        self.counters
+            .lock()
+            .expect("cache metrics lock must not be poisoned")
+            .entry(label)
+            .or_default()
+            .clone()
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<String, Stats> {
+        self.counters
+            .lock()
+            .expect("cache metrics lock must not be poisoned")
+            .iter()
+            .map(|(label, counters)| (label.clone(), counters.stats()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+#[derive(Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl Counters {
+    fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::Duration;
+
+    use tokio::time::sleep;
+
+    use crate::cache::{Cache, Config, Metrics, Stats};
+
+    fn config(capacity: usize, ttl: Duration) -> Config {
+        Config { capacity, ttl }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_fetch_value_once_until_it_expires() {
+        let cache = Cache::new(
+            "test",
+            config(10, Duration::from_secs(5)),
+            &Metrics::default(),
+        );
+        let fetch_count = &AtomicU64::new(0);
+        let fetch = || async move {
+            fetch_count.fetch_add(1, Ordering::Relaxed);
+            Ok::<_, ()>("value")
+        };
+
+        assert_eq!(cache.get_or_try_fetch(1, fetch).await, Ok("value"));
+        assert_eq!(cache.get_or_try_fetch(1, fetch).await, Ok("value"));
+        assert_eq!(cache.counters.stats().hits, 1);
+        assert_eq!(cache.counters.stats().misses, 1);
+        assert_eq!(fetch_count.load(Ordering::Relaxed), 1);
+
+        sleep(Duration::from_secs(5)).await;
+
+        assert_eq!(cache.get_or_try_fetch(1, fetch).await, Ok("value"));
+        assert_eq!(cache.counters.stats().misses, 2);
+        assert_eq!(fetch_count.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn should_share_values_between_clones() {
+        let cache = Cache::new(
+            "test",
+            config(10, Duration::from_secs(5)),
+            &Metrics::default(),
+        );
+        let clone = cache.clone();
+
+        assert_eq!(
+            cache.get_or_try_fetch(1, || async { Ok::<_, ()>(1) }).await,
+            Ok(1)
+        );
+        assert_eq!(
+            clone.get_or_try_fetch(1, || async { Ok::<_, ()>(2) }).await,
+            Ok(1)
+        );
+        assert_eq!(clone.counters.stats().hits, 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_cache_failed_fetches() {
+        let cache = Cache::new(
+            "test",
+            config(10, Duration::from_secs(5)),
+            &Metrics::default(),
+        );
+
+        assert_eq!(
+            cache.get_or_try_fetch(1, || async { Err("error") }).await,
+            Err("error")
+        );
+        assert!(cache.entries.lock().unwrap().values.is_empty());
+        assert!(cache.entries.lock().unwrap().insertion_order.is_empty());
+
+        assert_eq!(cache.get_or_try_fetch(1, || async { Ok(1) }).await, Ok(1));
+        assert_eq!(cache.counters.stats(), Stats { hits: 0, misses: 2 });
+    }
+
+    #[tokio::test]
+    async fn should_share_counters_between_caches_with_the_same_label() {
+        let metrics = Metrics::default();
+        let cache = Cache::new("test", config(10, Duration::from_secs(5)), &metrics);
+        let other_cache = Cache::new("test", config(10, Duration::from_secs(5)), &metrics);
+        let unrelated_cache = Cache::new("other", config(10, Duration::from_secs(5)), &metrics);
+
+        for cache in [&cache, &other_cache, &unrelated_cache] {
+            assert_eq!(
+                cache.get_or_try_fetch(1, || async { Ok::<_, ()>(1) }).await,
+                Ok(1)
+            );
+        }
+        assert_eq!(cache.get(&1), Some(1));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot["test"], Stats { hits: 1, misses: 2 });
+        assert_eq!(snapshot["other"], Stats { hits: 0, misses: 1 });
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_get_inserted_values_until_they_expire() {
+        let cache = Cache::new(
+            "test",
+            config(10, Duration::from_secs(5)),
+            &Metrics::default(),
+        );
+
+        assert_eq!(cache.get(&1), None);
+
+        cache.insert(1, "value");
+        assert_eq!(cache.get(&1), Some("value"));
+        assert_eq!(
+            cache
+                .get_or_try_fetch(1, || async { Ok::<_, ()>("other") })
+                .await,
+            Ok("value")
+        );
+
+        sleep(Duration::from_secs(5)).await;
+
+        assert_eq!(cache.get(&1), None);
+    }
+
+    #[tokio::test]
+    async fn should_evict_oldest_entries_when_full() {
+        let cache = Cache::new(
+            "test",
+            config(2, Duration::from_secs(5)),
+            &Metrics::default(),
+        );
+
+        for key in 0..3 {
+            assert_eq!(
+                cache
+                    .get_or_try_fetch(key, || async move { Ok::<_, ()>(key) })
+                    .await,
+                Ok(key)
+            );
+        }
+
+        assert_eq!(
+            cache
+                .get_or_try_fetch(0, || async { Ok::<_, ()>(10) })
+                .await,
+            Ok(10)
+        );
+        assert_eq!(
+            cache
+                .get_or_try_fetch(2, || async { Ok::<_, ()>(20) })
+                .await,
+            Ok(2)
+        );
+    }
+}
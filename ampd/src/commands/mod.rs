@@ -25,6 +25,7 @@ pub mod daemon;
 pub mod deregister_chain_support;
 pub mod register_chain_support;
 pub mod register_public_key;
+pub mod rotate_key;
 pub mod send_tokens;
 pub mod set_rewards_proxy;
 pub mod unbond_verifier;
@@ -46,6 +47,8 @@ pub enum SubCommand {
     DeregisterChainSupport(deregister_chain_support::Args),
     /// Register public key to the multisig contract
     RegisterPublicKey(register_public_key::Args),
+    /// Register a new public key to the multisig contract that replaces the current one with the next verifier set
+    RotateKey(rotate_key::Args),
     /// Query the verifier address
     VerifierAddress,
     /// Send tokens from the verifier account to a specified address
@@ -2,6 +2,7 @@ use std::convert::{TryFrom, TryInto};
 
 use cosmrs::cosmwasm::MsgExecuteContract;
 use cosmrs::tx::Msg;
+use cosmwasm_std::HexBinary;
 use error_stack::{Result, ResultExt};
 use multisig::key::PublicKey;
 use multisig::msg::ExecuteMsg;
@@ -18,9 +19,11 @@ use crate::types::TMAddress;
 use crate::{handlers, Error, PREFIX};
 
 #[derive(clap::ValueEnum, Clone, Debug, Valuable, Copy)]
-enum KeyType {
+pub(crate) enum KeyType {
     Ecdsa,
     Ed25519,
+    Schnorr,
+    Bls,
 }
 
 impl From<KeyType> for tofnd::Algorithm {
@@ -28,6 +31,8 @@ impl From<KeyType> for tofnd::Algorithm {
         match val {
             KeyType::Ecdsa => tofnd::Algorithm::Ecdsa,
             KeyType::Ed25519 => tofnd::Algorithm::Ed25519,
+            KeyType::Schnorr => tofnd::Algorithm::Schnorr,
+            KeyType::Bls => tofnd::Algorithm::Bls,
         }
     }
 }
@@ -37,6 +42,8 @@ impl From<KeyType> for multisig::key::KeyType {
         match val {
             KeyType::Ecdsa => multisig::key::KeyType::Ecdsa,
             KeyType::Ed25519 => multisig::key::KeyType::Ed25519,
+            KeyType::Schnorr => multisig::key::KeyType::Schnorr,
+            KeyType::Bls => multisig::key::KeyType::Bls,
         }
     }
 }
@@ -47,9 +54,35 @@ pub struct Args {
 }
 
 pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    let tx_hash = broadcast_key(
+        config,
+        args.key_type,
+        0,
+        |public_key, signed_sender_address| ExecuteMsg::RegisterPublicKey {
+            public_key,
+            signed_sender_address,
+        },
+    )
+    .await?;
+
+    Ok(Some(format!(
+        "successfully broadcast register public key transaction, tx hash: {}",
+        tx_hash
+    )))
+}
+
+/// Generates the multisig key of the given rotation with tofnd and broadcasts the message built from it,
+/// together with the sender address signed by the key to prove ownership. Returns the transaction hash.
+pub(crate) async fn broadcast_key(
+    config: Config,
+    key_type: KeyType,
+    rotation: u32,
+    to_msg: impl FnOnce(PublicKey, HexBinary) -> ExecuteMsg,
+) -> Result<String, Error> {
     let pub_key = verifier_pub_key(config.tofnd_config.clone()).await?;
 
     let multisig_address = multisig_address(&config)?;
+    let key_uid = handlers::multisig::key_uid(&multisig_address, rotation);
 
     let tofnd_config = config.tofnd_config.clone();
 
@@ -57,12 +90,13 @@ pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
         .await
         .change_context(Error::Connection)
         .attach_printable(tofnd_config.url)?;
+    // the raw key is used because BLS keys can't be represented as tendermint keys
     let multisig_key = multisig_client
-        .keygen(&multisig_address.to_string(), args.key_type.into())
+        .keygen_raw(&key_uid, key_type.into())
         .await
         .change_context(Error::Tofnd)?;
 
-    info!(key_id = multisig_address.to_string(), "keygen successful");
+    info!(key_id = key_uid, "keygen successful");
 
     let sender = pub_key.account_id(PREFIX).change_context(Error::Tofnd)?;
 
@@ -72,22 +106,21 @@ pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
         .expect("wrong length");
 
     let signed_sender_address = multisig_client
-        .sign(
-            &multisig_address.to_string(),
+        .sign_raw(
+            &key_uid,
             address_hash.into(),
             &multisig_key,
-            args.key_type.into(),
+            key_type.into(),
         )
         .await
         .change_context(Error::Tofnd)?
         .into();
 
-    let msg = serde_json::to_vec(&ExecuteMsg::RegisterPublicKey {
-        public_key: PublicKey::try_from((args.key_type.into(), multisig_key.to_bytes().into()))
-            .change_context(Error::Tofnd)?,
+    let msg = serde_json::to_vec(&to_msg(
+        PublicKey::try_from((key_type.into(), multisig_key.into())).change_context(Error::Tofnd)?,
         signed_sender_address,
-    })
-    .expect("register public key msg should serialize");
+    ))
+    .expect("public key msg should serialize");
 
     let tx = MsgExecuteContract {
         sender,
@@ -98,12 +131,7 @@ pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
     .into_any()
     .expect("failed to serialize proto message");
 
-    let tx_hash = broadcast_tx(config, tx, pub_key).await?.txhash;
-
-    Ok(Some(format!(
-        "successfully broadcast register public key transaction, tx hash: {}",
-        tx_hash
-    )))
+    Ok(broadcast_tx(config, tx, pub_key).await?.txhash)
 }
 
 fn multisig_address(config: &Config) -> Result<TMAddress, Error> {
@@ -111,7 +139,10 @@ fn multisig_address(config: &Config) -> Result<TMAddress, Error> {
         .handlers
         .iter()
         .find_map(|config| {
-            if let handlers::config::Config::MultisigSigner { cosmwasm_contract } = config {
+            if let handlers::config::Config::MultisigSigner {
+                cosmwasm_contract, ..
+            } = config
+            {
                 Some(cosmwasm_contract.clone())
             } else {
                 None
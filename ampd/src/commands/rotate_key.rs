@@ -0,0 +1,42 @@
+use error_stack::{report, Result, ResultExt};
+use multisig::msg::ExecuteMsg;
+use valuable::Valuable;
+
+use crate::commands::register_public_key::{broadcast_key, KeyType};
+use crate::config::Config;
+use crate::handlers::multisig::MAX_KEY_ROTATIONS;
+use crate::Error;
+
+#[derive(clap::Args, Debug, Valuable)]
+pub struct Args {
+    key_type: KeyType,
+    /// Number of the rotation, starting at 1 for the first key that replaces the registered key.
+    /// Every rotation generates a different key, so it must be incremented with each rotation.
+    #[arg(long)]
+    rotation: u32,
+}
+
+pub async fn run(config: Config, args: Args) -> Result<Option<String>, Error> {
+    if args.rotation == 0 || args.rotation > MAX_KEY_ROTATIONS {
+        return Err(report!(Error::InvalidInput)).attach_printable(format!(
+            "rotation must be between 1 and {}",
+            MAX_KEY_ROTATIONS
+        ));
+    }
+
+    let tx_hash = broadcast_key(
+        config,
+        args.key_type,
+        args.rotation,
+        |public_key, signed_sender_address| ExecuteMsg::RotatePublicKey {
+            public_key,
+            signed_sender_address,
+        },
+    )
+    .await?;
+
+    Ok(Some(format!(
+        "successfully broadcast rotate public key transaction, tx hash: {}. Set key_rotation = {} in the MultisigSigner handler config before restarting ampd",
+        tx_hash, args.rotation
+    )))
+}
@@ -7,7 +7,7 @@ use crate::handlers::config::deserialize_handler_configs;
 use crate::handlers::{self};
 use crate::tofnd::Config as TofndConfig;
 use crate::url::Url;
-use crate::{broadcaster, event_processor};
+use crate::{broadcaster, cache, event_processor};
 
 #[derive(Deserialize, Serialize, Debug, PartialEq)]
 #[serde(default)]
@@ -22,6 +22,7 @@ pub struct Config {
     pub tofnd_config: TofndConfig,
     pub service_registry: ServiceRegistryConfig,
     pub rewards: RewardsConfig,
+    pub rpc_cache: cache::Config,
 }
 
 impl Default for Config {
@@ -35,6 +36,7 @@ impl Default for Config {
             event_processor: event_processor::Config::default(),
             service_registry: ServiceRegistryConfig::default(),
             rewards: RewardsConfig::default(),
+            rpc_cache: cache::Config::default(),
             health_check_bind_addr: SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 3000),
         }
     }
@@ -45,6 +47,7 @@ mod tests {
     use std::fs;
     use std::fs::File;
     use std::io::Write;
+    use std::num::NonZeroUsize;
     use std::path::PathBuf;
     use std::str::FromStr;
     use std::time::Duration;
@@ -55,6 +58,9 @@ mod tests {
     use super::Config;
     use crate::evm::finalizer::Finalization;
     use crate::handlers::config::{Chain, Config as HandlerConfig};
+    use crate::mvx::finalizer::Finalization as MvxFinalization;
+    use crate::starknet::finality::Finality as StarknetFinality;
+    use crate::stellar::ApiType;
     use crate::types::TMAddress;
     use crate::url::Url;
 
@@ -132,6 +138,39 @@ mod tests {
             type = 'StellarVerifierSetVerifier'
             cosmwasm_contract = '{}'
             http_url = 'http://localhost:8000'
+
+            [[handlers]]
+            type = 'CosmosMsgVerifier'
+            cosmwasm_contract = '{}'
+            chain_name = 'osmosis'
+            rpc_url = 'http://localhost:26657'
+
+            [[handlers]]
+            type = 'CosmosVerifierSetVerifier'
+            cosmwasm_contract = '{}'
+            chain_name = 'osmosis'
+            rpc_url = 'http://localhost:26657'
+            confirmation_blocks = 1
+
+            [[handlers]]
+            type = 'StarknetMsgVerifier'
+            cosmwasm_contract = '{}'
+            rpc_url = 'http://localhost:9545'
+
+            [[handlers]]
+            type = 'StarknetVerifierSetVerifier'
+            cosmwasm_contract = '{}'
+            rpc_url = 'http://localhost:9545'
+            finality = 'AcceptedOnL2'
+
+            [handlers.rpc_timeout]
+            secs = 3
+            nanos = 0
+
+            [[handlers]]
+            type = 'XrplMsgVerifier'
+            cosmwasm_contract = '{}'
+            rpc_url = 'http://localhost:5005'
             ",
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
@@ -143,10 +182,15 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
+            TMAddress::random(PREFIX),
+            TMAddress::random(PREFIX),
+            TMAddress::random(PREFIX),
+            TMAddress::random(PREFIX),
+            TMAddress::random(PREFIX),
         );
 
         let cfg: Config = toml::from_str(config_str.as_str()).unwrap();
-        assert_eq!(cfg.handlers.len(), 10);
+        assert_eq!(cfg.handlers.len(), 15);
     }
 
     #[test]
@@ -290,11 +334,13 @@ mod tests {
                         name: ChainName::from_str("Ethereum").unwrap(),
                         finalization: Finalization::RPCFinalizedBlock,
                         rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                        rpc_batch_size: NonZeroUsize::new(20).unwrap(),
                     },
                     rpc_timeout: Some(Duration::from_secs(3)),
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
+                    max_concurrent_events: None,
                 },
                 HandlerConfig::EvmVerifierSetVerifier {
                     cosmwasm_contract: TMAddress::from(
@@ -304,51 +350,117 @@ mod tests {
                         name: ChainName::from_str("Fantom").unwrap(),
                         finalization: Finalization::ConfirmationHeight,
                         rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                        rpc_batch_size: NonZeroUsize::new(20).unwrap(),
                     },
                     rpc_timeout: Some(Duration::from_secs(3)),
+                    max_concurrent_events: None,
                 },
                 HandlerConfig::MultisigSigner {
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
+                    key_rotation: 1,
+                    max_concurrent_events: None,
                 },
                 HandlerConfig::SuiMsgVerifier {
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    cross_check_rpc_url: Some(Url::from_str("http://127.0.0.2").unwrap()),
                     rpc_timeout: Some(Duration::from_secs(3)),
+                    confirmation_checkpoints: 1,
+                    max_concurrent_events: None,
                 },
                 HandlerConfig::SuiVerifierSetVerifier {
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    cross_check_rpc_url: None,
                     rpc_timeout: Some(Duration::from_secs(3)),
+                    confirmation_checkpoints: 1,
+                    max_concurrent_events: None,
                 },
                 HandlerConfig::MvxMsgVerifier {
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     proxy_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    finalization: MvxFinalization::FinalizedHyperblock,
+                    confirmation_depth: 1,
+                    max_concurrent_events: None,
                 },
                 HandlerConfig::MvxVerifierSetVerifier {
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     proxy_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    finalization: MvxFinalization::LatestHyperblock,
+                    confirmation_depth: 0,
+                    max_concurrent_events: None,
                 },
                 HandlerConfig::StellarMsgVerifier {
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     http_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    api_type: ApiType::Horizon,
+                    confirmation_ledgers: 0,
+                    max_concurrent_events: None,
                 },
                 HandlerConfig::StellarVerifierSetVerifier {
                     cosmwasm_contract: TMAddress::from(
                         AccountId::new("axelar", &[0u8; 32]).unwrap(),
                     ),
                     http_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    api_type: ApiType::SorobanRpc,
+                    confirmation_ledgers: 1,
+                    max_concurrent_events: None,
+                },
+                HandlerConfig::CosmosMsgVerifier {
+                    cosmwasm_contract: TMAddress::from(
+                        AccountId::new("axelar", &[0u8; 32]).unwrap(),
+                    ),
+                    chain_name: ChainName::from_str("osmosis").unwrap(),
+                    rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    confirmation_blocks: 1,
+                    max_concurrent_events: None,
+                },
+                HandlerConfig::CosmosVerifierSetVerifier {
+                    cosmwasm_contract: TMAddress::from(
+                        AccountId::new("axelar", &[0u8; 32]).unwrap(),
+                    ),
+                    chain_name: ChainName::from_str("osmosis").unwrap(),
+                    rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    confirmation_blocks: 1,
+                    max_concurrent_events: None,
+                },
+                HandlerConfig::StarknetMsgVerifier {
+                    cosmwasm_contract: TMAddress::from(
+                        AccountId::new("axelar", &[0u8; 32]).unwrap(),
+                    ),
+                    rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    finality: StarknetFinality::AcceptedOnL1,
+                    rpc_timeout: Some(Duration::from_secs(3)),
+                    max_concurrent_events: None,
+                },
+                HandlerConfig::StarknetVerifierSetVerifier {
+                    cosmwasm_contract: TMAddress::from(
+                        AccountId::new("axelar", &[0u8; 32]).unwrap(),
+                    ),
+                    rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    finality: StarknetFinality::AcceptedOnL2,
+                    rpc_timeout: Some(Duration::from_secs(3)),
+                    max_concurrent_events: None,
+                },
+                HandlerConfig::XrplMsgVerifier {
+                    cosmwasm_contract: TMAddress::from(
+                        AccountId::new("axelar", &[0u8; 32]).unwrap(),
+                    ),
+                    rpc_url: Url::from_str("http://127.0.0.1").unwrap(),
+                    rpc_timeout: Some(Duration::from_secs(3)),
+                    max_concurrent_events: None,
                 },
             ],
             ..Config::default()
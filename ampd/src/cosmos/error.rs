@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to get the transaction from the CometBFT RPC")]
+    TxResponse,
+    #[error("failed to get the latest block height from the CometBFT RPC")]
+    LatestBlockHeight,
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use error_stack::Result;
+
+use crate::cosmos::error::Error;
+use crate::cosmos::rpc_client::{CosmosClient, TxResponse};
+use crate::types::Hash;
+
+/// Only keeps the transactions whose block is followed by at least `confirmation_blocks` blocks.
+/// CometBFT has instant finality, so the confirmations only guard against lagging or misbehaving RPC nodes.
+pub async fn finalized_transactions<C>(
+    rpc_client: &C,
+    transactions: HashMap<Hash, TxResponse>,
+    confirmation_blocks: u64,
+) -> Result<HashMap<Hash, TxResponse>, Error>
+where
+    C: CosmosClient + Send + Sync,
+{
+    if transactions.is_empty() {
+        return Ok(transactions);
+    }
+
+    let latest_block_height = rpc_client.latest_block_height().await?;
+
+    Ok(transactions
+        .into_iter()
+        .filter(|(_, tx)| tx.height.saturating_add(confirmation_blocks) <= latest_block_height)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use error_stack::report;
+    use tokio::test as async_test;
+
+    use crate::cosmos::error::Error;
+    use crate::cosmos::finality::finalized_transactions;
+    use crate::cosmos::rpc_client::{MockCosmosClient, TxResponse};
+    use crate::types::Hash;
+
+    #[async_test]
+    async fn should_not_query_latest_block_height_without_transactions() {
+        let rpc_client = MockCosmosClient::new();
+
+        let finalized = finalized_transactions(&rpc_client, HashMap::new(), 1)
+            .await
+            .unwrap();
+
+        assert!(finalized.is_empty());
+    }
+
+    #[async_test]
+    async fn should_only_keep_confirmed_transactions() {
+        let mut rpc_client = MockCosmosClient::new();
+        rpc_client
+            .expect_latest_block_height()
+            .returning(|| Ok(100));
+
+        let confirmed = transaction(98);
+        let unconfirmed = transaction(99);
+        let transactions = HashMap::from([
+            (confirmed.tx_hash, confirmed.clone()),
+            (unconfirmed.tx_hash, unconfirmed),
+        ]);
+
+        let finalized = finalized_transactions(&rpc_client, transactions, 2)
+            .await
+            .unwrap();
+
+        assert_eq!(finalized, HashMap::from([(confirmed.tx_hash, confirmed)]));
+    }
+
+    #[async_test]
+    async fn should_fail_if_latest_block_height_is_unavailable() {
+        let mut rpc_client = MockCosmosClient::new();
+        rpc_client
+            .expect_latest_block_height()
+            .returning(|| Err(report!(Error::LatestBlockHeight)));
+
+        let tx = transaction(1);
+        let result =
+            finalized_transactions(&rpc_client, HashMap::from([(tx.tx_hash, tx)]), 0).await;
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            Error::LatestBlockHeight
+        ));
+    }
+
+    fn transaction(height: u64) -> TxResponse {
+        TxResponse {
+            tx_hash: Hash::random(),
+            height,
+            successful: true,
+            events: vec![],
+        }
+    }
+}
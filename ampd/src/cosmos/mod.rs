@@ -0,0 +1,4 @@
+pub mod error;
+pub mod finality;
+pub mod rpc_client;
+pub mod verifier;
@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use error_stack::{report, Result};
+use futures::future::join_all;
+use mockall::automock;
+use tendermint::abci;
+use tendermint_rpc::endpoint::tx;
+use tendermint_rpc::error::ErrorDetail;
+use tendermint_rpc::{Client, HttpClient};
+
+use crate::cosmos::error::Error;
+use crate::types::Hash;
+
+// CometBFT responds with "tx (<hash>) not found" for unknown transactions
+const TRANSACTION_NOT_FOUND: &str = "not found";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxEvent {
+    pub event_type: String,
+    pub attributes: HashMap<String, String>,
+}
+
+impl TxEvent {
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+}
+
+impl From<abci::Event> for TxEvent {
+    fn from(event: abci::Event) -> Self {
+        Self {
+            event_type: event.kind,
+            attributes: event
+                .attributes
+                .into_iter()
+                .map(|attribute| (attribute.key, attribute.value))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxResponse {
+    pub tx_hash: Hash,
+    pub height: u64,
+    pub successful: bool,
+    pub events: Vec<TxEvent>,
+}
+
+impl TxResponse {
+    fn new(tx_hash: Hash, response: tx::Response) -> Self {
+        Self {
+            tx_hash,
+            height: response.height.value(),
+            successful: response.tx_result.code.is_ok(),
+            events: response
+                .tx_result
+                .events
+                .into_iter()
+                .map(TxEvent::from)
+                .collect(),
+        }
+    }
+}
+
+#[automock]
+#[async_trait]
+pub trait CosmosClient {
+    /// Only returns the transactions that were found
+    async fn transactions(
+        &self,
+        tx_hashes: HashSet<Hash>,
+    ) -> Result<HashMap<Hash, TxResponse>, Error>;
+
+    async fn transaction(&self, tx_hash: Hash) -> Result<Option<TxResponse>, Error>;
+
+    async fn latest_block_height(&self) -> Result<u64, Error>;
+}
+
+#[async_trait]
+impl CosmosClient for HttpClient {
+    async fn transactions(
+        &self,
+        tx_hashes: HashSet<Hash>,
+    ) -> Result<HashMap<Hash, TxResponse>, Error> {
+        join_all(
+            tx_hashes
+                .into_iter()
+                .map(|tx_hash| CosmosClient::transaction(self, tx_hash)),
+        )
+        .await
+        .into_iter()
+        .filter_map(|tx_response| match tx_response {
+            Ok(tx_response) => {
+                tx_response.map(|tx_response| Ok((tx_response.tx_hash, tx_response)))
+            }
+            Err(err) => Some(Err(err)),
+        })
+        .collect()
+    }
+
+    async fn transaction(&self, tx_hash: Hash) -> Result<Option<TxResponse>, Error> {
+        match Client::tx(
+            self,
+            tendermint::Hash::Sha256(tx_hash.to_fixed_bytes()),
+            false,
+        )
+        .await
+        {
+            Ok(response) => Ok(Some(TxResponse::new(tx_hash, response))),
+            Err(err) if is_not_found(&err) => Ok(None),
+            Err(err) => Err(report!(err).change_context(Error::TxResponse)),
+        }
+    }
+
+    async fn latest_block_height(&self) -> Result<u64, Error> {
+        Client::status(self)
+            .await
+            .map(|status| status.sync_info.latest_block_height.value())
+            .map_err(|err| report!(err).change_context(Error::LatestBlockHeight))
+    }
+}
+
+fn is_not_found(err: &tendermint_rpc::Error) -> bool {
+    matches!(err.detail(), ErrorDetail::Response(_))
+        && err.to_string().contains(TRANSACTION_NOT_FOUND)
+}
+
+#[cfg(test)]
+mod tests {
+    use tendermint::abci;
+
+    use crate::cosmos::rpc_client::TxEvent;
+
+    #[test]
+    fn should_convert_abci_event_to_tx_event() {
+        let event: TxEvent = abci::Event::new(
+            "wasm-contract_called",
+            vec![
+                ("_contract_address", "osmo1gateway"),
+                ("destination_chain", "ethereum"),
+            ],
+        )
+        .into();
+
+        assert_eq!(event.event_type, "wasm-contract_called");
+        assert_eq!(event.attribute("_contract_address"), Some("osmo1gateway"));
+        assert_eq!(event.attribute("destination_chain"), Some("ethereum"));
+        assert_eq!(event.attribute("payload_hash"), None);
+    }
+}
@@ -0,0 +1,315 @@
+use axelar_wasm_std::voting::Vote;
+use router_api::ChainName;
+
+use crate::cosmos::rpc_client::{TxEvent, TxResponse};
+use crate::handlers::cosmos_verify_msg::Message;
+use crate::handlers::cosmos_verify_verifier_set::VerifierSetConfirmation;
+use crate::types::Hash;
+
+// CosmWasm prefixes the event types of contracts with "wasm-" and adds the emitting contract as an attribute
+const CONTRACT_CALLED_EVENT: &str = "wasm-contract_called";
+const SIGNERS_ROTATED_EVENT: &str = "wasm-signers_rotated";
+const CONTRACT_ADDRESS_ATTRIBUTE: &str = "_contract_address";
+
+impl PartialEq<TxEvent> for Message {
+    fn eq(&self, event: &TxEvent) -> bool {
+        event.event_type == CONTRACT_CALLED_EVENT
+            && event.attribute("source_address") == Some(self.source_address.as_str())
+            && event
+                .attribute("destination_chain")
+                .and_then(|chain| chain.parse::<ChainName>().ok())
+                .is_some_and(|chain| chain == self.destination_chain)
+            && event.attribute("destination_address") == Some(self.destination_address.as_str())
+            && event
+                .attribute("payload_hash")
+                .and_then(|payload_hash| hex::decode(payload_hash).ok())
+                .is_some_and(|payload_hash| payload_hash == self.payload_hash.as_bytes())
+    }
+}
+
+impl PartialEq<TxEvent> for VerifierSetConfirmation {
+    fn eq(&self, event: &TxEvent) -> bool {
+        event.event_type == SIGNERS_ROTATED_EVENT
+            && event.attribute("verifier_set_id") == Some(self.verifier_set.id().as_str())
+    }
+}
+
+pub fn verify_message(gateway_address: &str, tx_response: &TxResponse, msg: &Message) -> Vote {
+    verify(
+        gateway_address,
+        tx_response,
+        msg,
+        msg.tx_id,
+        msg.event_index,
+    )
+}
+
+pub fn verify_verifier_set(
+    gateway_address: &str,
+    tx_response: &TxResponse,
+    verifier_set: &VerifierSetConfirmation,
+) -> Vote {
+    verify(
+        gateway_address,
+        tx_response,
+        verifier_set,
+        verifier_set.tx_id,
+        verifier_set.event_index,
+    )
+}
+
+fn verify(
+    gateway_address: &str,
+    tx_response: &TxResponse,
+    to_verify: &impl PartialEq<TxEvent>,
+    expected_tx_id: Hash,
+    expected_event_index: u32,
+) -> Vote {
+    if tx_response.tx_hash != expected_tx_id {
+        return Vote::NotFound;
+    }
+
+    if !tx_response.successful {
+        return Vote::FailedOnChain;
+    }
+
+    match usize::try_from(expected_event_index)
+        .ok()
+        .and_then(|index| tx_response.events.get(index))
+    {
+        Some(event)
+            if event.attribute(CONTRACT_ADDRESS_ATTRIBUTE) == Some(gateway_address)
+                && to_verify.eq(event) =>
+        {
+            Vote::SucceededOnChain
+        }
+        _ => Vote::NotFound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axelar_wasm_std::voting::Vote;
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ecdsa_test_data};
+
+    use crate::cosmos::rpc_client::{TxEvent, TxResponse};
+    use crate::cosmos::verifier::{
+        verify_message, verify_verifier_set, CONTRACT_ADDRESS_ATTRIBUTE, CONTRACT_CALLED_EVENT,
+        SIGNERS_ROTATED_EVENT,
+    };
+    use crate::handlers::cosmos_verify_msg::Message;
+    use crate::handlers::cosmos_verify_verifier_set::VerifierSetConfirmation;
+    use crate::types::{EVMAddress, Hash, TMAddress};
+
+    #[test]
+    fn should_verify_msg_if_correct() {
+        let (gateway_address, tx_response, msg) = matching_msg_and_tx_response();
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::SucceededOnChain
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_tx_id_does_not_match() {
+        let (gateway_address, tx_response, mut msg) = matching_msg_and_tx_response();
+        msg.tx_id = Hash::random();
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_event_index_does_not_match() {
+        let (gateway_address, tx_response, mut msg) = matching_msg_and_tx_response();
+        msg.event_index = 0;
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::NotFound
+        );
+
+        msg.event_index = 2;
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_event_is_not_from_gateway() {
+        let (_, tx_response, msg) = matching_msg_and_tx_response();
+
+        assert_eq!(
+            verify_message(&TMAddress::random("osmo").to_string(), &tx_response, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_source_address_does_not_match() {
+        let (gateway_address, tx_response, mut msg) = matching_msg_and_tx_response();
+        msg.source_address = TMAddress::random("osmo").to_string();
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_destination_does_not_match() {
+        let (gateway_address, tx_response, mut msg) = matching_msg_and_tx_response();
+        msg.destination_chain = "avalanche".parse().unwrap();
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::NotFound
+        );
+
+        let (gateway_address, tx_response, mut msg) = matching_msg_and_tx_response();
+        msg.destination_address = format!("0x{:x}", EVMAddress::random());
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_payload_hash_does_not_match() {
+        let (gateway_address, tx_response, mut msg) = matching_msg_and_tx_response();
+        msg.payload_hash = Hash::random();
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_vote_failed_on_chain_if_tx_failed() {
+        let (gateway_address, mut tx_response, msg) = matching_msg_and_tx_response();
+        tx_response.successful = false;
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::FailedOnChain
+        );
+    }
+
+    #[test]
+    fn should_verify_verifier_set_if_correct() {
+        let (gateway_address, tx_response, verifier_set) = matching_verifier_set_and_tx_response();
+
+        assert_eq!(
+            verify_verifier_set(&gateway_address, &tx_response, &verifier_set),
+            Vote::SucceededOnChain
+        );
+    }
+
+    #[test]
+    fn should_not_verify_verifier_set_if_verifier_set_does_not_match() {
+        let (gateway_address, tx_response, mut verifier_set) =
+            matching_verifier_set_and_tx_response();
+        verifier_set.verifier_set.created_at =
+            verifier_set.verifier_set.created_at.saturating_add(1);
+
+        assert_eq!(
+            verify_verifier_set(&gateway_address, &tx_response, &verifier_set),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_verifier_set_if_event_type_does_not_match() {
+        let (gateway_address, mut tx_response, verifier_set) =
+            matching_verifier_set_and_tx_response();
+        tx_response.events[0].event_type = CONTRACT_CALLED_EVENT.to_string();
+
+        assert_eq!(
+            verify_verifier_set(&gateway_address, &tx_response, &verifier_set),
+            Vote::NotFound
+        );
+    }
+
+    fn matching_msg_and_tx_response() -> (String, TxResponse, Message) {
+        let gateway_address = TMAddress::random("osmo").to_string();
+        let msg = Message {
+            tx_id: Hash::random(),
+            event_index: 1,
+            destination_address: format!("0x{:x}", EVMAddress::random()),
+            destination_chain: "ethereum".parse().unwrap(),
+            source_address: TMAddress::random("osmo").to_string(),
+            payload_hash: Hash::random(),
+        };
+
+        let event = TxEvent {
+            event_type: CONTRACT_CALLED_EVENT.to_string(),
+            attributes: HashMap::from([
+                (
+                    CONTRACT_ADDRESS_ATTRIBUTE.to_string(),
+                    gateway_address.clone(),
+                ),
+                ("source_address".to_string(), msg.source_address.clone()),
+                ("destination_chain".to_string(), "Ethereum".to_string()),
+                (
+                    "destination_address".to_string(),
+                    msg.destination_address.clone(),
+                ),
+                ("payload_hash".to_string(), hex::encode(msg.payload_hash)),
+            ]),
+        };
+
+        let tx_response = TxResponse {
+            tx_hash: msg.tx_id,
+            height: 1,
+            successful: true,
+            events: vec![
+                TxEvent {
+                    event_type: "message".to_string(),
+                    attributes: HashMap::new(),
+                },
+                event,
+            ],
+        };
+
+        (gateway_address, tx_response, msg)
+    }
+
+    fn matching_verifier_set_and_tx_response() -> (String, TxResponse, VerifierSetConfirmation) {
+        let gateway_address = TMAddress::random("osmo").to_string();
+        let verifier_set = VerifierSetConfirmation {
+            tx_id: Hash::random(),
+            event_index: 0,
+            verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+        };
+
+        let tx_response = TxResponse {
+            tx_hash: verifier_set.tx_id,
+            height: 1,
+            successful: true,
+            events: vec![TxEvent {
+                event_type: SIGNERS_ROTATED_EVENT.to_string(),
+                attributes: HashMap::from([
+                    (
+                        CONTRACT_ADDRESS_ATTRIBUTE.to_string(),
+                        gateway_address.clone(),
+                    ),
+                    (
+                        "verifier_set_id".to_string(),
+                        verifier_set.verifier_set.id(),
+                    ),
+                ]),
+            }],
+        };
+
+        (gateway_address, tx_response, verifier_set)
+    }
+}
@@ -1,3 +1,4 @@
+use std::num::NonZeroUsize;
 use std::pin::Pin;
 use std::time::Duration;
 
@@ -5,10 +6,12 @@ use async_trait::async_trait;
 use cosmrs::Any;
 use error_stack::{Context, Result, ResultExt};
 use events::Event;
+use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use report::LoggableError;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tokio::select;
 use tokio::time::timeout;
 use tokio_stream::Stream;
 use tokio_util::sync::CancellationToken;
@@ -17,6 +20,7 @@ use valuable::Valuable;
 
 use crate::asyncutil::future::{self, RetryPolicy};
 use crate::asyncutil::task::TaskError;
+use crate::event_sub::filter::EventFilters;
 use crate::queue::queued_broadcaster::BroadcasterClient;
 
 #[async_trait]
@@ -24,6 +28,11 @@ pub trait EventHandler {
     type Err: Context;
 
     async fn handle(&self, event: &Event) -> Result<Vec<Any>, Self::Err>;
+
+    /// Events that do not match the filters are not dispatched to the handler. All events are dispatched by default.
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::default()
+    }
 }
 
 #[derive(Error, Debug)]
@@ -44,6 +53,12 @@ pub struct Config {
     #[serde(with = "humantime_serde")]
     pub stream_timeout: Duration,
     pub stream_buffer_size: usize,
+    #[serde(default = "default_max_concurrent_events")]
+    pub max_concurrent_events: NonZeroUsize,
+}
+
+fn default_max_concurrent_events() -> NonZeroUsize {
+    NonZeroUsize::MIN
 }
 
 impl Default for Config {
@@ -53,13 +68,26 @@ impl Default for Config {
             retry_max_attempts: 3,
             stream_timeout: Duration::from_secs(15),
             stream_buffer_size: 100000,
+            max_concurrent_events: default_max_concurrent_events(),
         }
     }
 }
 
-/// Let the `handler` consume events from the `event_stream`. The token is checked for cancellation
-/// at the end of each consumed block or when the `event_stream` times out. If the token is cancelled or the
-/// `event_stream` is closed, the function returns
+impl Config {
+    /// Returns a copy of the config with the handler specific concurrency limit, if one is set
+    pub fn with_max_concurrent_events(&self, max_concurrent_events: Option<NonZeroUsize>) -> Self {
+        Self {
+            max_concurrent_events: max_concurrent_events.unwrap_or(self.max_concurrent_events),
+            ..self.clone()
+        }
+    }
+}
+
+/// Let the `handler` consume events from the `event_stream`. Only events matching the handler's event filters are dispatched to it.
+/// Up to `max_concurrent_events` ABCI events are handled concurrently, while block begin and end events act as barriers,
+/// i.e. they are only handled after all preceding events have been handled.
+/// The token is checked for cancellation at the end of each consumed block or when the `event_stream` times out.
+/// If the token is cancelled or the `event_stream` is closed, the function returns
 pub async fn consume_events<H, B, S, E>(
     handler_label: String,
     handler: H,
@@ -74,24 +102,51 @@ where
     S: Stream<Item = Result<Event, E>>,
     E: Context,
 {
+    let event_filters = handler.event_filters();
+    let retry_policy = RetryPolicy::RepeatConstant {
+        sleep: event_processor_config.retry_delay,
+        max_attempts: event_processor_config.retry_max_attempts,
+    };
+    let max_concurrent_events = event_processor_config.max_concurrent_events.get();
+
     let mut event_stream = Box::pin(event_stream);
+    let mut in_flight = FuturesUnordered::new();
     loop {
-        let stream_status =
-            retrieve_next_event(&mut event_stream, event_processor_config.stream_timeout)
-                .await
-                .change_context(Error::EventStream)?;
+        let stream_status = select! {
+            Some(result) = in_flight.next() => {
+                result?;
+                continue;
+            }
+            stream_status = retrieve_next_event(&mut event_stream, event_processor_config.stream_timeout),
+                if in_flight.len() < max_concurrent_events =>
+            {
+                stream_status.change_context(Error::EventStream)?
+            }
+        };
+
+        // events that are filtered out are not dispatched, but block ends still count towards cancellation
+        if let StreamStatus::Active(event @ Event::Abci { .. }) = &stream_status {
+            if event_filters.matches(event) {
+                in_flight.push(handle_event(
+                    &handler,
+                    &broadcaster,
+                    event.clone(),
+                    retry_policy,
+                ));
+            }
+
+            continue;
+        }
+
+        // block begin and end events and changes of the stream status wait for all in-flight events to be handled
+        while let Some(result) = in_flight.next().await {
+            result?;
+        }
 
         if let StreamStatus::Active(event) = &stream_status {
-            handle_event(
-                &handler,
-                &broadcaster,
-                event,
-                RetryPolicy::RepeatConstant {
-                    sleep: event_processor_config.retry_delay,
-                    max_attempts: event_processor_config.retry_max_attempts,
-                },
-            )
-            .await?;
+            if event_filters.matches(event) {
+                handle_event(&handler, &broadcaster, event.clone(), retry_policy).await?;
+            }
         }
 
         if let StreamStatus::Active(Event::BlockEnd(height)) = &stream_status {
@@ -111,7 +166,7 @@ where
 async fn handle_event<H, B>(
     handler: &H,
     broadcaster: &B,
-    event: &Event,
+    event: Event,
     retry_policy: RetryPolicy,
 ) -> Result<(), Error>
 where
@@ -119,7 +174,7 @@ where
     B: BroadcasterClient,
 {
     // if handlers run into errors we log them and then move on to the next event
-    match future::with_retry(|| handler.handle(event), retry_policy).await {
+    match future::with_retry(|| handler.handle(&event), retry_policy).await {
         Ok(msgs) => {
             for msg in msgs {
                 broadcaster
@@ -175,6 +230,10 @@ enum StreamStatus {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+    use std::iter;
+    use std::num::NonZeroUsize;
+    use std::sync::{Arc, Mutex};
     use std::time::Duration;
 
     use async_trait::async_trait;
@@ -183,14 +242,17 @@ mod tests {
     use cosmrs::{AccountId, Any};
     use error_stack::{report, Result};
     use events::Event;
-    use futures::stream;
+    use futures::{stream, Stream};
     use mockall::mock;
-    use tokio::time::timeout;
+    use tokio::time::{sleep, timeout, Instant};
     use tokio_util::sync::CancellationToken;
 
     use crate::event_processor;
     use crate::event_processor::{consume_events, Config, Error, EventHandler};
+    use crate::event_sub::filter::{EventFilter, EventFilters};
     use crate::queue::queued_broadcaster::MockBroadcasterClient;
+    use crate::types::TMAddress;
+    use crate::PREFIX;
 
     pub fn setup_event_config(
         retry_delay_value: Duration,
@@ -201,9 +263,25 @@ mod tests {
             retry_max_attempts: 3,
             stream_timeout: stream_timeout_value,
             stream_buffer_size: 100000,
+            max_concurrent_events: NonZeroUsize::MIN,
         }
     }
 
+    #[test]
+    fn handler_max_concurrent_events_should_override_default() {
+        let config = setup_event_config(Duration::from_secs(1), Duration::from_secs(1000));
+        let max_concurrent_events = NonZeroUsize::new(8).unwrap();
+
+        assert_eq!(config.with_max_concurrent_events(None), config);
+        assert_eq!(
+            config.with_max_concurrent_events(Some(max_concurrent_events)),
+            Config {
+                max_concurrent_events,
+                ..config.clone()
+            }
+        );
+    }
+
     #[tokio::test]
     async fn stop_when_stream_closes() {
         let events: Vec<Result<Event, event_processor::Error>> = vec![
@@ -397,6 +475,133 @@ mod tests {
         assert!(result_with_timeout.unwrap().is_ok());
     }
 
+    #[tokio::test]
+    async fn only_dispatch_events_matching_handler_filters() {
+        let contract = TMAddress::random(PREFIX);
+        let matching_event = abci_event("some_event", &contract);
+        let events: Vec<Result<Event, event_processor::Error>> = vec![
+            Ok(Event::BlockBegin(0_u32.into())),
+            Ok(abci_event("other_event", &contract)),
+            Ok(abci_event("some_event", &TMAddress::random(PREFIX))),
+            Ok(matching_event.clone()),
+            Ok(Event::BlockEnd(0_u32.into())),
+        ];
+
+        let mut handler = MockFilteredEventHandler::new();
+        handler.expect_event_filters().once().returning(move || {
+            EventFilters::new(
+                vec![EventFilter::new("some_event").with_contract(&contract)],
+                false,
+            )
+        });
+        handler
+            .expect_handle()
+            .once()
+            .withf(move |event| event == &matching_event)
+            .returning(|_| Ok(vec![]));
+
+        let broadcaster = MockBroadcasterClient::new();
+        let event_config = setup_event_config(Duration::from_secs(1), Duration::from_secs(1000));
+
+        let result_with_timeout = timeout(
+            Duration::from_secs(1),
+            consume_events(
+                "handler".to_string(),
+                handler,
+                broadcaster,
+                stream::iter(events),
+                event_config,
+                CancellationToken::new(),
+            ),
+        )
+        .await;
+
+        assert!(result_with_timeout.is_ok());
+        assert!(result_with_timeout.unwrap().is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn handle_events_concurrently_up_to_the_limit() {
+        let event_count = 10;
+        let events: Vec<Event> = iter::once(Event::BlockBegin(0_u32.into()))
+            .chain(
+                (0..event_count)
+                    .map(|i| abci_event(&format!("event_{}", i), &TMAddress::random(PREFIX))),
+            )
+            .chain(iter::once(Event::BlockEnd(0_u32.into())))
+            .collect();
+
+        for (max_concurrent_events, expected_duration) in [
+            (1, Duration::from_secs(10)),
+            (5, Duration::from_secs(2)),
+            (10, Duration::from_secs(1)),
+        ] {
+            let handler = SlowEventHandler::new(Duration::from_secs(1), 0);
+            let handled_events = handler.handled_events.clone();
+
+            let mut event_config =
+                setup_event_config(Duration::from_secs(1), Duration::from_secs(1000));
+            event_config.max_concurrent_events = NonZeroUsize::new(max_concurrent_events).unwrap();
+
+            let start = Instant::now();
+            let result = consume_events(
+                "handler".to_string(),
+                handler,
+                MockBroadcasterClient::new(),
+                ok_stream(events.clone()),
+                event_config,
+                CancellationToken::new(),
+            )
+            .await;
+
+            assert!(result.is_ok());
+            assert!(start.elapsed() >= expected_duration);
+            assert!(start.elapsed() < expected_duration + Duration::from_millis(100));
+
+            // block begin and end events are not handled before or after all ABCI events respectively
+            let handled_events = handled_events.lock().unwrap();
+            assert_eq!(handled_events.len(), events.len());
+            assert_eq!(
+                handled_events.first(),
+                Some(&Event::BlockBegin(0_u32.into()))
+            );
+            assert_eq!(handled_events.last(), Some(&Event::BlockEnd(0_u32.into())));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_failed_events_when_handling_concurrently() {
+        let event_count = 5;
+        let events: Vec<Event> = (0..event_count)
+            .map(|i| abci_event(&format!("event_{}", i), &TMAddress::random(PREFIX)))
+            .chain(iter::once(Event::BlockEnd(0_u32.into())))
+            .collect();
+
+        let handler = SlowEventHandler::new(Duration::ZERO, 2);
+        let handled_events = handler.handled_events.clone();
+
+        let mut event_config =
+            setup_event_config(Duration::from_secs(1), Duration::from_secs(1000));
+        event_config.max_concurrent_events = NonZeroUsize::new(event_count).unwrap();
+
+        let start = Instant::now();
+        let result = consume_events(
+            "handler".to_string(),
+            handler,
+            MockBroadcasterClient::new(),
+            ok_stream(events.clone()),
+            event_config,
+            CancellationToken::new(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        // every event fails twice before succeeding, and the retries of different events overlap
+        assert!(start.elapsed() >= Duration::from_secs(2));
+        assert!(start.elapsed() < Duration::from_secs(3));
+        assert_eq!(handled_events.lock().unwrap().len(), events.len());
+    }
+
     #[derive(Error, Debug)]
     pub enum EventHandlerError {
         #[error("failed")]
@@ -414,6 +619,73 @@ mod tests {
             }
     }
 
+    mock! {
+            FilteredEventHandler{}
+
+            #[async_trait]
+            impl EventHandler for FilteredEventHandler {
+                type Err = EventHandlerError;
+
+                async fn handle(&self, event: &Event) -> Result<Vec<Any>, EventHandlerError>;
+
+                fn event_filters(&self) -> EventFilters;
+            }
+    }
+
+    /// Handles ABCI events only after a delay and after failing a configurable number of times per event
+    struct SlowEventHandler {
+        delay: Duration,
+        failures_per_event: usize,
+        attempts: Mutex<HashMap<String, usize>>,
+        handled_events: Arc<Mutex<Vec<Event>>>,
+    }
+
+    impl SlowEventHandler {
+        fn new(delay: Duration, failures_per_event: usize) -> Self {
+            Self {
+                delay,
+                failures_per_event,
+                attempts: Mutex::new(HashMap::new()),
+                handled_events: Arc::new(Mutex::new(vec![])),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl EventHandler for SlowEventHandler {
+        type Err = EventHandlerError;
+
+        async fn handle(&self, event: &Event) -> Result<Vec<Any>, EventHandlerError> {
+            if let Event::Abci { .. } = event {
+                sleep(self.delay).await;
+
+                let mut attempts = self.attempts.lock().unwrap();
+                let attempt = attempts.entry(event.to_string()).or_default();
+                if *attempt < self.failures_per_event {
+                    *attempt = attempt.saturating_add(1);
+                    return Err(report!(EventHandlerError::Failed));
+                }
+            }
+
+            self.handled_events.lock().unwrap().push(event.clone());
+            Ok(vec![])
+        }
+    }
+
+    fn ok_stream(events: Vec<Event>) -> impl Stream<Item = Result<Event, Error>> {
+        stream::iter(events.into_iter().map(Ok))
+    }
+
+    fn abci_event(event_type: &str, contract: &TMAddress) -> Event {
+        let mut attributes = serde_json::Map::new();
+        attributes.insert("_contract_address".into(), contract.to_string().into());
+
+        Event::Abci {
+            event_type: event_type.into(),
+            attributes,
+        }
+    }
+
     fn dummy_msg() -> Any {
         MsgSend {
             from_address: AccountId::new("", &[1, 2, 3]).unwrap(),
@@ -17,6 +17,8 @@ use tracing::info;
 
 use crate::tm_client::TmClient;
 
+pub mod filter;
+
 #[automock]
 pub trait EventSub {
     fn subscribe(
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use events::Event;
+
+use crate::types::TMAddress;
+
+const CONTRACT_ADDRESS_ATTRIBUTE: &str = "_contract_address";
+
+/// Matches ABCI events of the given type whose attributes contain all the given key-value pairs.
+/// This is the same model as an entry of `SubscribeRequest.event_filters` in `ampd.proto`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventFilter {
+    event_type: String,
+    attributes: HashMap<String, String>,
+}
+
+impl EventFilter {
+    pub fn new(event_type: impl Into<String>) -> Self {
+        Self {
+            event_type: event_type.into(),
+            attributes: HashMap::new(),
+        }
+    }
+
+    pub fn with_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(key.into(), value.into());
+        self
+    }
+
+    /// Only match events emitted by the given contract
+    pub fn with_contract(self, contract: &TMAddress) -> Self {
+        self.with_attribute(CONTRACT_ADDRESS_ATTRIBUTE, contract.to_string())
+    }
+
+    pub fn matches(&self, event: &Event) -> bool {
+        match event {
+            Event::Abci {
+                event_type,
+                attributes,
+            } => {
+                self.event_type == *event_type
+                    && self.attributes.iter().all(|(key, value)| {
+                        attributes.get(key).map(|v| v == value).unwrap_or_default()
+                    })
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A set of filters that an event must satisfy to be dispatched to a handler.
+/// An ABCI event matches if it matches ANY of the filters, or if there are no filters at all.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventFilters {
+    filters: Vec<EventFilter>,
+    include_block_begin_end: bool,
+}
+
+impl EventFilters {
+    pub fn new(filters: Vec<EventFilter>, include_block_begin_end: bool) -> Self {
+        Self {
+            filters,
+            include_block_begin_end,
+        }
+    }
+
+    pub fn matches(&self, event: &Event) -> bool {
+        match event {
+            Event::BlockBegin(_) | Event::BlockEnd(_) => self.include_block_begin_end,
+            Event::Abci { .. } => {
+                self.filters.is_empty() || self.filters.iter().any(|filter| filter.matches(event))
+            }
+        }
+    }
+}
+
+/// Matches all events
+impl Default for EventFilters {
+    fn default() -> Self {
+        Self::new(vec![], true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use events::Event;
+    use serde_json::Map;
+
+    use crate::event_sub::filter::{EventFilter, EventFilters};
+    use crate::types::TMAddress;
+    use crate::PREFIX;
+
+    fn event(event_type: &str, contract: &TMAddress) -> Event {
+        let mut attributes = Map::new();
+        attributes.insert("_contract_address".into(), contract.to_string().into());
+        attributes.insert("key".into(), "value".into());
+
+        Event::Abci {
+            event_type: event_type.into(),
+            attributes,
+        }
+    }
+
+    #[test]
+    fn default_filters_should_match_all_events() {
+        let filters = EventFilters::default();
+
+        assert!(filters.matches(&Event::BlockBegin(1u32.into())));
+        assert!(filters.matches(&Event::BlockEnd(1u32.into())));
+        assert!(filters.matches(&event("some_event", &TMAddress::random(PREFIX))));
+    }
+
+    #[test]
+    fn filters_should_match_event_type_and_contract() {
+        let contract = TMAddress::random(PREFIX);
+        let filters = EventFilters::new(
+            vec![EventFilter::new("some_event").with_contract(&contract)],
+            false,
+        );
+
+        assert!(filters.matches(&event("some_event", &contract)));
+        assert!(!filters.matches(&event("other_event", &contract)));
+        assert!(!filters.matches(&event("some_event", &TMAddress::random(PREFIX))));
+        assert!(!filters.matches(&Event::BlockBegin(1u32.into())));
+        assert!(!filters.matches(&Event::BlockEnd(1u32.into())));
+    }
+
+    #[test]
+    fn filters_should_match_any_filter() {
+        let contract = TMAddress::random(PREFIX);
+        let filters = EventFilters::new(
+            vec![
+                EventFilter::new("some_event").with_attribute("key", "other_value"),
+                EventFilter::new("other_event").with_attribute("key", "value"),
+            ],
+            true,
+        );
+
+        assert!(!filters.matches(&event("some_event", &contract)));
+        assert!(filters.matches(&event("other_event", &contract)));
+        assert!(filters.matches(&Event::BlockEnd(1u32.into())));
+    }
+}
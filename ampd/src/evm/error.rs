@@ -6,4 +6,8 @@ pub enum Error {
     JsonRPC,
     #[error("block number missing in JSON-RPC response for finalized block")]
     MissBlockNumber,
+    #[error("no L1 JSON-RPC client configured for the L1 batch finalizer")]
+    MissingL1Client,
+    #[error("invalid response from the L2 output oracle")]
+    InvalidOutputOracleResponse,
 }
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
 use error_stack::{self, Report, ResultExt};
-use ethers_core::types::U64;
+use ethers_core::types::{BlockNumber, U256, U64};
+use ethers_core::utils::id;
 use mockall::automock;
 use serde::{Deserialize, Serialize};
 
 use super::error::Error;
 use crate::evm::json_rpc::EthereumClient;
+use crate::types::EVMAddress;
+use crate::url::Url;
 
 type Result<T> = error_stack::Result<T, Error>;
 
@@ -20,11 +23,29 @@ pub enum Finalization {
     #[default]
     RPCFinalizedBlock,
     ConfirmationHeight,
+    RPCSafeBlock,
+    /// For rollups that commit their outputs to an L2 output oracle on L1. An L2 block is final once it is committed
+    /// to by an output that is part of a finalized L1 block
+    L1BatchFinalized {
+        l1_rpc_url: Url,
+        output_oracle_address: EVMAddress,
+    },
 }
 
+impl Finalization {
+    pub fn l1_rpc_url(&self) -> Option<&Url> {
+        match self {
+            Finalization::L1BatchFinalized { l1_rpc_url, .. } => Some(l1_rpc_url),
+            _ => None,
+        }
+    }
+}
+
+/// The `l1_rpc_client` is only required for the [Finalization::L1BatchFinalized] finalizer
 pub fn pick<'a, C, H>(
     finalizer_type: &'a Finalization,
     rpc_client: &'a C,
+    l1_rpc_client: Option<&'a C>,
     confirmation_height: H,
 ) -> Box<dyn Finalizer + 'a>
 where
@@ -37,6 +58,11 @@ where
             rpc_client,
             confirmation_height,
         )),
+        Finalization::RPCSafeBlock => Box::new(RPCSafeBlockFinalizer::new(rpc_client)),
+        Finalization::L1BatchFinalized {
+            output_oracle_address,
+            ..
+        } => Box::new(L1BatchFinalizer::new(l1_rpc_client, *output_oracle_address)),
     }
 }
 
@@ -114,14 +140,99 @@ where
     }
 }
 
+pub struct RPCSafeBlockFinalizer<'a, C>
+where
+    C: EthereumClient,
+{
+    rpc_client: &'a C,
+}
+
+impl<'a, C> RPCSafeBlockFinalizer<'a, C>
+where
+    C: EthereumClient,
+{
+    pub fn new(rpc_client: &'a C) -> Self {
+        RPCSafeBlockFinalizer { rpc_client }
+    }
+}
+
+#[async_trait]
+impl<'a, C> Finalizer for RPCSafeBlockFinalizer<'a, C>
+where
+    C: EthereumClient + Send + Sync,
+{
+    async fn latest_finalized_block_height(&self) -> Result<U64> {
+        self.rpc_client
+            .safe_block()
+            .await
+            .change_context(Error::JsonRPC)?
+            .number
+            .ok_or_else(|| Report::new(Error::MissBlockNumber))
+    }
+}
+
+pub struct L1BatchFinalizer<'a, C>
+where
+    C: EthereumClient,
+{
+    l1_rpc_client: Option<&'a C>,
+    output_oracle_address: EVMAddress,
+}
+
+impl<'a, C> L1BatchFinalizer<'a, C>
+where
+    C: EthereumClient,
+{
+    pub fn new(l1_rpc_client: Option<&'a C>, output_oracle_address: EVMAddress) -> Self {
+        L1BatchFinalizer {
+            l1_rpc_client,
+            output_oracle_address,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C> Finalizer for L1BatchFinalizer<'a, C>
+where
+    C: EthereumClient + Send + Sync,
+{
+    async fn latest_finalized_block_height(&self) -> Result<U64> {
+        let l1_rpc_client = self
+            .l1_rpc_client
+            .ok_or_else(|| Report::new(Error::MissingL1Client))?;
+
+        // the latest L2 block number committed to by the output oracle as of the latest finalized L1 block
+        let output = l1_rpc_client
+            .call(
+                self.output_oracle_address,
+                id("latestBlockNumber()").to_vec().into(),
+                BlockNumber::Finalized,
+            )
+            .await
+            .change_context(Error::JsonRPC)?;
+
+        if output.len() != 32 {
+            return Err(Report::new(Error::InvalidOutputOracleResponse))
+                .attach_printable(format!("{{ output = {output} }}"));
+        }
+
+        u64::try_from(U256::from_big_endian(&output))
+            .map(U64::from)
+            .map_err(|_| Report::new(Error::InvalidOutputOracleResponse))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ethers_core::abi::Hash;
-    use ethers_core::types::{Block, U64};
+    use ethers_core::types::{Block, BlockNumber, U256, U64};
+    use ethers_core::utils::id;
     use tokio::test;
 
+    use crate::evm::error::Error;
     use crate::evm::finalizer::{pick, ConfirmationHeightFinalizer, Finalization, Finalizer};
     use crate::evm::json_rpc::MockEthereumClient;
+    use crate::types::EVMAddress;
 
     #[test]
     async fn latest_finalized_block_height_should_work() {
@@ -189,7 +300,7 @@ mod tests {
             .expect_finalized_block()
             .returning(move || Ok(block.clone()));
 
-        let finalizer = pick(&Finalization::RPCFinalizedBlock, &rpc_client, 1);
+        let finalizer = pick(&Finalization::RPCFinalizedBlock, &rpc_client, None, 1);
         assert_eq!(
             finalizer.latest_finalized_block_height().await.unwrap(),
             block_number
@@ -209,6 +320,7 @@ mod tests {
         let finalizer = pick(
             &Finalization::ConfirmationHeight,
             &rpc_client,
+            None,
             pow_confirmation_height,
         );
         assert_eq!(
@@ -216,4 +328,97 @@ mod tests {
             block_number - U64::from(pow_confirmation_height - 1)
         );
     }
+
+    #[test]
+    async fn pick_should_work_for_safe_block_finalizer() {
+        let mut rpc_client = MockEthereumClient::new();
+        let mut block = Block::<Hash>::default();
+        let block_number: U64 = 10.into();
+        block.number = Some(block_number);
+
+        rpc_client
+            .expect_safe_block()
+            .returning(move || Ok(block.clone()));
+
+        let finalizer = pick(&Finalization::RPCSafeBlock, &rpc_client, None, 1);
+        assert_eq!(
+            finalizer.latest_finalized_block_height().await.unwrap(),
+            block_number
+        );
+    }
+
+    #[test]
+    async fn pick_should_work_for_l1_batch_finalizer() {
+        let rpc_client = MockEthereumClient::new();
+        let output_oracle_address = EVMAddress::random();
+        let l2_block_number: U64 = 1000.into();
+
+        let mut l1_rpc_client = MockEthereumClient::new();
+        l1_rpc_client
+            .expect_call()
+            .once()
+            .withf(move |to, data, block| {
+                to == &output_oracle_address
+                    && data.as_ref() == id("latestBlockNumber()")
+                    && block == &BlockNumber::Finalized
+            })
+            .returning(move |_, _, _| {
+                let mut output = [0u8; 32];
+                U256::from(l2_block_number.as_u64()).to_big_endian(&mut output);
+                Ok(output.to_vec().into())
+            });
+
+        let finalization = Finalization::L1BatchFinalized {
+            l1_rpc_url: "http://localhost:8545".parse().unwrap(),
+            output_oracle_address,
+        };
+        let finalizer = pick(&finalization, &rpc_client, Some(&l1_rpc_client), 1);
+        assert_eq!(
+            finalizer.latest_finalized_block_height().await.unwrap(),
+            l2_block_number
+        );
+    }
+
+    #[test]
+    async fn l1_batch_finalizer_should_fail_without_l1_client() {
+        let rpc_client = MockEthereumClient::new();
+        let finalization = Finalization::L1BatchFinalized {
+            l1_rpc_url: "http://localhost:8545".parse().unwrap(),
+            output_oracle_address: EVMAddress::random(),
+        };
+
+        let finalizer = pick(&finalization, &rpc_client, None, 1);
+        assert!(matches!(
+            finalizer
+                .latest_finalized_block_height()
+                .await
+                .unwrap_err()
+                .current_context(),
+            Error::MissingL1Client
+        ));
+    }
+
+    #[test]
+    async fn l1_batch_finalizer_should_fail_on_invalid_oracle_response() {
+        let rpc_client = MockEthereumClient::new();
+        let mut l1_rpc_client = MockEthereumClient::new();
+        l1_rpc_client
+            .expect_call()
+            .returning(|_, _, _| Ok(vec![1, 2, 3].into()));
+
+        let finalization = Finalization::L1BatchFinalized {
+            l1_rpc_url: "http://localhost:8545".parse().unwrap(),
+            output_oracle_address: EVMAddress::random(),
+        };
+
+        let finalizer = pick(&finalization, &rpc_client, Some(&l1_rpc_client), 1);
+        assert!(matches!(
+            finalizer
+                .latest_finalized_block_height()
+                .await
+                .unwrap_err()
+                .current_context(),
+            Error::InvalidOutputOracleResponse
+        ));
+    }
 }
@@ -1,10 +1,19 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use async_trait::async_trait;
-use ethers_core::types::{Block, BlockNumber, TransactionReceipt, H256, U64};
+use ethers_core::types::{
+    Address, Block, BlockNumber, Bytes, TransactionReceipt, TransactionRequest, H256, U64,
+};
 use ethers_core::utils::serialize;
-use ethers_providers::{JsonRpcClient, ProviderError};
+use ethers_providers::ProviderError;
+use futures::future::join_all;
 use mockall::automock;
+use router_api::ChainName;
+use tracing::warn;
 
-use crate::json_rpc::Client;
+use crate::cache::{self, Cache};
+use crate::json_rpc::{BatchJsonRpcClient, Client};
 use crate::types::Hash;
 
 type Result<T> = error_stack::Result<T, ProviderError>;
@@ -13,14 +22,21 @@ type Result<T> = error_stack::Result<T, ProviderError>;
 #[async_trait]
 pub trait EthereumClient {
     async fn finalized_block(&self) -> Result<Block<Hash>>;
+    async fn safe_block(&self) -> Result<Block<Hash>>;
     async fn block_number(&self) -> Result<U64>;
     async fn transaction_receipt(&self, hash: H256) -> Result<Option<TransactionReceipt>>;
+    /// Only returns the receipts of transactions that were found
+    async fn transaction_receipts(
+        &self,
+        hashes: Vec<H256>,
+    ) -> Result<HashMap<H256, TransactionReceipt>>;
+    async fn call(&self, to: Address, data: Bytes, block: BlockNumber) -> Result<Bytes>;
 }
 
 #[async_trait]
 impl<P> EthereumClient for Client<P>
 where
-    P: JsonRpcClient + Send + Sync + 'static,
+    P: BatchJsonRpcClient + Send + Sync + 'static,
 {
     async fn finalized_block(&self) -> Result<Block<Hash>> {
         self.request(
@@ -30,6 +46,14 @@ where
         .await
     }
 
+    async fn safe_block(&self) -> Result<Block<Hash>> {
+        self.request(
+            "eth_getBlockByNumber",
+            [serialize(&BlockNumber::Safe), serialize(&false)],
+        )
+        .await
+    }
+
     async fn block_number(&self) -> Result<U64> {
         self.request("eth_blockNumber", ()).await
     }
@@ -37,4 +61,357 @@ where
     async fn transaction_receipt(&self, hash: H256) -> Result<Option<TransactionReceipt>> {
         self.request("eth_getTransactionReceipt", [hash]).await
     }
+
+    async fn transaction_receipts(
+        &self,
+        hashes: Vec<H256>,
+    ) -> Result<HashMap<H256, TransactionReceipt>> {
+        let mut receipts = HashMap::new();
+
+        for chunk in hashes.chunks(self.max_batch_size().get()) {
+            let chunk_receipts: Vec<Option<TransactionReceipt>> = match self
+                .batch_request(
+                    "eth_getTransactionReceipt",
+                    chunk.iter().map(|hash| [hash]).collect(),
+                )
+                .await
+            {
+                Ok(chunk_receipts) => chunk_receipts,
+                Err(err) => {
+                    warn!(
+                        err = err.to_string(),
+                        "batch request failed, falling back to individual requests"
+                    );
+
+                    join_all(chunk.iter().map(|hash| self.transaction_receipt(*hash)))
+                        .await
+                        .into_iter()
+                        .collect::<Result<_>>()?
+                }
+            };
+
+            receipts.extend(
+                chunk
+                    .iter()
+                    .copied()
+                    .zip(chunk_receipts)
+                    .filter_map(|(hash, receipt)| receipt.map(|receipt| (hash, receipt))),
+            );
+        }
+
+        Ok(receipts)
+    }
+
+    async fn call(&self, to: Address, data: Bytes, block: BlockNumber) -> Result<Bytes> {
+        self.request(
+            "eth_call",
+            [
+                serialize(&TransactionRequest::new().to(to).data(data)),
+                serialize(&block),
+            ],
+        )
+        .await
+    }
+}
+
+/// Caches the responses of the wrapped client, so that all handlers of the same chain can share them.
+pub struct CachedClient<C>
+where
+    C: EthereumClient,
+{
+    client: Arc<C>,
+    finalized_block: Cache<(), Block<Hash>>,
+    safe_block: Cache<(), Block<Hash>>,
+    block_number: Cache<(), U64>,
+    transaction_receipts: Cache<H256, Option<TransactionReceipt>>,
+}
+
+impl<C> CachedClient<C>
+where
+    C: EthereumClient,
+{
+    pub fn new(
+        client: C,
+        chain: &ChainName,
+        config: cache::Config,
+        metrics: &cache::Metrics,
+    ) -> Self {
+        Self {
+            client: Arc::new(client),
+            finalized_block: Cache::new(
+                format!("{}-finalized-block", chain),
+                config.clone(),
+                metrics,
+            ),
+            safe_block: Cache::new(format!("{}-safe-block", chain), config.clone(), metrics),
+            block_number: Cache::new(format!("{}-block-number", chain), config.clone(), metrics),
+            transaction_receipts: Cache::new(
+                format!("{}-transaction-receipts", chain),
+                config,
+                metrics,
+            ),
+        }
+    }
+}
+
+impl<C> Clone for CachedClient<C>
+where
+    C: EthereumClient,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            finalized_block: self.finalized_block.clone(),
+            safe_block: self.safe_block.clone(),
+            block_number: self.block_number.clone(),
+            transaction_receipts: self.transaction_receipts.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C> EthereumClient for CachedClient<C>
+where
+    C: EthereumClient + Send + Sync,
+{
+    async fn finalized_block(&self) -> Result<Block<Hash>> {
+        self.finalized_block
+            .get_or_try_fetch((), || self.client.finalized_block())
+            .await
+    }
+
+    async fn safe_block(&self) -> Result<Block<Hash>> {
+        self.safe_block
+            .get_or_try_fetch((), || self.client.safe_block())
+            .await
+    }
+
+    async fn block_number(&self) -> Result<U64> {
+        self.block_number
+            .get_or_try_fetch((), || self.client.block_number())
+            .await
+    }
+
+    async fn transaction_receipt(&self, hash: H256) -> Result<Option<TransactionReceipt>> {
+        self.transaction_receipts
+            .get_or_try_fetch(hash, || self.client.transaction_receipt(hash))
+            .await
+    }
+
+    async fn transaction_receipts(
+        &self,
+        hashes: Vec<H256>,
+    ) -> Result<HashMap<H256, TransactionReceipt>> {
+        let (cached, missing): (Vec<_>, Vec<_>) = hashes
+            .into_iter()
+            .map(|hash| (hash, self.transaction_receipts.get(&hash)))
+            .partition(|(_, receipt)| receipt.is_some());
+
+        let missing: Vec<_> = missing.into_iter().map(|(hash, _)| hash).collect();
+        let mut receipts = if missing.is_empty() {
+            HashMap::new()
+        } else {
+            self.client.transaction_receipts(missing.clone()).await?
+        };
+
+        for hash in missing {
+            self.transaction_receipts
+                .insert(hash, receipts.get(&hash).cloned());
+        }
+
+        receipts.extend(
+            cached
+                .into_iter()
+                .filter_map(|(hash, receipt)| receipt.flatten().map(|receipt| (hash, receipt))),
+        );
+
+        Ok(receipts)
+    }
+
+    // contract calls are not cached, because their results depend on the call data
+    async fn call(&self, to: Address, data: Bytes, block: BlockNumber) -> Result<Bytes> {
+        self.client.call(to, data, block).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use ethers_core::types::{Block, TransactionReceipt, H256, U64};
+    use serde_json::{json, Value};
+
+    use crate::cache;
+    use crate::evm::json_rpc::{CachedClient, EthereumClient, MockEthereumClient};
+    use crate::json_rpc::test_utils::MockProvider;
+    use crate::json_rpc::Client;
+
+    #[tokio::test]
+    async fn cached_client_should_share_responses_between_clones() {
+        let mut client = MockEthereumClient::new();
+        client
+            .expect_finalized_block()
+            .once()
+            .returning(|| Ok(Block::default()));
+        client
+            .expect_block_number()
+            .once()
+            .returning(|| Ok(U64::from(10)));
+        client
+            .expect_transaction_receipt()
+            .times(2)
+            .returning(|hash| {
+                Ok(Some(TransactionReceipt {
+                    transaction_hash: hash,
+                    ..TransactionReceipt::default()
+                }))
+            });
+
+        let cached_client = CachedClient::new(
+            client,
+            &"ethereum".parse().unwrap(),
+            cache::Config::default(),
+            &cache::Metrics::default(),
+        );
+        let other_cached_client = cached_client.clone();
+
+        for client in [&cached_client, &other_cached_client] {
+            assert!(client.finalized_block().await.is_ok());
+            assert_eq!(client.block_number().await.unwrap(), U64::from(10));
+
+            for hash in [H256::repeat_byte(1), H256::repeat_byte(2)] {
+                let receipt = client.transaction_receipt(hash).await.unwrap().unwrap();
+                assert_eq!(receipt.transaction_hash, hash);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn cached_client_should_only_fetch_missing_transaction_receipts() {
+        let found = H256::repeat_byte(1);
+        let not_found = H256::repeat_byte(2);
+        let new = H256::repeat_byte(3);
+
+        let mut client = MockEthereumClient::new();
+        client
+            .expect_transaction_receipts()
+            .once()
+            .withf(move |hashes| *hashes == vec![found, not_found])
+            .returning(move |_| {
+                Ok(HashMap::from([(
+                    found,
+                    TransactionReceipt {
+                        transaction_hash: found,
+                        ..TransactionReceipt::default()
+                    },
+                )]))
+            });
+        client
+            .expect_transaction_receipts()
+            .once()
+            .withf(move |hashes| *hashes == vec![new])
+            .returning(|_| Ok(HashMap::new()));
+
+        let cached_client = CachedClient::new(
+            client,
+            &"ethereum".parse().unwrap(),
+            cache::Config::default(),
+            &cache::Metrics::default(),
+        );
+
+        let receipts = cached_client
+            .transaction_receipts(vec![found, not_found])
+            .await
+            .unwrap();
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[&found].transaction_hash, found);
+
+        let receipts = cached_client
+            .transaction_receipts(vec![found, not_found, new])
+            .await
+            .unwrap();
+        assert_eq!(receipts.len(), 1);
+        assert!(receipts.contains_key(&found));
+    }
+
+    #[tokio::test]
+    async fn should_request_transaction_receipts_in_batches() {
+        let hashes = vec![
+            H256::repeat_byte(1),
+            H256::repeat_byte(2),
+            H256::repeat_byte(3),
+        ];
+
+        let provider = MockProvider::default();
+        provider.push(json!([receipt(hashes[0]), null]));
+        provider.push(json!([receipt(hashes[2])]));
+
+        let receipts = Client::new(provider.clone())
+            .transaction_receipts(hashes.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[&hashes[0]].transaction_hash, hashes[0]);
+        assert_eq!(receipts[&hashes[2]].transaction_hash, hashes[2]);
+        assert_eq!(
+            provider.requests(),
+            vec![
+                (
+                    "eth_getTransactionReceipt".to_string(),
+                    json!([[hashes[0]], [hashes[1]]])
+                ),
+                (
+                    "eth_getTransactionReceipt".to_string(),
+                    json!([[hashes[2]]])
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fall_back_to_individual_requests_if_batch_is_rejected() {
+        let found = H256::repeat_byte(1);
+        let not_found = H256::repeat_byte(2);
+
+        let provider = MockProvider::default();
+        provider.push_error(-32600, "batch requests are not supported");
+        provider.push(receipt(found));
+        provider.push(Value::Null);
+
+        let receipts = Client::new(provider.clone())
+            .transaction_receipts(vec![found, not_found])
+            .await
+            .unwrap();
+
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[&found].transaction_hash, found);
+        assert_eq!(
+            provider.requests()[1..],
+            [
+                ("eth_getTransactionReceipt".to_string(), json!([found])),
+                ("eth_getTransactionReceipt".to_string(), json!([not_found])),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_if_individual_requests_fail_after_batch_is_rejected() {
+        let provider = MockProvider::default();
+        provider.push_error(-32600, "batch requests are not supported");
+        provider.push_error(-32603, "internal error");
+
+        assert!(Client::new(provider)
+            .transaction_receipts(vec![H256::repeat_byte(1)])
+            .await
+            .is_err());
+    }
+
+    fn receipt(hash: H256) -> Value {
+        serde_json::to_value(TransactionReceipt {
+            transaction_hash: hash,
+            ..TransactionReceipt::default()
+        })
+        .unwrap()
+    }
 }
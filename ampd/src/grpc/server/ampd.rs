@@ -7,6 +7,7 @@ use futures::{Stream, StreamExt, TryStreamExt};
 use tonic::{Request, Response, Status};
 
 use super::proto;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::event_sub::EventSub;
 use crate::queue::queued_broadcaster::BroadcasterClient;
 
@@ -33,23 +34,20 @@ impl From<Event> for proto::subscribe_response::Event {
     }
 }
 
-impl proto::SubscribeRequest {
-    fn matches(&self, event: &Event) -> bool {
-        match event {
-            Event::BlockBegin(_) | Event::BlockEnd(_) => self.include_block_begin_end,
-            Event::Abci {
-                event_type,
-                attributes,
-            } => {
-                self.event_filters.is_empty()
-                    || self.event_filters.iter().any(|filter| {
-                        filter.event_type == *event_type
-                            && filter.event_attributes.iter().all(|(key, value)| {
-                                attributes.get(key).map(|v| v == value).unwrap_or_default()
-                            })
-                    })
-            }
-        }
+impl From<proto::SubscribeRequest> for EventFilters {
+    fn from(req: proto::SubscribeRequest) -> Self {
+        EventFilters::new(
+            req.event_filters
+                .into_iter()
+                .map(|filter| {
+                    filter.event_attributes.into_iter().fold(
+                        EventFilter::new(filter.event_type),
+                        |filter, (key, value)| filter.with_attribute(key, value),
+                    )
+                })
+                .collect(),
+            req.include_block_begin_end,
+        )
     }
 }
 
@@ -88,7 +86,7 @@ where
         &self,
         req: Request<proto::SubscribeRequest>,
     ) -> Result<Response<Self::SubscribeStream>, Status> {
-        let req = req.into_inner();
+        let filters: EventFilters = req.into_inner().into();
         let stream = self
             .event_subscriber
             .subscribe()
@@ -96,7 +94,7 @@ where
                 future::ready(
                     event
                         .as_ref()
-                        .map(|event| req.matches(event))
+                        .map(|event| filters.matches(event))
                         .unwrap_or(true),
                 )
             })
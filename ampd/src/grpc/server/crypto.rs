@@ -12,6 +12,7 @@ impl From<proto::Algorithm> for tofnd::Algorithm {
         match algorithm {
             proto::Algorithm::Ed25519 => Self::Ed25519,
             proto::Algorithm::Ecdsa => Self::Ecdsa,
+            proto::Algorithm::Schnorr => Self::Schnorr,
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::num::NonZeroUsize;
 use std::time::Duration;
 
 use itertools::Itertools;
@@ -7,6 +8,9 @@ use serde::{Deserialize, Serialize};
 use serde_with::with_prefix;
 
 use crate::evm::finalizer::Finalization;
+use crate::mvx::finalizer::Finalization as MvxFinalization;
+use crate::starknet::finality::Finality as StarknetFinality;
+use crate::stellar::ApiType;
 use crate::types::TMAddress;
 use crate::url::Url;
 
@@ -16,6 +20,13 @@ pub struct Chain {
     pub rpc_url: Url,
     #[serde(default)]
     pub finalization: Finalization,
+    /// Maximum number of requests sent to the RPC in a single JSON-RPC batch
+    #[serde(default = "default_rpc_batch_size")]
+    pub rpc_batch_size: NonZeroUsize,
+}
+
+fn default_rpc_batch_size() -> NonZeroUsize {
+    NonZeroUsize::new(20).expect("default rpc batch size must not be zero")
 }
 
 with_prefix!(chain "chain_");
@@ -27,41 +38,140 @@ pub enum Config {
         #[serde(flatten, with = "chain")]
         chain: Chain,
         rpc_timeout: Option<Duration>,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
     EvmVerifierSetVerifier {
         cosmwasm_contract: TMAddress,
         #[serde(flatten, with = "chain")]
         chain: Chain,
         rpc_timeout: Option<Duration>,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
     MultisigSigner {
         cosmwasm_contract: TMAddress,
+        /// Number of times the multisig key was rotated, 0 if it was never rotated.
+        /// Must be updated after each `rotate-key` command.
+        #[serde(default)]
+        key_rotation: u32,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
     SuiMsgVerifier {
         cosmwasm_contract: TMAddress,
         rpc_url: Url,
+        /// Checkpoints are only trusted if this RPC reports the same digest for them
+        cross_check_rpc_url: Option<Url>,
         rpc_timeout: Option<Duration>,
+        /// Number of checkpoints that must follow the one including the transaction before voting
+        #[serde(default)]
+        confirmation_checkpoints: u64,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
     SuiVerifierSetVerifier {
         cosmwasm_contract: TMAddress,
         rpc_url: Url,
+        /// Checkpoints are only trusted if this RPC reports the same digest for them
+        cross_check_rpc_url: Option<Url>,
         rpc_timeout: Option<Duration>,
+        /// Number of checkpoints that must follow the one including the transaction before voting
+        #[serde(default)]
+        confirmation_checkpoints: u64,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
     MvxMsgVerifier {
         cosmwasm_contract: TMAddress,
         proxy_url: Url,
+        #[serde(default)]
+        finalization: MvxFinalization,
+        /// Number of hyperblocks that must follow the one including the transaction before voting
+        #[serde(default)]
+        confirmation_depth: u64,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
     MvxVerifierSetVerifier {
         cosmwasm_contract: TMAddress,
         proxy_url: Url,
+        #[serde(default)]
+        finalization: MvxFinalization,
+        /// Number of hyperblocks that must follow the one including the transaction before voting
+        #[serde(default)]
+        confirmation_depth: u64,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
     StellarMsgVerifier {
         cosmwasm_contract: TMAddress,
         http_url: Url,
+        #[serde(default)]
+        api_type: ApiType,
+        /// Number of ledgers that must follow the one including the transaction before voting.
+        /// Only used by the Soroban RPC, Horizon serves transactions of closed ledgers only
+        #[serde(default)]
+        confirmation_ledgers: u32,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
     StellarVerifierSetVerifier {
         cosmwasm_contract: TMAddress,
         http_url: Url,
+        #[serde(default)]
+        api_type: ApiType,
+        /// Number of ledgers that must follow the one including the transaction before voting.
+        /// Only used by the Soroban RPC, Horizon serves transactions of closed ledgers only
+        #[serde(default)]
+        confirmation_ledgers: u32,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
+    },
+    CosmosMsgVerifier {
+        cosmwasm_contract: TMAddress,
+        chain_name: ChainName,
+        rpc_url: Url,
+        /// Number of blocks that must follow the one including the transaction before voting
+        #[serde(default)]
+        confirmation_blocks: u64,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
+    },
+    CosmosVerifierSetVerifier {
+        cosmwasm_contract: TMAddress,
+        chain_name: ChainName,
+        rpc_url: Url,
+        /// Number of blocks that must follow the one including the transaction before voting
+        #[serde(default)]
+        confirmation_blocks: u64,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
+    },
+    StarknetMsgVerifier {
+        cosmwasm_contract: TMAddress,
+        rpc_url: Url,
+        #[serde(default)]
+        finality: StarknetFinality,
+        rpc_timeout: Option<Duration>,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
+    },
+    StarknetVerifierSetVerifier {
+        cosmwasm_contract: TMAddress,
+        rpc_url: Url,
+        #[serde(default)]
+        finality: StarknetFinality,
+        rpc_timeout: Option<Duration>,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
+    },
+    XrplMsgVerifier {
+        cosmwasm_contract: TMAddress,
+        rpc_url: Url,
+        rpc_timeout: Option<Duration>,
+        /// Maximum number of events handled concurrently, defaults to the event processor setting
+        max_concurrent_events: Option<NonZeroUsize>,
     },
 }
 
@@ -88,6 +198,46 @@ where
     Ok(())
 }
 
+fn validate_cosmos_msg_verifier_configs<'de, D>(configs: &[Config]) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if !configs
+        .iter()
+        .filter_map(|config| match config {
+            Config::CosmosMsgVerifier { chain_name, .. } => Some(chain_name),
+            _ => None,
+        })
+        .all_unique()
+    {
+        return Err(de::Error::custom(
+            "the chain name Cosmos msg verifier configs must be unique",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_cosmos_verifier_set_verifier_configs<'de, D>(configs: &[Config]) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if !configs
+        .iter()
+        .filter_map(|config| match config {
+            Config::CosmosVerifierSetVerifier { chain_name, .. } => Some(chain_name),
+            _ => None,
+        })
+        .all_unique()
+    {
+        return Err(de::Error::custom(
+            "the chain name Cosmos verifier set verifier configs must be unique",
+        ));
+    }
+
+    Ok(())
+}
+
 fn validate_evm_msg_verifier_configs<'de, D>(configs: &[Config]) -> Result<(), D::Error>
 where
     D: Deserializer<'de>,
@@ -135,6 +285,8 @@ where
 
     validate_evm_msg_verifier_configs::<D>(&configs)?;
     validate_evm_verifier_set_verifier_configs::<D>(&configs)?;
+    validate_cosmos_msg_verifier_configs::<D>(&configs)?;
+    validate_cosmos_verifier_set_verifier_configs::<D>(&configs)?;
 
     ensure_unique_config!(&configs, Config::MultisigSigner, "Multisig signer")?;
     ensure_unique_config!(&configs, Config::SuiMsgVerifier, "Sui message verifier")?;
@@ -159,16 +311,31 @@ where
         Config::StellarVerifierSetVerifier,
         "Stellar verifier set verifier"
     )?;
+    ensure_unique_config!(
+        &configs,
+        Config::StarknetMsgVerifier,
+        "Starknet message verifier"
+    )?;
+    ensure_unique_config!(
+        &configs,
+        Config::StarknetVerifierSetVerifier,
+        "Starknet verifier set verifier"
+    )?;
+    ensure_unique_config!(&configs, Config::XrplMsgVerifier, "XRPL message verifier")?;
 
     Ok(configs)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroUsize;
+
     use serde_json::to_value;
 
     use crate::evm::finalizer::Finalization;
     use crate::handlers::config::{deserialize_handler_configs, Chain, Config};
+    use crate::mvx::finalizer::Finalization as MvxFinalization;
+    use crate::stellar::ApiType;
     use crate::types::TMAddress;
     use crate::PREFIX;
 
@@ -183,14 +350,90 @@ mod tests {
         assert_eq!(chain_config.finalization, Finalization::RPCFinalizedBlock);
     }
 
+    #[test]
+    fn deserialize_l2_finalizers() {
+        let chain_config_toml = "
+        name = 'optimism'
+        rpc_url = 'http://127.0.0.1/'
+        finalization = 'RPCSafeBlock'
+        ";
+
+        let chain_config: Chain = toml::from_str(chain_config_toml).unwrap();
+        assert_eq!(chain_config.finalization, Finalization::RPCSafeBlock);
+
+        let chain_config_toml = "
+        name = 'optimism'
+        rpc_url = 'http://127.0.0.1/'
+
+        [finalization.L1BatchFinalized]
+        l1_rpc_url = 'http://127.0.0.2/'
+        output_oracle_address = '0xdfe97868233d1aa22e815a266982f2cf17685a27'
+        ";
+
+        let chain_config: Chain = toml::from_str(chain_config_toml).unwrap();
+        assert_eq!(
+            chain_config.finalization,
+            Finalization::L1BatchFinalized {
+                l1_rpc_url: "http://127.0.0.2/".parse().unwrap(),
+                output_oracle_address: "0xdfe97868233d1aa22e815a266982f2cf17685a27"
+                    .parse()
+                    .unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn max_concurrent_events_should_be_configurable_per_handler() {
+        let contract = TMAddress::random(PREFIX);
+
+        let config_toml = format!(
+            "
+            type = 'MultisigSigner'
+            cosmwasm_contract = '{}'
+            max_concurrent_events = 4
+            ",
+            contract
+        );
+        let config: Config = toml::from_str(&config_toml).unwrap();
+        assert_eq!(
+            config,
+            Config::MultisigSigner {
+                cosmwasm_contract: contract.clone(),
+                key_rotation: 0,
+                max_concurrent_events: NonZeroUsize::new(4),
+            }
+        );
+
+        let config_toml = format!(
+            "
+            type = 'MultisigSigner'
+            cosmwasm_contract = '{}'
+            ",
+            contract
+        );
+        let config: Config = toml::from_str(&config_toml).unwrap();
+        assert_eq!(
+            config,
+            Config::MultisigSigner {
+                cosmwasm_contract: contract,
+                key_rotation: 0,
+                max_concurrent_events: None,
+            }
+        );
+    }
+
     #[test]
     fn unique_config_validation() {
         let configs = vec![
             Config::MultisigSigner {
                 cosmwasm_contract: TMAddress::random(PREFIX),
+                key_rotation: 0,
+                max_concurrent_events: None,
             },
             Config::MultisigSigner {
                 cosmwasm_contract: TMAddress::random(PREFIX),
+                key_rotation: 0,
+                max_concurrent_events: None,
             },
         ];
 
@@ -204,12 +447,18 @@ mod tests {
             Config::SuiMsgVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: "http://localhost:7545/".parse().unwrap(),
+                cross_check_rpc_url: None,
                 rpc_timeout: None,
+                confirmation_checkpoints: 0,
+                max_concurrent_events: None,
             },
             Config::SuiMsgVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: "http://localhost:7545/".parse().unwrap(),
+                cross_check_rpc_url: None,
                 rpc_timeout: None,
+                confirmation_checkpoints: 0,
+                max_concurrent_events: None,
             },
         ];
 
@@ -223,12 +472,18 @@ mod tests {
             Config::SuiVerifierSetVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: "http://localhost:7545/".parse().unwrap(),
+                cross_check_rpc_url: None,
                 rpc_timeout: None,
+                confirmation_checkpoints: 0,
+                max_concurrent_events: None,
             },
             Config::SuiVerifierSetVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 rpc_url: "http://localhost:7545/".parse().unwrap(),
+                cross_check_rpc_url: None,
                 rpc_timeout: None,
+                confirmation_checkpoints: 0,
+                max_concurrent_events: None,
             },
         ];
 
@@ -242,10 +497,16 @@ mod tests {
             Config::MvxMsgVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 proxy_url: "http://localhost:7545/".parse().unwrap(),
+                finalization: MvxFinalization::default(),
+                confirmation_depth: 0,
+                max_concurrent_events: None,
             },
             Config::MvxMsgVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 proxy_url: "http://localhost:7545/".parse().unwrap(),
+                finalization: MvxFinalization::default(),
+                confirmation_depth: 0,
+                max_concurrent_events: None,
             },
         ];
 
@@ -259,10 +520,16 @@ mod tests {
             Config::MvxVerifierSetVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 proxy_url: "http://localhost:7545/".parse().unwrap(),
+                finalization: MvxFinalization::default(),
+                confirmation_depth: 0,
+                max_concurrent_events: None,
             },
             Config::MvxVerifierSetVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 proxy_url: "http://localhost:7545/".parse().unwrap(),
+                finalization: MvxFinalization::default(),
+                confirmation_depth: 0,
+                max_concurrent_events: None,
             },
         ];
 
@@ -276,10 +543,16 @@ mod tests {
             Config::StellarMsgVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 http_url: "http://localhost:8080/".parse().unwrap(),
+                api_type: ApiType::Horizon,
+                confirmation_ledgers: 0,
+                max_concurrent_events: None,
             },
             Config::StellarMsgVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 http_url: "http://localhost:8080/".parse().unwrap(),
+                api_type: ApiType::Horizon,
+                confirmation_ledgers: 0,
+                max_concurrent_events: None,
             },
         ];
 
@@ -293,10 +566,16 @@ mod tests {
             Config::StellarVerifierSetVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 http_url: "http://localhost:8080/".parse().unwrap(),
+                api_type: ApiType::Horizon,
+                confirmation_ledgers: 0,
+                max_concurrent_events: None,
             },
             Config::StellarVerifierSetVerifier {
                 cosmwasm_contract: TMAddress::random(PREFIX),
                 http_url: "http://localhost:8080/".parse().unwrap(),
+                api_type: ApiType::Horizon,
+                confirmation_ledgers: 0,
+                max_concurrent_events: None,
             },
         ];
 
@@ -306,4 +585,40 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn cosmos_configs_should_have_unique_chain_names() {
+        let cosmos_config = |chain_name: &str| Config::CosmosMsgVerifier {
+            cosmwasm_contract: TMAddress::random(PREFIX),
+            chain_name: chain_name.parse().unwrap(),
+            rpc_url: "http://localhost:26657/".parse().unwrap(),
+            confirmation_blocks: 0,
+            max_concurrent_events: None,
+        };
+
+        let configs = vec![cosmos_config("osmosis"), cosmos_config("neutron")];
+        assert!(deserialize_handler_configs(to_value(configs).unwrap()).is_ok());
+
+        let configs = vec![cosmos_config("osmosis"), cosmos_config("Osmosis")];
+        assert!(
+            matches!(deserialize_handler_configs(to_value(configs).unwrap()),
+                Err(e) if e.to_string().contains("the chain name Cosmos msg verifier configs must be unique")
+            )
+        );
+
+        let cosmos_config = |chain_name: &str| Config::CosmosVerifierSetVerifier {
+            cosmwasm_contract: TMAddress::random(PREFIX),
+            chain_name: chain_name.parse().unwrap(),
+            rpc_url: "http://localhost:26657/".parse().unwrap(),
+            confirmation_blocks: 0,
+            max_concurrent_events: None,
+        };
+
+        let configs = vec![cosmos_config("osmosis"), cosmos_config("osmosis")];
+        assert!(
+            matches!(deserialize_handler_configs(to_value(configs).unwrap()),
+                Err(e) if e.to_string().contains("the chain name Cosmos verifier set verifier configs must be unique")
+            )
+        );
+    }
 }
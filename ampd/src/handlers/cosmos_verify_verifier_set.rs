@@ -0,0 +1,339 @@
+use std::convert::TryInto;
+
+use async_trait::async_trait;
+use axelar_wasm_std::msg_id::HexTxHashAndEventIndex;
+use axelar_wasm_std::voting::{PollId, Vote};
+use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::tx::Msg;
+use cosmrs::Any;
+use error_stack::ResultExt;
+use events::Error::EventTypeMismatch;
+use events::Event;
+use events_derive::try_from;
+use multisig::verifier_set::VerifierSet;
+use router_api::ChainName;
+use serde::Deserialize;
+use tokio::sync::watch::Receiver;
+use tracing::{info, info_span};
+use valuable::Valuable;
+use voting_verifier::msg::ExecuteMsg;
+
+use crate::cosmos::finality::finalized_transactions;
+use crate::cosmos::rpc_client::CosmosClient;
+use crate::cosmos::verifier::verify_verifier_set;
+use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
+use crate::handlers::errors::Error;
+use crate::types::{Hash, TMAddress};
+
+#[derive(Deserialize, Debug)]
+pub struct VerifierSetConfirmation {
+    pub tx_id: Hash,
+    pub event_index: u32,
+    pub verifier_set: VerifierSet,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-verifier_set_poll_started")]
+struct PollStartedEvent {
+    poll_id: PollId,
+    source_chain: ChainName,
+    source_gateway_address: String,
+    verifier_set: VerifierSetConfirmation,
+    participants: Vec<TMAddress>,
+    expires_at: u64,
+}
+
+pub struct Handler<C>
+where
+    C: CosmosClient + Send + Sync,
+{
+    verifier: TMAddress,
+    voting_verifier_contract: TMAddress,
+    chain: ChainName,
+    rpc_client: C,
+    confirmation_blocks: u64,
+    latest_block_height: Receiver<u64>,
+}
+
+impl<C> Handler<C>
+where
+    C: CosmosClient + Send + Sync,
+{
+    pub fn new(
+        verifier: TMAddress,
+        voting_verifier_contract: TMAddress,
+        chain: ChainName,
+        rpc_client: C,
+        confirmation_blocks: u64,
+        latest_block_height: Receiver<u64>,
+    ) -> Self {
+        Self {
+            verifier,
+            voting_verifier_contract,
+            chain,
+            rpc_client,
+            confirmation_blocks,
+            latest_block_height,
+        }
+    }
+
+    fn vote_msg(&self, poll_id: PollId, vote: Vote) -> MsgExecuteContract {
+        MsgExecuteContract {
+            sender: self.verifier.as_ref().clone(),
+            contract: self.voting_verifier_contract.as_ref().clone(),
+            msg: serde_json::to_vec(&ExecuteMsg::Vote {
+                poll_id,
+                votes: vec![vote],
+            })
+            .expect("vote msg should serialize"),
+            funds: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl<C> EventHandler for Handler<C>
+where
+    C: CosmosClient + Send + Sync,
+{
+    type Err = Error;
+
+    async fn handle(&self, event: &Event) -> error_stack::Result<Vec<Any>, Error> {
+        if !event.is_from_contract(self.voting_verifier_contract.as_ref()) {
+            return Ok(vec![]);
+        }
+
+        let PollStartedEvent {
+            poll_id,
+            source_chain,
+            source_gateway_address,
+            verifier_set,
+            participants,
+            expires_at,
+        } = match event.try_into() as error_stack::Result<_, _> {
+            Err(report) if matches!(report.current_context(), EventTypeMismatch(_)) => {
+                return Ok(vec![]);
+            }
+            event => event.change_context(Error::DeserializeEvent)?,
+        };
+
+        if self.chain != source_chain {
+            return Ok(vec![]);
+        }
+
+        if !participants.contains(&self.verifier) {
+            return Ok(vec![]);
+        }
+
+        if *self.latest_block_height.borrow() >= expires_at {
+            info!(poll_id = poll_id.to_string(), "skipping expired poll");
+            return Ok(vec![]);
+        }
+
+        let transaction = self
+            .rpc_client
+            .transaction(verifier_set.tx_id)
+            .await
+            .change_context(Error::TxReceipts)?;
+        let transaction = finalized_transactions(
+            &self.rpc_client,
+            transaction
+                .map(|tx| (verifier_set.tx_id, tx))
+                .into_iter()
+                .collect(),
+            self.confirmation_blocks,
+        )
+        .await
+        .change_context(Error::Finalizer)?
+        .remove(&verifier_set.tx_id);
+
+        let vote = info_span!(
+            "verify a new verifier set for a Cosmos chain",
+            poll_id = poll_id.to_string(),
+            id = HexTxHashAndEventIndex::new(verifier_set.tx_id, verifier_set.event_index)
+                .to_string()
+        )
+        .in_scope(|| {
+            info!("ready to verify verifier set in poll",);
+
+            let vote = transaction.map_or(Vote::NotFound, |tx_response| {
+                verify_verifier_set(&source_gateway_address, &tx_response, &verifier_set)
+            });
+
+            info!(
+                vote = vote.as_value(),
+                "ready to vote for a new verifier set in poll"
+            );
+
+            vote
+        });
+
+        Ok(vec![self
+            .vote_msg(poll_id, vote)
+            .into_any()
+            .expect("vote msg should serialize")])
+    }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-verifier_set_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use error_stack::{report, Result};
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ecdsa_test_data};
+    use tokio::sync::watch;
+    use tokio::test as async_test;
+    use voting_verifier::events::{PollMetadata, PollStarted, VerifierSetConfirmation};
+
+    use super::PollStartedEvent;
+    use crate::cosmos::error::Error as CosmosError;
+    use crate::cosmos::rpc_client::MockCosmosClient;
+    use crate::event_processor::EventHandler;
+    use crate::handlers::errors::Error;
+    use crate::handlers::tests::{into_structured_event, participants};
+    use crate::types::{Hash, TMAddress};
+    use crate::PREFIX;
+
+    #[test]
+    fn should_deserialize_verifier_set_poll_started_event() {
+        let event: Result<PollStartedEvent, events::Error> = into_structured_event(
+            poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        )
+        .try_into();
+
+        assert!(event.is_ok());
+    }
+
+    #[async_test]
+    async fn verifier_is_not_a_participant() {
+        let voting_verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, None), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            TMAddress::random(PREFIX),
+            voting_verifier,
+            "osmosis".parse().unwrap(),
+            MockCosmosClient::new(),
+            0,
+            watch::channel(0).1,
+        );
+
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
+
+    #[async_test]
+    async fn should_skip_poll_of_other_chain() {
+        let mut rpc_client = MockCosmosClient::new();
+        rpc_client.expect_transaction().never();
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            "neutron".parse().unwrap(),
+            rpc_client,
+            0,
+            watch::channel(0).1,
+        );
+
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
+
+    #[async_test]
+    async fn should_vote_correctly() {
+        let mut rpc_client = MockCosmosClient::new();
+        rpc_client.expect_transaction().returning(|_| Ok(None));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            "osmosis".parse().unwrap(),
+            rpc_client,
+            0,
+            watch::channel(0).1,
+        );
+
+        let actual = handler.handle(&event).await.unwrap();
+        assert_eq!(actual.len(), 1);
+        assert!(MsgExecuteContract::from_any(actual.first().unwrap()).is_ok());
+    }
+
+    #[async_test]
+    async fn should_not_vote_when_rpc_is_unavailable() {
+        let mut rpc_client = MockCosmosClient::new();
+        rpc_client
+            .expect_transaction()
+            .returning(|_| Err(report!(CosmosError::TxResponse)));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            "osmosis".parse().unwrap(),
+            rpc_client,
+            0,
+            watch::channel(0).1,
+        );
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::TxReceipts
+        ));
+    }
+
+    fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
+        PollStarted::VerifierSet {
+            metadata: PollMetadata {
+                poll_id: "100".parse().unwrap(),
+                source_chain: "osmosis".parse().unwrap(),
+                source_gateway_address: TMAddress::random("osmo").to_string().parse().unwrap(),
+                confirmation_height: 15,
+                expires_at,
+                participants: participants
+                    .into_iter()
+                    .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
+                    .collect(),
+            },
+            verifier_set: VerifierSetConfirmation {
+                tx_id: format!("0x{:x}", Hash::random()).parse().unwrap(),
+                event_index: 0,
+                verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+            },
+        }
+    }
+}
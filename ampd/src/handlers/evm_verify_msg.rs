@@ -11,7 +11,6 @@ use error_stack::ResultExt;
 use ethers_core::types::{TransactionReceipt, U64};
 use events::Error::EventTypeMismatch;
 use events_derive::try_from;
-use futures::future::join_all;
 use router_api::ChainName;
 use serde::Deserialize;
 use tokio::sync::watch::Receiver;
@@ -20,6 +19,7 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::evm::finalizer;
 use crate::evm::finalizer::Finalization;
 use crate::evm::json_rpc::EthereumClient;
@@ -61,6 +61,7 @@ where
     chain: ChainName,
     finalizer_type: Finalization,
     rpc_client: C,
+    l1_rpc_client: Option<C>,
     latest_block_height: Receiver<u64>,
 }
 
@@ -74,6 +75,7 @@ where
         chain: ChainName,
         finalizer_type: Finalization,
         rpc_client: C,
+        l1_rpc_client: Option<C>,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
@@ -82,6 +84,7 @@ where
             chain,
             finalizer_type,
             rpc_client,
+            l1_rpc_client,
             latest_block_height,
         }
     }
@@ -94,32 +97,29 @@ where
     where
         T: IntoIterator<Item = Hash>,
     {
-        let latest_finalized_block_height =
-            finalizer::pick(&self.finalizer_type, &self.rpc_client, confirmation_height)
-                .latest_finalized_block_height()
-                .await
-                .change_context(Error::Finalizer)?;
-
-        Ok(join_all(
-            tx_hashes
-                .into_iter()
-                .map(|tx_hash| self.rpc_client.transaction_receipt(tx_hash)),
+        let latest_finalized_block_height = finalizer::pick(
+            &self.finalizer_type,
+            &self.rpc_client,
+            self.l1_rpc_client.as_ref(),
+            confirmation_height,
         )
+        .latest_finalized_block_height()
         .await
-        .into_iter()
-        .filter_map(std::result::Result::unwrap_or_default)
-        .filter_map(|tx_receipt| {
-            if tx_receipt
-                .block_number
-                .unwrap_or(U64::MAX)
-                .le(&latest_finalized_block_height)
-            {
-                Some((tx_receipt.transaction_hash, tx_receipt))
-            } else {
-                None
-            }
-        })
-        .collect())
+        .change_context(Error::Finalizer)?;
+
+        Ok(self
+            .rpc_client
+            .transaction_receipts(tx_hashes.into_iter().collect())
+            .await
+            .change_context(Error::TxReceipts)?
+            .into_iter()
+            .filter(|(_, tx_receipt)| {
+                tx_receipt
+                    .block_number
+                    .unwrap_or(U64::MAX)
+                    .le(&latest_finalized_block_height)
+            })
+            .collect())
     }
 
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
@@ -219,6 +219,14 @@ where
             .into_any()
             .expect("vote msg should serialize")])
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-messages_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -230,6 +238,7 @@ mod tests {
     use base64::Engine;
     use cosmwasm_std;
     use error_stack::{Report, Result};
+    use ethers_core::types::{Block, U64};
     use ethers_providers::ProviderError;
     use events::Error::{DeserializationFailed, EventTypeMismatch};
     use events::Event;
@@ -243,9 +252,48 @@ mod tests {
     use crate::event_processor::EventHandler;
     use crate::evm::finalizer::Finalization;
     use crate::evm::json_rpc::MockEthereumClient;
+    use crate::handlers::errors::Error;
     use crate::types::{EVMAddress, Hash, TMAddress};
     use crate::PREFIX;
 
+    #[async_test]
+    async fn should_not_vote_when_rpc_is_unavailable() {
+        let mut rpc_client = MockEthereumClient::new();
+        rpc_client.expect_finalized_block().returning(|| {
+            Ok(Block {
+                number: Some(U64::from(10)),
+                ..Block::default()
+            })
+        });
+        rpc_client.expect_transaction_receipts().returning(|_| {
+            Err(Report::from(ProviderError::CustomError(
+                "service unavailable".to_string(),
+            )))
+        });
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = to_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            ChainName::from_str("ethereum").unwrap(),
+            Finalization::RPCFinalizedBlock,
+            rpc_client,
+            None,
+            watch::channel(0).1,
+        );
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::TxReceipts
+        ));
+    }
+
     fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
         PollStarted::Messages {
             metadata: PollMetadata {
@@ -370,6 +418,7 @@ mod tests {
             ChainName::from_str("ethereum").unwrap(),
             Finalization::RPCFinalizedBlock,
             rpc_client,
+            None,
             rx,
         );
 
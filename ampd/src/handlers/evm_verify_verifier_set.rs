@@ -19,6 +19,7 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::evm::finalizer;
 use crate::evm::finalizer::Finalization;
 use crate::evm::json_rpc::EthereumClient;
@@ -56,6 +57,7 @@ where
     chain: ChainName,
     finalizer_type: Finalization,
     rpc_client: C,
+    l1_rpc_client: Option<C>,
     latest_block_height: Receiver<u64>,
 }
 
@@ -69,6 +71,7 @@ where
         chain: ChainName,
         finalizer_type: Finalization,
         rpc_client: C,
+        l1_rpc_client: Option<C>,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
@@ -77,6 +80,7 @@ where
             chain,
             finalizer_type,
             rpc_client,
+            l1_rpc_client,
             latest_block_height,
         }
     }
@@ -86,16 +90,20 @@ where
         tx_hash: Hash,
         confirmation_height: u64,
     ) -> Result<Option<TransactionReceipt>> {
-        let latest_finalized_block_height =
-            finalizer::pick(&self.finalizer_type, &self.rpc_client, confirmation_height)
-                .latest_finalized_block_height()
-                .await
-                .change_context(Error::Finalizer)?;
+        let latest_finalized_block_height = finalizer::pick(
+            &self.finalizer_type,
+            &self.rpc_client,
+            self.l1_rpc_client.as_ref(),
+            confirmation_height,
+        )
+        .latest_finalized_block_height()
+        .await
+        .change_context(Error::Finalizer)?;
         let tx_receipt = self
             .rpc_client
             .transaction_receipt(tx_hash)
             .await
-            .change_context(Error::Finalizer)?;
+            .change_context(Error::TxReceipts)?;
 
         Ok(tx_receipt.and_then(|tx_receipt| {
             if tx_receipt
@@ -194,6 +202,14 @@ where
             .into_any()
             .expect("vote msg should serialize")])
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-verifier_set_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -204,6 +220,7 @@ mod tests {
     use base64::engine::general_purpose::STANDARD;
     use base64::Engine;
     use error_stack::{Report, Result};
+    use ethers_core::types::{Block, U64};
     use ethers_providers::ProviderError;
     use events::Event;
     use multisig::key::KeyType;
@@ -217,6 +234,7 @@ mod tests {
     use crate::event_processor::EventHandler;
     use crate::evm::finalizer::Finalization;
     use crate::evm::json_rpc::MockEthereumClient;
+    use crate::handlers::errors::Error;
     use crate::handlers::evm_verify_verifier_set::PollStartedEvent;
     use crate::types::{Hash, TMAddress};
     use crate::PREFIX;
@@ -258,6 +276,7 @@ mod tests {
             ChainName::from_str("ethereum").unwrap(),
             Finalization::RPCFinalizedBlock,
             rpc_client,
+            None,
             rx,
         );
 
@@ -270,6 +289,44 @@ mod tests {
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
 
+    #[async_test]
+    async fn should_not_vote_when_rpc_is_unavailable() {
+        let mut rpc_client = MockEthereumClient::new();
+        rpc_client.expect_finalized_block().returning(|| {
+            Ok(Block {
+                number: Some(U64::from(10)),
+                ..Block::default()
+            })
+        });
+        rpc_client.expect_transaction_receipt().returning(|_| {
+            Err(Report::from(ProviderError::CustomError(
+                "service unavailable".to_string(),
+            )))
+        });
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event: Event = to_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            ChainName::from_str("ethereum").unwrap(),
+            Finalization::RPCFinalizedBlock,
+            rpc_client,
+            None,
+            watch::channel(0).1,
+        );
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::TxReceipts
+        ));
+    }
+
     fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
         PollStarted::VerifierSet {
             verifier_set: VerifierSetConfirmation {
@@ -1,14 +1,19 @@
 pub mod config;
+pub mod cosmos_verify_msg;
+pub mod cosmos_verify_verifier_set;
 mod errors;
 pub mod evm_verify_msg;
 pub mod evm_verify_verifier_set;
 pub mod multisig;
 pub mod mvx_verify_msg;
 pub mod mvx_verify_verifier_set;
+pub mod starknet_verify_msg;
+pub mod starknet_verify_verifier_set;
 pub(crate) mod stellar_verify_msg;
 pub(crate) mod stellar_verify_verifier_set;
 pub mod sui_verify_msg;
 pub mod sui_verify_verifier_set;
+pub mod xrpl_verify_msg;
 
 #[cfg(test)]
 mod tests {
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 use std::convert::TryInto;
+use std::sync::Mutex;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use cosmrs::cosmwasm::MsgExecuteContract;
@@ -7,7 +9,7 @@ use cosmrs::tx::Msg;
 use cosmrs::Any;
 use cosmwasm_std::{HexBinary, Uint64};
 use ecdsa::VerifyingKey;
-use error_stack::{Report, ResultExt};
+use error_stack::{report, ResultExt};
 use events_derive;
 use events_derive::try_from;
 use hex::encode;
@@ -15,28 +17,48 @@ use multisig::msg::ExecuteMsg;
 use serde::de::Error as DeserializeError;
 use serde::{Deserialize, Deserializer};
 use tokio::sync::watch::Receiver;
+use tokio::time::Instant;
 use tracing::info;
 
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error::{self, DeserializeEvent};
 use crate::tofnd::grpc::Multisig;
 use crate::tofnd::{self, MessageDigest};
 use crate::types::{PublicKey, TMAddress};
 
+const SEC1_EVEN_Y_TAG: u8 = 0x02;
+
+/// Maximum number of times the multisig key of a verifier can be rotated
+pub const MAX_KEY_ROTATIONS: u32 = 32;
+// tofnd reports unknown keys the same way as other signing failures, so unknown keys are tried again after a while
+const UNKNOWN_PUB_KEY_TTL: Duration = Duration::from_secs(60);
+
+/// Returns the tofnd key uid of the verifier's multisig key after the given number of rotations.
+/// The initial key uses the multisig contract address as its uid.
+pub fn key_uid(multisig: &TMAddress, rotation: u32) -> String {
+    match rotation {
+        0 => multisig.to_string(),
+        _ => format!("{}_{}", multisig, rotation),
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[try_from("wasm-signing_started")]
 struct SigningStartedEvent {
     session_id: u64,
     #[serde(deserialize_with = "deserialize_public_keys")]
-    pub_keys: HashMap<TMAddress, PublicKey>,
+    pub_keys: HashMap<TMAddress, (Vec<u8>, tofnd::Algorithm)>,
     #[serde(with = "hex")]
     msg: MessageDigest,
     expires_at: u64,
 }
 
+// the public key type alone is ambiguous for secp256k1 keys, so the signing algorithm is kept alongside.
+// Keys are kept in the encoding tofnd uses, because BLS keys have no tendermint representation.
 fn deserialize_public_keys<'de, D>(
     deserializer: D,
-) -> Result<HashMap<TMAddress, PublicKey>, D::Error>
+) -> Result<HashMap<TMAddress, (Vec<u8>, tofnd::Algorithm)>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -48,9 +70,13 @@ where
         .map(|(address, pk)| match pk {
             multisig::key::PublicKey::Ecdsa(hex) => Ok((
                 address,
-                VerifyingKey::from_sec1_bytes(hex.as_ref())
-                    .map_err(D::Error::custom)?
-                    .into(),
+                (
+                    PublicKey::from(
+                        VerifyingKey::from_sec1_bytes(hex.as_ref()).map_err(D::Error::custom)?,
+                    )
+                    .to_bytes(),
+                    tofnd::Algorithm::Ecdsa,
+                ),
             )),
 
             multisig::key::PublicKey::Ed25519(hex) => {
@@ -58,7 +84,30 @@ where
                     cosmrs::tendermint::crypto::ed25519::VerificationKey::try_from(hex.as_ref())
                         .map_err(D::Error::custom)?
                         .into();
-                Ok((address, pk.into()))
+                Ok((
+                    address,
+                    (PublicKey::from(pk).to_bytes(), tofnd::Algorithm::Ed25519),
+                ))
+            }
+
+            // BIP-340 keys are x-only, the full point is the one with an even y coordinate
+            multisig::key::PublicKey::Schnorr(hex) => Ok((
+                address,
+                (
+                    PublicKey::from(
+                        VerifyingKey::from_sec1_bytes(
+                            &[[SEC1_EVEN_Y_TAG].as_slice(), hex.as_slice()].concat(),
+                        )
+                        .map_err(D::Error::custom)?,
+                    )
+                    .to_bytes(),
+                    tofnd::Algorithm::Schnorr,
+                ),
+            )),
+
+            // compressed G1 points, they are validated by the multisig contract on registration
+            multisig::key::PublicKey::Bls(hex) => {
+                Ok((address, (hex.to_vec(), tofnd::Algorithm::Bls)))
             }
         })
         .collect()
@@ -68,7 +117,11 @@ pub struct Handler<S> {
     verifier: TMAddress,
     multisig: TMAddress,
     signer: S,
+    key_rotation: u32,
     latest_block_height: Receiver<u64>,
+    key_uids: Mutex<HashMap<Vec<u8>, String>>,
+    /// Public keys that none of the key uids could sign for, with the time of the last attempt
+    unknown_pub_keys: Mutex<HashMap<Vec<u8>, Instant>>,
 }
 
 impl<S> Handler<S>
@@ -78,17 +131,87 @@ where
     pub fn new(
         verifier: TMAddress,
         multisig: TMAddress,
+        key_rotation: u32,
         signer: S,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
             verifier,
             multisig,
+            key_rotation,
             signer,
             latest_block_height,
+            key_uids: Mutex::new(HashMap::new()),
+            unknown_pub_keys: Mutex::new(HashMap::new()),
         }
     }
 
+    // the key of a session is either the key of the configured rotation or, for sessions of verifier sets
+    // registered before a rotation, one of the keys it replaced. tofnd only signs if the key uid matches the public key
+    async fn sign(
+        &self,
+        msg: MessageDigest,
+        pub_key: &[u8],
+        algorithm: tofnd::Algorithm,
+    ) -> error_stack::Result<Vec<u8>, Error> {
+        if self
+            .unknown_pub_keys
+            .lock()
+            .expect("unknown public key cache should not be poisoned")
+            .get(pub_key)
+            .is_some_and(|last_attempt| last_attempt.elapsed() < UNKNOWN_PUB_KEY_TTL)
+        {
+            return Err(report!(Error::Sign)).attach_printable(format!(
+                "no tofnd key found for public key {}",
+                encode(pub_key)
+            ));
+        }
+
+        let cached = self
+            .key_uids
+            .lock()
+            .expect("key uid cache should not be poisoned")
+            .get(pub_key)
+            .cloned();
+        let candidates = match cached {
+            Some(key_uid) => vec![key_uid],
+            None => (0..=self.key_rotation)
+                .rev()
+                .map(|rotation| key_uid(&self.multisig, rotation))
+                .collect(),
+        };
+
+        for key_uid in candidates {
+            match self
+                .signer
+                .sign_raw(key_uid.as_str(), msg.clone(), pub_key, algorithm)
+                .await
+            {
+                Ok(signature) => {
+                    self.key_uids
+                        .lock()
+                        .expect("key uid cache should not be poisoned")
+                        .insert(pub_key.to_vec(), key_uid);
+
+                    return Ok(signature);
+                }
+                // tofnd rejected the key uid, so the next candidate is tried
+                Err(err) if matches!(err.current_context(), tofnd::error::Error::SignFailed) => {}
+                Err(err) => return Err(err.change_context(Error::Sign)),
+            }
+        }
+
+        self.unknown_pub_keys
+            .lock()
+            .expect("unknown public key cache should not be poisoned")
+            .insert(pub_key.to_vec(), Instant::now());
+
+        Err(report!(Error::Sign)).attach_printable(format!(
+            "no tofnd key found for public key {}",
+            encode(pub_key)
+        ))
+    }
+
     fn submit_signature_msg(
         &self,
         session_id: impl Into<Uint64>,
@@ -152,23 +275,8 @@ where
         }
 
         match pub_keys.get(&self.verifier) {
-            Some(pub_key) => {
-                let key_type = match pub_key.type_url() {
-                    PublicKey::ED25519_TYPE_URL => tofnd::Algorithm::Ed25519,
-                    PublicKey::SECP256K1_TYPE_URL => tofnd::Algorithm::Ecdsa,
-                    unspported => return Err(Report::from(Error::KeyType(unspported.to_string()))),
-                };
-
-                let signature = self
-                    .signer
-                    .sign(
-                        self.multisig.to_string().as_str(),
-                        msg.clone(),
-                        pub_key,
-                        key_type,
-                    )
-                    .await
-                    .change_context(Error::Sign)?;
+            Some((pub_key, algorithm)) => {
+                let signature = self.sign(msg.clone(), pub_key, *algorithm).await?;
 
                 info!(signature = encode(&signature), "ready to submit signature");
 
@@ -184,6 +292,13 @@ where
             }
         }
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-signing_started").with_contract(&self.multisig)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -198,6 +313,7 @@ mod test {
     use cosmwasm_std::{HexBinary, Uint64};
     use ecdsa::SigningKey;
     use error_stack::{Report, Result};
+    use mockall::predicate;
     use multisig::events::Event;
     use multisig::types::MsgToSign;
     use rand::distributions::Alphanumeric;
@@ -317,7 +433,7 @@ mod test {
 
         let (_, rx) = watch::channel(latest_block_height);
 
-        Handler::new(verifier, multisig, signer, rx)
+        Handler::new(verifier, multisig, 0, signer, rx)
     }
 
     #[test]
@@ -367,6 +483,67 @@ mod test {
         ));
     }
 
+    #[test]
+    fn should_deserialize_schnorr_pub_key_as_even_y_secp256k1_key() {
+        let mut event = signing_started_event();
+
+        let compressed_pub_key =
+            types::PublicKey::from(SigningKey::random(&mut OsRng).verifying_key()).to_bytes();
+        let (_, x_only_pub_key) = compressed_pub_key.split_first().unwrap();
+        let verifier = rand_account();
+
+        let mut map: HashMap<String, multisig::key::PublicKey> = HashMap::new();
+        map.insert(
+            verifier.to_string(),
+            multisig::key::PublicKey::Schnorr(HexBinary::from(x_only_pub_key)),
+        );
+        match event {
+            events::Event::Abci {
+                ref mut attributes, ..
+            } => {
+                attributes.insert("pub_keys".into(), serde_json::to_value(map).unwrap());
+            }
+            _ => panic!("incorrect event type"),
+        }
+
+        let event: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let (pub_key, algorithm) = event.pub_keys.get(&verifier).unwrap();
+
+        assert_eq!(*algorithm, tofnd::Algorithm::Schnorr);
+        assert_eq!(
+            *pub_key,
+            [[SEC1_EVEN_Y_TAG].as_slice(), x_only_pub_key].concat()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_bls_pub_key() {
+        let mut event = signing_started_event();
+
+        let bls_pub_key = HexBinary::from_hex("97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb").unwrap();
+        let verifier = rand_account();
+
+        let mut map: HashMap<String, multisig::key::PublicKey> = HashMap::new();
+        map.insert(
+            verifier.to_string(),
+            multisig::key::PublicKey::Bls(bls_pub_key.clone()),
+        );
+        match event {
+            events::Event::Abci {
+                ref mut attributes, ..
+            } => {
+                attributes.insert("pub_keys".into(), serde_json::to_value(map).unwrap());
+            }
+            _ => panic!("incorrect event type"),
+        }
+
+        let event: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let (pub_key, algorithm) = event.pub_keys.get(&verifier).unwrap();
+
+        assert_eq!(*algorithm, tofnd::Algorithm::Bls);
+        assert_eq!(*pub_key, bls_pub_key.to_vec());
+    }
+
     #[test]
     fn should_deserialize_event() {
         let event: Result<SigningStartedEvent, events::Error> =
@@ -430,7 +607,7 @@ mod test {
     async fn should_not_handle_event_if_verifier_is_not_a_participant() {
         let mut client = MockMultisig::default();
         client
-            .expect_sign()
+            .expect_sign_raw()
             .returning(move |_, _, _, _| Err(Report::from(tofnd::error::Error::SignFailed)));
 
         let handler = handler(
@@ -448,14 +625,15 @@ mod test {
 
     #[tokio::test]
     async fn should_not_handle_event_if_sign_failed() {
-        let mut client = MockMultisig::default();
-        client
-            .expect_sign()
-            .returning(move |_, _, _, _| Err(Report::from(tofnd::error::Error::SignFailed)));
-
         let event = signing_started_event();
         let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
         let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign_raw()
+            .times(1)
+            .returning(move |_, _, _, _| Err(Report::from(tofnd::error::Error::SignFailed)));
         let handler = handler(
             verifier,
             TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
@@ -473,7 +651,7 @@ mod test {
     async fn should_not_handle_event_if_session_expired() {
         let mut client = MockMultisig::default();
         client
-            .expect_sign()
+            .expect_sign_raw()
             .returning(move |_, _, _, _| Err(Report::from(tofnd::error::Error::SignFailed)));
 
         let event = signing_started_event();
@@ -488,4 +666,197 @@ mod test {
 
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
+
+    #[tokio::test]
+    async fn should_sign_with_rotated_key() {
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+        let multisig = TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap());
+
+        // the session belongs to a verifier set that was registered before the last rotation
+        let mut client = MockMultisig::default();
+        client.expect_keygen_raw().never();
+        client
+            .expect_sign_raw()
+            .with(
+                predicate::eq(key_uid(&multisig, 2)),
+                predicate::always(),
+                predicate::always(),
+                predicate::always(),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Err(Report::from(tofnd::error::Error::SignFailed)));
+        client
+            .expect_sign_raw()
+            .with(
+                predicate::eq(key_uid(&multisig, 1)),
+                predicate::always(),
+                predicate::always(),
+                predicate::always(),
+            )
+            .times(2)
+            .returning(|_, _, _, _| Ok(vec![1; 64]));
+
+        let (_, rx) = watch::channel(99u64);
+        let handler = Handler::new(verifier, multisig, 2, client, rx);
+
+        // the matching key uid is cached after the first session
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_sign_with_key_of_any_previous_rotation() {
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+        let multisig = TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap());
+
+        // the session belongs to a verifier set that was registered before the first rotation
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign_raw()
+            .with(
+                predicate::ne(key_uid(&multisig, 0)),
+                predicate::always(),
+                predicate::always(),
+                predicate::always(),
+            )
+            .times(3)
+            .returning(|_, _, _, _| Err(Report::from(tofnd::error::Error::SignFailed)));
+        client
+            .expect_sign_raw()
+            .with(
+                predicate::eq(key_uid(&multisig, 0)),
+                predicate::always(),
+                predicate::always(),
+                predicate::always(),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(vec![1; 64]));
+
+        let (_, rx) = watch::channel(99u64);
+        let handler = Handler::new(verifier, multisig, 3, client, rx);
+
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_sign_with_bls_key() {
+        let mut event = signing_started_event();
+
+        let bls_pub_key = HexBinary::from_hex("97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb").unwrap();
+        let verifier = rand_account();
+        let multisig = TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap());
+
+        let mut map: HashMap<String, multisig::key::PublicKey> = HashMap::new();
+        map.insert(
+            verifier.to_string(),
+            multisig::key::PublicKey::Bls(bls_pub_key.clone()),
+        );
+        match event {
+            events::Event::Abci {
+                ref mut attributes, ..
+            } => {
+                attributes.insert("pub_keys".into(), serde_json::to_value(map).unwrap());
+            }
+            _ => panic!("incorrect event type"),
+        }
+
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign_raw()
+            .with(
+                predicate::eq(key_uid(&multisig, 0)),
+                predicate::always(),
+                predicate::eq(bls_pub_key.to_vec()),
+                predicate::eq(tofnd::Algorithm::Bls),
+            )
+            .return_once(|_, _, _, _| Ok(vec![1; 96]));
+
+        let handler = handler(verifier, multisig, client, 99u64);
+
+        assert_eq!(handler.handle(&event).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_handle_event_if_no_key_matches() {
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+        let multisig = TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap());
+
+        let mut client = MockMultisig::default();
+        client.expect_keygen_raw().never();
+        // all rotations are tried once, afterwards the key is known to be missing
+        client
+            .expect_sign_raw()
+            .times(2)
+            .returning(|_, _, _, _| Err(Report::from(tofnd::error::Error::SignFailed)));
+
+        let (_, rx) = watch::channel(99u64);
+        let handler = Handler::new(verifier, multisig, 1, client, rx);
+
+        for _ in 0..2 {
+            assert!(matches!(
+                *handler.handle(&event).await.unwrap_err().current_context(),
+                Error::Sign
+            ));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_try_unknown_key_again_after_a_while() {
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign_raw()
+            .times(2)
+            .returning(|_, _, _, _| Err(Report::from(tofnd::error::Error::SignFailed)));
+
+        let handler = handler(
+            verifier,
+            TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
+            client,
+            99u64,
+        );
+
+        assert!(handler.handle(&event).await.is_err());
+        assert!(handler.handle(&event).await.is_err());
+
+        tokio::time::advance(UNKNOWN_PUB_KEY_TTL).await;
+
+        assert!(handler.handle(&event).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_retry_signing_if_tofnd_is_unavailable() {
+        let event = signing_started_event();
+        let signing_started: SigningStartedEvent = ((&event).try_into() as Result<_, _>).unwrap();
+        let verifier = signing_started.pub_keys.keys().next().unwrap().clone();
+
+        let mut client = MockMultisig::default();
+        client
+            .expect_sign_raw()
+            .times(2)
+            .returning(|_, _, _, _| Err(Report::from(tofnd::error::Error::Grpc)));
+
+        let handler = handler(
+            verifier,
+            TMAddress::from(MULTISIG_ADDRESS.parse::<AccountId>().unwrap()),
+            client,
+            99u64,
+        );
+
+        for _ in 0..2 {
+            assert!(matches!(
+                *handler.handle(&event).await.unwrap_err().current_context(),
+                Error::Sign
+            ));
+        }
+    }
 }
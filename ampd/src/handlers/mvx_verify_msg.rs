@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 
 use async_trait::async_trait;
@@ -11,13 +11,16 @@ use events::Error::EventTypeMismatch;
 use events::Event;
 use events_derive::try_from;
 use multiversx_sdk::data::address::Address;
+use multiversx_sdk::data::transaction::TransactionOnNetwork;
 use serde::Deserialize;
 use tokio::sync::watch::Receiver;
 use tracing::info;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
+use crate::mvx::finalizer::{self, Finalization};
 use crate::mvx::proxy::MvxProxy;
 use crate::mvx::verifier::verify_message;
 use crate::types::{Hash, TMAddress};
@@ -51,6 +54,8 @@ where
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
     blockchain: P,
+    finalization: Finalization,
+    confirmation_depth: u64,
     latest_block_height: Receiver<u64>,
 }
 
@@ -62,16 +67,46 @@ where
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         blockchain: P,
+        finalization: Finalization,
+        confirmation_depth: u64,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
             verifier,
             voting_verifier_contract,
             blockchain,
+            finalization,
+            confirmation_depth,
             latest_block_height,
         }
     }
 
+    async fn finalized_transactions(
+        &self,
+        transactions: HashMap<Hash, TransactionOnNetwork>,
+    ) -> Result<HashMap<Hash, TransactionOnNetwork>> {
+        if transactions.is_empty() {
+            return Ok(transactions);
+        }
+
+        let latest_hyperblock_nonce = self
+            .blockchain
+            .latest_hyperblock_nonce(&self.finalization)
+            .await
+            .change_context(Error::Finalizer)?;
+
+        Ok(transactions
+            .into_iter()
+            .filter(|(_, transaction)| {
+                finalizer::is_finalized(
+                    transaction,
+                    latest_hyperblock_nonce,
+                    self.confirmation_depth,
+                )
+            })
+            .collect())
+    }
+
     fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
         MsgExecuteContract {
             sender: self.verifier.as_ref().clone(),
@@ -124,7 +159,9 @@ where
         let transactions_info = self
             .blockchain
             .transactions_info_with_results(tx_hashes)
-            .await;
+            .await
+            .change_context(Error::TxReceipts)?;
+        let transactions_info = self.finalized_transactions(transactions_info).await?;
 
         let votes: Vec<Vote> = messages
             .iter()
@@ -142,6 +179,14 @@ where
             .into_any()
             .expect("vote msg should serialize")])
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-messages_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -149,22 +194,38 @@ mod tests {
     use std::collections::HashMap;
     use std::convert::TryInto;
 
+    use axelar_wasm_std::voting::Vote;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
     use cosmrs::cosmwasm::MsgExecuteContract;
     use cosmrs::tx::Msg;
+    use cosmrs::Any;
     use cosmwasm_std;
-    use error_stack::Result;
+    use error_stack::{report, Result};
     use hex::ToHex;
+    use multiversx_sdk::data::address::Address;
+    use multiversx_sdk::data::transaction::{ApiLogs, Events, TransactionOnNetwork};
     use tokio::sync::watch;
     use tokio::test as async_test;
     use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
+    use voting_verifier::msg::ExecuteMsg;
 
     use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
+    use crate::handlers::errors::Error;
     use crate::handlers::tests::into_structured_event;
+    use crate::mvx::error::Error as MvxError;
+    use crate::mvx::finalizer::Finalization;
     use crate::mvx::proxy::MockMvxProxy;
-    use crate::types::{EVMAddress, Hash, TMAddress};
+    use crate::types::TMAddress;
     use crate::PREFIX;
 
+    const TX_ID: &str = "dfaf64de66510723f2efbacd7ead3c4f8c856aed1afc2cb30254552aeda47312";
+    const GATEWAY_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqsvzyz88e8v8j6x3wquatxuztnxjwnw92kkls6rdtzx";
+    const SOURCE_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqzqvm5ywqqf524efwrhr039tjs29w0qltkklsa05pk7";
+    const DESTINATION_ADDRESS: &str = "0x4f4495243837681061c4743b74b3eedf548d56a5";
+    const PAYLOAD_HASH: [u8; 32] = [1u8; 32];
+
     #[test]
     fn should_deserialize_poll_started_event() {
         let event: Result<PollStartedEvent, events::Error> = into_structured_event(
@@ -209,6 +270,8 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockMvxProxy::new(),
+            Finalization::default(),
+            0,
             watch::channel(0).1,
         );
 
@@ -227,6 +290,8 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockMvxProxy::new(),
+            Finalization::default(),
+            0,
             watch::channel(0).1,
         );
 
@@ -244,6 +309,8 @@ mod tests {
             TMAddress::random(PREFIX),
             voting_verifier,
             MockMvxProxy::new(),
+            Finalization::default(),
+            0,
             watch::channel(0).1,
         );
 
@@ -255,7 +322,7 @@ mod tests {
         let mut proxy = MockMvxProxy::new();
         proxy
             .expect_transactions_info_with_results()
-            .returning(|_| HashMap::new());
+            .returning(|_| Ok(HashMap::new()));
 
         let voting_verifier = TMAddress::random(PREFIX);
         let worker = TMAddress::random(PREFIX);
@@ -264,19 +331,55 @@ mod tests {
             &voting_verifier,
         );
 
-        let handler = super::Handler::new(worker, voting_verifier, proxy, watch::channel(0).1);
+        let handler = super::Handler::new(
+            worker,
+            voting_verifier,
+            proxy,
+            Finalization::default(),
+            0,
+            watch::channel(0).1,
+        );
 
         let actual = handler.handle(&event).await.unwrap();
         assert_eq!(actual.len(), 1);
         assert!(MsgExecuteContract::from_any(actual.first().unwrap()).is_ok());
     }
 
+    #[async_test]
+    async fn should_not_vote_when_proxy_is_unavailable() {
+        let mut proxy = MockMvxProxy::new();
+        proxy
+            .expect_transactions_info_with_results()
+            .returning(|_| Err(report!(MvxError::Proxy)));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let worker = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(worker.clone()))),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            worker,
+            voting_verifier,
+            proxy,
+            Finalization::default(),
+            0,
+            watch::channel(0).1,
+        );
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::TxReceipts
+        ));
+    }
+
     #[async_test]
     async fn should_skip_expired_poll() {
         let mut proxy = MockMvxProxy::new();
         proxy
             .expect_transactions_info_with_results()
-            .returning(|_| HashMap::new());
+            .returning(|_| Ok(HashMap::new()));
 
         let voting_verifier = TMAddress::random(PREFIX);
         let worker = TMAddress::random(PREFIX);
@@ -288,7 +391,14 @@ mod tests {
 
         let (tx, rx) = watch::channel(expiration - 1);
 
-        let handler = super::Handler::new(worker, voting_verifier, proxy, rx);
+        let handler = super::Handler::new(
+            worker,
+            voting_verifier,
+            proxy,
+            Finalization::default(),
+            0,
+            rx,
+        );
 
         // poll is not expired yet, should hit proxy
         let actual = handler.handle(&event).await.unwrap();
@@ -300,15 +410,146 @@ mod tests {
         assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
     }
 
+    #[async_test]
+    async fn should_not_vote_for_transactions_before_they_are_confirmed() {
+        let voting_verifier = TMAddress::random(PREFIX);
+        let worker = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(worker.clone()))),
+            &voting_verifier,
+        );
+
+        // the transaction is included in hyperblock 10 and needs 2 confirmations
+        for (latest_hyperblock_nonce, expected_vote) in
+            [(11, Vote::NotFound), (12, Vote::SucceededOnChain)]
+        {
+            let mut proxy = MockMvxProxy::new();
+            proxy
+                .expect_transactions_info_with_results()
+                .returning(|_| Ok(HashMap::from([(TX_ID.parse().unwrap(), transaction(10))])));
+            proxy
+                .expect_latest_hyperblock_nonce()
+                .once()
+                .returning(move |_| Ok(latest_hyperblock_nonce));
+
+            let handler = super::Handler::new(
+                worker.clone(),
+                voting_verifier.clone(),
+                proxy,
+                Finalization::FinalizedHyperblock,
+                2,
+                watch::channel(0).1,
+            );
+
+            assert_eq!(
+                votes(handler.handle(&event).await.unwrap()),
+                vec![expected_vote]
+            );
+        }
+    }
+
+    #[async_test]
+    async fn should_not_vote_when_latest_hyperblock_is_unavailable() {
+        let mut proxy = MockMvxProxy::new();
+        proxy
+            .expect_transactions_info_with_results()
+            .returning(|_| Ok(HashMap::from([(TX_ID.parse().unwrap(), transaction(10))])));
+        proxy
+            .expect_latest_hyperblock_nonce()
+            .returning(|_| Err(report!(MvxError::Proxy)));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let worker = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(worker.clone()))),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            worker,
+            voting_verifier,
+            proxy,
+            Finalization::FinalizedHyperblock,
+            0,
+            watch::channel(0).1,
+        );
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::Finalizer
+        ));
+    }
+
+    fn votes(msgs: Vec<Any>) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(msgs.first().unwrap()).unwrap();
+
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected a vote msg"),
+        }
+    }
+
+    fn transaction(hyperblock_nonce: u64) -> TransactionOnNetwork {
+        let gateway_address = Address::from_bech32_string(GATEWAY_ADDRESS).unwrap();
+        let source_address = Address::from_bech32_string(SOURCE_ADDRESS).unwrap();
+
+        // the message is emitted as the second event
+        let contract_call_event = || Events {
+            address: gateway_address.clone(),
+            identifier: "callContract".into(),
+            topics: Some(vec![
+                STANDARD.encode("contract_call_event"),
+                STANDARD.encode(source_address.clone().to_bytes()),
+                STANDARD.encode("ethereum"),
+                STANDARD.encode(DESTINATION_ADDRESS),
+                STANDARD.encode(PAYLOAD_HASH),
+            ]),
+            data: None,
+        };
+
+        TransactionOnNetwork {
+            hash: Some(TX_ID.into()),
+            logs: Some(ApiLogs {
+                address: source_address.clone(),
+                events: vec![contract_call_event(), contract_call_event()],
+            }),
+            status: "success".into(),
+            // The rest are irrelevant but there is no default
+            kind: "".into(),
+            nonce: 1,
+            round: 1,
+            epoch: 1,
+            value: "".into(),
+            receiver: source_address.clone(),
+            sender: source_address,
+            gas_price: 0,
+            gas_limit: 0,
+            signature: "".into(),
+            source_shard: 1,
+            destination_shard: 1,
+            block_nonce: 1,
+            block_hash: "".into(),
+            notarized_at_source_in_meta_nonce: Some(hyperblock_nonce),
+            notarized_at_source_in_meta_hash: Some("".into()),
+            notarized_at_destination_in_meta_nonce: Some(hyperblock_nonce),
+            notarized_at_destination_in_meta_hash: Some("".into()),
+            miniblock_type: "".into(),
+            miniblock_hash: "".into(),
+            timestamp: 1,
+            data: None,
+            hyperblock_nonce: Some(hyperblock_nonce),
+            hyperblock_hash: Some("".into()),
+            smart_contract_results: None,
+            processing_type_on_destination: "".into(),
+        }
+    }
+
     fn poll_started_event(participants: Vec<TMAddress>) -> PollStarted {
         PollStarted::Messages {
             metadata: PollMetadata {
                 poll_id: "100".parse().unwrap(),
                 source_chain: "multiversx".parse().unwrap(),
-                source_gateway_address:
-                    "erd1qqqqqqqqqqqqqpgqsvzyz88e8v8j6x3wquatxuztnxjwnw92kkls6rdtzx"
-                        .parse()
-                        .unwrap(),
+                source_gateway_address: GATEWAY_ADDRESS.parse().unwrap(),
                 confirmation_height: 15,
                 expires_at: 100,
                 participants: participants
@@ -317,16 +558,12 @@ mod tests {
                     .collect(),
             },
             messages: vec![TxEventConfirmation {
-                tx_id: "dfaf64de66510723f2efbacd7ead3c4f8c856aed1afc2cb30254552aeda47312"
-                    .parse()
-                    .unwrap(),
+                tx_id: TX_ID.parse().unwrap(),
                 event_index: 1,
-                source_address: "erd1qqqqqqqqqqqqqpgqzqvm5ywqqf524efwrhr039tjs29w0qltkklsa05pk7"
-                    .parse()
-                    .unwrap(),
+                source_address: SOURCE_ADDRESS.parse().unwrap(),
                 destination_chain: "ethereum".parse().unwrap(),
-                destination_address: format!("0x{:x}", EVMAddress::random()).parse().unwrap(),
-                payload_hash: Hash::random().to_fixed_bytes(),
+                destination_address: DESTINATION_ADDRESS.parse().unwrap(),
+                payload_hash: PAYLOAD_HASH,
             }],
         }
     }
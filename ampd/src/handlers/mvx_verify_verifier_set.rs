@@ -18,7 +18,9 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
+use crate::mvx::finalizer::{self, Finalization};
 use crate::mvx::proxy::MvxProxy;
 use crate::mvx::verifier::verify_verifier_set;
 use crate::types::{Hash, TMAddress};
@@ -47,6 +49,8 @@ where
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
     blockchain: P,
+    finalization: Finalization,
+    confirmation_depth: u64,
     latest_block_height: Receiver<u64>,
 }
 
@@ -58,12 +62,16 @@ where
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         blockchain: P,
+        finalization: Finalization,
+        confirmation_depth: u64,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
             verifier,
             voting_verifier_contract,
             blockchain,
+            finalization,
+            confirmation_depth,
             latest_block_height,
         }
     }
@@ -121,7 +129,26 @@ where
         let transaction_info = self
             .blockchain
             .transaction_info_with_results(&verifier_set.tx_id)
-            .await;
+            .await
+            .change_context(Error::TxReceipts)?;
+        let transaction_info = match transaction_info {
+            Some(transaction) => {
+                let latest_hyperblock_nonce = self
+                    .blockchain
+                    .latest_hyperblock_nonce(&self.finalization)
+                    .await
+                    .change_context(Error::Finalizer)?;
+
+                Some(transaction).filter(|transaction| {
+                    finalizer::is_finalized(
+                        transaction,
+                        latest_hyperblock_nonce,
+                        self.confirmation_depth,
+                    )
+                })
+            }
+            None => None,
+        };
 
         let vote = info_span!(
             "verify a new verifier set for MultiversX",
@@ -147,32 +174,54 @@ where
             .into_any()
             .expect("vote msg should serialize")])
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-verifier_set_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::convert::TryInto;
 
+    use axelar_wasm_std::voting::Vote;
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
     use cosmrs::cosmwasm::MsgExecuteContract;
     use cosmrs::tx::Msg;
+    use cosmrs::Any;
     use cosmwasm_std;
     use cosmwasm_std::{HexBinary, Uint128};
-    use error_stack::Result;
+    use error_stack::{report, Result};
     use events::Event;
     use hex::ToHex;
     use multisig::key::KeyType;
     use multisig::test::common::{build_verifier_set, ed25519_test_data};
+    use multiversx_sdk::data::address::Address;
+    use multiversx_sdk::data::transaction::{ApiLogs, Events, TransactionOnNetwork};
     use tokio::sync::watch;
     use tokio::test as async_test;
     use voting_verifier::events::{PollMetadata, PollStarted, VerifierSetConfirmation};
+    use voting_verifier::msg::ExecuteMsg;
 
     use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
+    use crate::handlers::errors::Error;
     use crate::handlers::tests::into_structured_event;
+    use crate::mvx::error::Error as MvxError;
+    use crate::mvx::finalizer::Finalization;
     use crate::mvx::proxy::MockMvxProxy;
+    use crate::mvx::WeightedSigners;
     use crate::types::TMAddress;
     use crate::PREFIX;
 
+    const TX_ID: &str = "dfaf64de66510723f2efbacd7ead3c4f8c856aed1afc2cb30254552aeda47312";
+    const GATEWAY_ADDRESS: &str = "erd1qqqqqqqqqqqqqpgqsvzyz88e8v8j6x3wquatxuztnxjwnw92kkls6rdtzx";
+
     #[test]
     fn should_deserialize_verifier_set_poll_started_event() {
         let event: Result<PollStartedEvent, events::Error> = into_structured_event(
@@ -229,6 +278,8 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockMvxProxy::new(),
+            Finalization::default(),
+            0,
             watch::channel(0).1,
         );
 
@@ -246,6 +297,8 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockMvxProxy::new(),
+            Finalization::default(),
+            0,
             watch::channel(0).1,
         );
 
@@ -264,6 +317,8 @@ mod tests {
             TMAddress::random(PREFIX),
             voting_verifier,
             MockMvxProxy::new(),
+            Finalization::default(),
+            0,
             watch::channel(0).1,
         );
 
@@ -275,7 +330,7 @@ mod tests {
         let mut proxy = MockMvxProxy::new();
         proxy
             .expect_transaction_info_with_results()
-            .returning(|_| None);
+            .returning(|_| Ok(None));
 
         let voting_verifier = TMAddress::random(PREFIX);
         let verifier = TMAddress::random(PREFIX);
@@ -290,7 +345,14 @@ mod tests {
 
         let (tx, rx) = watch::channel(expiration - 1);
 
-        let handler = super::Handler::new(verifier, voting_verifier, proxy, rx);
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            proxy,
+            Finalization::default(),
+            0,
+            rx,
+        );
 
         // poll is not expired yet, should hit proxy
         let actual = handler.handle(&event).await.unwrap();
@@ -307,7 +369,7 @@ mod tests {
         let mut proxy = MockMvxProxy::new();
         proxy
             .expect_transaction_info_with_results()
-            .returning(|_| None);
+            .returning(|_| Ok(None));
 
         let voting_verifier = TMAddress::random(PREFIX);
         let worker = TMAddress::random(PREFIX);
@@ -317,13 +379,153 @@ mod tests {
             &voting_verifier,
         );
 
-        let handler = super::Handler::new(worker, voting_verifier, proxy, watch::channel(0).1);
+        let handler = super::Handler::new(
+            worker,
+            voting_verifier,
+            proxy,
+            Finalization::default(),
+            0,
+            watch::channel(0).1,
+        );
 
         let actual = handler.handle(&event).await.unwrap();
         assert_eq!(actual.len(), 1);
         assert!(MsgExecuteContract::from_any(actual.first().unwrap()).is_ok());
     }
 
+    #[async_test]
+    async fn should_not_vote_when_proxy_is_unavailable() {
+        let mut proxy = MockMvxProxy::new();
+        proxy
+            .expect_transaction_info_with_results()
+            .returning(|_| Err(report!(MvxError::Proxy)));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let worker = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            verifier_set_poll_started_event(participants(5, Some(worker.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            worker,
+            voting_verifier,
+            proxy,
+            Finalization::default(),
+            0,
+            watch::channel(0).1,
+        );
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::TxReceipts
+        ));
+    }
+
+    #[async_test]
+    async fn should_not_vote_for_transactions_before_they_are_confirmed() {
+        let voting_verifier = TMAddress::random(PREFIX);
+        let worker = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            verifier_set_poll_started_event(participants(5, Some(worker.clone())), 100),
+            &voting_verifier,
+        );
+
+        // the transaction is included in hyperblock 10 and needs 2 confirmations
+        for (latest_hyperblock_nonce, expected_vote) in
+            [(11, Vote::NotFound), (12, Vote::SucceededOnChain)]
+        {
+            let mut proxy = MockMvxProxy::new();
+            proxy
+                .expect_transaction_info_with_results()
+                .returning(|_| Ok(Some(transaction(10))));
+            proxy
+                .expect_latest_hyperblock_nonce()
+                .once()
+                .returning(move |_| Ok(latest_hyperblock_nonce));
+
+            let handler = super::Handler::new(
+                worker.clone(),
+                voting_verifier.clone(),
+                proxy,
+                Finalization::FinalizedHyperblock,
+                2,
+                watch::channel(0).1,
+            );
+
+            assert_eq!(
+                votes(handler.handle(&event).await.unwrap()),
+                vec![expected_vote]
+            );
+        }
+    }
+
+    fn votes(msgs: Vec<Any>) -> Vec<Vote> {
+        let msg = MsgExecuteContract::from_any(msgs.first().unwrap()).unwrap();
+
+        match serde_json::from_slice(&msg.msg).unwrap() {
+            ExecuteMsg::Vote { votes, .. } => votes,
+            _ => panic!("expected a vote msg"),
+        }
+    }
+
+    fn transaction(hyperblock_nonce: u64) -> TransactionOnNetwork {
+        let gateway_address = Address::from_bech32_string(GATEWAY_ADDRESS).unwrap();
+        let signers_hash = WeightedSigners::from(&build_verifier_set(
+            KeyType::Ed25519,
+            &ed25519_test_data::signers(),
+        ))
+        .hash();
+
+        // the verifier set is emitted as the second event
+        let signers_rotated_event = || Events {
+            address: gateway_address.clone(),
+            identifier: "rotateSigners".into(),
+            topics: Some(vec![
+                STANDARD.encode("signers_rotated_event"),
+                STANDARD.encode("0"),
+                STANDARD.encode(signers_hash),
+            ]),
+            data: None,
+        };
+
+        TransactionOnNetwork {
+            hash: Some(TX_ID.into()),
+            logs: Some(ApiLogs {
+                address: gateway_address.clone(),
+                events: vec![signers_rotated_event(), signers_rotated_event()],
+            }),
+            status: "success".into(),
+            // The rest are irrelevant but there is no default
+            kind: "".into(),
+            nonce: 1,
+            round: 1,
+            epoch: 1,
+            value: "".into(),
+            receiver: gateway_address.clone(),
+            sender: gateway_address.clone(),
+            gas_price: 0,
+            gas_limit: 0,
+            signature: "".into(),
+            source_shard: 1,
+            destination_shard: 1,
+            block_nonce: 1,
+            block_hash: "".into(),
+            notarized_at_source_in_meta_nonce: Some(hyperblock_nonce),
+            notarized_at_source_in_meta_hash: Some("".into()),
+            notarized_at_destination_in_meta_nonce: Some(hyperblock_nonce),
+            notarized_at_destination_in_meta_hash: Some("".into()),
+            miniblock_type: "".into(),
+            miniblock_hash: "".into(),
+            timestamp: 1,
+            data: None,
+            hyperblock_nonce: Some(hyperblock_nonce),
+            hyperblock_hash: Some("".into()),
+            smart_contract_results: None,
+            processing_type_on_destination: "".into(),
+        }
+    }
+
     fn verifier_set_poll_started_event(
         participants: Vec<TMAddress>,
         expires_at: u64,
@@ -332,10 +534,7 @@ mod tests {
             metadata: PollMetadata {
                 poll_id: "100".parse().unwrap(),
                 source_chain: "multiversx".parse().unwrap(),
-                source_gateway_address:
-                    "erd1qqqqqqqqqqqqqpgqsvzyz88e8v8j6x3wquatxuztnxjwnw92kkls6rdtzx"
-                        .parse()
-                        .unwrap(),
+                source_gateway_address: GATEWAY_ADDRESS.parse().unwrap(),
                 confirmation_height: 15,
                 expires_at,
                 participants: participants
@@ -344,9 +543,7 @@ mod tests {
                     .collect(),
             },
             verifier_set: VerifierSetConfirmation {
-                tx_id: "dfaf64de66510723f2efbacd7ead3c4f8c856aed1afc2cb30254552aeda47312"
-                    .parse()
-                    .unwrap(),
+                tx_id: TX_ID.parse().unwrap(),
                 event_index: 1,
                 verifier_set: build_verifier_set(KeyType::Ed25519, &ed25519_test_data::signers()),
             },
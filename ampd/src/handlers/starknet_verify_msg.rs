@@ -0,0 +1,370 @@
+use std::collections::HashSet;
+use std::convert::TryInto;
+
+use async_trait::async_trait;
+use axelar_wasm_std::msg_id::FieldElementAndEventIndex;
+use axelar_wasm_std::voting::{PollId, Vote};
+use cosmrs::cosmwasm::MsgExecuteContract;
+use cosmrs::tx::Msg;
+use cosmrs::Any;
+use error_stack::ResultExt;
+use events::Error::EventTypeMismatch;
+use events::Event;
+use events_derive::try_from;
+use router_api::ChainName;
+use serde::Deserialize;
+use tokio::sync::watch::Receiver;
+use tracing::{info, info_span};
+use valuable::Valuable;
+use voting_verifier::msg::ExecuteMsg;
+
+use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
+use crate::handlers::errors::Error;
+use crate::starknet::finality::{finalized_receipts, Finality};
+use crate::starknet::json_rpc::{FieldElement, StarknetClient};
+use crate::starknet::verifier::verify_message;
+use crate::types::{Hash, TMAddress};
+
+type Result<T> = error_stack::Result<T, Error>;
+
+#[derive(Deserialize, Debug)]
+pub struct Message {
+    pub tx_id: FieldElement,
+    pub event_index: u32,
+    pub destination_address: String,
+    pub destination_chain: ChainName,
+    pub source_address: FieldElement,
+    pub payload_hash: Hash,
+}
+
+#[derive(Deserialize, Debug)]
+#[try_from("wasm-messages_poll_started")]
+struct PollStartedEvent {
+    poll_id: PollId,
+    source_gateway_address: FieldElement,
+    messages: Vec<Message>,
+    participants: Vec<TMAddress>,
+    expires_at: u64,
+}
+
+pub struct Handler<C>
+where
+    C: StarknetClient + Send + Sync,
+{
+    verifier: TMAddress,
+    voting_verifier_contract: TMAddress,
+    rpc_client: C,
+    finality: Finality,
+    latest_block_height: Receiver<u64>,
+}
+
+impl<C> Handler<C>
+where
+    C: StarknetClient + Send + Sync,
+{
+    pub fn new(
+        verifier: TMAddress,
+        voting_verifier_contract: TMAddress,
+        rpc_client: C,
+        finality: Finality,
+        latest_block_height: Receiver<u64>,
+    ) -> Self {
+        Self {
+            verifier,
+            voting_verifier_contract,
+            rpc_client,
+            finality,
+            latest_block_height,
+        }
+    }
+
+    fn vote_msg(&self, poll_id: PollId, votes: Vec<Vote>) -> MsgExecuteContract {
+        MsgExecuteContract {
+            sender: self.verifier.as_ref().clone(),
+            contract: self.voting_verifier_contract.as_ref().clone(),
+            msg: serde_json::to_vec(&ExecuteMsg::Vote { poll_id, votes })
+                .expect("vote msg should serialize"),
+            funds: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl<C> EventHandler for Handler<C>
+where
+    C: StarknetClient + Send + Sync,
+{
+    type Err = Error;
+
+    async fn handle(&self, event: &Event) -> Result<Vec<Any>> {
+        if !event.is_from_contract(self.voting_verifier_contract.as_ref()) {
+            return Ok(vec![]);
+        }
+
+        let PollStartedEvent {
+            poll_id,
+            source_gateway_address,
+            messages,
+            participants,
+            expires_at,
+        } = match event.try_into() as error_stack::Result<_, _> {
+            Err(report) if matches!(report.current_context(), EventTypeMismatch(_)) => {
+                return Ok(vec![]);
+            }
+            event => event.change_context(Error::DeserializeEvent)?,
+        };
+
+        if !participants.contains(&self.verifier) {
+            return Ok(vec![]);
+        }
+
+        if *self.latest_block_height.borrow() >= expires_at {
+            info!(poll_id = poll_id.to_string(), "skipping expired poll");
+            return Ok(vec![]);
+        }
+
+        let tx_hashes: HashSet<_> = messages.iter().map(|message| message.tx_id).collect();
+        let receipts = self
+            .rpc_client
+            .transaction_receipts(tx_hashes)
+            .await
+            .change_context(Error::TxReceipts)?;
+        let receipts = finalized_receipts(receipts, self.finality);
+
+        let message_ids = messages
+            .iter()
+            .map(|message| {
+                FieldElementAndEventIndex::new(message.tx_id, message.event_index).to_string()
+            })
+            .collect::<Vec<_>>();
+
+        let votes = info_span!(
+            "verify messages from Starknet",
+            poll_id = poll_id.to_string(),
+            message_ids = message_ids.as_value()
+        )
+        .in_scope(|| {
+            info!("ready to verify messages in poll",);
+
+            let votes: Vec<_> = messages
+                .iter()
+                .map(|msg| {
+                    receipts.get(&msg.tx_id).map_or(Vote::NotFound, |receipt| {
+                        verify_message(&source_gateway_address, receipt, msg)
+                    })
+                })
+                .collect();
+
+            info!(
+                votes = votes.as_value(),
+                "ready to vote for messages in poll"
+            );
+
+            votes
+        });
+
+        Ok(vec![self
+            .vote_msg(poll_id, votes)
+            .into_any()
+            .expect("vote msg should serialize")])
+    }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-messages_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use error_stack::{report, Result};
+    use ethers_providers::ProviderError;
+    use events::Event;
+    use tokio::sync::watch;
+    use tokio::test as async_test;
+    use voting_verifier::events::{PollMetadata, PollStarted, TxEventConfirmation};
+
+    use super::PollStartedEvent;
+    use crate::event_processor::EventHandler;
+    use crate::handlers::errors::Error;
+    use crate::handlers::tests::{into_structured_event, participants};
+    use crate::starknet::finality::Finality;
+    use crate::starknet::json_rpc::MockStarknetClient;
+    use crate::types::{EVMAddress, Hash, TMAddress};
+    use crate::PREFIX;
+
+    #[test]
+    fn should_deserialize_poll_started_event() {
+        let event: Result<PollStartedEvent, events::Error> = into_structured_event(
+            poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        )
+        .try_into();
+
+        assert!(event.is_ok());
+    }
+
+    #[async_test]
+    async fn contract_is_not_voting_verifier() {
+        let event = into_structured_event(
+            poll_started_event(participants(5, None), 100),
+            &TMAddress::random(PREFIX),
+        );
+
+        let handler = super::Handler::new(
+            TMAddress::random(PREFIX),
+            TMAddress::random(PREFIX),
+            MockStarknetClient::new(),
+            Finality::default(),
+            watch::channel(0).1,
+        );
+
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
+
+    #[async_test]
+    async fn verifier_is_not_a_participant() {
+        let voting_verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, None), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            TMAddress::random(PREFIX),
+            voting_verifier,
+            MockStarknetClient::new(),
+            Finality::default(),
+            watch::channel(0).1,
+        );
+
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
+
+    #[async_test]
+    async fn should_vote_correctly() {
+        let mut rpc_client = MockStarknetClient::new();
+        rpc_client
+            .expect_transaction_receipts()
+            .returning(|_| Ok(HashMap::new()));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            rpc_client,
+            Finality::default(),
+            watch::channel(0).1,
+        );
+
+        let actual = handler.handle(&event).await.unwrap();
+        assert_eq!(actual.len(), 1);
+        assert!(MsgExecuteContract::from_any(actual.first().unwrap()).is_ok());
+    }
+
+    #[async_test]
+    async fn should_not_vote_when_rpc_is_unavailable() {
+        let mut rpc_client = MockStarknetClient::new();
+        rpc_client
+            .expect_transaction_receipts()
+            .returning(|_| Err(report!(ProviderError::CustomError("failed".to_string()))));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            rpc_client,
+            Finality::default(),
+            watch::channel(0).1,
+        );
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::TxReceipts
+        ));
+    }
+
+    #[async_test]
+    async fn should_skip_expired_poll() {
+        let mut rpc_client = MockStarknetClient::new();
+        // mock the rpc client as erroring. If the handler successfully ignores the poll, we won't hit this
+        rpc_client
+            .expect_transaction_receipts()
+            .returning(|_| Err(report!(ProviderError::CustomError("failed".to_string()))));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let expiration = 100u64;
+        let event: Event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), expiration),
+            &voting_verifier,
+        );
+
+        let (tx, rx) = watch::channel(expiration - 1);
+
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            rpc_client,
+            Finality::default(),
+            rx,
+        );
+
+        // poll is not expired yet, should hit rpc error
+        assert!(handler.handle(&event).await.is_err());
+
+        let _ = tx.send(expiration + 1);
+
+        // poll is expired, should not hit rpc error now
+        assert_eq!(handler.handle(&event).await.unwrap(), vec![]);
+    }
+
+    fn random_felt() -> String {
+        format!("0x00{}", hex::encode(&Hash::random().as_bytes()[1..]))
+    }
+
+    fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
+        PollStarted::Messages {
+            metadata: PollMetadata {
+                poll_id: "100".parse().unwrap(),
+                source_chain: "starknet".parse().unwrap(),
+                source_gateway_address: random_felt().parse().unwrap(),
+                confirmation_height: 15,
+                expires_at,
+                participants: participants
+                    .into_iter()
+                    .map(|addr| cosmwasm_std::Addr::unchecked(addr.to_string()))
+                    .collect(),
+            },
+            messages: vec![TxEventConfirmation {
+                tx_id: random_felt().parse().unwrap(),
+                event_index: 0,
+                source_address: random_felt().parse().unwrap(),
+                destination_chain: "ethereum".parse().unwrap(),
+                destination_address: format!("0x{:x}", EVMAddress::random()).parse().unwrap(),
+                payload_hash: Hash::random().to_fixed_bytes(),
+            }],
+        }
+    }
+}
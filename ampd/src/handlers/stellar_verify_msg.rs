@@ -21,10 +21,11 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
-use crate::stellar::http_client::Client;
 use crate::stellar::verifier::verify_message;
+use crate::stellar::StellarClient;
 use crate::types::TMAddress;
 
 pub fn deserialize_tx_id<'de, D>(deserializer: D) -> Result<String, D::Error>
@@ -65,24 +66,30 @@ struct PollStartedEvent {
     participants: Vec<TMAddress>,
 }
 
-pub struct Handler {
+pub struct Handler<C>
+where
+    C: StellarClient,
+{
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
-    http_client: Client,
+    client: C,
     latest_block_height: Receiver<u64>,
 }
 
-impl Handler {
+impl<C> Handler<C>
+where
+    C: StellarClient + Send + Sync,
+{
     pub fn new(
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
-        http_client: Client,
+        client: C,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
             verifier,
             voting_verifier_contract,
-            http_client,
+            client,
             latest_block_height,
         }
     }
@@ -99,7 +106,10 @@ impl Handler {
 }
 
 #[async_trait]
-impl EventHandler for Handler {
+impl<C> EventHandler for Handler<C>
+where
+    C: StellarClient + Send + Sync,
+{
     type Err = Error;
 
     async fn handle(&self, event: &Event) -> error_stack::Result<Vec<Any>, Self::Err> {
@@ -136,7 +146,7 @@ impl EventHandler for Handler {
             .collect();
 
         let transaction_responses = self
-            .http_client
+            .client
             .transaction_responses(tx_hashes)
             .await
             .change_context(Error::TxReceipts)?;
@@ -178,6 +188,14 @@ impl EventHandler for Handler {
             .into_any()
             .expect("vote msg should serialize")])
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-messages_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -187,7 +205,7 @@ mod tests {
 
     use cosmrs::cosmwasm::MsgExecuteContract;
     use cosmrs::tx::Msg;
-    use error_stack::Result;
+    use error_stack::{report, Result};
     use events::Error::{DeserializationFailed, EventTypeMismatch};
     use events::Event;
     use stellar_xdr::curr::ScAddress;
@@ -197,7 +215,9 @@ mod tests {
 
     use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
+    use crate::handlers::errors::Error;
     use crate::handlers::tests::{into_structured_event, participants};
+    use crate::stellar::error::Error as StellarError;
     use crate::stellar::http_client::Client;
     use crate::types::{EVMAddress, Hash, TMAddress};
     use crate::PREFIX;
@@ -310,6 +330,26 @@ mod tests {
         assert!(MsgExecuteContract::from_any(actual.first().unwrap()).is_ok());
     }
 
+    #[async_test]
+    async fn should_not_vote_when_horizon_is_unavailable() {
+        let mut client = Client::faux();
+        faux::when!(client.transaction_responses).then(|_| Err(report!(StellarError::TxResponse)));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(verifier, voting_verifier, client, watch::channel(0).1);
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::TxReceipts
+        ));
+    }
+
     fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
         PollStarted::Messages {
             metadata: PollMetadata {
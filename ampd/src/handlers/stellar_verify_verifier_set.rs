@@ -20,10 +20,11 @@ use voting_verifier::msg::ExecuteMsg;
 
 use super::stellar_verify_msg::deserialize_tx_id;
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
 use crate::handlers::errors::Error::DeserializeEvent;
-use crate::stellar::http_client::Client;
 use crate::stellar::verifier::verify_verifier_set;
+use crate::stellar::StellarClient;
 use crate::types::TMAddress;
 
 #[derive(Deserialize, Debug)]
@@ -46,24 +47,30 @@ struct PollStartedEvent {
     expires_at: u64,
 }
 
-pub struct Handler {
+pub struct Handler<C>
+where
+    C: StellarClient,
+{
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
-    http_client: Client,
+    client: C,
     latest_block_height: Receiver<u64>,
 }
 
-impl Handler {
+impl<C> Handler<C>
+where
+    C: StellarClient + Send + Sync,
+{
     pub fn new(
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
-        http_client: Client,
+        client: C,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
             verifier,
             voting_verifier_contract,
-            http_client,
+            client,
             latest_block_height,
         }
     }
@@ -80,7 +87,10 @@ impl Handler {
 }
 
 #[async_trait]
-impl EventHandler for Handler {
+impl<C> EventHandler for Handler<C>
+where
+    C: StellarClient + Send + Sync,
+{
     type Err = Error;
 
     async fn handle(&self, event: &Event) -> error_stack::Result<Vec<Any>, Self::Err> {
@@ -111,7 +121,7 @@ impl EventHandler for Handler {
         }
 
         let transaction_response = self
-            .http_client
+            .client
             .transaction_response(verifier_set.tx_id.clone())
             .await
             .change_context(Error::TxReceipts)?;
@@ -141,6 +151,14 @@ impl EventHandler for Handler {
             .into_any()
             .expect("vote msg should serialize")])
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-verifier_set_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -149,7 +167,7 @@ mod tests {
 
     use cosmrs::cosmwasm::MsgExecuteContract;
     use cosmrs::tx::Msg;
-    use error_stack::Result;
+    use error_stack::{report, Result};
     use events::Error::{DeserializationFailed, EventTypeMismatch};
     use events::Event;
     use multisig::key::KeyType;
@@ -161,7 +179,9 @@ mod tests {
 
     use super::PollStartedEvent;
     use crate::event_processor::EventHandler;
+    use crate::handlers::errors::Error;
     use crate::handlers::tests::{into_structured_event, participants};
+    use crate::stellar::error::Error as StellarError;
     use crate::stellar::http_client::Client;
     use crate::types::{Hash, TMAddress};
     use crate::PREFIX;
@@ -274,6 +294,26 @@ mod tests {
         assert!(MsgExecuteContract::from_any(actual.first().unwrap()).is_ok());
     }
 
+    #[async_test]
+    async fn should_not_vote_when_horizon_is_unavailable() {
+        let mut client = Client::faux();
+        faux::when!(client.transaction_response).then(|_| Err(report!(StellarError::TxResponse)));
+
+        let voting_verifier = TMAddress::random(PREFIX);
+        let verifier = TMAddress::random(PREFIX);
+        let event = into_structured_event(
+            poll_started_event(participants(5, Some(verifier.clone())), 100),
+            &voting_verifier,
+        );
+
+        let handler = super::Handler::new(verifier, voting_verifier, client, watch::channel(0).1);
+
+        assert!(matches!(
+            handler.handle(&event).await.unwrap_err().current_context(),
+            Error::TxReceipts
+        ));
+    }
+
     fn poll_started_event(participants: Vec<TMAddress>, expires_at: u64) -> PollStarted {
         PollStarted::VerifierSet {
             metadata: PollMetadata {
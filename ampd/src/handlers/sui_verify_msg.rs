@@ -17,7 +17,9 @@ use tracing::info;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
+use crate::sui::finality::finalized_transaction_blocks;
 use crate::sui::json_rpc::SuiClient;
 use crate::sui::verifier::verify_message;
 use crate::types::{Hash, TMAddress};
@@ -51,6 +53,8 @@ where
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
     rpc_client: C,
+    cross_check_client: Option<C>,
+    confirmation_checkpoints: u64,
     latest_block_height: Receiver<u64>,
 }
 
@@ -62,12 +66,16 @@ where
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         rpc_client: C,
+        cross_check_client: Option<C>,
+        confirmation_checkpoints: u64,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
             verifier,
             voting_verifier_contract,
             rpc_client,
+            cross_check_client,
+            confirmation_checkpoints,
             latest_block_height,
         }
     }
@@ -128,6 +136,14 @@ where
             .finalized_transaction_blocks(deduplicated_tx_ids)
             .await
             .change_context(Error::TxReceipts)?;
+        let transaction_blocks = finalized_transaction_blocks(
+            &self.rpc_client,
+            self.cross_check_client.as_ref(),
+            self.confirmation_checkpoints,
+            transaction_blocks,
+        )
+        .await
+        .change_context(Error::Finalizer)?;
 
         let votes = messages
             .iter()
@@ -145,6 +161,14 @@ where
             .into_any()
             .expect("vote msg should serialize")])
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-messages_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -195,6 +219,8 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockSuiClient::new(),
+            None,
+            0,
             watch::channel(0).1,
         );
 
@@ -213,6 +239,8 @@ mod tests {
             TMAddress::random(PREFIX),
             TMAddress::random(PREFIX),
             MockSuiClient::new(),
+            None,
+            0,
             watch::channel(0).1,
         );
 
@@ -232,6 +260,8 @@ mod tests {
             TMAddress::random(PREFIX),
             voting_verifier,
             MockSuiClient::new(),
+            None,
+            0,
             watch::channel(0).1,
         );
 
@@ -257,8 +287,14 @@ mod tests {
             &voting_verifier,
         );
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, watch::channel(0).1);
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            rpc_client,
+            None,
+            0,
+            watch::channel(0).1,
+        );
 
         assert!(matches!(
             *handler.handle(&event).await.unwrap_err().current_context(),
@@ -280,8 +316,14 @@ mod tests {
             &voting_verifier,
         );
 
-        let handler =
-            super::Handler::new(verifier, voting_verifier, rpc_client, watch::channel(0).1);
+        let handler = super::Handler::new(
+            verifier,
+            voting_verifier,
+            rpc_client,
+            None,
+            0,
+            watch::channel(0).1,
+        );
 
         let actual = handler.handle(&event).await.unwrap();
         assert_eq!(actual.len(), 1);
@@ -310,7 +352,7 @@ mod tests {
 
         let (tx, rx) = watch::channel(expiration - 1);
 
-        let handler = super::Handler::new(verifier, voting_verifier, rpc_client, rx);
+        let handler = super::Handler::new(verifier, voting_verifier, rpc_client, None, 0, rx);
 
         // poll is not expired yet, should hit rpc error
         assert!(handler.handle(&event).await.is_err());
@@ -19,7 +19,9 @@ use valuable::Valuable;
 use voting_verifier::msg::ExecuteMsg;
 
 use crate::event_processor::EventHandler;
+use crate::event_sub::filter::{EventFilter, EventFilters};
 use crate::handlers::errors::Error;
+use crate::sui::finality::finalized_transaction_blocks;
 use crate::sui::json_rpc::SuiClient;
 use crate::sui::verifier::verify_verifier_set;
 use crate::types::TMAddress;
@@ -48,6 +50,8 @@ where
     verifier: TMAddress,
     voting_verifier_contract: TMAddress,
     rpc_client: C,
+    cross_check_client: Option<C>,
+    confirmation_checkpoints: u64,
     latest_block_height: Receiver<u64>,
 }
 
@@ -59,12 +63,16 @@ where
         verifier: TMAddress,
         voting_verifier_contract: TMAddress,
         rpc_client: C,
+        cross_check_client: Option<C>,
+        confirmation_checkpoints: u64,
         latest_block_height: Receiver<u64>,
     ) -> Self {
         Self {
             verifier,
             voting_verifier_contract,
             rpc_client,
+            cross_check_client,
+            confirmation_checkpoints,
             latest_block_height,
         }
     }
@@ -124,6 +132,18 @@ where
             .finalized_transaction_block(verifier_set.tx_id)
             .await
             .change_context(Error::TxReceipts)?;
+        let transaction_block = finalized_transaction_blocks(
+            &self.rpc_client,
+            self.cross_check_client.as_ref(),
+            self.confirmation_checkpoints,
+            transaction_block
+                .map(|block| (verifier_set.tx_id, block))
+                .into_iter()
+                .collect(),
+        )
+        .await
+        .change_context(Error::Finalizer)?
+        .remove(&verifier_set.tx_id);
 
         let vote = info_span!(
             "verify a new verifier set for Sui",
@@ -149,6 +169,14 @@ where
             .into_any()
             .expect("vote msg should serialize")])
     }
+
+    fn event_filters(&self) -> EventFilters {
+        EventFilters::new(
+            vec![EventFilter::new("wasm-verifier_set_poll_started")
+                .with_contract(&self.voting_verifier_contract)],
+            false,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -210,7 +238,7 @@ mod tests {
 
         let (tx, rx) = watch::channel(expiration - 1);
 
-        let handler = super::Handler::new(verifier, voting_verifier, rpc_client, rx);
+        let handler = super::Handler::new(verifier, voting_verifier, rpc_client, None, 0, rx);
 
         // poll is not expired yet, should hit rpc error
         assert!(handler.handle(&event).await.is_err());
@@ -1,5 +1,7 @@
+use std::collections::BTreeMap;
 use std::net::SocketAddrV4;
 
+use axum::extract::State;
 use axum::http::StatusCode;
 use axum::routing::get;
 use axum::{Json, Router};
@@ -9,6 +11,8 @@ use thiserror::Error;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
+use crate::cache;
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("failed to start the health check server")]
@@ -19,11 +23,15 @@ pub enum Error {
 
 pub struct Server {
     bind_address: SocketAddrV4,
+    cache_metrics: cache::Metrics,
 }
 
 impl Server {
-    pub fn new(bind_address: SocketAddrV4) -> Self {
-        Self { bind_address }
+    pub fn new(bind_address: SocketAddrV4, cache_metrics: cache::Metrics) -> Self {
+        Self {
+            bind_address,
+            cache_metrics,
+        }
     }
 
     pub async fn run(self, cancel: CancellationToken) -> Result<(), Error> {
@@ -36,7 +44,10 @@ impl Server {
             "starting health check server"
         );
 
-        let app = Router::new().route("/status", get(status));
+        let app = Router::new()
+            .route("/status", get(status))
+            .route("/metrics", get(metrics))
+            .with_state(self.cache_metrics);
         axum::serve(listener, app)
             .with_graceful_shutdown(async move {
                 cancel.cancelled().await;
@@ -57,6 +68,20 @@ struct Status {
     ok: bool,
 }
 
+async fn metrics(State(cache_metrics): State<cache::Metrics>) -> (StatusCode, Json<Metrics>) {
+    (
+        StatusCode::OK,
+        Json(Metrics {
+            rpc_cache: cache_metrics.snapshot(),
+        }),
+    )
+}
+
+#[derive(Serialize, Deserialize)]
+struct Metrics {
+    rpc_cache: BTreeMap<String, cache::Stats>,
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -71,7 +96,7 @@ mod tests {
     async fn server_lifecycle() {
         let bind_address = test_bind_addr();
 
-        let server = Server::new(bind_address);
+        let server = Server::new(bind_address, cache::Metrics::default());
 
         let cancel = CancellationToken::new();
 
@@ -87,6 +112,14 @@ mod tests {
         let status = response.json::<Status>().await.unwrap();
         assert!(status.ok);
 
+        let response = reqwest::get(format!("http://{}/metrics", bind_address))
+            .await
+            .unwrap();
+        assert_eq!(reqwest::StatusCode::OK, response.status());
+
+        let metrics = response.json::<Metrics>().await.unwrap();
+        assert!(metrics.rpc_cache.is_empty());
+
         cancel.cancel();
 
         tokio::time::sleep(Duration::from_millis(100)).await;
@@ -1,9 +1,13 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::num::NonZeroUsize;
 
+use async_trait::async_trait;
 use error_stack::Report;
-use ethers_providers::{Http, JsonRpcClient, ProviderError};
+use ethers_providers::{Http, HttpClientError, JsonRpcClient, ProviderError};
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 
 use crate::url::Url;
 
@@ -37,8 +41,311 @@ where
     }
 }
 
+impl<P> Client<P>
+where
+    P: BatchJsonRpcClient,
+{
+    pub fn max_batch_size(&self) -> NonZeroUsize {
+        self.provider.max_batch_size()
+    }
+
+    /// Sends one request per entry of `params` in a single batch.
+    /// The responses are returned in the same order as the `params`.
+    pub async fn batch_request<T, R>(&self, method: &str, params: Vec<T>) -> Result<Vec<R>>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let params = params
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(ProviderError::from)?;
+
+        self.provider
+            .batch_request(method, params)
+            .await?
+            .into_iter()
+            .map(|result| serde_json::from_value(result).map_err(ProviderError::from))
+            .collect::<std::result::Result<_, _>>()
+            .map_err(Report::from)
+    }
+}
+
 impl Client<Http> {
     pub fn new_http(url: &Url, client: reqwest::Client) -> Self {
         Client::new(Http::new_with_client(url, client))
     }
 }
+
+impl Client<BatchHttp> {
+    pub fn new_batch_http(
+        url: &Url,
+        client: reqwest::Client,
+        max_batch_size: NonZeroUsize,
+    ) -> Self {
+        Client::new(BatchHttp {
+            http: Http::new_with_client(url, client.clone()),
+            client,
+            url: url.clone(),
+            max_batch_size,
+        })
+    }
+}
+
+#[async_trait]
+pub trait BatchJsonRpcClient: JsonRpcClient {
+    fn max_batch_size(&self) -> NonZeroUsize;
+
+    /// Fails if the provider rejects the batch or any of the requests in it
+    async fn batch_request(&self, method: &str, params: Vec<Value>) -> Result<Vec<Value>>;
+}
+
+/// An HTTP provider that supports sending JSON-RPC requests in batches
+#[derive(Debug)]
+pub struct BatchHttp {
+    http: Http,
+    client: reqwest::Client,
+    url: Url,
+    max_batch_size: NonZeroUsize,
+}
+
+#[async_trait]
+impl JsonRpcClient for BatchHttp {
+    type Error = HttpClientError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> std::result::Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        self.http.request(method, params).await
+    }
+}
+
+#[derive(Deserialize)]
+struct BatchResponse {
+    id: usize,
+    result: Option<Value>,
+    error: Option<Value>,
+}
+
+#[async_trait]
+impl BatchJsonRpcClient for BatchHttp {
+    fn max_batch_size(&self) -> NonZeroUsize {
+        self.max_batch_size
+    }
+
+    async fn batch_request(&self, method: &str, params: Vec<Value>) -> Result<Vec<Value>> {
+        let request_count = params.len();
+        let requests: Vec<_> = params
+            .into_iter()
+            .enumerate()
+            .map(|(id, params)| {
+                json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+            })
+            .collect();
+
+        let body = self
+            .client
+            .post(self.url.as_str())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&requests).map_err(ProviderError::from)?)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(ProviderError::from)?
+            .bytes()
+            .await
+            .map_err(ProviderError::from)?;
+
+        batch_results(&body, request_count)
+    }
+}
+
+/// Returns the results of a batch response in the order of the request ids, which run from 0 to `request_count`
+fn batch_results(body: &[u8], request_count: usize) -> Result<Vec<Value>> {
+    // providers that do not support batching respond with a single error object instead of an array
+    let mut results: HashMap<_, _> = serde_json::from_slice::<Vec<BatchResponse>>(body)
+        .map_err(ProviderError::from)?
+        .into_iter()
+        .map(|response| match (response.result, response.error) {
+            (_, Some(error)) => Err(ProviderError::CustomError(error.to_string())),
+            (result, None) => Ok((response.id, result.unwrap_or(Value::Null))),
+        })
+        .collect::<std::result::Result<_, _>>()?;
+
+    (0..request_count)
+        .map(|id| {
+            results.remove(&id).ok_or_else(|| {
+                Report::new(ProviderError::CustomError(format!(
+                    "missing response for request {} in batch",
+                    id
+                )))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod test_utils {
+    use std::collections::VecDeque;
+    use std::fmt::Debug;
+    use std::num::NonZeroUsize;
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use error_stack::Report;
+    use ethers_providers::{HttpClientError, JsonRpcClient, JsonRpcError, ProviderError};
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use serde_json::Value;
+
+    use crate::json_rpc::{BatchJsonRpcClient, Result as BatchResult};
+
+    /// Responds with the queued responses in the order they were pushed and records all requests.
+    /// A batch request consumes a single response, which must be an array with one result per request.
+    /// Clones share the same queues, so a clone can be used to inspect the requests of the provider passed to a client
+    #[derive(Debug, Default, Clone)]
+    pub struct MockProvider {
+        requests: Arc<Mutex<Vec<(String, Value)>>>,
+        responses: Arc<Mutex<VecDeque<Result<Value, JsonRpcError>>>>,
+    }
+
+    impl MockProvider {
+        pub fn push(&self, result: impl Serialize) {
+            self.responses
+                .lock()
+                .unwrap()
+                .push_back(Ok(serde_json::to_value(result).unwrap()));
+        }
+
+        pub fn push_error(&self, code: i64, message: &str) {
+            self.responses.lock().unwrap().push_back(Err(JsonRpcError {
+                code,
+                message: message.to_string(),
+                data: None,
+            }));
+        }
+
+        pub fn requests(&self) -> Vec<(String, Value)> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        fn next_response(&self, method: &str, params: Value) -> Result<Value, JsonRpcError> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((method.to_string(), params));
+
+            self.responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("no response queued")
+        }
+    }
+
+    #[async_trait]
+    impl JsonRpcClient for MockProvider {
+        type Error = HttpClientError;
+
+        async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+        where
+            T: Debug + Serialize + Send + Sync,
+            R: DeserializeOwned + Send,
+        {
+            let response = self.next_response(method, serde_json::to_value(params).unwrap())?;
+
+            serde_json::from_value(response.clone()).map_err(|err| HttpClientError::SerdeJson {
+                err,
+                text: response.to_string(),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl BatchJsonRpcClient for MockProvider {
+        // small enough for tests to cover requests that are split into several batches
+        fn max_batch_size(&self) -> NonZeroUsize {
+            NonZeroUsize::new(2).unwrap()
+        }
+
+        async fn batch_request(&self, method: &str, params: Vec<Value>) -> BatchResult<Vec<Value>> {
+            let response = self
+                .next_response(method, Value::Array(params))
+                .map_err(HttpClientError::from)
+                .map_err(ProviderError::from)?;
+
+            serde_json::from_value(response)
+                .map_err(ProviderError::from)
+                .map_err(Report::from)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::json_rpc::batch_results;
+
+    #[test]
+    fn should_return_batch_results_in_request_order() {
+        let body = json!([
+            { "jsonrpc": "2.0", "id": 0, "result": "first" },
+            { "jsonrpc": "2.0", "id": 1, "result": null },
+            { "jsonrpc": "2.0", "id": 2, "result": { "value": 3 } },
+        ]);
+
+        assert_eq!(
+            batch_results(body.to_string().as_bytes(), 3).unwrap(),
+            vec![json!("first"), json!(null), json!({ "value": 3 })]
+        );
+    }
+
+    #[test]
+    fn should_reorder_out_of_order_batch_results() {
+        let body = json!([
+            { "jsonrpc": "2.0", "id": 2, "result": "third" },
+            { "jsonrpc": "2.0", "id": 0, "result": "first" },
+            { "jsonrpc": "2.0", "id": 1, "result": "second" },
+        ]);
+
+        assert_eq!(
+            batch_results(body.to_string().as_bytes(), 3).unwrap(),
+            vec![json!("first"), json!("second"), json!("third")]
+        );
+    }
+
+    #[test]
+    fn should_fail_if_batch_result_is_missing() {
+        let body = json!([
+            { "jsonrpc": "2.0", "id": 0, "result": "first" },
+            { "jsonrpc": "2.0", "id": 2, "result": "third" },
+        ]);
+
+        assert!(batch_results(body.to_string().as_bytes(), 3).is_err());
+    }
+
+    #[test]
+    fn should_fail_if_any_batch_request_fails() {
+        let body = json!([
+            { "jsonrpc": "2.0", "id": 0, "result": "first" },
+            { "jsonrpc": "2.0", "id": 1, "error": { "code": -32000, "message": "failed" } },
+        ]);
+
+        assert!(batch_results(body.to_string().as_bytes(), 2).is_err());
+    }
+
+    #[test]
+    fn should_fail_if_provider_does_not_support_batches() {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": null,
+            "error": { "code": -32600, "message": "batch requests are not supported" },
+        });
+
+        assert!(batch_results(body.to_string().as_bytes(), 1).is_err());
+    }
+}
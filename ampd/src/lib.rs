@@ -1,3 +1,5 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::time::Duration;
 
 use asyncutil::task::{CancellableTask, TaskError, TaskGroup};
@@ -7,13 +9,16 @@ use cosmrs::proto::cosmos::auth::v1beta1::query_client::QueryClient as AuthQuery
 use cosmrs::proto::cosmos::bank::v1beta1::query_client::QueryClient as BankQueryClient;
 use cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient;
 use error_stack::{FutureExt, Result, ResultExt};
+use ethers_providers::Http;
 use event_processor::EventHandler;
 use event_sub::EventSub;
 use evm::finalizer::{pick, Finalization};
 use evm::json_rpc::EthereumClient;
-use multiversx_sdk::blockchain::CommunicationProxy;
 use queue::queued_broadcaster::QueuedBroadcaster;
 use router_api::ChainName;
+use stellar::StellarClient;
+use tendermint_rpc::client::CompatMode;
+use tendermint_rpc::Client;
 use thiserror::Error;
 use tofnd::grpc::{Multisig, MultisigClient};
 use tokio::signal::unix::{signal, SignalKind};
@@ -29,8 +34,10 @@ use crate::config::Config;
 mod asyncutil;
 mod block_height_monitor;
 mod broadcaster;
+mod cache;
 pub mod commands;
 pub mod config;
+mod cosmos;
 mod event_processor;
 mod event_sub;
 mod evm;
@@ -40,21 +47,27 @@ mod health_check;
 mod json_rpc;
 mod mvx;
 mod queue;
+mod starknet;
 mod stellar;
 mod sui;
 mod tm_client;
 mod tofnd;
 mod types;
 mod url;
+mod xrpl;
 
 pub use grpc::{client, proto};
 
 use crate::asyncutil::future::RetryPolicy;
 use crate::broadcaster::confirm_tx::TxConfirmer;
+use crate::cache::Cache;
+use crate::url::Url;
 
 const PREFIX: &str = "axelar";
 const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(3);
 
+type EvmClient = evm::json_rpc::CachedClient<json_rpc::Client<json_rpc::BatchHttp>>;
+
 pub async fn run(cfg: Config) -> Result<(), Error> {
     prepare_app(cfg).await?.run().await
 }
@@ -70,6 +83,7 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         service_registry: _service_registry,
         rewards: _rewards,
         health_check_bind_addr,
+        rpc_cache,
     } = cfg;
 
     let tm_client = tendermint_rpc::HttpClient::new(tm_jsonrpc.to_string().as_str())
@@ -130,7 +144,9 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         },
     );
 
-    let health_check_server = health_check::Server::new(health_check_bind_addr);
+    let cache_metrics = cache::Metrics::default();
+    let health_check_server =
+        health_check::Server::new(health_check_bind_addr, cache_metrics.clone());
 
     let verifier: TMAddress = pub_key
         .account_id(PREFIX)
@@ -146,7 +162,13 @@ async fn prepare_app(cfg: Config) -> Result<App<impl Broadcaster>, Error> {
         block_height_monitor,
         health_check_server,
     )
-    .configure_handlers(verifier, handlers, event_processor)
+    .configure_handlers(
+        verifier,
+        handlers,
+        event_processor,
+        rpc_cache,
+        cache_metrics,
+    )
     .await
 }
 
@@ -154,11 +176,12 @@ async fn check_finalizer<'a, C>(
     chain_name: &ChainName,
     finalization: &Finalization,
     rpc_client: &'a C,
+    l1_rpc_client: Option<&'a C>,
 ) -> Result<(), Error>
 where
     C: EthereumClient + Send + Sync,
 {
-    let _ = pick(finalization, rpc_client, 0)
+    let _ = pick(finalization, rpc_client, l1_rpc_client, 0)
         .latest_finalized_block_height()
         .await
         .change_context_lazy(|| Error::InvalidFinalizerType(chain_name.to_owned()))?;
@@ -166,6 +189,162 @@ where
     Ok(())
 }
 
+/// Returns the chain's RPC client and, if its finalization relies on the L1, the L1 RPC client,
+/// after checking that the finalizer can determine the latest finalized block with them
+async fn evm_clients_with_l1(
+    clients: &mut HashMap<(ChainName, Url, Option<Duration>), EvmClient>,
+    chain: &handlers::config::Chain,
+    rpc_timeout: Option<Duration>,
+    cache_config: &cache::Config,
+    cache_metrics: &cache::Metrics,
+) -> Result<(EvmClient, Option<EvmClient>), Error> {
+    let rpc_client = cached_evm_client(
+        clients,
+        chain,
+        &chain.rpc_url,
+        rpc_timeout,
+        cache_config,
+        cache_metrics,
+    )?;
+    let l1_rpc_client = chain
+        .finalization
+        .l1_rpc_url()
+        .map(|l1_rpc_url| {
+            cached_evm_client(
+                clients,
+                chain,
+                l1_rpc_url,
+                rpc_timeout,
+                cache_config,
+                cache_metrics,
+            )
+        })
+        .transpose()?;
+
+    check_finalizer(
+        &chain.name,
+        &chain.finalization,
+        &rpc_client,
+        l1_rpc_client.as_ref(),
+    )
+    .await?;
+
+    Ok((rpc_client, l1_rpc_client))
+}
+
+/// Clients are only shared if they are configured with the same timeout
+fn cached_evm_client(
+    clients: &mut HashMap<(ChainName, Url, Option<Duration>), EvmClient>,
+    chain: &handlers::config::Chain,
+    rpc_url: &Url,
+    rpc_timeout: Option<Duration>,
+    cache_config: &cache::Config,
+    cache_metrics: &cache::Metrics,
+) -> Result<EvmClient, Error> {
+    match clients.entry((chain.name.clone(), rpc_url.clone(), rpc_timeout)) {
+        Entry::Occupied(entry) => Ok(entry.get().clone()),
+        Entry::Vacant(entry) => {
+            let rpc_client = json_rpc::Client::new_batch_http(
+                rpc_url,
+                http_client(rpc_timeout)?,
+                chain.rpc_batch_size,
+            );
+
+            Ok(entry
+                .insert(evm::json_rpc::CachedClient::new(
+                    rpc_client,
+                    &chain.name,
+                    cache_config.clone(),
+                    cache_metrics,
+                ))
+                .clone())
+        }
+    }
+}
+
+/// Clients are only shared if they are configured with the same timeout
+fn cached_sui_client(
+    clients: &mut HashMap<
+        (Url, Option<Duration>),
+        sui::json_rpc::CachedClient<json_rpc::Client<Http>>,
+    >,
+    rpc_url: &Url,
+    rpc_timeout: Option<Duration>,
+    cache_config: &cache::Config,
+    cache_metrics: &cache::Metrics,
+) -> Result<sui::json_rpc::CachedClient<json_rpc::Client<Http>>, Error> {
+    match clients.entry((rpc_url.clone(), rpc_timeout)) {
+        Entry::Occupied(entry) => Ok(entry.get().clone()),
+        Entry::Vacant(entry) => {
+            let rpc_client = json_rpc::Client::new_http(rpc_url, http_client(rpc_timeout)?);
+
+            Ok(entry
+                .insert(sui::json_rpc::CachedClient::new(
+                    rpc_client,
+                    cache_config.clone(),
+                    cache_metrics,
+                ))
+                .clone())
+        }
+    }
+}
+
+fn stellar_client(
+    api_type: stellar::ApiType,
+    http_url: &Url,
+    confirmation_ledgers: u32,
+    caches: &mut HashMap<Url, Cache<String, stellar::http_client::TxResponse>>,
+    cache_config: &cache::Config,
+    cache_metrics: &cache::Metrics,
+) -> Result<Box<dyn StellarClient + Send + Sync>, Error> {
+    let cache = caches
+        .entry(http_url.clone())
+        .or_insert_with(|| Cache::new("stellar-transactions", cache_config.clone(), cache_metrics))
+        .clone();
+
+    match api_type {
+        stellar::ApiType::Horizon => Ok(Box::new(
+            stellar::http_client::Client::new(
+                http_url.to_string().trim_end_matches('/').into(),
+                cache,
+            )
+            .change_context(Error::Connection)?,
+        )),
+        stellar::ApiType::SorobanRpc => Ok(Box::new(stellar::rpc_client::Client::new(
+            http_url,
+            http_client(None)?,
+            cache,
+            confirmation_ledgers,
+        ))),
+    }
+}
+
+/// CometBFT changed the encoding of event attributes in v0.37, so the client must speak the RPC dialect of the node
+async fn cosmos_client(rpc_url: &Url) -> Result<tendermint_rpc::HttpClient, Error> {
+    let node_version = tendermint_rpc::HttpClient::new(rpc_url.as_str())
+        .change_context(Error::Connection)?
+        .status()
+        .await
+        .change_context(Error::Connection)
+        .attach_printable(rpc_url.clone())?
+        .node_info
+        .version;
+    let compat_mode = CompatMode::from_version(node_version).change_context(Error::Connection)?;
+
+    tendermint_rpc::HttpClient::builder(rpc_url.as_str().parse().change_context(Error::Connection)?)
+        .compat_mode(compat_mode)
+        .build()
+        .change_context(Error::Connection)
+}
+
+fn http_client(rpc_timeout: Option<Duration>) -> Result<reqwest::Client, Error> {
+    reqwest::ClientBuilder::new()
+        .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+        .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
+        .build()
+        .change_context(Error::Connection)
+}
+
 struct App<T>
 where
     T: Broadcaster,
@@ -216,24 +395,30 @@ where
         verifier: TMAddress,
         handler_configs: Vec<handlers::config::Config>,
         event_processor_config: event_processor::Config,
+        rpc_cache: cache::Config,
+        cache_metrics: cache::Metrics,
     ) -> Result<App<T>, Error> {
+        // handlers connecting to the same RPC endpoint share a client so they can share its cached responses
+        let mut evm_clients = HashMap::new();
+        let mut sui_clients = HashMap::new();
+        let mut stellar_caches = HashMap::new();
+
         for config in handler_configs {
             let task = match config {
                 handlers::config::Config::EvmMsgVerifier {
                     chain,
                     cosmwasm_contract,
                     rpc_timeout,
+                    max_concurrent_events,
                 } => {
-                    let rpc_client = json_rpc::Client::new_http(
-                        &chain.rpc_url,
-                        reqwest::ClientBuilder::new()
-                            .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                            .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                            .build()
-                            .change_context(Error::Connection)?,
-                    );
-
-                    check_finalizer(&chain.name, &chain.finalization, &rpc_client).await?;
+                    let (rpc_client, l1_rpc_client) = evm_clients_with_l1(
+                        &mut evm_clients,
+                        &chain,
+                        rpc_timeout,
+                        &rpc_cache,
+                        &cache_metrics,
+                    )
+                    .await?;
 
                     self.create_handler_task(
                         format!("{}-msg-verifier", chain.name),
@@ -243,26 +428,26 @@ where
                             chain.name,
                             chain.finalization,
                             rpc_client,
+                            l1_rpc_client,
                             self.block_height_monitor.latest_block_height(),
                         ),
-                        event_processor_config.clone(),
+                        event_processor_config.with_max_concurrent_events(max_concurrent_events),
                     )
                 }
                 handlers::config::Config::EvmVerifierSetVerifier {
                     chain,
                     cosmwasm_contract,
                     rpc_timeout,
+                    max_concurrent_events,
                 } => {
-                    let rpc_client = json_rpc::Client::new_http(
-                        &chain.rpc_url,
-                        reqwest::ClientBuilder::new()
-                            .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                            .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                            .build()
-                            .change_context(Error::Connection)?,
-                    );
-
-                    check_finalizer(&chain.name, &chain.finalization, &rpc_client).await?;
+                    let (rpc_client, l1_rpc_client) = evm_clients_with_l1(
+                        &mut evm_clients,
+                        &chain,
+                        rpc_timeout,
+                        &rpc_cache,
+                        &cache_metrics,
+                    )
+                    .await?;
 
                     self.create_handler_task(
                         format!("{}-verifier-set-verifier", chain.name),
@@ -272,121 +457,263 @@ where
                             chain.name,
                             chain.finalization,
                             rpc_client,
+                            l1_rpc_client,
                             self.block_height_monitor.latest_block_height(),
                         ),
-                        event_processor_config.clone(),
+                        event_processor_config.with_max_concurrent_events(max_concurrent_events),
                     )
                 }
-                handlers::config::Config::MultisigSigner { cosmwasm_contract } => self
-                    .create_handler_task(
-                        "multisig-signer",
-                        handlers::multisig::Handler::new(
-                            verifier.clone(),
-                            cosmwasm_contract,
-                            self.multisig_client.clone(),
-                            self.block_height_monitor.latest_block_height(),
-                        ),
-                        event_processor_config.clone(),
+                handlers::config::Config::MultisigSigner {
+                    cosmwasm_contract,
+                    key_rotation,
+                    max_concurrent_events,
+                } => self.create_handler_task(
+                    "multisig-signer",
+                    handlers::multisig::Handler::new(
+                        verifier.clone(),
+                        cosmwasm_contract,
+                        key_rotation,
+                        self.multisig_client.clone(),
+                        self.block_height_monitor.latest_block_height(),
                     ),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
+                ),
                 handlers::config::Config::SuiMsgVerifier {
                     cosmwasm_contract,
                     rpc_url,
                     rpc_timeout,
+                    cross_check_rpc_url,
+                    confirmation_checkpoints,
+                    max_concurrent_events,
                 } => self.create_handler_task(
                     "sui-msg-verifier",
                     handlers::sui_verify_msg::Handler::new(
                         verifier.clone(),
                         cosmwasm_contract,
-                        json_rpc::Client::new_http(
+                        cached_sui_client(
+                            &mut sui_clients,
                             &rpc_url,
-                            reqwest::ClientBuilder::new()
-                                .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .build()
-                                .change_context(Error::Connection)?,
-                        ),
+                            rpc_timeout,
+                            &rpc_cache,
+                            &cache_metrics,
+                        )?,
+                        cross_check_rpc_url
+                            .map(|cross_check_rpc_url| {
+                                cached_sui_client(
+                                    &mut sui_clients,
+                                    &cross_check_rpc_url,
+                                    rpc_timeout,
+                                    &rpc_cache,
+                                    &cache_metrics,
+                                )
+                            })
+                            .transpose()?,
+                        confirmation_checkpoints,
                         self.block_height_monitor.latest_block_height(),
                     ),
-                    event_processor_config.clone(),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
                 ),
                 handlers::config::Config::SuiVerifierSetVerifier {
                     cosmwasm_contract,
                     rpc_url,
                     rpc_timeout,
+                    cross_check_rpc_url,
+                    confirmation_checkpoints,
+                    max_concurrent_events,
                 } => self.create_handler_task(
                     "sui-verifier-set-verifier",
                     handlers::sui_verify_verifier_set::Handler::new(
                         verifier.clone(),
                         cosmwasm_contract,
-                        json_rpc::Client::new_http(
+                        cached_sui_client(
+                            &mut sui_clients,
                             &rpc_url,
-                            reqwest::ClientBuilder::new()
-                                .connect_timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .timeout(rpc_timeout.unwrap_or(DEFAULT_RPC_TIMEOUT))
-                                .build()
-                                .change_context(Error::Connection)?,
-                        ),
+                            rpc_timeout,
+                            &rpc_cache,
+                            &cache_metrics,
+                        )?,
+                        cross_check_rpc_url
+                            .map(|cross_check_rpc_url| {
+                                cached_sui_client(
+                                    &mut sui_clients,
+                                    &cross_check_rpc_url,
+                                    rpc_timeout,
+                                    &rpc_cache,
+                                    &cache_metrics,
+                                )
+                            })
+                            .transpose()?,
+                        confirmation_checkpoints,
                         self.block_height_monitor.latest_block_height(),
                     ),
-                    event_processor_config.clone(),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
                 ),
                 handlers::config::Config::MvxMsgVerifier {
                     cosmwasm_contract,
                     proxy_url,
+                    finalization,
+                    confirmation_depth,
+                    max_concurrent_events,
                 } => self.create_handler_task(
                     "mvx-msg-verifier",
                     handlers::mvx_verify_msg::Handler::new(
                         verifier.clone(),
                         cosmwasm_contract,
-                        CommunicationProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
+                        mvx::proxy::Client::new(proxy_url.to_string().trim_end_matches('/').into()),
+                        finalization,
+                        confirmation_depth,
                         self.block_height_monitor.latest_block_height(),
                     ),
-                    event_processor_config.clone(),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
                 ),
                 handlers::config::Config::MvxVerifierSetVerifier {
                     cosmwasm_contract,
                     proxy_url,
+                    finalization,
+                    confirmation_depth,
+                    max_concurrent_events,
                 } => self.create_handler_task(
                     "mvx-worker-set-verifier",
                     handlers::mvx_verify_verifier_set::Handler::new(
                         verifier.clone(),
                         cosmwasm_contract,
-                        CommunicationProxy::new(proxy_url.to_string().trim_end_matches('/').into()),
+                        mvx::proxy::Client::new(proxy_url.to_string().trim_end_matches('/').into()),
+                        finalization,
+                        confirmation_depth,
                         self.block_height_monitor.latest_block_height(),
                     ),
-                    event_processor_config.clone(),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
                 ),
                 handlers::config::Config::StellarMsgVerifier {
                     cosmwasm_contract,
                     http_url,
+                    api_type,
+                    confirmation_ledgers,
+                    max_concurrent_events,
                 } => self.create_handler_task(
                     "stellar-msg-verifier",
                     handlers::stellar_verify_msg::Handler::new(
                         verifier.clone(),
                         cosmwasm_contract,
-                        stellar::http_client::Client::new(
-                            http_url.to_string().trim_end_matches('/').into(),
-                        )
-                        .change_context(Error::Connection)?,
+                        stellar_client(
+                            api_type,
+                            &http_url,
+                            confirmation_ledgers,
+                            &mut stellar_caches,
+                            &rpc_cache,
+                            &cache_metrics,
+                        )?,
                         self.block_height_monitor.latest_block_height(),
                     ),
-                    event_processor_config.clone(),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
                 ),
                 handlers::config::Config::StellarVerifierSetVerifier {
                     cosmwasm_contract,
                     http_url,
+                    api_type,
+                    confirmation_ledgers,
+                    max_concurrent_events,
                 } => self.create_handler_task(
                     "stellar-verifier-set-verifier",
                     handlers::stellar_verify_verifier_set::Handler::new(
                         verifier.clone(),
                         cosmwasm_contract,
-                        stellar::http_client::Client::new(
-                            http_url.to_string().trim_end_matches('/').into(),
-                        )
-                        .change_context(Error::Connection)?,
+                        stellar_client(
+                            api_type,
+                            &http_url,
+                            confirmation_ledgers,
+                            &mut stellar_caches,
+                            &rpc_cache,
+                            &cache_metrics,
+                        )?,
+                        self.block_height_monitor.latest_block_height(),
+                    ),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
+                ),
+                handlers::config::Config::CosmosMsgVerifier {
+                    cosmwasm_contract,
+                    chain_name,
+                    rpc_url,
+                    confirmation_blocks,
+                    max_concurrent_events,
+                } => self.create_handler_task(
+                    format!("{}-msg-verifier", chain_name),
+                    handlers::cosmos_verify_msg::Handler::new(
+                        verifier.clone(),
+                        cosmwasm_contract,
+                        chain_name,
+                        cosmos_client(&rpc_url).await?,
+                        confirmation_blocks,
+                        self.block_height_monitor.latest_block_height(),
+                    ),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
+                ),
+                handlers::config::Config::CosmosVerifierSetVerifier {
+                    cosmwasm_contract,
+                    chain_name,
+                    rpc_url,
+                    confirmation_blocks,
+                    max_concurrent_events,
+                } => self.create_handler_task(
+                    format!("{}-verifier-set-verifier", chain_name),
+                    handlers::cosmos_verify_verifier_set::Handler::new(
+                        verifier.clone(),
+                        cosmwasm_contract,
+                        chain_name,
+                        cosmos_client(&rpc_url).await?,
+                        confirmation_blocks,
+                        self.block_height_monitor.latest_block_height(),
+                    ),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
+                ),
+                handlers::config::Config::StarknetMsgVerifier {
+                    cosmwasm_contract,
+                    rpc_url,
+                    finality,
+                    rpc_timeout,
+                    max_concurrent_events,
+                } => self.create_handler_task(
+                    "starknet-msg-verifier",
+                    handlers::starknet_verify_msg::Handler::new(
+                        verifier.clone(),
+                        cosmwasm_contract,
+                        json_rpc::Client::new_http(&rpc_url, http_client(rpc_timeout)?),
+                        finality,
+                        self.block_height_monitor.latest_block_height(),
+                    ),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
+                ),
+                handlers::config::Config::StarknetVerifierSetVerifier {
+                    cosmwasm_contract,
+                    rpc_url,
+                    finality,
+                    rpc_timeout,
+                    max_concurrent_events,
+                } => self.create_handler_task(
+                    "starknet-verifier-set-verifier",
+                    handlers::starknet_verify_verifier_set::Handler::new(
+                        verifier.clone(),
+                        cosmwasm_contract,
+                        json_rpc::Client::new_http(&rpc_url, http_client(rpc_timeout)?),
+                        finality,
+                        self.block_height_monitor.latest_block_height(),
+                    ),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
+                ),
+                handlers::config::Config::XrplMsgVerifier {
+                    cosmwasm_contract,
+                    rpc_url,
+                    rpc_timeout,
+                    max_concurrent_events,
+                } => self.create_handler_task(
+                    "xrpl-msg-verifier",
+                    handlers::xrpl_verify_msg::Handler::new(
+                        verifier.clone(),
+                        cosmwasm_contract,
+                        json_rpc::Client::new_http(&rpc_url, http_client(rpc_timeout)?),
                         self.block_height_monitor.latest_block_height(),
                     ),
-                    event_processor_config.clone(),
+                    event_processor_config.with_max_concurrent_events(max_concurrent_events),
                 ),
             };
             self.event_processor = self.event_processor.add_task(task);
@@ -6,8 +6,8 @@ use std::process::ExitCode;
 use ::config::{Config as cfg, Environment, File, FileFormat, FileSourceFile};
 use ampd::commands::{
     bond_verifier, claim_stake, daemon, deregister_chain_support, register_chain_support,
-    register_public_key, send_tokens, set_rewards_proxy, unbond_verifier, verifier_address,
-    SubCommand,
+    register_public_key, rotate_key, send_tokens, set_rewards_proxy, unbond_verifier,
+    verifier_address, SubCommand,
 };
 use ampd::config::Config;
 use ampd::Error;
@@ -66,6 +66,7 @@ async fn main() -> ExitCode {
             deregister_chain_support::run(cfg, args).await
         }
         Some(SubCommand::RegisterPublicKey(args)) => register_public_key::run(cfg, args).await,
+        Some(SubCommand::RotateKey(args)) => rotate_key::run(cfg, args).await,
         Some(SubCommand::VerifierAddress) => verifier_address::run(cfg.tofnd_config).await,
         Some(SubCommand::UnbondVerifier(args)) => unbond_verifier::run(cfg, args).await,
         Some(SubCommand::ClaimStake(args)) => claim_stake::run(cfg, args).await,
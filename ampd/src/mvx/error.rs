@@ -6,4 +6,6 @@ pub enum Error {
     NotEd25519Key,
     #[error("required property is empty")]
     PropertyEmpty,
+    #[error("failed to get transaction from the proxy")]
+    Proxy,
 }
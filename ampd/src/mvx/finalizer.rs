@@ -0,0 +1,92 @@
+use multiversx_sdk::data::transaction::TransactionOnNetwork;
+use serde::{Deserialize, Serialize};
+
+/// Determines which metachain nonce a transaction's hyperblock must not exceed to be considered final
+#[derive(Debug, Deserialize, Serialize, PartialEq, Default, Clone)]
+pub enum Finalization {
+    /// The highest final nonce reported by the metachain
+    #[default]
+    FinalizedHyperblock,
+    /// The latest nonce of the metachain, only safe in combination with a confirmation depth
+    LatestHyperblock,
+}
+
+/// A transaction is final once its block is notarized by the metachain and its hyperblock is
+/// at least `confirmation_depth` hyperblocks below the latest nonce picked by the finalization
+pub fn is_finalized(
+    tx: &TransactionOnNetwork,
+    latest_hyperblock_nonce: u64,
+    confirmation_depth: u64,
+) -> bool {
+    tx.notarized_at_destination_in_meta_nonce.is_some()
+        && tx.hyperblock_nonce.is_some_and(|hyperblock_nonce| {
+            hyperblock_nonce.saturating_add(confirmation_depth) <= latest_hyperblock_nonce
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use multiversx_sdk::data::address::Address;
+    use multiversx_sdk::data::transaction::TransactionOnNetwork;
+
+    use crate::mvx::finalizer::is_finalized;
+
+    #[test]
+    fn should_only_finalize_notarized_transactions() {
+        let mut tx = transaction(Some(10));
+        assert!(is_finalized(&tx, 10, 0));
+
+        tx.notarized_at_destination_in_meta_nonce = None;
+        assert!(!is_finalized(&tx, 10, 0));
+
+        assert!(!is_finalized(&transaction(None), 10, 0));
+    }
+
+    #[test]
+    fn should_respect_confirmation_depth() {
+        let tx = transaction(Some(10));
+
+        assert!(!is_finalized(&tx, 9, 0));
+        assert!(!is_finalized(&tx, 12, 3));
+        assert!(is_finalized(&tx, 13, 3));
+    }
+
+    fn transaction(hyperblock_nonce: Option<u64>) -> TransactionOnNetwork {
+        let address = Address::from_bech32_string(
+            "erd1qqqqqqqqqqqqqpgqsvzyz88e8v8j6x3wquatxuztnxjwnw92kkls6rdtzx",
+        )
+        .unwrap();
+
+        TransactionOnNetwork {
+            hash: Some("".into()),
+            logs: None,
+            status: "success".into(),
+            kind: "".into(),
+            nonce: 1,
+            round: 1,
+            epoch: 1,
+            value: "".into(),
+            receiver: address.clone(),
+            sender: address,
+            gas_price: 0,
+            gas_limit: 0,
+            signature: "".into(),
+            source_shard: 1,
+            destination_shard: 1,
+            block_nonce: 1,
+            block_hash: "".into(),
+            notarized_at_source_in_meta_nonce: hyperblock_nonce,
+            notarized_at_source_in_meta_hash: Some("".into()),
+            notarized_at_destination_in_meta_nonce: hyperblock_nonce,
+            notarized_at_destination_in_meta_hash: Some("".into()),
+            miniblock_type: "".into(),
+            miniblock_hash: "".into(),
+            timestamp: 1,
+            data: None,
+            hyperblock_nonce,
+            hyperblock_hash: Some("".into()),
+            smart_contract_results: None,
+            processing_type_on_destination: "".into(),
+        }
+    }
+}
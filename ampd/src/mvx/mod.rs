@@ -8,6 +8,7 @@ use sha3::{Digest, Keccak256};
 use crate::mvx::error::Error;
 
 pub mod error;
+pub mod finalizer;
 pub mod proxy;
 pub mod verifier;
 
@@ -1,35 +1,91 @@
 use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
+use error_stack::{report, Result};
 use futures::future::join_all;
 use hex::ToHex;
 use mockall::automock;
 use multiversx_sdk::blockchain::CommunicationProxy;
 use multiversx_sdk::data::transaction::TransactionOnNetwork;
+use reqwest::StatusCode;
+use serde::Deserialize;
 
+use crate::mvx::error::Error;
+use crate::mvx::finalizer::Finalization;
 use crate::types::Hash;
 
 const STATUS_SUCCESS: &str = "success";
+const METACHAIN_SHARD_ID: u32 = u32::MAX;
 
 #[automock]
 #[async_trait]
 pub trait MvxProxy {
+    /// Only returns the valid transactions that were found, and fails if any of the requests fails
     async fn transactions_info_with_results(
         &self,
         tx_hashes: HashSet<Hash>,
-    ) -> HashMap<Hash, TransactionOnNetwork>;
+    ) -> Result<HashMap<Hash, TransactionOnNetwork>, Error>;
 
-    async fn transaction_info_with_results(&self, tx_hash: &Hash) -> Option<TransactionOnNetwork>;
+    async fn transaction_info_with_results(
+        &self,
+        tx_hash: &Hash,
+    ) -> Result<Option<TransactionOnNetwork>, Error>;
+
+    async fn latest_hyperblock_nonce(&self, finalization: &Finalization) -> Result<u64, Error>;
 
     fn is_valid_transaction(tx: &TransactionOnNetwork) -> bool;
 }
 
+#[derive(Deserialize)]
+struct TransactionInfoResponse {
+    #[serde(default)]
+    error: String,
+    data: Option<TransactionInfoData>,
+}
+
+#[derive(Deserialize)]
+struct TransactionInfoData {
+    transaction: TransactionOnNetwork,
+}
+
+/// The proxy responds with status 404 and without data if a transaction does not exist
+fn transaction_info(
+    status: StatusCode,
+    body: &[u8],
+) -> Result<Option<TransactionOnNetwork>, Error> {
+    let response: TransactionInfoResponse = serde_json::from_slice(body)
+        .map_err(|err| report!(Error::Proxy).attach_printable(err.to_string()))?;
+
+    match response.data {
+        Some(data) if status.is_success() => Ok(Some(data.transaction)),
+        None if status == StatusCode::NOT_FOUND => Ok(None),
+        _ => Err(report!(Error::Proxy).attach_printable(format!("{}: {}", status, response.error))),
+    }
+}
+
+/// Client for the MultiversX proxy. Transactions are requested directly, because the SDK does not expose the response status
+pub struct Client {
+    proxy: CommunicationProxy,
+    http_client: reqwest::Client,
+    url: String,
+}
+
+impl Client {
+    pub fn new(url: String) -> Self {
+        Self {
+            proxy: CommunicationProxy::new(url.clone()),
+            http_client: reqwest::Client::new(),
+            url,
+        }
+    }
+}
+
 #[async_trait]
-impl MvxProxy for CommunicationProxy {
+impl MvxProxy for Client {
     async fn transactions_info_with_results(
         &self,
         tx_hashes: HashSet<Hash>,
-    ) -> HashMap<Hash, TransactionOnNetwork> {
+    ) -> Result<HashMap<Hash, TransactionOnNetwork>, Error> {
         let tx_hashes = Vec::from_iter(tx_hashes);
 
         let txs = join_all(
@@ -37,27 +93,85 @@ impl MvxProxy for CommunicationProxy {
                 .iter()
                 .map(|tx_hash| self.transaction_info_with_results(tx_hash)),
         )
-        .await;
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
 
-        tx_hashes
+        Ok(tx_hashes
             .into_iter()
             .zip(txs)
-            .filter_map(|(hash, tx)| {
-                tx.as_ref()?;
+            .filter_map(|(hash, tx)| tx.map(|tx| (hash, tx)))
+            .collect())
+    }
 
-                Some((hash, tx.unwrap()))
-            })
-            .collect()
+    async fn transaction_info_with_results(
+        &self,
+        tx_hash: &Hash,
+    ) -> Result<Option<TransactionOnNetwork>, Error> {
+        let response = self
+            .http_client
+            .get(format!(
+                "{}/transaction/{}?withResults=true",
+                self.url,
+                tx_hash.encode_hex::<String>()
+            ))
+            .send()
+            .await
+            .map_err(|err| report!(Error::Proxy).attach_printable(err.to_string()))?;
+
+        let status = response.status();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|err| report!(Error::Proxy).attach_printable(err.to_string()))?;
+
+        Ok(transaction_info(status, &body)?.filter(Self::is_valid_transaction))
     }
 
-    async fn transaction_info_with_results(&self, tx_hash: &Hash) -> Option<TransactionOnNetwork> {
-        self.get_transaction_info_with_results(tx_hash.encode_hex::<String>().as_str())
+    async fn latest_hyperblock_nonce(&self, finalization: &Finalization) -> Result<u64, Error> {
+        let status = self
+            .proxy
+            .get_network_status(METACHAIN_SHARD_ID)
             .await
-            .ok()
-            .filter(Self::is_valid_transaction)
+            .map_err(|err| report!(Error::Proxy).attach_printable(err.to_string()))?;
+
+        Ok(match finalization {
+            Finalization::FinalizedHyperblock => status.highest_final_nonce,
+            Finalization::LatestHyperblock => status.nonce,
+        })
     }
 
     fn is_valid_transaction(tx: &TransactionOnNetwork) -> bool {
         tx.hash.is_some() && tx.logs.is_some() && tx.status == *STATUS_SUCCESS
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+
+    use crate::mvx::proxy::transaction_info;
+
+    #[test]
+    fn should_treat_not_found_response_as_missing_transaction() {
+        let body = r#"{"data":null,"error":"transaction not found","code":"internal_issue"}"#;
+
+        assert!(transaction_info(StatusCode::NOT_FOUND, body.as_bytes())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn should_fail_on_error_responses_other_than_not_found() {
+        let body = r#"{"data":null,"error":"system busy","code":"system_busy"}"#;
+
+        assert!(transaction_info(StatusCode::INTERNAL_SERVER_ERROR, body.as_bytes()).is_err());
+        assert!(transaction_info(StatusCode::TOO_MANY_REQUESTS, body.as_bytes()).is_err());
+        assert!(transaction_info(StatusCode::OK, body.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn should_fail_if_not_found_response_does_not_come_from_the_proxy() {
+        assert!(transaction_info(StatusCode::NOT_FOUND, b"404 page not found").is_err());
+    }
+}
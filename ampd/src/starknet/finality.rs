@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::starknet::json_rpc::{FieldElement, FinalityStatus, TransactionReceipt};
+
+/// The finality status a transaction must have reached before it is voted on
+#[derive(Debug, Deserialize, Serialize, PartialEq, Default, Clone, Copy)]
+pub enum Finality {
+    /// The block including the transaction is proven on Ethereum
+    #[default]
+    AcceptedOnL1,
+    /// The block including the transaction is accepted by the Starknet sequencer, but not yet proven on Ethereum
+    AcceptedOnL2,
+}
+
+impl Finality {
+    pub fn is_reached(&self, status: &FinalityStatus) -> bool {
+        match self {
+            Finality::AcceptedOnL1 => *status == FinalityStatus::AcceptedOnL1,
+            Finality::AcceptedOnL2 => true,
+        }
+    }
+}
+
+/// Only keeps the receipts of transactions that reached the given finality
+pub fn finalized_receipts(
+    receipts: HashMap<FieldElement, TransactionReceipt>,
+    finality: Finality,
+) -> HashMap<FieldElement, TransactionReceipt> {
+    receipts
+        .into_iter()
+        .filter(|(_, receipt)| finality.is_reached(&receipt.finality_status))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::starknet::finality::{finalized_receipts, Finality};
+    use crate::starknet::json_rpc::{
+        ExecutionStatus, FieldElement, FinalityStatus, TransactionReceipt,
+    };
+
+    #[test]
+    fn should_only_keep_receipts_accepted_on_l1_by_default() {
+        let receipts = receipts();
+
+        let finalized = finalized_receipts(receipts, Finality::default());
+        assert_eq!(finalized.len(), 1);
+        assert!(finalized.contains_key(&FieldElement::from(2u128)));
+    }
+
+    #[test]
+    fn should_keep_receipts_accepted_on_l2_if_configured() {
+        let finalized = finalized_receipts(receipts(), Finality::AcceptedOnL2);
+        assert_eq!(finalized.len(), 2);
+    }
+
+    fn receipts() -> HashMap<FieldElement, TransactionReceipt> {
+        [
+            (1u128, FinalityStatus::AcceptedOnL2),
+            (2u128, FinalityStatus::AcceptedOnL1),
+        ]
+        .into_iter()
+        .map(|(tx_hash, finality_status)| {
+            let tx_hash = FieldElement::from(tx_hash);
+
+            (
+                tx_hash,
+                TransactionReceipt {
+                    transaction_hash: tx_hash,
+                    execution_status: ExecutionStatus::Succeeded,
+                    finality_status,
+                    events: vec![],
+                },
+            )
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use axelar_wasm_std::msg_id::STARKNET_PRIME;
+use error_stack::{bail, report, Report, ResultExt};
+use ethers_providers::{JsonRpcClient, ProviderError};
+use futures::future::try_join_all;
+use mockall::automock;
+use serde::Deserialize;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::json_rpc::Client;
+use crate::starknet::error::Error;
+
+type Result<T> = error_stack::Result<T, ProviderError>;
+
+// Starknet responds with an error instead of null if a transaction does not exist
+const TRANSACTION_HASH_NOT_FOUND: i64 = 29;
+
+fn is_not_found(err: &ProviderError) -> bool {
+    err.as_error_response()
+        .is_some_and(|err| err.code == TRANSACTION_HASH_NOT_FOUND)
+}
+
+/// An element of the Starknet field, i.e. an integer below the Starknet prime.
+/// The RPC encodes felts as hex without leading zeros, so both padded and unpadded hex are accepted.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Default, SerializeDisplay, DeserializeFromStr,
+)]
+pub struct FieldElement([u8; 32]);
+
+impl FieldElement {
+    /// Fails if the big endian bytes are longer than 32 bytes or do not encode a felt
+    pub fn from_be_slice(bytes: &[u8]) -> Option<Self> {
+        let offset = 32usize.checked_sub(bytes.len())?;
+
+        let mut felt = [0u8; 32];
+        felt.get_mut(offset..)?.copy_from_slice(bytes);
+
+        Some(felt)
+            .filter(|felt| *felt < STARKNET_PRIME)
+            .map(FieldElement)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Fails if the felt does not fit into 128 bits
+    pub fn to_u128(&self) -> Option<u128> {
+        let (high, low) = self.0.split_at(16);
+
+        if high.iter().any(|byte| *byte != 0) {
+            return None;
+        }
+
+        low.try_into().ok().map(u128::from_be_bytes)
+    }
+}
+
+impl From<u128> for FieldElement {
+    fn from(value: u128) -> Self {
+        let mut felt = [0u8; 32];
+        felt[16..].copy_from_slice(&value.to_be_bytes());
+
+        FieldElement(felt)
+    }
+}
+
+impl From<FieldElement> for [u8; 32] {
+    fn from(felt: FieldElement) -> Self {
+        felt.0
+    }
+}
+
+impl FromStr for FieldElement {
+    type Err = Report<Error>;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let hex = s
+            .strip_prefix("0x")
+            .filter(|hex| !hex.is_empty() && hex.len() <= 64)
+            .ok_or_else(|| report!(Error::InvalidFieldElement(s.to_string())))?;
+
+        let bytes = hex::decode(format!("{:0>64}", hex))
+            .change_context(Error::InvalidFieldElement(s.to_string()))?;
+
+        match FieldElement::from_be_slice(&bytes) {
+            Some(felt) => Ok(felt),
+            None => bail!(Error::InvalidFieldElement(s.to_string())),
+        }
+    }
+}
+
+impl Display for FieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = hex::encode(self.0);
+
+        match hex.trim_start_matches('0') {
+            "" => write!(f, "0x0"),
+            trimmed => write!(f, "0x{}", trimmed),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum ExecutionStatus {
+    #[serde(rename = "SUCCEEDED")]
+    Succeeded,
+    #[serde(rename = "REVERTED")]
+    Reverted,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub enum FinalityStatus {
+    #[serde(rename = "ACCEPTED_ON_L2")]
+    AcceptedOnL2,
+    #[serde(rename = "ACCEPTED_ON_L1")]
+    AcceptedOnL1,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Event {
+    pub from_address: FieldElement,
+    pub keys: Vec<FieldElement>,
+    pub data: Vec<FieldElement>,
+}
+
+/// The fields of a transaction receipt that are needed to verify gateway events
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TransactionReceipt {
+    pub transaction_hash: FieldElement,
+    pub execution_status: ExecutionStatus,
+    pub finality_status: FinalityStatus,
+    pub events: Vec<Event>,
+}
+
+#[automock]
+#[async_trait]
+pub trait StarknetClient {
+    async fn transaction_receipt(
+        &self,
+        tx_hash: FieldElement,
+    ) -> Result<Option<TransactionReceipt>>;
+
+    /// Only returns the receipts of transactions that were found
+    async fn transaction_receipts(
+        &self,
+        tx_hashes: HashSet<FieldElement>,
+    ) -> Result<HashMap<FieldElement, TransactionReceipt>>;
+}
+
+#[async_trait]
+impl<P> StarknetClient for Client<P>
+where
+    P: JsonRpcClient + Send + Sync + 'static,
+{
+    async fn transaction_receipt(
+        &self,
+        tx_hash: FieldElement,
+    ) -> Result<Option<TransactionReceipt>> {
+        match self
+            .request("starknet_getTransactionReceipt", [tx_hash])
+            .await
+        {
+            Ok(receipt) => Ok(Some(receipt)),
+            Err(err) if is_not_found(err.current_context()) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn transaction_receipts(
+        &self,
+        tx_hashes: HashSet<FieldElement>,
+    ) -> Result<HashMap<FieldElement, TransactionReceipt>> {
+        Ok(try_join_all(
+            tx_hashes
+                .into_iter()
+                .map(|tx_hash| self.transaction_receipt(tx_hash)),
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .map(|receipt| (receipt.transaction_hash, receipt))
+        .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axelar_wasm_std::msg_id::STARKNET_PRIME;
+
+    use crate::starknet::json_rpc::{FieldElement, TransactionReceipt};
+
+    #[test]
+    fn should_parse_padded_and_unpadded_field_elements() {
+        let unpadded: FieldElement = "0x1a2b".parse().unwrap();
+        let padded: FieldElement = format!("0x{:0>64}", "1a2b").parse().unwrap();
+
+        assert_eq!(unpadded, padded);
+        assert_eq!(unpadded, FieldElement::from(0x1a2bu128));
+        assert_eq!(padded.to_string(), "0x1a2b");
+        assert_eq!(FieldElement::default().to_string(), "0x0");
+        assert_eq!(unpadded.to_u128(), Some(0x1a2b));
+    }
+
+    #[test]
+    fn should_not_parse_invalid_field_elements() {
+        assert!("1a2b".parse::<FieldElement>().is_err());
+        assert!("0x".parse::<FieldElement>().is_err());
+        assert!("0xz".parse::<FieldElement>().is_err());
+        assert!(format!("0x{}", "1".repeat(65))
+            .parse::<FieldElement>()
+            .is_err());
+        assert!(format!("0x{}", hex::encode(STARKNET_PRIME))
+            .parse::<FieldElement>()
+            .is_err());
+    }
+
+    #[test]
+    fn should_deserialize_transaction_receipt() {
+        let receipt: TransactionReceipt = serde_json::from_value(serde_json::json!({
+            "type": "INVOKE",
+            "transaction_hash": "0x670d1dd42a19cb229bb4378b58b9c3e76aa43edaaea46845cd8c456c1224d89",
+            "actual_fee": { "amount": "0x1", "unit": "FRI" },
+            "execution_status": "SUCCEEDED",
+            "finality_status": "ACCEPTED_ON_L1",
+            "block_hash": "0x1",
+            "block_number": 1,
+            "messages_sent": [],
+            "events": [{
+                "from_address": "0x2",
+                "keys": ["0x3"],
+                "data": ["0x4", "0x5"]
+            }],
+            "execution_resources": {}
+        }))
+        .unwrap();
+
+        assert_eq!(
+            receipt.transaction_hash.to_string(),
+            "0x670d1dd42a19cb229bb4378b58b9c3e76aa43edaaea46845cd8c456c1224d89"
+        );
+        assert_eq!(receipt.events.len(), 1);
+        assert_eq!(receipt.events[0].data, vec![4u128.into(), 5u128.into()]);
+    }
+}
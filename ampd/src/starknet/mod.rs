@@ -0,0 +1,4 @@
+pub mod error;
+pub mod finality;
+pub mod json_rpc;
+pub mod verifier;
@@ -0,0 +1,444 @@
+use axelar_wasm_std::voting::Vote;
+use evm_gateway::WeightedSigners;
+use router_api::ChainName;
+use sha3::{Digest, Keccak256};
+
+use crate::handlers::starknet_verify_msg::Message;
+use crate::handlers::starknet_verify_verifier_set::VerifierSetConfirmation;
+use crate::starknet::json_rpc::{Event, ExecutionStatus, FieldElement, TransactionReceipt};
+
+const CONTRACT_CALL_EVENT: &str = "ContractCall";
+const SIGNERS_ROTATED_EVENT: &str = "SignersRotated";
+
+// a ByteArray is split into words of 31 bytes so that every word fits into a felt
+const BYTES31_LEN: usize = 31;
+
+/// The first key of a Starknet event is the starknet keccak of the event name,
+/// i.e. the keccak256 hash truncated to its 250 least significant bits
+fn selector(event_name: &str) -> FieldElement {
+    let mut hash: [u8; 32] = Keccak256::digest(event_name.as_bytes()).into();
+    hash[0] &= 0x03;
+
+    FieldElement::from_be_slice(&hash).expect("250-bit selector must be a valid felt")
+}
+
+/// Cairo serializes a ByteArray as the number of full words, the full words themselves,
+/// a pending word and the number of bytes in the pending word
+fn decode_byte_array<'a>(felts: &mut impl Iterator<Item = &'a FieldElement>) -> Option<String> {
+    let full_words = usize::try_from(felts.next()?.to_u128()?).ok()?;
+
+    let mut bytes = vec![];
+    for _ in 0..full_words {
+        let (padding, word) = felts.next()?.as_bytes().split_at(1);
+        if padding.iter().any(|byte| *byte != 0) {
+            return None;
+        }
+
+        bytes.extend_from_slice(word);
+    }
+
+    let pending_word = felts.next()?.as_bytes();
+    let pending_word_len = usize::try_from(felts.next()?.to_u128()?)
+        .ok()
+        .filter(|len| *len < BYTES31_LEN)?;
+    let (padding, pending_bytes) =
+        pending_word.split_at(pending_word.len().checked_sub(pending_word_len)?);
+    if padding.iter().any(|byte| *byte != 0) {
+        return None;
+    }
+
+    bytes.extend_from_slice(pending_bytes);
+
+    String::from_utf8(bytes).ok()
+}
+
+/// Cairo serializes a u256 as its low 128 bits followed by its high 128 bits
+fn decode_u256<'a>(felts: &mut impl Iterator<Item = &'a FieldElement>) -> Option<[u8; 32]> {
+    let low = felts.next()?.to_u128()?;
+    let high = felts.next()?.to_u128()?;
+
+    let mut bytes = [0u8; 32];
+    bytes[..16].copy_from_slice(&high.to_be_bytes());
+    bytes[16..].copy_from_slice(&low.to_be_bytes());
+
+    Some(bytes)
+}
+
+fn encode_u256(bytes: &[u8; 32]) -> Option<[FieldElement; 2]> {
+    let (high, low) = bytes.split_at(16);
+
+    Some([
+        FieldElement::from_be_slice(low)?,
+        FieldElement::from_be_slice(high)?,
+    ])
+}
+
+/// The gateway emits the new signers as the length of the signer list, each signer's Ethereum address and weight,
+/// the threshold and the nonce
+fn encode_weighted_signers(weighted_signers: &WeightedSigners) -> Option<Vec<FieldElement>> {
+    let mut felts = vec![FieldElement::from(
+        u128::try_from(weighted_signers.signers.len()).ok()?,
+    )];
+
+    for signer in weighted_signers.signers.iter() {
+        felts.push(FieldElement::from_be_slice(signer.signer.as_bytes())?);
+        felts.push(FieldElement::from(signer.weight));
+    }
+
+    felts.push(FieldElement::from(weighted_signers.threshold));
+    felts.extend(encode_u256(&weighted_signers.nonce)?);
+
+    Some(felts)
+}
+
+impl PartialEq<Event> for Message {
+    fn eq(&self, event: &Event) -> bool {
+        let mut keys = event.keys.iter();
+        let mut data = event.data.iter();
+
+        keys.next() == Some(&selector(CONTRACT_CALL_EVENT))
+            && keys.next() == Some(&self.source_address)
+            && decode_byte_array(&mut keys)
+                .and_then(|chain| chain.parse::<ChainName>().ok())
+                .is_some_and(|chain| chain == self.destination_chain)
+            && keys.next().is_none()
+            && decode_byte_array(&mut data)
+                .is_some_and(|destination_address| destination_address == self.destination_address)
+            && decode_u256(&mut data)
+                .is_some_and(|payload_hash| payload_hash == self.payload_hash.to_fixed_bytes())
+    }
+}
+
+impl PartialEq<Event> for VerifierSetConfirmation {
+    fn eq(&self, event: &Event) -> bool {
+        event.keys.first() == Some(&selector(SIGNERS_ROTATED_EVENT))
+            && WeightedSigners::try_from(&self.verifier_set)
+                .ok()
+                .and_then(|weighted_signers| encode_weighted_signers(&weighted_signers))
+                .is_some_and(|signers| signers == event.data)
+    }
+}
+
+pub fn verify_message(
+    gateway_address: &FieldElement,
+    receipt: &TransactionReceipt,
+    msg: &Message,
+) -> Vote {
+    verify(gateway_address, receipt, msg, msg.tx_id, msg.event_index)
+}
+
+pub fn verify_verifier_set(
+    gateway_address: &FieldElement,
+    receipt: &TransactionReceipt,
+    verifier_set: &VerifierSetConfirmation,
+) -> Vote {
+    verify(
+        gateway_address,
+        receipt,
+        verifier_set,
+        verifier_set.tx_id,
+        verifier_set.event_index,
+    )
+}
+
+fn verify(
+    gateway_address: &FieldElement,
+    receipt: &TransactionReceipt,
+    to_verify: &impl PartialEq<Event>,
+    expected_tx_id: FieldElement,
+    expected_event_index: u32,
+) -> Vote {
+    if receipt.transaction_hash != expected_tx_id {
+        return Vote::NotFound;
+    }
+
+    if receipt.execution_status == ExecutionStatus::Reverted {
+        return Vote::FailedOnChain;
+    }
+
+    match usize::try_from(expected_event_index)
+        .ok()
+        .and_then(|index| receipt.events.get(index))
+    {
+        Some(event) if event.from_address == *gateway_address && to_verify.eq(event) => {
+            Vote::SucceededOnChain
+        }
+        _ => Vote::NotFound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axelar_wasm_std::voting::Vote;
+    use evm_gateway::WeightedSigners;
+    use multisig::key::KeyType;
+    use multisig::test::common::{build_verifier_set, ecdsa_test_data};
+
+    use crate::handlers::starknet_verify_msg::Message;
+    use crate::handlers::starknet_verify_verifier_set::VerifierSetConfirmation;
+    use crate::starknet::json_rpc::{
+        Event, ExecutionStatus, FieldElement, FinalityStatus, TransactionReceipt,
+    };
+    use crate::starknet::verifier::{
+        decode_byte_array, encode_u256, encode_weighted_signers, selector, verify_message,
+        verify_verifier_set, CONTRACT_CALL_EVENT, SIGNERS_ROTATED_EVENT,
+    };
+    use crate::types::{EVMAddress, Hash};
+
+    #[test]
+    fn should_compute_event_selector() {
+        // selector of the Transfer event of ERC20 contracts
+        assert_eq!(
+            selector("Transfer").to_string(),
+            "0x99cd8bde557814842a3121e8ddfd433a539b8c9f14bf31ebf108d12e6196e9"
+        );
+    }
+
+    #[test]
+    fn should_decode_byte_array() {
+        for s in [
+            "",
+            "ethereum",
+            "a string that is longer than a single word of 31 bytes",
+        ] {
+            let encoded = encode_byte_array(s);
+            assert_eq!(decode_byte_array(&mut encoded.iter()), Some(s.to_string()));
+        }
+
+        // pending word length must be shorter than a full word
+        let mut encoded = encode_byte_array("ethereum");
+        *encoded.last_mut().unwrap() = FieldElement::from(31u128);
+        assert_eq!(decode_byte_array(&mut encoded.iter()), None);
+
+        // encoding must not be truncated
+        let encoded = encode_byte_array("ethereum");
+        assert_eq!(
+            decode_byte_array(&mut encoded.split_last().unwrap().1.iter()),
+            None
+        );
+    }
+
+    #[test]
+    fn should_verify_msg_if_correct() {
+        let (gateway_address, receipt, msg) = matching_msg_and_receipt();
+
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::SucceededOnChain
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_tx_id_does_not_match() {
+        let (gateway_address, receipt, mut msg) = matching_msg_and_receipt();
+        msg.tx_id = random_felt();
+
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_event_index_does_not_match() {
+        let (gateway_address, receipt, mut msg) = matching_msg_and_receipt();
+        msg.event_index = 0;
+
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::NotFound
+        );
+
+        msg.event_index = 2;
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_event_is_not_from_gateway() {
+        let (_, receipt, msg) = matching_msg_and_receipt();
+
+        assert_eq!(
+            verify_message(&random_felt(), &receipt, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_source_address_does_not_match() {
+        let (gateway_address, receipt, mut msg) = matching_msg_and_receipt();
+        msg.source_address = random_felt();
+
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_destination_does_not_match() {
+        let (gateway_address, receipt, mut msg) = matching_msg_and_receipt();
+        msg.destination_chain = "avalanche".parse().unwrap();
+
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::NotFound
+        );
+
+        let (gateway_address, receipt, mut msg) = matching_msg_and_receipt();
+        msg.destination_address = format!("0x{:x}", EVMAddress::random());
+
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_payload_hash_does_not_match() {
+        let (gateway_address, receipt, mut msg) = matching_msg_and_receipt();
+        msg.payload_hash = Hash::random();
+
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_vote_failed_on_chain_if_tx_reverted() {
+        let (gateway_address, mut receipt, msg) = matching_msg_and_receipt();
+        receipt.execution_status = ExecutionStatus::Reverted;
+
+        assert_eq!(
+            verify_message(&gateway_address, &receipt, &msg),
+            Vote::FailedOnChain
+        );
+    }
+
+    #[test]
+    fn should_verify_verifier_set_if_correct() {
+        let (gateway_address, receipt, verifier_set) = matching_verifier_set_and_receipt();
+
+        assert_eq!(
+            verify_verifier_set(&gateway_address, &receipt, &verifier_set),
+            Vote::SucceededOnChain
+        );
+    }
+
+    #[test]
+    fn should_not_verify_verifier_set_if_verifier_set_does_not_match() {
+        let (gateway_address, receipt, mut verifier_set) = matching_verifier_set_and_receipt();
+        verifier_set.verifier_set.created_at =
+            verifier_set.verifier_set.created_at.saturating_add(1);
+
+        assert_eq!(
+            verify_verifier_set(&gateway_address, &receipt, &verifier_set),
+            Vote::NotFound
+        );
+    }
+
+    #[test]
+    fn should_not_verify_verifier_set_if_event_selector_does_not_match() {
+        let (gateway_address, mut receipt, verifier_set) = matching_verifier_set_and_receipt();
+        receipt.events[0].keys[0] = selector(CONTRACT_CALL_EVENT);
+
+        assert_eq!(
+            verify_verifier_set(&gateway_address, &receipt, &verifier_set),
+            Vote::NotFound
+        );
+    }
+
+    fn random_felt() -> FieldElement {
+        FieldElement::from_be_slice(&Hash::random().as_bytes()[1..]).unwrap()
+    }
+
+    fn encode_byte_array(s: &str) -> Vec<FieldElement> {
+        let chunks: Vec<_> = s.as_bytes().chunks(31).collect();
+        let (full_words, pending_word) = match chunks.split_last() {
+            Some((last, full_words)) if last.len() < 31 => (full_words.to_vec(), *last),
+            _ => (chunks.clone(), &[][..]),
+        };
+
+        let mut felts = vec![FieldElement::from(full_words.len() as u128)];
+        felts.extend(
+            full_words
+                .iter()
+                .map(|word| FieldElement::from_be_slice(word).unwrap()),
+        );
+        felts.push(FieldElement::from_be_slice(pending_word).unwrap());
+        felts.push(FieldElement::from(pending_word.len() as u128));
+
+        felts
+    }
+
+    fn matching_msg_and_receipt() -> (FieldElement, TransactionReceipt, Message) {
+        let gateway_address = random_felt();
+        let msg = Message {
+            tx_id: random_felt(),
+            event_index: 1,
+            destination_address: format!("0x{:x}", EVMAddress::random()),
+            destination_chain: "ethereum".parse().unwrap(),
+            source_address: random_felt(),
+            payload_hash: Hash::random(),
+        };
+
+        let mut keys = vec![selector(CONTRACT_CALL_EVENT), msg.source_address];
+        keys.extend(encode_byte_array("Ethereum"));
+
+        let mut data = encode_byte_array(&msg.destination_address);
+        data.extend(encode_u256(&msg.payload_hash.to_fixed_bytes()).unwrap());
+        data.extend(encode_byte_array("payload"));
+
+        let receipt = TransactionReceipt {
+            transaction_hash: msg.tx_id,
+            execution_status: ExecutionStatus::Succeeded,
+            finality_status: FinalityStatus::AcceptedOnL1,
+            events: vec![
+                Event {
+                    from_address: random_felt(),
+                    keys: vec![selector("Transfer")],
+                    data: vec![],
+                },
+                Event {
+                    from_address: gateway_address,
+                    keys,
+                    data,
+                },
+            ],
+        };
+
+        (gateway_address, receipt, msg)
+    }
+
+    fn matching_verifier_set_and_receipt(
+    ) -> (FieldElement, TransactionReceipt, VerifierSetConfirmation) {
+        let gateway_address = random_felt();
+        let verifier_set = VerifierSetConfirmation {
+            tx_id: random_felt(),
+            event_index: 0,
+            verifier_set: build_verifier_set(KeyType::Ecdsa, &ecdsa_test_data::signers()),
+        };
+
+        let weighted_signers = WeightedSigners::try_from(&verifier_set.verifier_set).unwrap();
+        let receipt = TransactionReceipt {
+            transaction_hash: verifier_set.tx_id,
+            execution_status: ExecutionStatus::Succeeded,
+            finality_status: FinalityStatus::AcceptedOnL1,
+            events: vec![Event {
+                from_address: gateway_address,
+                keys: vec![
+                    selector(SIGNERS_ROTATED_EVENT),
+                    FieldElement::from(1u128),
+                    random_felt(),
+                    random_felt(),
+                ],
+                data: encode_weighted_signers(&weighted_signers).unwrap(),
+            }],
+        };
+
+        (gateway_address, receipt, verifier_set)
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to create client")]
+    Client,
+    #[error("invalid tx hash")]
+    TxHash,
+    #[error("failed to get transaction")]
+    TxResponse,
+    #[error("failed to get contract events")]
+    Events,
+    #[error("invalid response from the Stellar node")]
+    InvalidResponse,
+}
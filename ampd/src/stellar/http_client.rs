@@ -1,28 +1,42 @@
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
+use async_trait::async_trait;
 use error_stack::{report, Result};
 use futures::future::join_all;
 use num_traits::cast;
+use serde::Deserialize;
 use stellar_rs::horizon_client::HorizonClient;
 use stellar_rs::transactions::prelude::{SingleTransactionRequest, TransactionResponse};
 use stellar_xdr::curr::{ContractEvent, Limits, ReadXdr, ScAddress, TransactionMeta, VecM};
-use thiserror::Error;
-
-#[derive(Error, Debug)]
-pub enum Error {
-    #[error("failed to create client")]
-    Client,
-    #[error("invalid tx hash")]
-    TxHash,
+
+use crate::cache::Cache;
+use crate::stellar::error::Error;
+use crate::stellar::StellarClient;
+
+const NOT_FOUND_PROBLEM_TYPE: &str = "https://stellar.org/horizon-errors/not_found";
+
+#[derive(Deserialize)]
+struct Problem {
+    #[serde(rename = "type")]
+    problem_type: String,
+    status: u16,
+}
+
+/// Horizon responds with a problem document of the not found type if a transaction does not exist
+fn is_not_found(err: &str) -> bool {
+    serde_json::from_str::<Problem>(err).is_ok_and(|problem| {
+        problem.problem_type == NOT_FOUND_PROBLEM_TYPE && problem.status == 404
+    })
 }
 
 /// TxResponse parses XDR encoded TransactionMeta to ContractEvent type, and only contains necessary fields for verification
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TxResponse {
     pub transaction_hash: String,
     pub source_address: ScAddress,
     pub successful: bool,
+    pub ledger_closed: bool,
     pub contract_events: Option<VecM<ContractEvent>>,
 }
 
@@ -40,6 +54,8 @@ impl From<TransactionResponse> for TxResponse {
         Self {
             transaction_hash: response.id().to_owned(),
             successful: *response.successful(),
+            // Horizon only ingests transactions of closed ledgers
+            ledger_closed: true,
             source_address: ScAddress::from_str(response.source_account())
                 .expect("must convert to Stellar address"),
             contract_events: Some(events),
@@ -52,6 +68,10 @@ impl TxResponse {
         !self.successful
     }
 
+    pub fn is_ledger_closed(&self) -> bool {
+        self.ledger_closed
+    }
+
     pub fn event(&self, index: u32) -> Option<&ContractEvent> {
         match self.contract_events {
             Some(ref events) => {
@@ -67,55 +87,125 @@ impl TxResponse {
     }
 }
 
+/// Found transactions are cached, so the cache can be shared between clients of the same Horizon server
 #[cfg_attr(test, faux::create)]
-pub struct Client(HorizonClient);
+pub struct Client {
+    client: HorizonClient,
+    cache: Cache<String, TxResponse>,
+}
 
 #[cfg_attr(test, faux::methods)]
 impl Client {
-    pub fn new(url: String) -> Result<Self, Error> {
-        Ok(Self(HorizonClient::new(url).map_err(|err_str| {
-            report!(Error::Client).attach_printable(err_str)
-        })?))
+    pub fn new(url: String, cache: Cache<String, TxResponse>) -> Result<Self, Error> {
+        Ok(Self {
+            client: HorizonClient::new(url)
+                .map_err(|err_str| report!(Error::Client).attach_printable(err_str))?,
+            cache,
+        })
     }
 
     pub async fn transaction_responses(
         &self,
         tx_hashes: HashSet<String>,
     ) -> Result<HashMap<String, TxResponse>, Error> {
-        let tx_hashes: Vec<_> = tx_hashes
+        let (cached, missing): (Vec<_>, Vec<_>) = tx_hashes
+            .into_iter()
+            .map(|tx_hash| (self.cache.get(&tx_hash), tx_hash))
+            .partition(|(tx_response, _)| tx_response.is_some());
+
+        let tx_hashes: Vec<_> = missing
             .into_iter()
-            .map(|tx_hash| {
+            .map(|(_, tx_hash)| {
                 SingleTransactionRequest::new()
                     .set_transaction_hash(tx_hash)
                     .map_err(|err_str| report!(Error::TxHash).attach_printable(err_str))
             })
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
-        Ok(join_all(
+        let fetched = join_all(
             tx_hashes
                 .iter()
-                .map(|tx_hash| self.0.get_single_transaction(tx_hash)),
+                .map(|tx_hash| self.client.get_single_transaction(tx_hash)),
         )
         .await
         .into_iter()
-        .map(|tx_response| tx_response.map(TxResponse::from))
         .filter_map(|tx_response| match tx_response {
-            Ok(tx_response) => Some((tx_response.tx_hash(), tx_response)),
-            Err(_) => None,
+            Ok(tx_response) => {
+                let tx_response = TxResponse::from(tx_response);
+                self.cache
+                    .insert(tx_response.tx_hash(), tx_response.clone());
+                Some(Ok((tx_response.tx_hash(), tx_response)))
+            }
+            Err(err) if is_not_found(&err) => None,
+            Err(err) => Some(Err(report!(Error::TxResponse).attach_printable(err))),
         })
-        .collect::<HashMap<_, _>>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(cached
+            .into_iter()
+            .filter_map(|(tx_response, tx_hash)| {
+                tx_response.map(|tx_response| (tx_hash, tx_response))
+            })
+            .chain(fetched)
+            .collect::<HashMap<_, _>>())
     }
 
     pub async fn transaction_response(&self, tx_hash: String) -> Result<Option<TxResponse>, Error> {
+        if let Some(tx_response) = self.cache.get(&tx_hash) {
+            return Ok(Some(tx_response));
+        }
+
         let tx_hash = SingleTransactionRequest::new()
             .set_transaction_hash(tx_hash)
             .map_err(|err_str| report!(Error::TxHash).attach_printable(err_str))?;
 
-        Ok(self
-            .0
-            .get_single_transaction(&tx_hash)
-            .await
-            .map(|tx_response| Some(tx_response.into()))
-            .unwrap_or_default())
+        match self.client.get_single_transaction(&tx_hash).await {
+            Ok(tx_response) => {
+                let tx_response = TxResponse::from(tx_response);
+                self.cache
+                    .insert(tx_response.tx_hash(), tx_response.clone());
+                Ok(Some(tx_response))
+            }
+            Err(err) if is_not_found(&err) => Ok(None),
+            Err(err) => Err(report!(Error::TxResponse).attach_printable(err)),
+        }
+    }
+}
+
+#[async_trait]
+impl StellarClient for Client {
+    async fn transaction_responses(
+        &self,
+        tx_hashes: HashSet<String>,
+    ) -> Result<HashMap<String, TxResponse>, Error> {
+        Client::transaction_responses(self, tx_hashes).await
+    }
+
+    async fn transaction_response(&self, tx_hash: String) -> Result<Option<TxResponse>, Error> {
+        Client::transaction_response(self, tx_hash).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stellar::http_client::is_not_found;
+
+    #[test]
+    fn should_only_treat_missing_resources_as_not_found() {
+        let not_found = r#"{
+            "type": "https://stellar.org/horizon-errors/not_found",
+            "title": "Resource Missing",
+            "status": 404,
+            "detail": "The resource at the url requested was not found."
+        }"#;
+        let rate_limited = r#"{
+            "type": "https://stellar.org/horizon-errors/rate_limit_exceeded",
+            "title": "Rate Limit Exceeded",
+            "status": 429
+        }"#;
+
+        assert!(is_not_found(not_found));
+        assert!(!is_not_found(rate_limited));
+        assert!(!is_not_found("error sending request"));
     }
 }
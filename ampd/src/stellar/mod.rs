@@ -1,2 +1,49 @@
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use error_stack::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::stellar::error::Error;
+use crate::stellar::http_client::TxResponse;
+
+pub(crate) mod error;
 pub(crate) mod http_client;
+pub(crate) mod rpc_client;
 pub(crate) mod verifier;
+
+/// The API that a Stellar handler uses to fetch transactions
+#[derive(Debug, Deserialize, Serialize, PartialEq, Default, Clone, Copy)]
+pub enum ApiType {
+    #[default]
+    Horizon,
+    SorobanRpc,
+}
+
+#[async_trait]
+pub trait StellarClient {
+    /// Only returns the transactions that were found
+    async fn transaction_responses(
+        &self,
+        tx_hashes: HashSet<String>,
+    ) -> Result<HashMap<String, TxResponse>, Error>;
+
+    async fn transaction_response(&self, tx_hash: String) -> Result<Option<TxResponse>, Error>;
+}
+
+#[async_trait]
+impl<C> StellarClient for Box<C>
+where
+    C: StellarClient + Send + Sync + ?Sized,
+{
+    async fn transaction_responses(
+        &self,
+        tx_hashes: HashSet<String>,
+    ) -> Result<HashMap<String, TxResponse>, Error> {
+        self.as_ref().transaction_responses(tx_hashes).await
+    }
+
+    async fn transaction_response(&self, tx_hash: String) -> Result<Option<TxResponse>, Error> {
+        self.as_ref().transaction_response(tx_hash).await
+    }
+}
@@ -0,0 +1,504 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use error_stack::{report, Result, ResultExt};
+use ethers_providers::{Http, JsonRpcClient};
+use futures::future::join_all;
+use serde::Deserialize;
+use serde_json::json;
+use stellar_xdr::curr::{
+    AccountId, ContractEvent, ContractEventBody, ContractEventType, ContractEventV0,
+    ExtensionPoint, FeeBumpTransactionInnerTx, Limits, MuxedAccount, PublicKey, ReadXdr, ScAddress,
+    ScVal, TransactionEnvelope, TransactionMeta, VecM,
+};
+
+use crate::cache::Cache;
+use crate::json_rpc;
+use crate::stellar::error::Error;
+use crate::stellar::http_client::TxResponse;
+use crate::stellar::StellarClient;
+use crate::url::Url;
+
+// maximum page size supported by Soroban RPC
+const EVENTS_PAGE_LIMIT: usize = 10000;
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum TransactionStatus {
+    Success,
+    NotFound,
+    Failed,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct GetTransactionResponse {
+    status: TransactionStatus,
+    latest_ledger: u32,
+    ledger: Option<u32>,
+    envelope_xdr: Option<String>,
+    result_meta_xdr: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct GetEventsResponse {
+    events: Vec<EventResponse>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EventResponse {
+    pub id: String,
+    pub ledger: u32,
+    pub contract_id: String,
+    pub tx_hash: String,
+    pub topic: Vec<String>,
+    pub value: String,
+}
+
+impl TryFrom<EventResponse> for ContractEvent {
+    type Error = error_stack::Report<Error>;
+
+    fn try_from(event: EventResponse) -> std::result::Result<Self, Self::Error> {
+        let contract_id =
+            match ScAddress::from_str(&event.contract_id).change_context(Error::InvalidResponse)? {
+                ScAddress::Contract(hash) => hash,
+                _ => return Err(report!(Error::InvalidResponse)),
+            };
+
+        let topics = event
+            .topic
+            .iter()
+            .map(|topic| ScVal::from_xdr_base64(topic, Limits::none()))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .change_context(Error::InvalidResponse)?;
+
+        Ok(ContractEvent {
+            ext: ExtensionPoint::V0,
+            contract_id: Some(contract_id),
+            type_: ContractEventType::Contract,
+            body: ContractEventBody::V0(ContractEventV0 {
+                topics: topics.try_into().change_context(Error::InvalidResponse)?,
+                data: ScVal::from_xdr_base64(&event.value, Limits::none())
+                    .change_context(Error::InvalidResponse)?,
+            }),
+        })
+    }
+}
+
+/// Client for the Soroban RPC. Transactions of confirmed ledgers are cached, so the cache can be shared between clients of the same server
+pub struct Client<P = Http>
+where
+    P: JsonRpcClient,
+{
+    client: json_rpc::Client<P>,
+    cache: Cache<String, TxResponse>,
+    confirmation_ledgers: u32,
+}
+
+impl Client<Http> {
+    pub fn new(
+        url: &Url,
+        client: reqwest::Client,
+        cache: Cache<String, TxResponse>,
+        confirmation_ledgers: u32,
+    ) -> Self {
+        Self {
+            client: json_rpc::Client::new_http(url, client),
+            cache,
+            confirmation_ledgers,
+        }
+    }
+}
+
+impl<P> Client<P>
+where
+    P: JsonRpcClient,
+{
+    /// Returns all contract events emitted in the given ledger
+    pub async fn ledger_events(&self, ledger: u32) -> Result<Vec<EventResponse>, Error> {
+        let filters = json!([{ "type": "contract" }]);
+        let mut params = json!({
+            "startLedger": ledger,
+            "filters": filters,
+            "pagination": { "limit": EVENTS_PAGE_LIMIT },
+        });
+        let mut events = vec![];
+
+        loop {
+            let page: GetEventsResponse = self
+                .client
+                .request("getEvents", params)
+                .await
+                .change_context(Error::Events)?;
+
+            let is_full_page = page.events.len() >= EVENTS_PAGE_LIMIT;
+            let last_event = page
+                .events
+                .last()
+                .map(|event| (event.id.clone(), event.ledger));
+
+            // pages may contain events of later ledgers
+            events.extend(
+                page.events
+                    .into_iter()
+                    .filter(|event| event.ledger == ledger),
+            );
+
+            match last_event {
+                Some((cursor, last_ledger)) if is_full_page && last_ledger <= ledger => {
+                    params = json!({
+                        "filters": filters,
+                        "pagination": { "cursor": cursor, "limit": EVENTS_PAGE_LIMIT },
+                    });
+                }
+                _ => return Ok(events),
+            }
+        }
+    }
+
+    async fn fetch_transaction_response(
+        &self,
+        tx_hash: String,
+    ) -> Result<Option<TxResponse>, Error> {
+        let response: GetTransactionResponse = self
+            .client
+            .request("getTransaction", json!({ "hash": tx_hash }))
+            .await
+            .change_context(Error::TxResponse)?;
+
+        let (Some(ledger), Some(envelope_xdr), Some(result_meta_xdr)) = (
+            response.ledger,
+            response.envelope_xdr,
+            response.result_meta_xdr,
+        ) else {
+            return match response.status {
+                TransactionStatus::NotFound => Ok(None),
+                _ => Err(report!(Error::InvalidResponse)),
+            };
+        };
+
+        let envelope = TransactionEnvelope::from_xdr_base64(envelope_xdr, Limits::none())
+            .change_context(Error::InvalidResponse)?;
+
+        let contract_events =
+            match TransactionMeta::from_xdr_base64(result_meta_xdr, Limits::none()) {
+                Ok(TransactionMeta::V3(data)) => data
+                    .soroban_meta
+                    .map(|meta| meta.events)
+                    .unwrap_or_default(),
+                // fall back to the events endpoint if the meta does not contain the contract events
+                _ => self.transaction_events(ledger, &tx_hash).await?,
+            };
+
+        let tx_response = TxResponse {
+            transaction_hash: tx_hash,
+            source_address: source_address(&envelope),
+            successful: response.status == TransactionStatus::Success,
+            ledger_closed: ledger.saturating_add(self.confirmation_ledgers)
+                <= response.latest_ledger,
+            contract_events: Some(contract_events),
+        };
+
+        if tx_response.is_ledger_closed() {
+            self.cache
+                .insert(tx_response.tx_hash(), tx_response.clone());
+        }
+
+        Ok(Some(tx_response))
+    }
+
+    async fn transaction_events(
+        &self,
+        ledger: u32,
+        tx_hash: &str,
+    ) -> Result<VecM<ContractEvent>, Error> {
+        self.ledger_events(ledger)
+            .await?
+            .into_iter()
+            .filter(|event| event.tx_hash == tx_hash)
+            .map(ContractEvent::try_from)
+            .collect::<Result<Vec<_>, _>>()?
+            .try_into()
+            .change_context(Error::InvalidResponse)
+    }
+}
+
+#[async_trait]
+impl<P> StellarClient for Client<P>
+where
+    P: JsonRpcClient,
+{
+    async fn transaction_responses(
+        &self,
+        tx_hashes: HashSet<String>,
+    ) -> Result<HashMap<String, TxResponse>, Error> {
+        join_all(
+            tx_hashes
+                .into_iter()
+                .map(|tx_hash| self.transaction_response(tx_hash)),
+        )
+        .await
+        .into_iter()
+        .filter_map(|tx_response| match tx_response {
+            Ok(tx_response) => {
+                tx_response.map(|tx_response| Ok((tx_response.tx_hash(), tx_response)))
+            }
+            Err(err) => Some(Err(err)),
+        })
+        .collect()
+    }
+
+    async fn transaction_response(&self, tx_hash: String) -> Result<Option<TxResponse>, Error> {
+        if let Some(tx_response) = self.cache.get(&tx_hash) {
+            return Ok(Some(tx_response));
+        }
+
+        self.fetch_transaction_response(tx_hash).await
+    }
+}
+
+fn source_address(envelope: &TransactionEnvelope) -> ScAddress {
+    let source_account = match envelope {
+        TransactionEnvelope::TxV0(envelope) => {
+            MuxedAccount::Ed25519(envelope.tx.source_account_ed25519.clone())
+        }
+        TransactionEnvelope::Tx(envelope) => envelope.tx.source_account.clone(),
+        TransactionEnvelope::TxFeeBump(envelope) => match &envelope.tx.inner_tx {
+            FeeBumpTransactionInnerTx::Tx(inner) => inner.tx.source_account.clone(),
+        },
+    };
+
+    let key = match source_account {
+        MuxedAccount::Ed25519(key) => key,
+        MuxedAccount::MuxedEd25519(account) => account.ed25519,
+    };
+
+    ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::{json, Value};
+    use stellar_xdr::curr::{
+        ContractEvent, ContractEventBody, Limits, Memo, MuxedAccount, Preconditions, ScAddress,
+        ScSymbol, ScVal, SequenceNumber, StringM, Transaction, TransactionEnvelope, TransactionExt,
+        TransactionMeta, TransactionV1Envelope, Uint256, VecM, WriteXdr,
+    };
+
+    use crate::cache::{self, Cache};
+    use crate::json_rpc;
+    use crate::json_rpc::test_utils::MockProvider;
+    use crate::stellar::rpc_client::{source_address, Client, EventResponse, EVENTS_PAGE_LIMIT};
+    use crate::stellar::StellarClient;
+
+    #[test]
+    fn should_get_source_address_of_transaction() {
+        let key = Uint256([1u8; 32]);
+
+        assert_eq!(
+            source_address(&envelope(key.clone())),
+            ScAddress::Account(stellar_xdr::curr::AccountId(
+                stellar_xdr::curr::PublicKey::PublicKeyTypeEd25519(key)
+            ))
+        );
+    }
+
+    #[test]
+    fn should_convert_event_response_to_contract_event() {
+        let contract = ScAddress::Contract(stellar_xdr::curr::Hash::from([2u8; 32])).to_string();
+        let topic: ScVal = ScSymbol(StringM::from_str("called").unwrap()).into();
+        let value = ScVal::U32(1);
+
+        let event = ContractEvent::try_from(EventResponse {
+            id: "1".to_string(),
+            ledger: 1,
+            contract_id: contract.clone(),
+            tx_hash: "tx_hash".to_string(),
+            topic: vec![topic.to_xdr_base64(Limits::none()).unwrap()],
+            value: value.to_xdr_base64(Limits::none()).unwrap(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            event
+                .contract_id
+                .map(|hash| ScAddress::Contract(hash).to_string()),
+            Some(contract)
+        );
+        let ContractEventBody::V0(body) = event.body;
+        assert_eq!(body.topics.to_vec(), vec![topic]);
+        assert_eq!(body.data, value);
+    }
+
+    #[test]
+    fn should_not_convert_event_response_with_invalid_xdr() {
+        let event = ContractEvent::try_from(EventResponse {
+            id: "1".to_string(),
+            ledger: 1,
+            contract_id: ScAddress::Contract(stellar_xdr::curr::Hash::from([2u8; 32])).to_string(),
+            tx_hash: "tx_hash".to_string(),
+            topic: vec!["invalid".to_string()],
+            value: "invalid".to_string(),
+        });
+
+        assert!(event.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_follow_events_pagination_until_the_ledger_is_complete() {
+        let provider = MockProvider::default();
+        provider.push(json!({
+            "events": (0..EVENTS_PAGE_LIMIT).map(|id| event(id, 5)).collect::<Vec<_>>()
+        }));
+        provider.push(json!({
+            "events": [
+                event(EVENTS_PAGE_LIMIT, 5),
+                event(EVENTS_PAGE_LIMIT.saturating_add(1), 6),
+            ]
+        }));
+
+        let events = client(provider.clone(), 0).ledger_events(5).await.unwrap();
+
+        assert_eq!(events.len(), EVENTS_PAGE_LIMIT.saturating_add(1));
+        assert!(events.iter().all(|event| event.ledger == 5));
+
+        let requests = provider.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].1["startLedger"], json!(5));
+        assert_eq!(
+            requests[1],
+            (
+                "getEvents".to_string(),
+                json!({
+                    "filters": [{ "type": "contract" }],
+                    "pagination": {
+                        "cursor": EVENTS_PAGE_LIMIT.saturating_sub(1).to_string(),
+                        "limit": EVENTS_PAGE_LIMIT
+                    },
+                })
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_request_next_events_page_once_a_later_ledger_is_reached() {
+        let provider = MockProvider::default();
+        let mut events: Vec<_> = (0..EVENTS_PAGE_LIMIT.saturating_sub(1))
+            .map(|id| event(id, 5))
+            .collect();
+        events.push(event(EVENTS_PAGE_LIMIT.saturating_sub(1), 6));
+        provider.push(json!({ "events": events }));
+
+        let events = client(provider.clone(), 0).ledger_events(5).await.unwrap();
+
+        assert_eq!(events.len(), EVENTS_PAGE_LIMIT.saturating_sub(1));
+        assert_eq!(provider.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_get_ledger_events_if_rpc_fails() {
+        let provider = MockProvider::default();
+        provider.push_error(-32603, "internal error");
+
+        assert!(client(provider, 0).ledger_events(5).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_only_cache_transactions_of_confirmed_ledgers() {
+        let provider = MockProvider::default();
+        provider.push(transaction_response(10, 11));
+        provider.push(json!({ "events": [] }));
+        provider.push(transaction_response(10, 12));
+        provider.push(json!({ "events": [] }));
+
+        let client = client(provider.clone(), 2);
+
+        let tx_response = client
+            .transaction_response("tx_hash".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!tx_response.is_ledger_closed());
+
+        let tx_response = client
+            .transaction_response("tx_hash".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(tx_response.is_ledger_closed());
+        assert!(!tx_response.has_failed());
+
+        // served from the cache
+        let tx_response = client
+            .transaction_response("tx_hash".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(tx_response.is_ledger_closed());
+        assert_eq!(provider.requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn should_return_none_if_transaction_is_not_found() {
+        let provider = MockProvider::default();
+        provider.push(json!({ "status": "NOT_FOUND", "latestLedger": 10 }));
+
+        assert!(client(provider, 0)
+            .transaction_response("tx_hash".to_string())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    fn client(provider: MockProvider, confirmation_ledgers: u32) -> Client<MockProvider> {
+        Client {
+            client: json_rpc::Client::new(provider),
+            cache: Cache::new("test", cache::Config::default(), &cache::Metrics::default()),
+            confirmation_ledgers,
+        }
+    }
+
+    fn event(id: usize, ledger: u32) -> Value {
+        json!({
+            "id": id.to_string(),
+            "ledger": ledger,
+            "contractId": "contract",
+            "txHash": "tx_hash",
+            "topic": [],
+            "value": "value",
+        })
+    }
+
+    // the result meta does not contain the contract events, so the client falls back to the events endpoint
+    fn transaction_response(ledger: u32, latest_ledger: u32) -> Value {
+        json!({
+            "status": "SUCCESS",
+            "latestLedger": latest_ledger,
+            "ledger": ledger,
+            "envelopeXdr": envelope(Uint256([1u8; 32])).to_xdr_base64(Limits::none()).unwrap(),
+            "resultMetaXdr": TransactionMeta::V0(VecM::default())
+                .to_xdr_base64(Limits::none())
+                .unwrap(),
+        })
+    }
+
+    fn envelope(key: Uint256) -> TransactionEnvelope {
+        TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx: Transaction {
+                source_account: MuxedAccount::Ed25519(key),
+                fee: 100,
+                seq_num: SequenceNumber(1),
+                cond: Preconditions::None,
+                memo: Memo::None,
+                operations: vec![].try_into().unwrap(),
+                ext: TransactionExt::V0,
+            },
+            signatures: vec![].try_into().unwrap(),
+        })
+    }
+}
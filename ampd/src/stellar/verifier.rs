@@ -114,6 +114,11 @@ fn verify<'a>(
         return Vote::NotFound;
     }
 
+    // transactions of ledgers that are not closed yet are not final
+    if !tx_receipt.is_ledger_closed() {
+        return Vote::NotFound;
+    }
+
     if tx_receipt.has_failed() {
         return Vote::FailedOnChain;
     }
@@ -243,6 +248,17 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_not_verify_msg_if_ledger_is_not_closed() {
+        let (gateway_address, mut tx_response, msg) = matching_msg_and_tx_block();
+        tx_response.ledger_closed = false;
+
+        assert_eq!(
+            verify_message(&gateway_address, &tx_response, &msg),
+            Vote::NotFound
+        );
+    }
+
     #[test]
     fn should_not_verify_verifier_set_if_tx_id_does_not_match() {
         let (gateway_address, tx_response, mut confirmation) = matching_verifier_set_and_tx_block();
@@ -285,6 +301,17 @@ mod test {
         );
     }
 
+    #[test]
+    fn should_not_verify_verifier_set_if_ledger_is_not_closed() {
+        let (gateway_address, mut tx_response, confirmation) = matching_verifier_set_and_tx_block();
+        tx_response.ledger_closed = false;
+
+        assert_eq!(
+            verify_verifier_set(&gateway_address, &tx_response, &confirmation),
+            Vote::NotFound
+        );
+    }
+
     #[test]
     fn should_verify_verifier_set_if_correct() {
         let (gateway_address, tx_response, confirmation) = matching_verifier_set_and_tx_block();
@@ -345,6 +372,7 @@ mod test {
             transaction_hash: msg.tx_id.clone(),
             source_address: msg.source_address.clone(),
             successful: true,
+            ledger_closed: true,
             contract_events: Some(vec![event].try_into().unwrap()),
         };
 
@@ -400,6 +428,7 @@ mod test {
                 Uint256::from(SigningKey::generate(&mut OsRng).verifying_key().to_bytes()),
             ))),
             successful: true,
+            ledger_closed: true,
             contract_events: Some(vec![event].try_into().unwrap()),
         };
 
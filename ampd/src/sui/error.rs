@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("JSON-RPC error")]
+    JsonRPC,
+    #[error("checkpoint {0} has different digests on the RPC and the cross-check RPC")]
+    CheckpointMismatch(u64),
+}
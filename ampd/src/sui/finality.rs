@@ -0,0 +1,231 @@
+use std::collections::{HashMap, HashSet};
+
+use error_stack::{report, Result, ResultExt};
+use futures::future::try_join_all;
+use sui_json_rpc_types::SuiTransactionBlockResponse;
+use sui_types::digests::TransactionDigest;
+
+use crate::sui::error::Error;
+use crate::sui::json_rpc::{Checkpoint, SuiClient};
+
+/// Only keeps the transaction blocks that are included in a certified checkpoint which is followed by at least
+/// `confirmation_checkpoints` checkpoints. If a cross-check client is given, the digests of these checkpoints must match on both RPCs.
+pub async fn finalized_transaction_blocks<C>(
+    rpc_client: &C,
+    cross_check_client: Option<&C>,
+    confirmation_checkpoints: u64,
+    blocks: HashMap<TransactionDigest, SuiTransactionBlockResponse>,
+) -> Result<HashMap<TransactionDigest, SuiTransactionBlockResponse>, Error>
+where
+    C: SuiClient + Send + Sync,
+{
+    if blocks.is_empty() {
+        return Ok(blocks);
+    }
+
+    let latest_checkpoint = rpc_client
+        .latest_checkpoint_sequence_number()
+        .await
+        .change_context(Error::JsonRPC)?;
+
+    let sequence_numbers: HashSet<_> = blocks
+        .values()
+        .filter_map(|block| block.checkpoint)
+        .filter(|sequence_number| {
+            sequence_number.saturating_add(confirmation_checkpoints) <= latest_checkpoint
+        })
+        .collect();
+
+    let checkpoints: HashMap<_, _> =
+        try_join_all(sequence_numbers.into_iter().map(|sequence_number| {
+            certified_checkpoint(rpc_client, cross_check_client, sequence_number)
+        }))
+        .await?
+        .into_iter()
+        .map(|checkpoint| (checkpoint.sequence_number, checkpoint))
+        .collect();
+
+    Ok(blocks
+        .into_iter()
+        .filter(|(digest, block)| {
+            block
+                .checkpoint
+                .and_then(|sequence_number| checkpoints.get(&sequence_number))
+                .is_some_and(|checkpoint| checkpoint.transactions.contains(digest))
+        })
+        .collect())
+}
+
+async fn certified_checkpoint<C>(
+    rpc_client: &C,
+    cross_check_client: Option<&C>,
+    sequence_number: u64,
+) -> Result<Checkpoint, Error>
+where
+    C: SuiClient + Send + Sync,
+{
+    let checkpoint = rpc_client
+        .checkpoint(sequence_number)
+        .await
+        .change_context(Error::JsonRPC)?;
+
+    if let Some(cross_check_client) = cross_check_client {
+        let other = cross_check_client
+            .checkpoint(sequence_number)
+            .await
+            .change_context(Error::JsonRPC)?;
+
+        if other.digest != checkpoint.digest {
+            return Err(report!(Error::CheckpointMismatch(sequence_number)));
+        }
+    }
+
+    Ok(checkpoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use sui_json_rpc_types::SuiTransactionBlockResponse;
+    use sui_types::digests::{CheckpointDigest, TransactionDigest};
+
+    use crate::sui::error::Error;
+    use crate::sui::finality::finalized_transaction_blocks;
+    use crate::sui::json_rpc::{Checkpoint, MockSuiClient};
+
+    #[tokio::test]
+    async fn should_only_keep_blocks_in_checkpoints_with_enough_confirmations() {
+        let finalized = TransactionDigest::random();
+        let not_confirmed = TransactionDigest::random();
+        let not_in_checkpoint = TransactionDigest::random();
+        let without_checkpoint = TransactionDigest::random();
+
+        let mut rpc_client = MockSuiClient::new();
+        rpc_client
+            .expect_latest_checkpoint_sequence_number()
+            .returning(|| Ok(10));
+        rpc_client
+            .expect_checkpoint()
+            .withf(|sequence_number| *sequence_number == 8)
+            .once()
+            .returning(move |sequence_number| {
+                Ok(checkpoint(
+                    sequence_number,
+                    CheckpointDigest::random(),
+                    vec![finalized],
+                ))
+            });
+
+        let blocks = HashMap::from([
+            (finalized, tx_block(finalized, Some(8))),
+            (not_confirmed, tx_block(not_confirmed, Some(9))),
+            (not_in_checkpoint, tx_block(not_in_checkpoint, Some(8))),
+            (without_checkpoint, tx_block(without_checkpoint, None)),
+        ]);
+
+        let blocks = finalized_transaction_blocks(&rpc_client, None, 2, blocks)
+            .await
+            .unwrap();
+
+        assert_eq!(blocks.len(), 1);
+        assert!(blocks.contains_key(&finalized));
+    }
+
+    #[tokio::test]
+    async fn should_accept_checkpoints_that_match_on_the_cross_check_rpc() {
+        let digest = TransactionDigest::random();
+        let checkpoint_digest = CheckpointDigest::random();
+
+        let mut rpc_client = MockSuiClient::new();
+        rpc_client
+            .expect_latest_checkpoint_sequence_number()
+            .returning(|| Ok(10));
+        rpc_client
+            .expect_checkpoint()
+            .returning(move |sequence_number| {
+                Ok(checkpoint(sequence_number, checkpoint_digest, vec![digest]))
+            });
+
+        let mut cross_check_client = MockSuiClient::new();
+        cross_check_client
+            .expect_checkpoint()
+            .returning(move |sequence_number| {
+                Ok(checkpoint(sequence_number, checkpoint_digest, vec![digest]))
+            });
+
+        let blocks = finalized_transaction_blocks(
+            &rpc_client,
+            Some(&cross_check_client),
+            0,
+            HashMap::from([(digest, tx_block(digest, Some(5)))]),
+        )
+        .await
+        .unwrap();
+
+        assert!(blocks.contains_key(&digest));
+    }
+
+    #[tokio::test]
+    async fn should_fail_if_checkpoint_digests_do_not_match() {
+        let digest = TransactionDigest::random();
+
+        let mut rpc_client = MockSuiClient::new();
+        rpc_client
+            .expect_latest_checkpoint_sequence_number()
+            .returning(|| Ok(10));
+        rpc_client
+            .expect_checkpoint()
+            .returning(move |sequence_number| {
+                Ok(checkpoint(
+                    sequence_number,
+                    CheckpointDigest::random(),
+                    vec![digest],
+                ))
+            });
+
+        let mut cross_check_client = MockSuiClient::new();
+        cross_check_client
+            .expect_checkpoint()
+            .returning(move |sequence_number| {
+                Ok(checkpoint(
+                    sequence_number,
+                    CheckpointDigest::random(),
+                    vec![digest],
+                ))
+            });
+
+        let result = finalized_transaction_blocks(
+            &rpc_client,
+            Some(&cross_check_client),
+            0,
+            HashMap::from([(digest, tx_block(digest, Some(5)))]),
+        )
+        .await;
+
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            Error::CheckpointMismatch(5)
+        ));
+    }
+
+    fn checkpoint(
+        sequence_number: u64,
+        digest: CheckpointDigest,
+        transactions: Vec<TransactionDigest>,
+    ) -> Checkpoint {
+        Checkpoint {
+            sequence_number,
+            digest,
+            transactions,
+        }
+    }
+
+    fn tx_block(digest: TransactionDigest, checkpoint: Option<u64>) -> SuiTransactionBlockResponse {
+        SuiTransactionBlockResponse {
+            digest,
+            checkpoint,
+            ..Default::default()
+        }
+    }
+}
@@ -1,15 +1,38 @@
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use async_trait::async_trait;
-use ethers_providers::{JsonRpcClient, ProviderError};
+use ethers_providers::{JsonRpcClient, ProviderError, RpcError};
 use mockall::automock;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
 use sui_json_rpc_types::{SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions};
-use sui_types::digests::TransactionDigest;
+use sui_types::digests::{CheckpointDigest, TransactionDigest};
 
+use crate::cache::{self, Cache};
 use crate::json_rpc::Client;
 
 type Result<T> = error_stack::Result<T, ProviderError>;
 
+// Sui responds with an invalid params error instead of null if a transaction does not exist
+const INVALID_PARAMS_CODE: i64 = -32602;
+
+fn is_not_found(err: &ProviderError) -> bool {
+    err.as_error_response()
+        .is_some_and(|err| err.code == INVALID_PARAMS_CODE)
+}
+
+/// The fields of a certified checkpoint that are needed to verify the finality of transactions
+#[serde_as]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    #[serde_as(as = "DisplayFromStr")]
+    pub sequence_number: u64,
+    pub digest: CheckpointDigest,
+    pub transactions: Vec<TransactionDigest>,
+}
+
 #[automock]
 #[async_trait]
 pub trait SuiClient {
@@ -21,6 +44,8 @@ pub trait SuiClient {
         &self,
         digests: HashSet<TransactionDigest>,
     ) -> Result<HashMap<TransactionDigest, SuiTransactionBlockResponse>>;
+    async fn latest_checkpoint_sequence_number(&self) -> Result<u64>;
+    async fn checkpoint(&self, sequence_number: u64) -> Result<Checkpoint>;
 }
 
 #[async_trait]
@@ -32,16 +57,22 @@ where
         &self,
         digest: TransactionDigest,
     ) -> Result<Option<SuiTransactionBlockResponse>> {
-        self.request(
-            "sui_getTransactionBlock",
-            (
-                digest.base58_encode(),
-                SuiTransactionBlockResponseOptions::new().with_events(),
-            ),
-        )
-        .await
-        // Checkpoint number exits when this transaction was included and finalized.
-        .map(|block: SuiTransactionBlockResponse| block.checkpoint.map(|_| block))
+        match self
+            .request(
+                "sui_getTransactionBlock",
+                (
+                    digest.base58_encode(),
+                    SuiTransactionBlockResponseOptions::new().with_events(),
+                ),
+            )
+            .await
+        {
+            // Checkpoint number exits when this transaction was included and finalized.
+            Ok(block) => Ok(Some(block)
+                .filter(|block: &SuiTransactionBlockResponse| block.checkpoint.is_some())),
+            Err(err) if is_not_found(err.current_context()) => Ok(None),
+            Err(err) => Err(err),
+        }
     }
 
     async fn finalized_transaction_blocks(
@@ -67,4 +98,302 @@ where
                 .collect()
         })
     }
+
+    async fn latest_checkpoint_sequence_number(&self) -> Result<u64> {
+        self.request("sui_getLatestCheckpointSequenceNumber", ())
+            .await
+            .and_then(|sequence_number: String| {
+                sequence_number.parse().map_err(|_| {
+                    ProviderError::CustomError(format!(
+                        "invalid checkpoint sequence number {}",
+                        sequence_number
+                    ))
+                    .into()
+                })
+            })
+    }
+
+    async fn checkpoint(&self, sequence_number: u64) -> Result<Checkpoint> {
+        self.request("sui_getCheckpoint", [sequence_number.to_string()])
+            .await
+    }
+}
+
+/// Caches the responses of the wrapped client, so that all Sui handlers can share them.
+pub struct CachedClient<C>
+where
+    C: SuiClient,
+{
+    client: Arc<C>,
+    transaction_blocks: Cache<TransactionDigest, Option<SuiTransactionBlockResponse>>,
+    latest_checkpoint_sequence_number: Cache<(), u64>,
+    checkpoints: Cache<u64, Checkpoint>,
+}
+
+impl<C> CachedClient<C>
+where
+    C: SuiClient,
+{
+    pub fn new(client: C, config: cache::Config, metrics: &cache::Metrics) -> Self {
+        Self {
+            client: Arc::new(client),
+            transaction_blocks: Cache::new("sui-transaction-blocks", config.clone(), metrics),
+            latest_checkpoint_sequence_number: Cache::new(
+                "sui-latest-checkpoint-sequence-number",
+                config.clone(),
+                metrics,
+            ),
+            checkpoints: Cache::new("sui-checkpoints", config, metrics),
+        }
+    }
+}
+
+impl<C> Clone for CachedClient<C>
+where
+    C: SuiClient,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            transaction_blocks: self.transaction_blocks.clone(),
+            latest_checkpoint_sequence_number: self.latest_checkpoint_sequence_number.clone(),
+            checkpoints: self.checkpoints.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl<C> SuiClient for CachedClient<C>
+where
+    C: SuiClient + Send + Sync,
+{
+    async fn finalized_transaction_block(
+        &self,
+        digest: TransactionDigest,
+    ) -> Result<Option<SuiTransactionBlockResponse>> {
+        self.transaction_blocks
+            .get_or_try_fetch(digest, || self.client.finalized_transaction_block(digest))
+            .await
+    }
+
+    async fn finalized_transaction_blocks(
+        &self,
+        digests: HashSet<TransactionDigest>,
+    ) -> Result<HashMap<TransactionDigest, SuiTransactionBlockResponse>> {
+        let mut blocks = HashMap::new();
+        let mut missing_digests = HashSet::new();
+        for digest in digests {
+            match self.transaction_blocks.get(&digest) {
+                Some(Some(block)) => {
+                    blocks.insert(digest, block);
+                }
+                _ => {
+                    missing_digests.insert(digest);
+                }
+            }
+        }
+
+        if !missing_digests.is_empty() {
+            for (digest, block) in self
+                .client
+                .finalized_transaction_blocks(missing_digests)
+                .await?
+            {
+                self.transaction_blocks.insert(digest, Some(block.clone()));
+                blocks.insert(digest, block);
+            }
+        }
+
+        Ok(blocks)
+    }
+
+    async fn latest_checkpoint_sequence_number(&self) -> Result<u64> {
+        self.latest_checkpoint_sequence_number
+            .get_or_try_fetch((), || self.client.latest_checkpoint_sequence_number())
+            .await
+    }
+
+    async fn checkpoint(&self, sequence_number: u64) -> Result<Checkpoint> {
+        self.checkpoints
+            .get_or_try_fetch(sequence_number, || self.client.checkpoint(sequence_number))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use ethers_providers::{HttpClientError, JsonRpcError, ProviderError};
+    use serde_json::json;
+    use sui_json_rpc_types::SuiTransactionBlockResponse;
+    use sui_types::digests::TransactionDigest;
+
+    use crate::cache;
+    use crate::json_rpc::test_utils::MockProvider;
+    use crate::json_rpc::Client;
+    use crate::sui::json_rpc::{is_not_found, CachedClient, MockSuiClient, SuiClient};
+
+    #[test]
+    fn should_only_treat_unknown_transactions_as_not_found() {
+        let rpc_error = |code: i64, message: &str| {
+            ProviderError::from(HttpClientError::JsonRpcError(JsonRpcError {
+                code,
+                message: message.to_string(),
+                data: None,
+            }))
+        };
+
+        assert!(is_not_found(&rpc_error(
+            -32602,
+            "Could not find the referenced transaction [TransactionDigest(11111111111111111111111111111111)]."
+        )));
+        assert!(!is_not_found(&rpc_error(-32603, "Internal error")));
+        assert!(!is_not_found(&rpc_error(-32050, "Transient error")));
+        assert!(!is_not_found(&ProviderError::CustomError(
+            "connection refused".to_string()
+        )));
+    }
+
+    #[tokio::test]
+    async fn should_get_finalized_transaction_block() {
+        let digest = TransactionDigest::random();
+
+        let provider = MockProvider::default();
+        provider.push(SuiTransactionBlockResponse {
+            checkpoint: Some(1),
+            ..tx_block(digest)
+        });
+        provider.push(tx_block(digest));
+
+        let client = Client::new(provider);
+        assert!(client
+            .finalized_transaction_block(digest)
+            .await
+            .unwrap()
+            .is_some());
+        // not included in a checkpoint yet
+        assert!(client
+            .finalized_transaction_block(digest)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn should_return_none_for_unknown_transaction_block() {
+        let provider = MockProvider::default();
+        provider.push_error(
+            -32602,
+            "Could not find the referenced transaction [TransactionDigest(11111111111111111111111111111111)].",
+        );
+
+        assert!(Client::new(provider)
+            .finalized_transaction_block(TransactionDigest::random())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn should_fail_if_rpc_is_unavailable() {
+        let provider = MockProvider::default();
+        provider.push_error(-32603, "Internal error");
+        provider.push_error(-32050, "Transient error");
+        provider.push_error(-32603, "Internal error");
+        provider.push(json!("not a number"));
+
+        let client = Client::new(provider);
+        assert!(client
+            .finalized_transaction_block(TransactionDigest::random())
+            .await
+            .is_err());
+        assert!(client
+            .finalized_transaction_blocks(HashSet::from([TransactionDigest::random()]))
+            .await
+            .is_err());
+        assert!(client.checkpoint(1).await.is_err());
+        assert!(client.latest_checkpoint_sequence_number().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn cached_client_should_not_cache_rpc_failures() {
+        let digest = TransactionDigest::random();
+
+        let mut client = MockSuiClient::new();
+        client
+            .expect_finalized_transaction_block()
+            .once()
+            .returning(
+                |_| Err(ProviderError::CustomError("connection refused".to_string()).into()),
+            );
+        client
+            .expect_finalized_transaction_block()
+            .once()
+            .returning(|digest| Ok(Some(tx_block(digest))));
+
+        let cached_client =
+            CachedClient::new(client, cache::Config::default(), &cache::Metrics::default());
+
+        assert!(cached_client
+            .finalized_transaction_block(digest)
+            .await
+            .is_err());
+        assert!(cached_client
+            .finalized_transaction_block(digest)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn cached_client_should_only_fetch_missing_transaction_blocks() {
+        let cached_digest = TransactionDigest::random();
+        let missing_digest = TransactionDigest::random();
+
+        let mut client = MockSuiClient::new();
+        client
+            .expect_finalized_transaction_block()
+            .once()
+            .returning(|digest| Ok(Some(tx_block(digest))));
+        client
+            .expect_finalized_transaction_blocks()
+            .once()
+            .withf(move |digests| digests == &HashSet::from([missing_digest]))
+            .returning(|digests| {
+                Ok(digests
+                    .into_iter()
+                    .map(|digest| (digest, tx_block(digest)))
+                    .collect::<HashMap<_, _>>())
+            });
+
+        let cached_client =
+            CachedClient::new(client, cache::Config::default(), &cache::Metrics::default());
+        let other_cached_client = cached_client.clone();
+
+        assert!(cached_client
+            .finalized_transaction_block(cached_digest)
+            .await
+            .unwrap()
+            .is_some());
+
+        let blocks = other_cached_client
+            .finalized_transaction_blocks(HashSet::from([cached_digest, missing_digest]))
+            .await
+            .unwrap();
+        assert_eq!(blocks.len(), 2);
+
+        let blocks = cached_client
+            .finalized_transaction_blocks(HashSet::from([cached_digest, missing_digest]))
+            .await
+            .unwrap();
+        assert_eq!(blocks.len(), 2);
+    }
+
+    fn tx_block(digest: TransactionDigest) -> SuiTransactionBlockResponse {
+        SuiTransactionBlockResponse {
+            digest,
+            ..Default::default()
+        }
+    }
 }
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use cosmrs::tendermint::public_key::PublicKey as TMPublicKey;
-use error_stack::{Report, ResultExt};
+use error_stack::{report, Report, ResultExt};
 use k256::Secp256k1;
 use mockall::automock;
 use tokio::sync::Mutex;
@@ -19,16 +19,48 @@ use crate::url::Url;
 
 type Result<T> = error_stack::Result<T, Error>;
 
+const BLS_PUB_KEY_LEN: usize = 48;
+const BLS_SIGNATURE_LEN: usize = 96;
+
 #[automock]
 #[async_trait]
-pub trait Multisig {
-    async fn keygen(&self, key_uid: &str, algorithm: Algorithm) -> Result<PublicKey>;
+pub trait Multisig: Send + Sync {
+    async fn keygen(&self, key_uid: &str, algorithm: Algorithm) -> Result<PublicKey> {
+        let pub_key = self.keygen_raw(key_uid, algorithm).await?;
+
+        match algorithm {
+            // schnorr keys are secp256k1 points, so tofnd returns them in SEC1 format as well
+            Algorithm::Ecdsa | Algorithm::Schnorr => TMPublicKey::from_raw_secp256k1(&pub_key),
+            Algorithm::Ed25519 => TMPublicKey::from_raw_ed25519(&pub_key),
+            // BLS keys have no tendermint representation, use keygen_raw instead
+            Algorithm::Bls => None,
+        }
+        .ok_or_else(|| Report::new(Error::ParsingFailed))
+        .attach_printable(format!("{{ invalid_value = {:?} }}", pub_key))
+        .map(Into::into)
+    }
+
     async fn sign(
         &self,
         key_uid: &str,
         data: MessageDigest,
         pub_key: &PublicKey,
         algorithm: Algorithm,
+    ) -> Result<Signature> {
+        self.sign_raw(key_uid, data, &pub_key.to_bytes(), algorithm)
+            .await
+    }
+
+    /// Returns the public key in the encoding tofnd uses for the given algorithm,
+    /// which also covers keys that can't be represented as a [PublicKey], like BLS keys
+    async fn keygen_raw(&self, key_uid: &str, algorithm: Algorithm) -> Result<Vec<u8>>;
+
+    async fn sign_raw(
+        &self,
+        key_uid: &str,
+        data: MessageDigest,
+        pub_key: &[u8],
+        algorithm: Algorithm,
     ) -> Result<Signature>;
 }
 
@@ -53,7 +85,7 @@ impl MultisigClient {
 
 #[async_trait]
 impl Multisig for MultisigClient {
-    async fn keygen(&self, key_uid: &str, algorithm: Algorithm) -> Result<PublicKey> {
+    async fn keygen_raw(&self, key_uid: &str, algorithm: Algorithm) -> Result<Vec<u8>> {
         let request = KeygenRequest {
             key_uid: key_uid.to_string(),
             party_uid: self.party_uid.to_string(),
@@ -74,30 +106,30 @@ impl Multisig for MultisigClient {
             .change_context(Error::Grpc)
             .and_then(|response| match response {
                 KeygenResponse::PubKey(pub_key) => match algorithm {
-                    Algorithm::Ecdsa => TMPublicKey::from_raw_secp256k1(&pub_key),
-                    Algorithm::Ed25519 => TMPublicKey::from_raw_ed25519(&pub_key),
-                }
-                .ok_or_else(|| Report::new(Error::ParsingFailed))
-                .attach_printable(format!("{{ invalid_value = {:?} }}", pub_key))
-                .map(Into::into),
+                    Algorithm::Bls if pub_key.len() != BLS_PUB_KEY_LEN => {
+                        Err(report!(Error::ParsingFailed))
+                            .attach_printable(format!("{{ invalid_value = {:?} }}", pub_key))
+                    }
+                    _ => Ok(pub_key),
+                },
                 KeygenResponse::Error(error_msg) => {
                     Err(TofndError::ExecutionFailed(error_msg)).change_context(Error::KeygenFailed)
                 }
             })
     }
 
-    async fn sign(
+    async fn sign_raw(
         &self,
         key_uid: &str,
         data: MessageDigest,
-        pub_key: &PublicKey,
+        pub_key: &[u8],
         algorithm: Algorithm,
     ) -> Result<Signature> {
         let request = SignRequest {
             key_uid: key_uid.to_string(),
             msg_to_sign: data.into(),
             party_uid: self.party_uid.to_string(),
-            pub_key: pub_key.to_bytes(),
+            pub_key: pub_key.to_vec(),
             algorithm: algorithm.into(),
         };
 
@@ -121,6 +153,13 @@ impl Multisig for MultisigClient {
                     Algorithm::Ed25519 => ed25519::Signature::from_slice(&signature)
                         .map(|sig| sig.to_vec())
                         .change_context(Error::ParsingFailed),
+                    Algorithm::Schnorr => k256::schnorr::Signature::try_from(signature.as_slice())
+                        .map(|sig| sig.to_bytes().to_vec())
+                        .change_context(Error::ParsingFailed),
+                    // compressed G2 point, it is validated by the multisig contract
+                    Algorithm::Bls if signature.len() == BLS_SIGNATURE_LEN => Ok(signature),
+                    Algorithm::Bls => Err(report!(Error::ParsingFailed))
+                        .attach_printable(format!("{{ invalid_value = {:?} }}", signature)),
                 },
 
                 SignResponse::Error(error_msg) => {
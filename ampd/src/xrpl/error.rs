@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("invalid transaction hash {0}")]
+    InvalidTxHash(String),
+}
@@ -0,0 +1,225 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use error_stack::{report, Report, ResultExt};
+use ethers_providers::{JsonRpcClient, ProviderError};
+use futures::future::try_join_all;
+use mockall::automock;
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::json_rpc::Client;
+use crate::xrpl::error::Error;
+
+type Result<T> = error_stack::Result<T, ProviderError>;
+
+// rippled reports failed requests in the result instead of as a JSON-RPC error
+const TRANSACTION_NOT_FOUND: &str = "txnNotFound";
+
+// the transaction fields are nested in "tx_json" from API version 2 on
+const API_VERSION: u8 = 1;
+
+/// The hash of an XRPL transaction, displayed as uppercase hex like the XRPL does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct TxHash([u8; 32]);
+
+impl From<TxHash> for [u8; 32] {
+    fn from(tx_hash: TxHash) -> Self {
+        tx_hash.0
+    }
+}
+
+impl From<[u8; 32]> for TxHash {
+    fn from(bytes: [u8; 32]) -> Self {
+        TxHash(bytes)
+    }
+}
+
+impl FromStr for TxHash {
+    type Err = Report<Error>;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        hex::decode(s)
+            .change_context(Error::InvalidTxHash(s.to_string()))?
+            .try_into()
+            .map(TxHash)
+            .map_err(|_| report!(Error::InvalidTxHash(s.to_string())))
+    }
+}
+
+impl Display for TxHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode_upper(self.0))
+    }
+}
+
+/// Memo type and data are hex encoded by the XRPL
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Memo {
+    #[serde(rename = "MemoType")]
+    pub memo_type: Option<String>,
+    #[serde(rename = "MemoData")]
+    pub memo_data: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct MemoEntry {
+    #[serde(rename = "Memo")]
+    pub memo: Memo,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Meta {
+    #[serde(rename = "TransactionResult")]
+    pub transaction_result: String,
+}
+
+/// The fields of a transaction that are needed to verify messages
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Transaction {
+    pub hash: TxHash,
+    #[serde(rename = "TransactionType")]
+    pub transaction_type: String,
+    #[serde(rename = "Account")]
+    pub account: String,
+    #[serde(rename = "Destination")]
+    pub destination: Option<String>,
+    #[serde(rename = "Memos", default)]
+    pub memos: Vec<MemoEntry>,
+    pub meta: Option<Meta>,
+    #[serde(default)]
+    pub validated: bool,
+}
+
+#[derive(Serialize, Debug)]
+struct TxParams {
+    transaction: TxHash,
+    binary: bool,
+    api_version: u8,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "status", rename_all = "lowercase")]
+enum Response<T> {
+    Success(T),
+    Error { error: String },
+}
+
+#[automock]
+#[async_trait]
+pub trait XrplClient {
+    /// Only returns the transaction if it is included in a validated ledger
+    async fn validated_tx(&self, tx_hash: TxHash) -> Result<Option<Transaction>>;
+
+    /// Only returns the transactions that were found and are included in a validated ledger
+    async fn validated_txs(
+        &self,
+        tx_hashes: HashSet<TxHash>,
+    ) -> Result<HashMap<TxHash, Transaction>>;
+}
+
+#[async_trait]
+impl<P> XrplClient for Client<P>
+where
+    P: JsonRpcClient + Send + Sync + 'static,
+{
+    async fn validated_tx(&self, tx_hash: TxHash) -> Result<Option<Transaction>> {
+        let params = TxParams {
+            transaction: tx_hash,
+            binary: false,
+            api_version: API_VERSION,
+        };
+
+        let response: Response<Transaction> = self.request("tx", [params]).await?;
+
+        match response {
+            Response::Success(tx) => Ok(Some(tx).filter(|tx| tx.validated)),
+            Response::Error { error } if error == TRANSACTION_NOT_FOUND => Ok(None),
+            Response::Error { error } => Err(report!(ProviderError::CustomError(error))),
+        }
+    }
+
+    async fn validated_txs(
+        &self,
+        tx_hashes: HashSet<TxHash>,
+    ) -> Result<HashMap<TxHash, Transaction>> {
+        Ok(try_join_all(
+            tx_hashes
+                .into_iter()
+                .map(|tx_hash| self.validated_tx(tx_hash)),
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .map(|tx| (tx.hash, tx))
+        .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::xrpl::json_rpc::{Response, Transaction, TxHash};
+
+    const TX_HASH: &str = "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9";
+
+    #[test]
+    fn should_parse_tx_hash_in_any_case() {
+        let upper_case: TxHash = TX_HASH.parse().unwrap();
+        let lower_case: TxHash = TX_HASH.to_lowercase().parse().unwrap();
+
+        assert_eq!(upper_case, lower_case);
+        assert_eq!(lower_case.to_string(), TX_HASH);
+
+        assert!(format!("0x{}", TX_HASH).parse::<TxHash>().is_err());
+        assert!(TX_HASH[2..].parse::<TxHash>().is_err());
+    }
+
+    #[test]
+    fn should_deserialize_tx_response() {
+        let response: Response<Transaction> = serde_json::from_value(serde_json::json!({
+            "Account": "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe",
+            "Amount": "1000000",
+            "Destination": "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            "Fee": "12",
+            "Memos": [{
+                "Memo": {
+                    "MemoData": "657468657265756D",
+                    "MemoType": "64657374696E6174696F6E5F636861696E"
+                }
+            }],
+            "Sequence": 1,
+            "TransactionType": "Payment",
+            "hash": TX_HASH,
+            "ledger_index": 1,
+            "meta": {
+                "TransactionIndex": 0,
+                "TransactionResult": "tesSUCCESS"
+            },
+            "status": "success",
+            "validated": true
+        }))
+        .unwrap();
+
+        let Response::Success(tx) = response else {
+            panic!("expected a successful response")
+        };
+        assert_eq!(tx.hash.to_string(), TX_HASH);
+        assert_eq!(tx.memos.len(), 1);
+        assert!(tx.validated);
+    }
+
+    #[test]
+    fn should_deserialize_error_response() {
+        let response: Response<Transaction> = serde_json::from_value(serde_json::json!({
+            "error": "txnNotFound",
+            "error_code": 29,
+            "error_message": "Transaction not found.",
+            "status": "error"
+        }))
+        .unwrap();
+
+        assert!(matches!(response, Response::Error { error } if error == "txnNotFound"));
+    }
+}
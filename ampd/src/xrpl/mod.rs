@@ -0,0 +1,3 @@
+pub mod error;
+pub mod json_rpc;
+pub mod verifier;
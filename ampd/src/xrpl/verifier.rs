@@ -0,0 +1,206 @@
+use axelar_wasm_std::voting::Vote;
+use router_api::ChainName;
+
+use crate::handlers::xrpl_verify_msg::Message;
+use crate::xrpl::json_rpc::Transaction;
+
+const PAYMENT_TRANSACTION_TYPE: &str = "Payment";
+const SUCCESS_RESULT: &str = "tesSUCCESS";
+
+// messages are sent as payments to the gateway account, with the message fields attached as memos
+const DESTINATION_CHAIN_MEMO: &str = "destination_chain";
+const DESTINATION_ADDRESS_MEMO: &str = "destination_address";
+const PAYLOAD_HASH_MEMO: &str = "payload_hash";
+
+/// Returns the decoded data of the memo with the given type.
+/// Memo types must be unique, so a type that occurs more than once is treated as missing.
+fn memo_data(tx: &Transaction, memo_type: &str) -> Option<Vec<u8>> {
+    let mut memos = tx.memos.iter().map(|entry| &entry.memo).filter(|memo| {
+        memo.memo_type
+            .as_deref()
+            .and_then(|encoded| hex::decode(encoded).ok())
+            .is_some_and(|decoded| decoded == memo_type.as_bytes())
+    });
+
+    match (memos.next(), memos.next()) {
+        (Some(memo), None) => hex::decode(memo.memo_data.as_deref()?).ok(),
+        _ => None,
+    }
+}
+
+fn memo_string(tx: &Transaction, memo_type: &str) -> Option<String> {
+    memo_data(tx, memo_type).and_then(|data| String::from_utf8(data).ok())
+}
+
+impl PartialEq<Transaction> for Message {
+    fn eq(&self, tx: &Transaction) -> bool {
+        tx.account == self.source_address
+            && memo_string(tx, DESTINATION_CHAIN_MEMO)
+                .and_then(|chain| chain.parse::<ChainName>().ok())
+                .is_some_and(|chain| chain == self.destination_chain)
+            && memo_string(tx, DESTINATION_ADDRESS_MEMO)
+                .is_some_and(|destination_address| destination_address == self.destination_address)
+            && memo_data(tx, PAYLOAD_HASH_MEMO)
+                .is_some_and(|payload_hash| payload_hash == self.payload_hash.as_bytes())
+    }
+}
+
+fn is_payment_to(tx: &Transaction, gateway_address: &str) -> bool {
+    tx.transaction_type == PAYMENT_TRANSACTION_TYPE
+        && tx.destination.as_deref() == Some(gateway_address)
+}
+
+fn has_failed(tx: &Transaction) -> bool {
+    !tx.meta
+        .as_ref()
+        .is_some_and(|meta| meta.transaction_result == SUCCESS_RESULT)
+}
+
+pub fn verify_message(gateway_address: &str, tx: &Transaction, msg: &Message) -> Vote {
+    if tx.hash != msg.tx_id || !is_payment_to(tx, gateway_address) {
+        return Vote::NotFound;
+    }
+
+    if has_failed(tx) {
+        return Vote::FailedOnChain;
+    }
+
+    if msg == tx {
+        Vote::SucceededOnChain
+    } else {
+        Vote::NotFound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axelar_wasm_std::voting::Vote;
+
+    use crate::handlers::xrpl_verify_msg::Message;
+    use crate::types::{EVMAddress, Hash};
+    use crate::xrpl::json_rpc::{Memo, MemoEntry, Meta, Transaction};
+    use crate::xrpl::verifier::{
+        verify_message, DESTINATION_ADDRESS_MEMO, DESTINATION_CHAIN_MEMO, PAYLOAD_HASH_MEMO,
+    };
+
+    const GATEWAY_ADDRESS: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+
+    #[test]
+    fn should_verify_msg_if_correct() {
+        let (tx, msg) = matching_msg_and_tx();
+
+        assert_eq!(
+            verify_message(GATEWAY_ADDRESS, &tx, &msg),
+            Vote::SucceededOnChain
+        );
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_tx_id_does_not_match() {
+        let (tx, mut msg) = matching_msg_and_tx();
+        msg.tx_id = Hash::random().to_fixed_bytes().into();
+
+        assert_eq!(verify_message(GATEWAY_ADDRESS, &tx, &msg), Vote::NotFound);
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_not_a_payment_to_the_gateway() {
+        let (mut tx, msg) = matching_msg_and_tx();
+        tx.destination = Some("rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".to_string());
+
+        assert_eq!(verify_message(GATEWAY_ADDRESS, &tx, &msg), Vote::NotFound);
+
+        let (mut tx, msg) = matching_msg_and_tx();
+        tx.transaction_type = "TrustSet".to_string();
+
+        assert_eq!(verify_message(GATEWAY_ADDRESS, &tx, &msg), Vote::NotFound);
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_source_address_does_not_match() {
+        let (tx, mut msg) = matching_msg_and_tx();
+        msg.source_address = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe".to_string();
+
+        assert_eq!(verify_message(GATEWAY_ADDRESS, &tx, &msg), Vote::NotFound);
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_destination_does_not_match() {
+        let (tx, mut msg) = matching_msg_and_tx();
+        msg.destination_chain = "avalanche".parse().unwrap();
+
+        assert_eq!(verify_message(GATEWAY_ADDRESS, &tx, &msg), Vote::NotFound);
+
+        let (tx, mut msg) = matching_msg_and_tx();
+        msg.destination_address = format!("0x{:x}", EVMAddress::random());
+
+        assert_eq!(verify_message(GATEWAY_ADDRESS, &tx, &msg), Vote::NotFound);
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_payload_hash_does_not_match() {
+        let (tx, mut msg) = matching_msg_and_tx();
+        msg.payload_hash = Hash::random();
+
+        assert_eq!(verify_message(GATEWAY_ADDRESS, &tx, &msg), Vote::NotFound);
+    }
+
+    #[test]
+    fn should_not_verify_msg_if_memo_is_duplicated() {
+        let (mut tx, msg) = matching_msg_and_tx();
+        tx.memos
+            .push(memo(PAYLOAD_HASH_MEMO, msg.payload_hash.as_bytes()));
+
+        assert_eq!(verify_message(GATEWAY_ADDRESS, &tx, &msg), Vote::NotFound);
+    }
+
+    #[test]
+    fn should_vote_failed_on_chain_if_tx_failed() {
+        let (mut tx, msg) = matching_msg_and_tx();
+        tx.meta = Some(Meta {
+            transaction_result: "tecPATH_DRY".to_string(),
+        });
+
+        assert_eq!(
+            verify_message(GATEWAY_ADDRESS, &tx, &msg),
+            Vote::FailedOnChain
+        );
+    }
+
+    fn memo(memo_type: &str, memo_data: &[u8]) -> MemoEntry {
+        MemoEntry {
+            memo: Memo {
+                memo_type: Some(hex::encode_upper(memo_type)),
+                memo_data: Some(hex::encode_upper(memo_data)),
+            },
+        }
+    }
+
+    fn matching_msg_and_tx() -> (Transaction, Message) {
+        let msg = Message {
+            tx_id: Hash::random().to_fixed_bytes().into(),
+            source_address: "rrrrrrrrrrrrrrrrrrrrrhoLvTp".to_string(),
+            destination_chain: "ethereum".parse().unwrap(),
+            destination_address: format!("0x{:x}", EVMAddress::random()),
+            payload_hash: Hash::random(),
+        };
+
+        let tx = Transaction {
+            hash: msg.tx_id,
+            transaction_type: "Payment".to_string(),
+            account: msg.source_address.clone(),
+            destination: Some(GATEWAY_ADDRESS.to_string()),
+            memos: vec![
+                memo(DESTINATION_CHAIN_MEMO, b"Ethereum"),
+                memo(DESTINATION_ADDRESS_MEMO, msg.destination_address.as_bytes()),
+                memo(PAYLOAD_HASH_MEMO, msg.payload_hash.as_bytes()),
+            ],
+            meta: Some(Meta {
+                transaction_result: "tesSUCCESS".to_string(),
+            }),
+            validated: true,
+        };
+
+        (tx, msg)
+    }
+}
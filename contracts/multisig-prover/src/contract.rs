@@ -2,7 +2,7 @@ use axelar_wasm_std::{address, permission_control};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response,
+    ensure, to_json_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response,
 };
 use error_stack::ResultExt;
 
@@ -16,6 +16,7 @@ mod query;
 mod reply;
 
 pub const START_MULTISIG_REPLY_ID: u64 = 1;
+pub const START_ARBITRARY_SIGNING_REPLY_ID: u64 = 2;
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -29,6 +30,14 @@ pub fn instantiate(
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    ensure!(
+        msg.encoder.supports_key_type(msg.key_type),
+        ContractError::KeyTypeNotSupported {
+            encoder: msg.encoder,
+            key_type: msg.key_type,
+        }
+    );
+
     let config = Config {
         gateway: address::validate_cosmwasm_address(deps.api, &msg.gateway_address)?,
         multisig: address::validate_cosmwasm_address(deps.api, &msg.multisig_address)?,
@@ -73,6 +82,9 @@ pub fn execute(
     match msg.ensure_permissions(deps.storage, &info.sender)? {
         ExecuteMsg::ConstructProof(message_ids) => Ok(execute::construct_proof(deps, message_ids)?),
         ExecuteMsg::UpdateVerifierSet {} => Ok(execute::update_verifier_set(deps, env)?),
+        ExecuteMsg::TriggerVerifierSetUpdate {} => {
+            Ok(execute::trigger_verifier_set_update(deps, env)?)
+        }
         ExecuteMsg::ConfirmVerifierSet {} => Ok(execute::confirm_verifier_set(deps, info.sender)?),
         ExecuteMsg::UpdateSigningThreshold {
             new_signing_threshold,
@@ -83,6 +95,21 @@ pub fn execute(
         ExecuteMsg::UpdateAdmin { new_admin_address } => {
             Ok(execute::update_admin(deps, new_admin_address)?)
         }
+        ExecuteMsg::UpdateProofLimits { proof_limits } => {
+            Ok(execute::update_proof_limits(deps, proof_limits)?)
+        }
+        ExecuteMsg::UpdateProofEpochWindow { epoch_window } => {
+            Ok(execute::update_proof_epoch_window(deps, epoch_window)?)
+        }
+        ExecuteMsg::UpdateVerifierSetUpdateInterval { min_interval } => Ok(
+            execute::update_verifier_set_update_interval(deps, min_interval)?,
+        ),
+        ExecuteMsg::SignArbitraryPayload { payload } => {
+            Ok(execute::sign_arbitrary_payload(deps, payload)?)
+        }
+        ExecuteMsg::BackfillMessageIndex { limit } => {
+            Ok(execute::backfill_message_index(deps, limit)?)
+        }
     }
 }
 
@@ -94,6 +121,7 @@ pub fn reply(
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
     match reply.id {
         START_MULTISIG_REPLY_ID => reply::start_multisig_reply(deps, reply),
+        START_ARBITRARY_SIGNING_REPLY_ID => reply::start_arbitrary_signing_reply(deps, reply),
         _ => unreachable!("unknown reply ID"),
     }
     .map_err(axelar_wasm_std::error::ContractError::from)
@@ -102,7 +130,7 @@ pub fn reply(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
@@ -111,6 +139,31 @@ pub fn query(
         } => to_json_binary(&query::proof(deps, multisig_session_id)?),
         QueryMsg::CurrentVerifierSet {} => to_json_binary(&query::current_verifier_set(deps)?),
         QueryMsg::NextVerifierSet {} => to_json_binary(&query::next_verifier_set(deps)?),
+        QueryMsg::VerifierSetUpdateStatus {} => {
+            to_json_binary(&query::verifier_set_update_status(deps, env)?)
+        }
+        QueryMsg::ProofByMessage { message_id } => {
+            to_json_binary(&query::proof_by_message(deps, message_id)?)
+        }
+        QueryMsg::ProofsByMessage {
+            message_id,
+            start_after,
+            limit,
+        } => to_json_binary(&query::proofs_by_message(
+            deps,
+            message_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::StaleProofs {
+            min_epoch,
+            start_after,
+            limit,
+        } => to_json_binary(&query::stale_proofs(deps, min_epoch, start_after, limit)?),
+        QueryMsg::ProofLimits {} => to_json_binary(&query::proof_limits(deps)?),
+        QueryMsg::ArbitraryPayloadProof {
+            multisig_session_id,
+        } => to_json_binary(&query::arbitrary_payload_proof(deps, multisig_session_id)?),
     }
     .change_context(ContractError::SerializeResponse)
     .map_err(axelar_wasm_std::error::ContractError::from)
@@ -136,9 +189,13 @@ mod tests {
         mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
     };
     use cosmwasm_std::{
-        from_json, Addr, Empty, Fraction, OwnedDeps, SubMsgResponse, SubMsgResult, Uint128, Uint64,
+        from_json, Addr, CosmosMsg, Empty, Fraction, HexBinary, OwnedDeps, SubMsgResponse,
+        SubMsgResult, Uint128, Uint64, WasmMsg,
     };
+    use multisig::key::{KeyType, PublicKey, Signature};
     use multisig::msg::Signer;
+    use multisig::multisig::Multisig;
+    use multisig::types::MultisigState;
     use multisig::verifier_set::VerifierSet;
     use prost::Message;
     use router_api::CrossChainId;
@@ -146,7 +203,10 @@ mod tests {
     use super::*;
     use crate::contract::execute::should_update_verifier_set;
     use crate::encoding::Encoder;
-    use crate::msg::{ProofResponse, ProofStatus, VerifierSetResponse};
+    use crate::msg::{
+        ArbitraryPayloadProofResponse, ArbitraryPayloadProofStatus, ProofLimits, ProofResponse,
+        ProofStatus, StaleProofResponse, VerifierSetResponse, VerifierSetUpdateStatusResponse,
+    };
     use crate::test::test_data::{self, TestOperator};
     use crate::test::test_utils::{
         mock_querier_handler, ADMIN, COORDINATOR_ADDRESS, GATEWAY_ADDRESS, GOVERNANCE,
@@ -240,12 +300,40 @@ mod tests {
         execute(deps, mock_env(), mock_info(RELAYER, &[]), msg)
     }
 
-    fn reply_construct_proof(
+    fn execute_update_proof_limits(
         deps: DepsMut,
+        sender: &str,
+        proof_limits: ProofLimits,
     ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-        let session_id = to_json_binary(&MULTISIG_SESSION_ID).unwrap();
+        let msg = ExecuteMsg::UpdateProofLimits { proof_limits };
+        execute(deps, mock_env(), mock_info(sender, &[]), msg)
+    }
 
-        let response = SubMsgResponse {
+    fn message_ids(count: usize) -> Vec<CrossChainId> {
+        (0..count)
+            .map(|i| CrossChainId::new("ganache-1", format!("message-{}", i)).unwrap())
+            .collect()
+    }
+
+    fn proof_message_ids(res: &Response) -> Vec<CrossChainId> {
+        let event = res
+            .events
+            .iter()
+            .find(|event| event.ty == "proof_under_construction")
+            .unwrap();
+        let message_ids = event
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key == "message_ids")
+            .unwrap();
+
+        serde_json::from_str(&message_ids.value).unwrap()
+    }
+
+    fn multisig_session_reply_data(multisig_session_id: Uint64) -> SubMsgResponse {
+        let session_id = to_json_binary(&multisig_session_id).unwrap();
+
+        SubMsgResponse {
             events: vec![],
             // the reply data gets protobuf encoded when moving through the wasm module. We need to emulate this behaviour in tests as well
             data: Some(
@@ -253,18 +341,79 @@ mod tests {
                     .encode_to_vec()
                     .into(),
             ),
-        };
+        }
+    }
+
+    fn reply_construct_proof(
+        deps: DepsMut,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        reply_construct_proof_with_session_id(deps, MULTISIG_SESSION_ID)
+    }
 
+    fn reply_construct_proof_with_session_id(
+        deps: DepsMut,
+        multisig_session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
         reply(
             deps,
             mock_env(),
             Reply {
                 id: START_MULTISIG_REPLY_ID,
-                result: SubMsgResult::Ok(response),
+                result: SubMsgResult::Ok(multisig_session_reply_data(multisig_session_id)),
             },
         )
     }
 
+    fn query_stale_proofs(deps: Deps, min_epoch: u64) -> Vec<StaleProofResponse> {
+        from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::StaleProofs {
+                    min_epoch,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn execute_update_proof_epoch_window(
+        deps: DepsMut,
+        sender: &str,
+        epoch_window: u64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::UpdateProofEpochWindow { epoch_window };
+        execute(deps, mock_env(), mock_info(sender, &[]), msg)
+    }
+
+    fn query_proofs_by_message(
+        deps: Deps,
+        message_id: CrossChainId,
+        start_after: Option<Uint64>,
+    ) -> Vec<Uint64> {
+        let proofs: Vec<ProofResponse> = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::ProofsByMessage {
+                    message_id,
+                    start_after,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        proofs
+            .into_iter()
+            .map(|proof| proof.multisig_session_id)
+            .collect()
+    }
+
     fn query_proof(
         deps: Deps,
         multisig_session_id: Option<Uint64>,
@@ -290,6 +439,31 @@ mod tests {
         query(deps, mock_env(), QueryMsg::CurrentVerifierSet {}).map(|res| from_json(res).unwrap())
     }
 
+    fn query_verifier_set_update_status(deps: Deps) -> VerifierSetUpdateStatusResponse {
+        from_json(query(deps, mock_env(), QueryMsg::VerifierSetUpdateStatus {}).unwrap()).unwrap()
+    }
+
+    fn execute_update_verifier_set_update_interval(
+        deps: DepsMut,
+        sender: &str,
+        min_interval: Option<u64>,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::UpdateVerifierSetUpdateInterval { min_interval };
+        execute(deps, mock_env(), mock_info(sender, &[]), msg)
+    }
+
+    fn execute_trigger_verifier_set_update(
+        deps: DepsMut,
+        env: Env,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        execute(
+            deps,
+            env,
+            mock_info(RELAYER, &[]),
+            ExecuteMsg::TriggerVerifierSetUpdate {},
+        )
+    }
+
     #[test]
     #[allow(clippy::arithmetic_side_effects)]
     fn test_instantiation() {
@@ -366,6 +540,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn instantiation_should_fail_if_encoder_does_not_support_bls_verifier_sets() {
+        let instantiate_msg = |encoder: Encoder| InstantiateMsg {
+            admin_address: ADMIN.to_string(),
+            governance_address: GOVERNANCE.to_string(),
+            gateway_address: GATEWAY_ADDRESS.to_string(),
+            multisig_address: MULTISIG_ADDRESS.to_string(),
+            coordinator_address: COORDINATOR_ADDRESS.to_string(),
+            service_registry_address: SERVICE_REGISTRY_ADDRESS.to_string(),
+            voting_verifier_address: VOTING_VERIFIER_ADDRESS.to_string(),
+            signing_threshold: test_data::threshold(),
+            service_name: SERVICE_NAME.to_string(),
+            chain_name: "ganache-0".to_string(),
+            verifier_set_diff_threshold: 0,
+            encoder,
+            key_type: multisig::key::KeyType::Bls,
+            domain_separator: [0; 32],
+        };
+
+        for encoder in [
+            Encoder::Bcs,
+            Encoder::StellarXdr,
+            Encoder::SolanaBorsh,
+            Encoder::Starknet,
+        ] {
+            let res = instantiate(
+                mock_dependencies().as_mut(),
+                mock_env(),
+                mock_info(ADMIN, &[]),
+                instantiate_msg(encoder),
+            );
+            assert_eq!(
+                res.unwrap_err().to_string(),
+                axelar_wasm_std::error::ContractError::from(ContractError::KeyTypeNotSupported {
+                    encoder,
+                    key_type: multisig::key::KeyType::Bls,
+                })
+                .to_string()
+            );
+        }
+
+        assert!(instantiate(
+            mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            instantiate_msg(Encoder::Abi),
+        )
+        .is_ok());
+    }
+
     #[allow(clippy::arithmetic_side_effects)]
     fn test_operators_to_verifier_set(operators: Vec<TestOperator>, nonce: u64) -> VerifierSet {
         let total_weight: Uint128 = operators
@@ -687,6 +911,344 @@ mod tests {
         }
     }
 
+    #[test]
+    fn query_proofs_by_message() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let message_id = test_data::messages()[0].cc_id.clone();
+        let other_message_id = message_ids(2)[1].clone();
+
+        let res: Option<ProofResponse> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ProofByMessage {
+                    message_id: message_id.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res, None);
+
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof_with_session_id(deps.as_mut(), Uint64::from(1u64)).unwrap();
+        execute_construct_proof(
+            deps.as_mut(),
+            Some(vec![message_id.clone(), other_message_id.clone()]),
+        )
+        .unwrap();
+        reply_construct_proof_with_session_id(deps.as_mut(), Uint64::from(2u64)).unwrap();
+
+        assert_eq!(
+            query_proofs_by_message(deps.as_ref(), message_id.clone(), None),
+            vec![Uint64::from(1u64), Uint64::from(2u64)]
+        );
+        assert_eq!(
+            query_proofs_by_message(deps.as_ref(), message_id.clone(), Some(Uint64::from(1u64))),
+            vec![Uint64::from(2u64)]
+        );
+        assert_eq!(
+            query_proofs_by_message(deps.as_ref(), other_message_id, None),
+            vec![Uint64::from(2u64)]
+        );
+
+        let res: Option<ProofResponse> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ProofByMessage { message_id },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let proof = res.unwrap();
+        assert_eq!(proof.multisig_session_id, Uint64::from(2u64));
+        assert!(matches!(proof.status, ProofStatus::Completed { .. }));
+    }
+
+    #[test]
+    fn backfill_message_index_should_require_elevated_permission() {
+        let mut deps = setup_test_case();
+        let msg = ExecuteMsg::BackfillMessageIndex { limit: 10 };
+
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            msg.clone()
+        )
+        .is_err());
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            msg.clone()
+        )
+        .is_ok());
+        assert!(execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).is_ok());
+    }
+
+    #[test]
+    fn proofs_of_rotated_verifier_sets_should_become_stale() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        let old_verifier_set_id = query_verifier_set(deps.as_ref()).unwrap().unwrap().id;
+
+        execute_construct_proof(deps.as_mut(), None).unwrap();
+        reply_construct_proof_with_session_id(deps.as_mut(), Uint64::from(1u64)).unwrap();
+        assert!(query_stale_proofs(deps.as_ref(), 0).is_empty());
+
+        let mut new_verifiers = test_data::operators();
+        new_verifiers.pop();
+        deps.querier.update_wasm(mock_querier_handler(
+            new_verifiers,
+            VerificationStatus::SucceededOnSourceChain,
+        ));
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        reply_construct_proof_with_session_id(deps.as_mut(), Uint64::from(2u64)).unwrap();
+        confirm_verifier_set(deps.as_mut(), Addr::unchecked(GOVERNANCE)).unwrap();
+
+        // the default window matches the retention of the gateways
+        assert!(query_stale_proofs(deps.as_ref(), 0).is_empty());
+
+        execute_update_proof_epoch_window(deps.as_mut(), GOVERNANCE, 0).unwrap();
+        let proof = query_proof(deps.as_ref(), Some(Uint64::from(1u64))).unwrap();
+        assert!(matches!(proof.status, ProofStatus::Completed { .. }));
+        assert!(proof.stale);
+        assert!(query_stale_proofs(deps.as_ref(), 2).is_empty());
+        assert_eq!(
+            query_stale_proofs(deps.as_ref(), 0),
+            vec![StaleProofResponse {
+                multisig_session_id: Uint64::from(1u64),
+                message_ids: test_data::messages()
+                    .into_iter()
+                    .map(|msg| msg.cc_id)
+                    .collect(),
+                verifier_set_id: old_verifier_set_id.clone(),
+                epoch: 1,
+            }]
+        );
+
+        // the proof is still accepted by gateways that keep one previous verifier set
+        execute_update_proof_epoch_window(deps.as_mut(), GOVERNANCE, 1).unwrap();
+        assert!(query_stale_proofs(deps.as_ref(), 0).is_empty());
+        assert!(
+            !query_proof(deps.as_ref(), Some(Uint64::from(1u64)))
+                .unwrap()
+                .stale
+        );
+
+        execute_update_proof_epoch_window(deps.as_mut(), GOVERNANCE, 0).unwrap();
+        let res = execute_construct_proof(deps.as_mut(), None).unwrap();
+        let event = res
+            .events
+            .iter()
+            .find(|event| event.ty == "stale_proof_resigned")
+            .unwrap();
+        assert!(event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "stale_verifier_set_id" && attr.value == old_verifier_set_id));
+
+        reply_construct_proof_with_session_id(deps.as_mut(), Uint64::from(3u64)).unwrap();
+        assert!(query_stale_proofs(deps.as_ref(), 0).is_empty());
+
+        let res = execute_construct_proof(deps.as_mut(), None).unwrap();
+        assert!(!res
+            .events
+            .iter()
+            .any(|event| event.ty == "stale_proof_resigned"));
+    }
+
+    #[test]
+    fn non_governance_should_not_be_able_to_update_proof_epoch_window() {
+        let mut deps = setup_test_case();
+        let res = execute_update_proof_epoch_window(deps.as_mut(), RELAYER, 5);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn governance_should_be_able_to_sign_arbitrary_payload() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        let payload = HexBinary::from_hex("deadbeef").unwrap();
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::SignArbitraryPayload {
+                payload: payload.clone(),
+            },
+        )
+        .unwrap();
+
+        let verifier_set = query_verifier_set(deps.as_ref()).unwrap().unwrap();
+        let expected_digest = Encoder::Abi
+            .arbitrary_payload_digest(&[0; 32], &verifier_set.verifier_set, &payload)
+            .unwrap();
+
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].id, START_ARBITRARY_SIGNING_REPLY_ID);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                assert_eq!(
+                    from_json::<multisig::msg::ExecuteMsg>(msg).unwrap(),
+                    multisig::msg::ExecuteMsg::StartSigningSession {
+                        verifier_set_id: verifier_set.id,
+                        msg: expected_digest.into(),
+                        chain_name: "ganache-0".parse().unwrap(),
+                        sig_verifier: None,
+                    }
+                );
+            }
+            _ => panic!("unexpected message"),
+        }
+
+        let res = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: START_ARBITRARY_SIGNING_REPLY_ID,
+                result: SubMsgResult::Ok(multisig_session_reply_data(MULTISIG_SESSION_ID)),
+            },
+        )
+        .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event.ty == "arbitrary_payload_signing_started"));
+
+        // arbitrary payloads are not proofs of the gateway payloads
+        assert!(query_proof(deps.as_ref(), None).is_err());
+
+        let multisig = ecdsa_multisig_signing_arbitrary_payload(&payload);
+        let verifier_set = multisig.verifier_set.clone();
+        deps.querier
+            .update_wasm(move |_| Ok(to_json_binary(&multisig).into()).into());
+
+        let proof: ArbitraryPayloadProofResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ArbitraryPayloadProof {
+                    multisig_session_id: MULTISIG_SESSION_ID,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(proof.multisig_session_id, MULTISIG_SESSION_ID);
+        assert_eq!(proof.payload, payload);
+        assert_eq!(
+            proof.digest,
+            HexBinary::from(
+                Encoder::Abi
+                    .arbitrary_payload_digest(&[0; 32], &verifier_set, &payload)
+                    .unwrap()
+            )
+        );
+        match proof.status {
+            ArbitraryPayloadProofStatus::Completed { signatures, .. } => {
+                assert!(!signatures.is_empty());
+                // the ABI encoding expects the recovery byte of the EVM
+                assert!(signatures.iter().all(|signer| matches!(
+                    &signer.signature,
+                    Signature::EcdsaRecoverable(sig) if matches!(sig.as_ref().last(), Some(27 | 28))
+                )));
+            }
+            status => panic!("unexpected status {:?}", status),
+        }
+    }
+
+    fn ecdsa_multisig_signing_arbitrary_payload(payload: &HexBinary) -> Multisig {
+        let signing_keys: Vec<_> = (1u8..=2)
+            .map(|i| k256::ecdsa::SigningKey::from_bytes(&[i; 32].into()).unwrap())
+            .collect();
+
+        let signers: Vec<_> = signing_keys
+            .iter()
+            .enumerate()
+            .map(|(i, signing_key)| Signer {
+                address: Addr::unchecked(format!("signer{}", i)),
+                weight: Uint128::one(),
+                pub_key: PublicKey::Ecdsa(HexBinary::from(
+                    signing_key.verifying_key().to_sec1_bytes().to_vec(),
+                )),
+            })
+            .collect();
+        let verifier_set = VerifierSet {
+            signers: signers
+                .iter()
+                .map(|signer| (signer.address.to_string(), signer.clone()))
+                .collect(),
+            threshold: Uint128::new(2),
+            created_at: 1,
+        };
+
+        let digest = Encoder::Abi
+            .arbitrary_payload_digest(&[0; 32], &verifier_set, payload)
+            .unwrap();
+        let signatures = signing_keys
+            .iter()
+            .zip(signers)
+            .map(|(signing_key, signer)| {
+                let (signature, _) = signing_key.sign_prehash_recoverable(&digest).unwrap();
+                (
+                    signer.address.to_string(),
+                    Signature::try_from((KeyType::Ecdsa, HexBinary::from(signature.to_vec())))
+                        .unwrap(),
+                )
+            })
+            .collect();
+
+        Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            verifier_set,
+            signatures,
+            aggregated_signature: None,
+        }
+    }
+
+    #[test]
+    fn non_governance_should_not_be_able_to_sign_arbitrary_payload() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        for sender in [ADMIN, RELAYER] {
+            assert!(execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(sender, &[]),
+                ExecuteMsg::SignArbitraryPayload {
+                    payload: HexBinary::from_hex("deadbeef").unwrap(),
+                },
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn sign_arbitrary_payload_should_fail_without_verifier_set() {
+        let mut deps = setup_test_case();
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(GOVERNANCE, &[]),
+            ExecuteMsg::SignArbitraryPayload {
+                payload: HexBinary::from_hex("deadbeef").unwrap(),
+            },
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::NoVerifierSet).to_string()
+        );
+    }
+
     #[test]
     fn test_construct_proof_no_verifier_set() {
         let mut deps = setup_test_case();
@@ -698,6 +1260,129 @@ mod tests {
         );
     }
 
+    #[test]
+    fn construct_proof_within_limits_should_start_single_signing_session() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_proof_limits(
+            deps.as_mut(),
+            GOVERNANCE,
+            ProofLimits {
+                max_messages: Some(3u64.try_into().unwrap()),
+                max_execute_data_size: Some(u64::MAX.try_into().unwrap()),
+                split_oversized: false,
+            },
+        )
+        .unwrap();
+
+        let res = execute_construct_proof(deps.as_mut(), Some(message_ids(3))).unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        let res = reply_construct_proof(deps.as_mut()).unwrap();
+        assert_eq!(proof_message_ids(&res), message_ids(3));
+    }
+
+    #[test]
+    fn construct_proof_exceeding_limits_should_fail_without_split() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_proof_limits(
+            deps.as_mut(),
+            GOVERNANCE,
+            ProofLimits {
+                max_messages: Some(2u64.try_into().unwrap()),
+                max_execute_data_size: None,
+                split_oversized: false,
+            },
+        )
+        .unwrap();
+
+        let res = execute_construct_proof(deps.as_mut(), Some(message_ids(3)));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::ProofLimitsExceeded)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn construct_proof_exceeding_limits_should_split_into_several_signing_sessions() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_proof_limits(
+            deps.as_mut(),
+            GOVERNANCE,
+            ProofLimits {
+                max_messages: Some(2u64.try_into().unwrap()),
+                max_execute_data_size: None,
+                split_oversized: true,
+            },
+        )
+        .unwrap();
+
+        let res = execute_construct_proof(deps.as_mut(), Some(message_ids(5))).unwrap();
+        assert_eq!(res.messages.len(), 3);
+
+        // replies are matched with the payloads in the order the signing sessions were started
+        for expected in message_ids(5).chunks(2) {
+            let res = reply_construct_proof(deps.as_mut()).unwrap();
+            assert_eq!(proof_message_ids(&res), expected);
+        }
+        assert!(reply_construct_proof(deps.as_mut()).is_err());
+    }
+
+    #[test]
+    fn construct_proof_with_message_exceeding_limits_by_itself_should_fail() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_proof_limits(
+            deps.as_mut(),
+            GOVERNANCE,
+            ProofLimits {
+                max_messages: None,
+                max_execute_data_size: Some(1u64.try_into().unwrap()),
+                split_oversized: true,
+            },
+        )
+        .unwrap();
+
+        let res = execute_construct_proof(deps.as_mut(), None);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::MessageExceedsProofLimits {
+                message_id: test_data::messages()[0].cc_id.to_string()
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn non_governance_should_not_be_able_to_update_proof_limits() {
+        let mut deps = setup_test_case();
+        let res = execute_update_proof_limits(deps.as_mut(), RELAYER, ProofLimits::default());
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn governance_should_be_able_to_update_proof_limits() {
+        let mut deps = setup_test_case();
+        let proof_limits = ProofLimits {
+            max_messages: Some(10u64.try_into().unwrap()),
+            max_execute_data_size: None,
+            split_oversized: true,
+        };
+
+        let res: ProofLimits =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::ProofLimits {}).unwrap()).unwrap();
+        assert_eq!(res, ProofLimits::default());
+
+        execute_update_proof_limits(deps.as_mut(), GOVERNANCE, proof_limits.clone()).unwrap();
+
+        let res: ProofLimits =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::ProofLimits {}).unwrap()).unwrap();
+        assert_eq!(res, proof_limits);
+    }
+
     #[test]
     fn non_governance_should_not_be_able_to_call_update_signing_threshold() {
         let mut deps = setup_test_case();
@@ -907,4 +1592,172 @@ mod tests {
             Permission::NoPrivilege.into()
         );
     }
+
+    #[test]
+    fn verifier_set_update_status_should_report_signer_changes() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        assert_eq!(
+            query_verifier_set_update_status(deps.as_ref()),
+            VerifierSetUpdateStatusResponse {
+                update_due: false,
+                update_in_progress: false,
+                threshold_changed: false,
+                signers_added: vec![],
+                signers_removed: vec![],
+                weights_changed: vec![],
+                pub_keys_changed: vec![],
+                permissionless_update_at: None,
+            }
+        );
+
+        let mut new_verifiers = test_data::operators();
+        let removed = new_verifiers.pop().unwrap();
+        let (a, b) = (
+            new_verifiers[0].pub_key.clone(),
+            new_verifiers[1].pub_key.clone(),
+        );
+        new_verifiers[0].pub_key = b;
+        new_verifiers[1].pub_key = a;
+
+        deps.querier.update_wasm(mock_querier_handler(
+            new_verifiers.clone(),
+            VerificationStatus::SucceededOnSourceChain,
+        ));
+
+        let mut pub_keys_changed = vec![
+            new_verifiers[0].address.clone(),
+            new_verifiers[1].address.clone(),
+        ];
+        pub_keys_changed.sort();
+
+        let status = query_verifier_set_update_status(deps.as_ref());
+        assert!(status.update_due);
+        assert!(!status.update_in_progress);
+        assert_eq!(status.signers_added, Vec::<Addr>::new());
+        assert_eq!(status.signers_removed, vec![removed.address]);
+        assert_eq!(status.weights_changed, Vec::<Addr>::new());
+        assert_eq!(status.pub_keys_changed, pub_keys_changed);
+
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        assert!(query_verifier_set_update_status(deps.as_ref()).update_in_progress);
+    }
+
+    #[test]
+    fn verifier_set_update_status_without_verifier_set_should_fail() {
+        let deps = setup_test_case();
+
+        let res = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::VerifierSetUpdateStatus {},
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::NoVerifierSet).to_string()
+        );
+    }
+
+    #[test]
+    fn non_governance_should_not_be_able_to_update_verifier_set_update_interval() {
+        let mut deps = setup_test_case();
+
+        let res = execute_update_verifier_set_update_interval(deps.as_mut(), ADMIN, Some(10));
+        assert!(res.is_err());
+
+        let res = execute_update_verifier_set_update_interval(deps.as_mut(), GOVERNANCE, Some(10));
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn trigger_verifier_set_update_should_fail_when_disabled() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+
+        deps.querier.update_wasm(mock_querier_handler(
+            test_data::operators()[..4].to_vec(),
+            VerificationStatus::SucceededOnSourceChain,
+        ));
+
+        let res = execute_trigger_verifier_set_update(deps.as_mut(), mock_env());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(
+                ContractError::PermissionlessVerifierSetUpdateDisabled
+            )
+            .to_string()
+        );
+
+        execute_update_verifier_set_update_interval(deps.as_mut(), GOVERNANCE, Some(0)).unwrap();
+        assert!(execute_trigger_verifier_set_update(deps.as_mut(), mock_env()).is_ok());
+
+        execute_update_verifier_set_update_interval(deps.as_mut(), GOVERNANCE, None).unwrap();
+        assert!(execute_trigger_verifier_set_update(deps.as_mut(), mock_env()).is_err());
+    }
+
+    #[test]
+    fn trigger_verifier_set_update_should_respect_min_interval() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_verifier_set_update_interval(deps.as_mut(), GOVERNANCE, Some(10)).unwrap();
+
+        let next_update_at = mock_env().block.height.saturating_add(10);
+        assert_eq!(
+            query_verifier_set_update_status(deps.as_ref()).permissionless_update_at,
+            Some(next_update_at)
+        );
+
+        let new_verifiers = test_data::operators()[..4].to_vec();
+        deps.querier.update_wasm(mock_querier_handler(
+            new_verifiers.clone(),
+            VerificationStatus::SucceededOnSourceChain,
+        ));
+
+        let res = execute_trigger_verifier_set_update(deps.as_mut(), mock_env());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(
+                ContractError::VerifierSetUpdateIntervalNotElapsed { next_update_at }
+            )
+            .to_string()
+        );
+
+        let mut env = mock_env();
+        env.block.height = next_update_at;
+        let res = execute_trigger_verifier_set_update(deps.as_mut(), env.clone()).unwrap();
+        assert_eq!(res.messages.len(), 2);
+
+        let next_verifier_set: Option<VerifierSetResponse> =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::NextVerifierSet {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            next_verifier_set,
+            Some(test_operators_to_verifier_set(new_verifiers, next_update_at).into())
+        );
+
+        // the pending update can only be restarted by the admin or governance
+        let res = execute_trigger_verifier_set_update(deps.as_mut(), env);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(
+                ContractError::VerifierSetConfirmationInProgress
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn trigger_verifier_set_update_should_fail_when_unchanged() {
+        let mut deps = setup_test_case();
+        execute_update_verifier_set(deps.as_mut()).unwrap();
+        execute_update_verifier_set_update_interval(deps.as_mut(), GOVERNANCE, Some(0)).unwrap();
+
+        let res = execute_trigger_verifier_set_update(deps.as_mut(), mock_env());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::VerifierSetUnchanged)
+                .to_string()
+        );
+    }
 }
@@ -5,19 +5,26 @@ use axelar_wasm_std::snapshot::{Participant, Snapshot};
 use axelar_wasm_std::{
     address, nonempty, permission_control, FnExt, MajorityThreshold, VerificationStatus,
 };
-use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, QuerierWrapper, Response, Storage, SubMsg};
+use cosmwasm_std::{
+    wasm_execute, Addr, Deps, DepsMut, Env, HexBinary, QuerierWrapper, Response, Storage, SubMsg,
+};
 use error_stack::{report, Result, ResultExt};
 use itertools::Itertools;
-use multisig::msg::Signer;
+use multisig::key::{KeyType, KeyTyped, Signature};
+use multisig::msg::{Signer, SignerWithSig};
+use multisig::multisig::AggregatedSignature;
 use multisig::verifier_set::VerifierSet;
 use router_api::{ChainName, CrossChainId, Message};
 use service_registry_api::WeightedVerifier;
 
-use crate::contract::START_MULTISIG_REPLY_ID;
+use crate::contract::{START_ARBITRARY_SIGNING_REPLY_ID, START_MULTISIG_REPLY_ID};
 use crate::error::ContractError;
-use crate::payload::Payload;
+use crate::events::Event;
+use crate::msg::ProofLimits;
+use crate::payload::{Payload, PayloadId};
 use crate::state::{
-    Config, CONFIG, CURRENT_VERIFIER_SET, NEXT_VERIFIER_SET, PAYLOAD, REPLY_TRACKER,
+    self, Config, ARBITRARY_PAYLOAD_REPLY_TRACKER, CONFIG, CURRENT_VERIFIER_SET, NEXT_VERIFIER_SET,
+    PAYLOAD, PROOF_EPOCH_WINDOW, PROOF_LIMITS, REPLY_TRACKER, VERIFIER_SET_UPDATE_INTERVAL,
 };
 
 pub fn construct_proof(
@@ -33,11 +40,62 @@ pub fn construct_proof(
         config.chain_name.clone(),
     )?;
 
-    let payload = Payload::Messages(messages);
+    let verifier_set = CURRENT_VERIFIER_SET
+        .may_load(deps.storage)
+        .map_err(ContractError::from)?
+        .ok_or(ContractError::NoVerifierSet)?;
+
+    let proof_limits = PROOF_LIMITS
+        .may_load(deps.storage)
+        .map_err(ContractError::from)?
+        .unwrap_or_default();
+
+    let mut response = Response::new();
+    for messages in split_into_batches(&config, &verifier_set, &proof_limits, messages)? {
+        let payload = Payload::Messages(messages);
+
+        if let Some(event) = stale_proof_resigned(deps.storage, &payload.id())? {
+            response = response.add_event(event.into());
+        }
+
+        response = response.add_submessage(start_signing_session(
+            deps.storage,
+            &config,
+            &verifier_set,
+            payload,
+        )?);
+    }
+
+    Ok(response)
+}
+
+fn stale_proof_resigned(
+    storage: &dyn Storage,
+    payload_id: &PayloadId,
+) -> Result<Option<Event>, ContractError> {
+    let current_epoch = state::verifier_set_epoch(storage).map_err(ContractError::from)?;
+    let epoch_window = state::proof_epoch_window(storage).map_err(ContractError::from)?;
+
+    Ok(state::latest_proof(storage, payload_id)
+        .map_err(ContractError::from)?
+        .filter(|(_, proof)| proof.is_stale(current_epoch, epoch_window))
+        .map(|(multisig_session_id, proof)| Event::StaleProofResigned {
+            payload_id: proof.payload_id,
+            stale_multisig_session_id: multisig_session_id.into(),
+            stale_verifier_set_id: proof.verifier_set_id,
+        }))
+}
+
+fn start_signing_session(
+    storage: &mut dyn Storage,
+    config: &Config,
+    verifier_set: &VerifierSet,
+    payload: Payload,
+) -> Result<SubMsg, ContractError> {
     let payload_id = payload.id();
 
     match PAYLOAD
-        .may_load(deps.storage, &payload_id)
+        .may_load(storage, &payload_id)
         .map_err(ContractError::from)?
     {
         Some(stored_payload) => {
@@ -48,24 +106,199 @@ pub fn construct_proof(
         }
         None => {
             PAYLOAD
-                .save(deps.storage, &payload_id, &payload)
+                .save(storage, &payload_id, &payload)
                 .map_err(ContractError::from)?;
         }
     };
 
     // keep track of the payload id to use during submessage reply
     REPLY_TRACKER
-        .save(deps.storage, &payload_id)
+        .push_back(storage, &payload_id)
+        .map_err(ContractError::from)?;
+
+    let digest = config
+        .encoder
+        .digest(&config.domain_separator, verifier_set, &payload)?;
+
+    let start_sig_msg = multisig::msg::ExecuteMsg::StartSigningSession {
+        verifier_set_id: verifier_set.id(),
+        msg: digest.into(),
+        chain_name: config.chain_name.clone(),
+        sig_verifier: None,
+    };
+
+    let wasm_msg = wasm_execute(config.multisig.clone(), &start_sig_msg, vec![])
         .map_err(ContractError::from)?;
 
+    Ok(SubMsg::reply_on_success(wasm_msg, START_MULTISIG_REPLY_ID))
+}
+
+/// Splits the messages into batches that each fit into a single proof. The messages keep their order.
+/// Without splitting enabled, all messages must fit into a single proof.
+fn split_into_batches(
+    config: &Config,
+    verifier_set: &VerifierSet,
+    proof_limits: &ProofLimits,
+    messages: Vec<Message>,
+) -> Result<Vec<Vec<Message>>, ContractError> {
+    let signatures = WorstCaseSignatures::new(verifier_set)?;
+    let within_limits = |batch: &[Message]| {
+        within_proof_limits(config, verifier_set, &signatures, proof_limits, batch)
+    };
+
+    if messages.is_empty() || within_limits(&messages)? {
+        return Ok(vec![messages]);
+    }
+
+    if !proof_limits.split_oversized {
+        return Err(report!(ContractError::ProofLimitsExceeded));
+    }
+
+    let mut batches = vec![];
+    let mut remaining = messages.as_slice();
+    while let Some(first) = remaining.first() {
+        let len = largest_batch_within_limits(remaining, within_limits)?;
+        if len == 0 {
+            return Err(report!(ContractError::MessageExceedsProofLimits {
+                message_id: first.cc_id.to_string(),
+            }));
+        }
+
+        let (batch, rest) = remaining.split_at(len);
+        batches.push(batch.to_vec());
+        remaining = rest;
+    }
+
+    Ok(batches)
+}
+
+// The proof size grows with the number of messages, so the batch size is doubled until the batch exceeds the limits,
+// and the largest batch within the limits is then searched between the last two sizes.
+// This keeps the number of encoded messages linearithmic in the size of the batch.
+fn largest_batch_within_limits(
+    messages: &[Message],
+    within_limits: impl Fn(&[Message]) -> Result<bool, ContractError>,
+) -> Result<usize, ContractError> {
+    let mut within = 0;
+    let mut exceeding = messages.len().saturating_add(1);
+
+    let mut len = 1;
+    while len <= messages.len() {
+        if !within_limits(&messages[..len])? {
+            exceeding = len;
+            break;
+        }
+
+        within = len;
+        len = len.saturating_mul(2);
+    }
+
+    while exceeding.saturating_sub(within) > 1 {
+        let len = within.saturating_add(exceeding.saturating_sub(within) / 2);
+        if within_limits(&messages[..len])? {
+            within = len;
+        } else {
+            exceeding = len;
+        }
+    }
+
+    Ok(within)
+}
+
+/// Signatures of the maximum length for every signer of the verifier set.
+/// Proofs are sized with them, so they cannot exceed the limits once they are signed.
+enum WorstCaseSignatures {
+    Aggregated(AggregatedSignature),
+    Individual(Vec<SignerWithSig>),
+}
+
+impl WorstCaseSignatures {
+    fn new(verifier_set: &VerifierSet) -> Result<Self, ContractError> {
+        if verifier_set.key_type() == Some(KeyType::Bls) {
+            // the bitmap and the aggregated signature have a fixed size, regardless of who signed
+            return AggregatedSignature::new(verifier_set, &[])
+                .map(Self::Aggregated)
+                .change_context(ContractError::Proof);
+        }
+
+        verifier_set
+            .signers
+            .values()
+            .map(|signer| {
+                let key_type = signer.pub_key.key_type();
+                Signature::try_from((
+                    key_type,
+                    HexBinary::from(vec![0; key_type.max_signature_len()]),
+                ))
+                .map(|signature| signer.with_sig(signature))
+                .change_context(ContractError::Proof)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::Individual)
+    }
+}
+
+fn within_proof_limits(
+    config: &Config,
+    verifier_set: &VerifierSet,
+    signatures: &WorstCaseSignatures,
+    proof_limits: &ProofLimits,
+    messages: &[Message],
+) -> Result<bool, ContractError> {
+    let exceeds = |limit: Option<nonempty::Uint64>, value: usize| {
+        limit.is_some_and(|limit| {
+            u64::try_from(value).map_or(true, |value| value > u64::from(limit))
+        })
+    };
+
+    if exceeds(proof_limits.max_messages, messages.len()) {
+        return Ok(false);
+    }
+
+    if proof_limits.max_execute_data_size.is_none() {
+        return Ok(true);
+    }
+
+    let payload = Payload::Messages(messages.to_vec());
+    let execute_data = match signatures {
+        WorstCaseSignatures::Aggregated(aggregated_signature) => config
+            .encoder
+            .aggregated_execute_data(verifier_set, aggregated_signature.clone(), &payload)?,
+        WorstCaseSignatures::Individual(signers) => config.encoder.execute_data(
+            &config.domain_separator,
+            verifier_set,
+            signers.clone(),
+            &payload,
+        )?,
+    };
+
+    Ok(!exceeds(
+        proof_limits.max_execute_data_size,
+        execute_data.len(),
+    ))
+}
+
+pub fn sign_arbitrary_payload(
+    deps: DepsMut,
+    payload: HexBinary,
+) -> error_stack::Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
     let verifier_set = CURRENT_VERIFIER_SET
         .may_load(deps.storage)
         .map_err(ContractError::from)?
         .ok_or(ContractError::NoVerifierSet)?;
 
-    let digest = config
-        .encoder
-        .digest(&config.domain_separator, &verifier_set, &payload)?;
+    let digest = config.encoder.arbitrary_payload_digest(
+        &config.domain_separator,
+        &verifier_set,
+        &payload,
+    )?;
+
+    // keep track of the payload to use during submessage reply
+    ARBITRARY_PAYLOAD_REPLY_TRACKER
+        .save(deps.storage, &payload)
+        .map_err(ContractError::from)?;
 
     let start_sig_msg = multisig::msg::ExecuteMsg::StartSigningSession {
         verifier_set_id: verifier_set.id(),
@@ -77,7 +310,10 @@ pub fn construct_proof(
     let wasm_msg =
         wasm_execute(config.multisig, &start_sig_msg, vec![]).map_err(ContractError::from)?;
 
-    Ok(Response::new().add_submessage(SubMsg::reply_on_success(wasm_msg, START_MULTISIG_REPLY_ID)))
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(
+        wasm_msg,
+        START_ARBITRARY_SIGNING_REPLY_ID,
+    )))
 }
 
 fn messages(
@@ -114,8 +350,8 @@ fn messages(
     }
 }
 
-fn make_verifier_set(
-    deps: &DepsMut,
+pub fn make_verifier_set(
+    deps: Deps,
     env: &Env,
     config: &Config,
 ) -> Result<VerifierSet, ContractError> {
@@ -137,7 +373,14 @@ fn make_verifier_set(
     let participants_with_pubkeys = verifiers
         .into_iter()
         .filter_map(|verifier| {
-            match multisig.public_key(verifier.verifier_info.address.to_string(), config.key_type) {
+            let address = verifier.verifier_info.address.to_string();
+
+            // a pending key only becomes active once a verifier set uses it, so it takes precedence
+            match multisig
+                .pending_public_key(address.clone(), config.key_type)
+                .and_then(|pending| {
+                    pending.map_or_else(|| multisig.public_key(address, config.key_type), Ok)
+                }) {
                 Ok(pub_key) => Some((Participant::from(verifier), pub_key)),
                 Err(_) => None,
             }
@@ -181,7 +424,7 @@ fn next_verifier_set(
     let cur_verifier_set = CURRENT_VERIFIER_SET
         .may_load(deps.storage)
         .change_context(ContractError::StorageError)?;
-    let new_verifier_set = make_verifier_set(deps, env, config)?;
+    let new_verifier_set = make_verifier_set(deps.as_ref(), env, config)?;
 
     match cur_verifier_set {
         Some(cur_verifier_set) => {
@@ -232,9 +475,8 @@ pub fn update_verifier_set(
     match cur_verifier_set {
         None => {
             // if no verifier set, just store it and return
-            let new_verifier_set = make_verifier_set(&deps, &env, &config)?;
-            CURRENT_VERIFIER_SET
-                .save(deps.storage, &new_verifier_set)
+            let new_verifier_set = make_verifier_set(deps.as_ref(), &env, &config)?;
+            state::save_current_verifier_set(deps.storage, &new_verifier_set)
                 .map_err(ContractError::from)?;
 
             Ok(Response::new()
@@ -261,7 +503,7 @@ pub fn update_verifier_set(
                 .save(deps.storage, &payload_id, &payload)
                 .map_err(ContractError::from)?;
             REPLY_TRACKER
-                .save(deps.storage, &payload_id)
+                .push_back(deps.storage, &payload_id)
                 .map_err(ContractError::from)?;
 
             let digest =
@@ -288,6 +530,39 @@ pub fn update_verifier_set(
     }
 }
 
+/// Permissionless counterpart of `update_verifier_set`. It only starts a new update once the
+/// minimum interval since the creation of the current verifier set has passed, and fails if an update
+/// is already waiting for confirmation, so it can't be used to repeatedly restart signing sessions.
+pub fn trigger_verifier_set_update(
+    deps: DepsMut,
+    env: Env,
+) -> error_stack::Result<Response, ContractError> {
+    let min_interval = VERIFIER_SET_UPDATE_INTERVAL
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::PermissionlessVerifierSetUpdateDisabled)?;
+
+    if NEXT_VERIFIER_SET
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .is_some()
+    {
+        return Err(ContractError::VerifierSetConfirmationInProgress.into());
+    }
+
+    let cur_verifier_set = CURRENT_VERIFIER_SET
+        .may_load(deps.storage)
+        .change_context(ContractError::StorageError)?
+        .ok_or(ContractError::NoVerifierSet)?;
+
+    let next_update_at = cur_verifier_set.created_at.saturating_add(min_interval);
+    if env.block.height < next_update_at {
+        return Err(ContractError::VerifierSetUpdateIntervalNotElapsed { next_update_at }.into());
+    }
+
+    update_verifier_set(deps, env)
+}
+
 fn ensure_verifier_set_verification(
     verifier_set: &VerifierSet,
     config: &Config,
@@ -320,8 +595,7 @@ pub fn confirm_verifier_set(deps: DepsMut, sender: Addr) -> Result<Response, Con
         ensure_verifier_set_verification(&verifier_set, &config, &deps)?;
     }
 
-    CURRENT_VERIFIER_SET
-        .save(deps.storage, &verifier_set)
+    state::save_current_verifier_set(deps.storage, &verifier_set)
         .change_context(ContractError::StorageError)?;
     NEXT_VERIFIER_SET.remove(deps.storage);
 
@@ -409,6 +683,45 @@ pub fn update_signing_threshold(
     Ok(Response::new())
 }
 
+pub fn update_proof_limits(
+    deps: DepsMut,
+    proof_limits: ProofLimits,
+) -> Result<Response, ContractError> {
+    PROOF_LIMITS
+        .save(deps.storage, &proof_limits)
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn update_proof_epoch_window(
+    deps: DepsMut,
+    epoch_window: u64,
+) -> Result<Response, ContractError> {
+    PROOF_EPOCH_WINDOW
+        .save(deps.storage, &epoch_window)
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn backfill_message_index(deps: DepsMut, limit: u32) -> Result<Response, ContractError> {
+    state::backfill_message_multisig_sessions(deps.storage, limit as usize)
+        .change_context(ContractError::StorageError)?;
+    Ok(Response::new())
+}
+
+pub fn update_verifier_set_update_interval(
+    deps: DepsMut,
+    min_interval: Option<u64>,
+) -> Result<Response, ContractError> {
+    match min_interval {
+        Some(min_interval) => VERIFIER_SET_UPDATE_INTERVAL
+            .save(deps.storage, &min_interval)
+            .change_context(ContractError::StorageError)?,
+        None => VERIFIER_SET_UPDATE_INTERVAL.remove(deps.storage),
+    }
+    Ok(Response::new())
+}
+
 pub fn update_admin(deps: DepsMut, new_admin_address: String) -> Result<Response, ContractError> {
     let new_admin = address::validate_cosmwasm_address(deps.api, &new_admin_address)
         .change_context(ContractError::FailedToUpdateAdmin)?;
@@ -426,7 +739,13 @@ mod tests {
     use cosmwasm_std::Addr;
     use router_api::ChainName;
 
-    use super::{different_set_in_progress, next_verifier_set, should_update_verifier_set};
+    use super::{
+        different_set_in_progress, next_verifier_set, should_update_verifier_set,
+        split_into_batches,
+    };
+    use crate::error::ContractError;
+    use crate::msg::ProofLimits;
+    use crate::payload::Payload;
     use crate::state::{Config, NEXT_VERIFIER_SET};
     use crate::test::test_data;
 
@@ -545,6 +864,72 @@ mod tests {
         assert_eq!(ret_verifier_set.unwrap().unwrap(), new_verifier_set);
     }
 
+    fn messages(count: usize) -> Vec<router_api::Message> {
+        (0..count)
+            .map(|i| {
+                let mut message = test_data::messages().remove(0);
+                message.cc_id =
+                    router_api::CrossChainId::new("ganache-1", format!("message-{}", i)).unwrap();
+                message
+            })
+            .collect()
+    }
+
+    #[test]
+    fn split_into_batches_fills_each_batch_up_to_the_limits() {
+        let proof_limits = ProofLimits {
+            max_messages: Some(3u64.try_into().unwrap()),
+            max_execute_data_size: None,
+            split_oversized: true,
+        };
+
+        let batches = split_into_batches(
+            &mock_config(),
+            &test_data::curr_verifier_set(),
+            &proof_limits,
+            messages(7),
+        )
+        .unwrap();
+
+        assert_eq!(
+            batches.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![3, 3, 1]
+        );
+        assert_eq!(batches.concat(), messages(7));
+    }
+
+    #[test]
+    fn split_into_batches_includes_signatures_of_all_signers_in_proof_size() {
+        let config = mock_config();
+        let verifier_set = test_data::curr_verifier_set();
+        let messages = messages(1);
+
+        let unsigned_size = config
+            .encoder
+            .execute_data(
+                &config.domain_separator,
+                &verifier_set,
+                vec![],
+                &Payload::Messages(messages.clone()),
+            )
+            .unwrap()
+            .len();
+        let proof_limits = ProofLimits {
+            max_messages: None,
+            max_execute_data_size: Some(u64::try_from(unsigned_size).unwrap().try_into().unwrap()),
+            split_oversized: true,
+        };
+
+        assert_eq!(
+            *split_into_batches(&config, &verifier_set, &proof_limits, messages.clone())
+                .unwrap_err()
+                .current_context(),
+            ContractError::MessageExceedsProofLimits {
+                message_id: messages[0].cc_id.to_string()
+            }
+        );
+    }
+
     fn mock_config() -> Config {
         Config {
             gateway: Addr::unchecked("doesn't matter"),
@@ -1,13 +1,14 @@
 #![allow(deprecated)]
 
 use axelar_wasm_std::error::ContractError;
-use cosmwasm_std::{wasm_execute, Response, Storage};
+use cosmwasm_std::{wasm_execute, Response, StdResult, Storage};
 
 use crate::contract::execute::all_active_verifiers;
 use crate::contract::CONTRACT_NAME;
-use crate::state::CONFIG;
+use crate::state::{self, CONFIG, CURRENT_VERIFIER_SET, VERIFIER_SET_EPOCH};
 
 const BASE_VERSION: &str = "1.0.0";
+const MIGRATION_BACKFILL_LIMIT: usize = 100;
 
 pub fn migrate(storage: &mut dyn Storage) -> Result<Response, ContractError> {
     cw2::assert_contract_version(storage, CONTRACT_NAME, BASE_VERSION)?;
@@ -15,6 +16,9 @@ pub fn migrate(storage: &mut dyn Storage) -> Result<Response, ContractError> {
 
     let verifiers = all_active_verifiers(storage)?;
 
+    index_message_multisig_sessions(storage)?;
+    seed_verifier_set_epoch(storage)?;
+
     Ok(Response::new().add_message(
         wasm_execute(
             config.coordinator,
@@ -25,6 +29,28 @@ pub fn migrate(storage: &mut dyn Storage) -> Result<Response, ContractError> {
     ))
 }
 
+/// Proofs created before the message index existed can only be looked up by their multisig session id.
+/// The latest sessions are indexed right away, older ones with ExecuteMsg::BackfillMessageIndex.
+fn index_message_multisig_sessions(storage: &mut dyn Storage) -> StdResult<()> {
+    state::start_message_index_backfill(storage)?;
+    state::backfill_message_multisig_sessions(storage, MIGRATION_BACKFILL_LIMIT)?;
+
+    Ok(())
+}
+
+/// The current verifier set was confirmed before epochs were tracked, so it starts the first epoch.
+/// Proofs created before the migration are not assigned an epoch, because the verifier set that signed them
+/// is only known to the multisig contract. They are never reported as stale.
+fn seed_verifier_set_epoch(storage: &mut dyn Storage) -> StdResult<()> {
+    if CURRENT_VERIFIER_SET.may_load(storage)?.is_some()
+        && VERIFIER_SET_EPOCH.may_load(storage)?.is_none()
+    {
+        VERIFIER_SET_EPOCH.save(storage, &1)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -32,7 +58,7 @@ mod tests {
     use axelar_wasm_std::{MajorityThreshold, Threshold};
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
     use cosmwasm_std::{
-        from_json, CosmosMsg, DepsMut, Env, MessageInfo, Response, SubMsg, WasmMsg,
+        from_json, CosmosMsg, DepsMut, Env, MessageInfo, Order, Response, SubMsg, WasmMsg,
     };
     use multisig::key::KeyType;
 
@@ -41,7 +67,11 @@ mod tests {
     use crate::encoding::Encoder;
     use crate::error::ContractError;
     use crate::msg::InstantiateMsg;
-    use crate::state::{Config, CURRENT_VERIFIER_SET, NEXT_VERIFIER_SET};
+    use crate::payload::Payload;
+    use crate::state::{
+        self, Config, CURRENT_VERIFIER_SET, MULTISIG_SESSION_EPOCH, MULTISIG_SESSION_PAYLOAD,
+        NEXT_VERIFIER_SET, PAYLOAD,
+    };
     use crate::test::test_data;
     use crate::test::test_utils::COORDINATOR_ADDRESS;
 
@@ -166,6 +196,131 @@ mod tests {
         );
     }
 
+    #[test]
+    fn migrate_indexes_existing_proofs_by_message() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut());
+        CURRENT_VERIFIER_SET
+            .save(deps.as_mut().storage, &test_data::curr_verifier_set())
+            .unwrap();
+
+        let payload = Payload::Messages(test_data::messages());
+        PAYLOAD
+            .save(deps.as_mut().storage, &payload.id(), &payload)
+            .unwrap();
+        MULTISIG_SESSION_PAYLOAD
+            .save(deps.as_mut().storage, 5, &payload.id())
+            .unwrap();
+
+        let cc_id = test_data::messages()[0].cc_id.clone();
+        assert_eq!(
+            state::message_multisig_sessions(deps.as_ref().storage, &cc_id, None, Order::Ascending)
+                .count(),
+            0
+        );
+
+        v1_0_0::migrate(deps.as_mut().storage).unwrap();
+
+        let sessions: Vec<_> =
+            state::message_multisig_sessions(deps.as_ref().storage, &cc_id, None, Order::Ascending)
+                .collect::<Result<_, _>>()
+                .unwrap();
+        assert_eq!(sessions, vec![5]);
+    }
+
+    #[test]
+    fn migrate_does_not_track_epochs_of_existing_proofs() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut());
+        CURRENT_VERIFIER_SET
+            .save(deps.as_mut().storage, &test_data::curr_verifier_set())
+            .unwrap();
+
+        let payload = Payload::Messages(test_data::messages());
+        PAYLOAD
+            .save(deps.as_mut().storage, &payload.id(), &payload)
+            .unwrap();
+        MULTISIG_SESSION_PAYLOAD
+            .save(deps.as_mut().storage, 5, &payload.id())
+            .unwrap();
+
+        v1_0_0::migrate(deps.as_mut().storage).unwrap();
+
+        assert_eq!(
+            state::latest_proof(deps.as_ref().storage, &payload.id()).unwrap(),
+            None
+        );
+        assert_eq!(
+            MULTISIG_SESSION_EPOCH
+                .may_load(deps.as_ref().storage, 5)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            state::first_multisig_session_since(deps.as_ref().storage, 0).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn migrate_indexes_latest_proofs_and_leaves_the_rest_to_the_backfill() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut());
+        CURRENT_VERIFIER_SET
+            .save(deps.as_mut().storage, &test_data::curr_verifier_set())
+            .unwrap();
+
+        let payload = Payload::Messages(test_data::messages());
+        PAYLOAD
+            .save(deps.as_mut().storage, &payload.id(), &payload)
+            .unwrap();
+        for multisig_session_id in 1..=101 {
+            MULTISIG_SESSION_PAYLOAD
+                .save(deps.as_mut().storage, multisig_session_id, &payload.id())
+                .unwrap();
+        }
+
+        v1_0_0::migrate(deps.as_mut().storage).unwrap();
+
+        let cc_id = test_data::messages()[0].cc_id.clone();
+        let sessions: Vec<_> =
+            state::message_multisig_sessions(deps.as_ref().storage, &cc_id, None, Order::Ascending)
+                .collect::<Result<_, _>>()
+                .unwrap();
+        assert_eq!(sessions, (2..=101).collect::<Vec<_>>());
+
+        assert_eq!(
+            state::backfill_message_multisig_sessions(deps.as_mut().storage, 10).unwrap(),
+            1
+        );
+        assert_eq!(
+            state::message_multisig_sessions(deps.as_ref().storage, &cc_id, None, Order::Ascending)
+                .count(),
+            101
+        );
+        assert_eq!(
+            state::backfill_message_multisig_sessions(deps.as_mut().storage, 10).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn migrate_seeds_verifier_set_epoch() {
+        let mut deps = mock_dependencies();
+
+        instantiate_contract(deps.as_mut());
+        CURRENT_VERIFIER_SET
+            .save(deps.as_mut().storage, &test_data::curr_verifier_set())
+            .unwrap();
+
+        v1_0_0::migrate(deps.as_mut().storage).unwrap();
+
+        assert_eq!(state::verifier_set_epoch(deps.as_ref().storage).unwrap(), 1);
+    }
+
     fn instantiate_contract(deps: DepsMut) {
         instantiate(
             deps,
@@ -1,14 +1,31 @@
-use cosmwasm_std::{to_json_binary, Deps, QueryRequest, StdResult, Uint64, WasmQuery};
+use cosmwasm_std::{
+    to_json_binary, Addr, Deps, Env, Order, QueryRequest, StdResult, Uint64, WasmQuery,
+};
+use cw_storage_plus::Bound;
 use error_stack::Result;
+use multisig::msg::Signer;
 use multisig::multisig::Multisig;
 use multisig::types::MultisigState;
+use multisig::verifier_set::VerifierSet;
+use router_api::CrossChainId;
 
+use crate::contract::execute;
 use crate::error::ContractError;
-use crate::msg::{ProofResponse, ProofStatus, VerifierSetResponse};
+use crate::msg::{
+    ArbitraryPayloadProofResponse, ArbitraryPayloadProofStatus, ProofLimits, ProofResponse,
+    ProofStatus, StaleProofResponse, VerifierSetResponse, VerifierSetUpdateStatusResponse,
+};
 use crate::state::{
-    CONFIG, CURRENT_VERIFIER_SET, MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, PAYLOAD,
+    self, CONFIG, CURRENT_VERIFIER_SET, LATEST_PROOFS, MULTISIG_SESSION_ARBITRARY_PAYLOAD,
+    MULTISIG_SESSION_EPOCH, MULTISIG_SESSION_PAYLOAD, NEXT_VERIFIER_SET, PAYLOAD, PROOF_LIMITS,
+    VERIFIER_SET_UPDATE_INTERVAL,
 };
 
+const DEFAULT_LIMIT: u32 = 30;
+const MAX_LIMIT: u32 = 100;
+// each session is checked with a query to the multisig contract, so only the latest sessions of a message are checked
+const MAX_SESSIONS_CHECKED_BY_MESSAGE: usize = 10;
+
 pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, ContractError> {
     let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
 
@@ -35,21 +52,191 @@ pub fn proof(deps: Deps, multisig_session_id: Uint64) -> Result<ProofResponse, C
     let status = match multisig.state {
         MultisigState::Pending => ProofStatus::Pending,
         MultisigState::Completed { .. } => {
-            let execute_data = config.encoder.execute_data(
-                &config.domain_separator,
-                &multisig.verifier_set,
-                multisig.optimize_signatures(),
-                &payload,
-            )?;
+            let execute_data = match multisig.aggregated_signature.clone() {
+                Some(aggregated_signature) => config.encoder.aggregated_execute_data(
+                    &multisig.verifier_set,
+                    aggregated_signature,
+                    &payload,
+                )?,
+                None => config.encoder.execute_data(
+                    &config.domain_separator,
+                    &multisig.verifier_set,
+                    multisig.optimize_signatures(),
+                    &payload,
+                )?,
+            };
             ProofStatus::Completed { execute_data }
         }
+        MultisigState::Expired => ProofStatus::Expired,
     };
 
+    let stale = matches!(status, ProofStatus::Completed { .. })
+        && is_stale_proof(deps, multisig_session_id.u64())?;
+
     Ok(ProofResponse {
         multisig_session_id,
         message_ids: payload.message_ids().unwrap_or_default(),
         payload,
         status,
+        stale,
+    })
+}
+
+fn is_stale_proof(deps: Deps, multisig_session_id: u64) -> Result<bool, ContractError> {
+    let Some(epoch) = MULTISIG_SESSION_EPOCH
+        .may_load(deps.storage, multisig_session_id)
+        .map_err(ContractError::from)?
+    else {
+        return Ok(false);
+    };
+
+    Ok(state::is_stale(
+        epoch,
+        state::verifier_set_epoch(deps.storage).map_err(ContractError::from)?,
+        state::proof_epoch_window(deps.storage).map_err(ContractError::from)?,
+    ))
+}
+
+pub fn proof_by_message(
+    deps: Deps,
+    message_id: CrossChainId,
+) -> Result<Option<ProofResponse>, ContractError> {
+    for multisig_session_id in
+        state::message_multisig_sessions(deps.storage, &message_id, None, Order::Descending)
+            .take(MAX_SESSIONS_CHECKED_BY_MESSAGE)
+    {
+        let proof = proof(
+            deps,
+            multisig_session_id.map_err(ContractError::from)?.into(),
+        )?;
+
+        if matches!(proof.status, ProofStatus::Completed { .. }) {
+            return Ok(Some(proof));
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn proofs_by_message(
+    deps: Deps,
+    message_id: CrossChainId,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> Result<Vec<ProofResponse>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    state::message_multisig_sessions(
+        deps.storage,
+        &message_id,
+        start_after.map(Uint64::u64),
+        Order::Ascending,
+    )
+    .take(limit)
+    .map(|multisig_session_id| {
+        proof(
+            deps,
+            multisig_session_id.map_err(ContractError::from)?.into(),
+        )
+    })
+    .collect()
+}
+
+pub fn stale_proofs(
+    deps: Deps,
+    min_epoch: u64,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> Result<Vec<StaleProofResponse>, ContractError> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let current_epoch = state::verifier_set_epoch(deps.storage).map_err(ContractError::from)?;
+    let epoch_window = state::proof_epoch_window(deps.storage).map_err(ContractError::from)?;
+
+    let Some(first_multisig_session_id) =
+        state::first_multisig_session_since(deps.storage, min_epoch)
+            .map_err(ContractError::from)?
+    else {
+        return Ok(vec![]);
+    };
+
+    let min = match start_after {
+        Some(start_after) if start_after.u64() >= first_multisig_session_id => {
+            Bound::exclusive(start_after.u64())
+        }
+        _ => Bound::inclusive(first_multisig_session_id),
+    };
+
+    // proofs are ordered by epoch, so all proofs after the first fresh one are fresh as well
+    LATEST_PROOFS
+        .range(deps.storage, Some(min), None, Order::Ascending)
+        .take_while(|entry| {
+            entry.as_ref().map_or(true, |(_, proof)| {
+                proof.is_stale(current_epoch, epoch_window)
+            })
+        })
+        .take(limit)
+        .map(|entry| {
+            let (multisig_session_id, proof) = entry.map_err(ContractError::from)?;
+            let message_ids = PAYLOAD
+                .load(deps.storage, &proof.payload_id)
+                .map_err(ContractError::from)?
+                .message_ids()
+                .unwrap_or_default();
+
+            Ok(StaleProofResponse {
+                multisig_session_id: multisig_session_id.into(),
+                message_ids,
+                verifier_set_id: proof.verifier_set_id,
+                epoch: proof.epoch,
+            })
+        })
+        .collect()
+}
+
+pub fn arbitrary_payload_proof(
+    deps: Deps,
+    multisig_session_id: Uint64,
+) -> Result<ArbitraryPayloadProofResponse, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
+    let payload = MULTISIG_SESSION_ARBITRARY_PAYLOAD
+        .load(deps.storage, multisig_session_id.u64())
+        .map_err(ContractError::from)?;
+
+    let query_msg = multisig::msg::QueryMsg::Multisig {
+        session_id: multisig_session_id,
+    };
+
+    let multisig: Multisig = deps
+        .querier
+        .query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: config.multisig.to_string(),
+            msg: to_json_binary(&query_msg).map_err(ContractError::from)?,
+        }))
+        .map_err(ContractError::from)?;
+
+    let digest = config.encoder.arbitrary_payload_digest(
+        &config.domain_separator,
+        &multisig.verifier_set,
+        &payload,
+    )?;
+
+    let status = match multisig.state {
+        MultisigState::Pending => ArbitraryPayloadProofStatus::Pending,
+        MultisigState::Completed { .. } => ArbitraryPayloadProofStatus::Completed {
+            signatures: config
+                .encoder
+                .recoverable_signatures(&digest, multisig.optimize_signatures())?,
+            verifier_set: multisig.verifier_set,
+        },
+        MultisigState::Expired => ArbitraryPayloadProofStatus::Expired,
+    };
+
+    Ok(ArbitraryPayloadProofResponse {
+        multisig_session_id,
+        payload,
+        digest: digest.into(),
+        status,
     })
 }
 
@@ -65,6 +252,72 @@ pub fn next_verifier_set(deps: Deps) -> StdResult<Option<VerifierSetResponse>> {
         .map(|op| op.map(|set| set.into()))
 }
 
+pub fn verifier_set_update_status(
+    deps: Deps,
+    env: Env,
+) -> Result<VerifierSetUpdateStatusResponse, ContractError> {
+    let config = CONFIG.load(deps.storage).map_err(ContractError::from)?;
+
+    let cur_verifier_set = CURRENT_VERIFIER_SET
+        .may_load(deps.storage)
+        .map_err(ContractError::from)?
+        .ok_or(ContractError::NoVerifierSet)?;
+    let new_verifier_set = execute::make_verifier_set(deps, &env, &config)?;
+
+    let changed_signers = |changed: fn(&Signer, &Signer) -> bool| {
+        new_verifier_set
+            .signers
+            .iter()
+            .filter(|(address, new_signer)| {
+                cur_verifier_set
+                    .signers
+                    .get(*address)
+                    .is_some_and(|cur_signer| changed(cur_signer, new_signer))
+            })
+            .map(|(_, signer)| signer.address.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let permissionless_update_at = VERIFIER_SET_UPDATE_INTERVAL
+        .may_load(deps.storage)
+        .map_err(ContractError::from)?
+        .map(|min_interval| cur_verifier_set.created_at.saturating_add(min_interval));
+
+    Ok(VerifierSetUpdateStatusResponse {
+        update_due: execute::should_update_verifier_set(
+            &new_verifier_set,
+            &cur_verifier_set,
+            config.verifier_set_diff_threshold as usize,
+        ),
+        update_in_progress: NEXT_VERIFIER_SET
+            .may_load(deps.storage)
+            .map_err(ContractError::from)?
+            .is_some(),
+        threshold_changed: new_verifier_set.threshold != cur_verifier_set.threshold,
+        signers_added: signers_difference(&new_verifier_set, &cur_verifier_set),
+        signers_removed: signers_difference(&cur_verifier_set, &new_verifier_set),
+        weights_changed: changed_signers(|cur, new| cur.weight != new.weight),
+        pub_keys_changed: changed_signers(|cur, new| cur.pub_key != new.pub_key),
+        permissionless_update_at,
+    })
+}
+
+// addresses of the signers of the first verifier set that are not part of the second one
+fn signers_difference(verifier_set: &VerifierSet, other: &VerifierSet) -> Vec<Addr> {
+    verifier_set
+        .signers
+        .iter()
+        .filter(|(address, _)| !other.signers.contains_key(*address))
+        .map(|(_, signer)| signer.address.clone())
+        .collect()
+}
+
+pub fn proof_limits(deps: Deps) -> StdResult<ProofLimits> {
+    PROOF_LIMITS
+        .may_load(deps.storage)
+        .map(Option::unwrap_or_default)
+}
+
 #[cfg(test)]
 mod test {
     use cosmwasm_std::testing::mock_dependencies;
@@ -3,14 +3,21 @@ use cw_utils::{parse_reply_execute_data, MsgExecuteContractResponse};
 
 use crate::error::ContractError;
 use crate::events::Event;
-use crate::state::{CONFIG, MULTISIG_SESSION_PAYLOAD, PAYLOAD, REPLY_TRACKER};
+use crate::state::{
+    self, PayloadProof, ARBITRARY_PAYLOAD_REPLY_TRACKER, CONFIG, CURRENT_VERIFIER_SET,
+    MULTISIG_SESSION_ARBITRARY_PAYLOAD, MULTISIG_SESSION_PAYLOAD, PAYLOAD, REPLY_TRACKER,
+};
 
 pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
     match parse_reply_execute_data(reply) {
         Ok(MsgExecuteContractResponse { data: Some(data) }) => {
-            let payload_id = REPLY_TRACKER.load(deps.storage)?;
+            let payload_id = REPLY_TRACKER.pop_front(deps.storage)?.ok_or_else(|| {
+                ContractError::InvalidContractReply {
+                    reason: "no payload waiting for a multisig session".to_string(),
+                }
+            })?;
 
             let multisig_session_id: Uint64 =
                 from_json(data).map_err(|_| ContractError::InvalidContractReply {
@@ -24,6 +31,27 @@ pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, Con
                 .message_ids()
                 .unwrap_or_default();
 
+            for cc_id in &msg_ids {
+                state::save_message_multisig_session(
+                    deps.storage,
+                    cc_id,
+                    multisig_session_id.u64(),
+                    &payload_id,
+                )?;
+            }
+
+            if !msg_ids.is_empty() {
+                state::save_latest_proof(
+                    deps.storage,
+                    multisig_session_id.u64(),
+                    &PayloadProof {
+                        payload_id: payload_id.clone(),
+                        verifier_set_id: CURRENT_VERIFIER_SET.load(deps.storage)?.id(),
+                        epoch: state::verifier_set_epoch(deps.storage)?,
+                    },
+                )?;
+            }
+
             Ok(Response::new().add_event(
                 Event::ProofUnderConstruction {
                     destination_chain: config.chain_name,
@@ -42,3 +70,42 @@ pub fn start_multisig_reply(deps: DepsMut, reply: Reply) -> Result<Response, Con
         }
     }
 }
+
+pub fn start_arbitrary_signing_reply(
+    deps: DepsMut,
+    reply: Reply,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    match parse_reply_execute_data(reply) {
+        Ok(MsgExecuteContractResponse { data: Some(data) }) => {
+            let payload = ARBITRARY_PAYLOAD_REPLY_TRACKER.load(deps.storage)?;
+
+            let multisig_session_id: Uint64 =
+                from_json(data).map_err(|_| ContractError::InvalidContractReply {
+                    reason: "invalid multisig session ID".to_string(),
+                })?;
+
+            MULTISIG_SESSION_ARBITRARY_PAYLOAD.save(
+                deps.storage,
+                multisig_session_id.u64(),
+                &payload,
+            )?;
+
+            Ok(Response::new().add_event(
+                Event::ArbitraryPayloadSigningStarted {
+                    destination_chain: config.chain_name,
+                    payload,
+                    multisig_session_id,
+                }
+                .into(),
+            ))
+        }
+        Ok(MsgExecuteContractResponse { data: None }) => Err(ContractError::InvalidContractReply {
+            reason: "no data".to_string(),
+        }),
+        Err(_) => {
+            unreachable!("violated invariant: replied failed submessage with ReplyOn::Success")
+        }
+    }
+}
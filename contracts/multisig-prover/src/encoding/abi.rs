@@ -1,20 +1,25 @@
 use axelar_wasm_std::hash::Hash;
 use cosmwasm_std::HexBinary;
-use error_stack::{Result, ResultExt};
+use error_stack::{report, Result, ResultExt};
 use ethers_contract::contract::EthCall;
 use ethers_core::abi::{encode as abi_encode, Token, Tokenize};
+use evm_gateway::bls::{
+    AggregatedProof, ApproveMessagesBlsCall, RotateSignersBlsCall, WeightedBlsSigners,
+};
 use evm_gateway::{
     ApproveMessagesCall, CommandType, Message, Proof, RotateSignersCall, WeightedSigners,
 };
 use itertools::Itertools;
 use k256::ecdsa::RecoveryId;
-use multisig::key::Signature;
+use multisig::key::KeyType;
 use multisig::msg::SignerWithSig;
+use multisig::multisig::AggregatedSignature;
 use multisig::verifier_set::VerifierSet;
 use sha3::{Digest, Keccak256};
 
+use crate::encoding::to_recoverable;
 use crate::error::ContractError;
-use crate::payload::Payload;
+use crate::payload::{arbitrary_payload_hash, Payload};
 
 const PREFIX: &str = "\x19Ethereum Signed Message:\n96";
 
@@ -32,11 +37,30 @@ pub fn payload_digest(
     signer: &VerifierSet,
     payload: &Payload,
 ) -> Result<Hash, ContractError> {
-    let signer_hash = WeightedSigners::try_from(signer)
-        .map(|signers| signers.hash())
-        .change_context(ContractError::InvalidVerifierSet)?;
+    let data_hash = Keccak256::digest(encode_payload(payload)?).into();
+
+    digest(domain_separator, signer, data_hash)
+}
+
+pub fn arbitrary_payload_digest(
+    domain_separator: &Hash,
+    signer: &VerifierSet,
+    payload: &[u8],
+) -> Result<Hash, ContractError> {
+    digest(domain_separator, signer, arbitrary_payload_hash(payload))
+}
 
-    let data_hash = Keccak256::digest(encode_payload(payload)?);
+fn digest(
+    domain_separator: &Hash,
+    signer: &VerifierSet,
+    data_hash: Hash,
+) -> Result<Hash, ContractError> {
+    let signer_hash = if is_bls(signer) {
+        WeightedBlsSigners::try_from(signer).map(|signers| signers.hash())
+    } else {
+        WeightedSigners::try_from(signer).map(|signers| signers.hash())
+    }
+    .change_context(ContractError::InvalidVerifierSet)?;
 
     // Prefix for standard EVM signed data https://eips.ethereum.org/EIPS/eip-191
     let unsigned = [
@@ -64,14 +88,16 @@ pub fn encode_payload(payload: &Payload) -> Result<Vec<u8>, ContractError> {
 
             Ok(abi_encode(&[command_type, Token::Array(messages)]))
         }
-        Payload::VerifierSet(verifier_set) => Ok(abi_encode(&[
-            command_type,
-            Token::Tuple(
-                WeightedSigners::try_from(verifier_set)
-                    .change_context(ContractError::InvalidVerifierSet)?
-                    .into_tokens(),
-            ),
-        ])),
+        Payload::VerifierSet(verifier_set) => {
+            let signers = if is_bls(verifier_set) {
+                WeightedBlsSigners::try_from(verifier_set).map(Tokenize::into_tokens)
+            } else {
+                WeightedSigners::try_from(verifier_set).map(Tokenize::into_tokens)
+            }
+            .change_context(ContractError::InvalidVerifierSet)?;
+
+            Ok(abi_encode(&[command_type, Token::Tuple(signers)]))
+        }
     }
 }
 
@@ -81,10 +107,17 @@ pub fn encode_execute_data(
     signers: Vec<SignerWithSig>,
     payload: &Payload,
 ) -> error_stack::Result<HexBinary, ContractError> {
+    if is_bls(verifier_set) {
+        return Err(report!(ContractError::Proof)).attach_printable(
+            "BLS verifier sets are proven with the aggregated signature of the signing session",
+        );
+    }
+
     let signers = to_recoverable(
-        payload_digest(domain_separator, verifier_set, payload)?,
+        &payload_digest(domain_separator, verifier_set, payload)?,
         signers,
-    );
+        recovery_byte,
+    )?;
 
     let proof = Proof::new(verifier_set, signers).change_context(ContractError::Proof)?;
 
@@ -119,31 +152,57 @@ pub fn encode_execute_data(
         .into())
 }
 
-// Convert non-recoverable ECDSA signatures to recoverable ones.
-fn to_recoverable<M>(msg: M, signers: Vec<SignerWithSig>) -> Vec<SignerWithSig>
-where
-    M: AsRef<[u8]>,
-{
-    let recovery_transform = |recovery_byte: RecoveryId| -> u8 {
-        recovery_byte
-            .to_byte()
-            .checked_add(27)
-            .expect("overflow when adding 27 to recovery byte")
+// Verifier sets with BLS keys are proven with a single aggregated signature and a bitmap of the signers,
+// so the proof size does not grow with the number of signers.
+pub fn encode_aggregated_execute_data(
+    verifier_set: &VerifierSet,
+    aggregated_signature: AggregatedSignature,
+    payload: &Payload,
+) -> error_stack::Result<HexBinary, ContractError> {
+    let proof = AggregatedProof::new(verifier_set, aggregated_signature)
+        .change_context(ContractError::Proof)?;
+
+    let (selector, encoded) = match payload {
+        Payload::Messages(messages) => {
+            let messages: Vec<_> = messages
+                .iter()
+                .map(Message::try_from)
+                .collect::<Result<_, _>>()
+                .change_context(ContractError::InvalidMessage)?;
+
+            (
+                ApproveMessagesBlsCall::selector(),
+                abi_encode(&ApproveMessagesBlsCall { messages, proof }.into_tokens()),
+            )
+        }
+        Payload::VerifierSet(new_verifier_set) => {
+            let new_signers = WeightedBlsSigners::try_from(new_verifier_set)
+                .change_context(ContractError::InvalidVerifierSet)?;
+
+            (
+                RotateSignersBlsCall::selector(),
+                abi_encode(&RotateSignersBlsCall { new_signers, proof }.into_tokens()),
+            )
+        }
     };
 
-    signers
+    Ok(selector
         .into_iter()
-        .map(|mut signer| {
-            if let Signature::Ecdsa(nonrecoverable) = signer.signature {
-                signer.signature = nonrecoverable
-                    .to_recoverable(msg.as_ref(), &signer.signer.pub_key, recovery_transform)
-                    .map(Signature::EcdsaRecoverable)
-                    .expect("failed to convert non-recoverable signature to recoverable");
-            }
+        .chain(encoded)
+        .collect::<Vec<_>>()
+        .into())
+}
+
+fn is_bls(verifier_set: &VerifierSet) -> bool {
+    verifier_set.key_type() == Some(KeyType::Bls)
+}
 
-            signer
-        })
-        .collect()
+// EVM signatures carry the recovery id offset by 27
+pub fn recovery_byte(recovery_id: RecoveryId) -> u8 {
+    recovery_id
+        .to_byte()
+        .checked_add(27)
+        .expect("overflow when adding 27 to recovery byte")
 }
 
 #[cfg(test)]
@@ -153,19 +212,26 @@ mod tests {
     use assert_ok::assert_ok;
     use cosmwasm_std::HexBinary;
     use elliptic_curve::consts::U32;
+    use ethers_core::abi::AbiDecode;
     use ethers_core::types::Signature as EthersSignature;
+    use evm_gateway::bls::{ApproveMessagesBlsCall, RotateSignersBlsCall, WeightedBlsSigners};
     use evm_gateway::evm_address;
     use generic_array::GenericArray;
     use hex::FromHex;
     use itertools::Itertools;
-    use k256::ecdsa::{RecoveryId, Signature as K256Signature};
+    use k256::ecdsa::Signature as K256Signature;
     use multisig::key::{KeyType, KeyTyped, Signature};
     use multisig::msg::{Signer, SignerWithSig};
+    use multisig::multisig::AggregatedSignature;
+    use multisig::test::common::bls_test_data;
+    use multisig::verifier_set::VerifierSet;
 
-    use crate::encoding::abi::{encode_execute_data, payload_digest, CommandType};
+    use crate::encoding::abi::{encode_execute_data, payload_digest, recovery_byte, CommandType};
+    use crate::error::ContractError;
     use crate::payload::Payload;
     use crate::test::test_data::{
-        curr_verifier_set, domain_separator, messages, new_verifier_set, verifier_set_from_pub_keys,
+        bls_verifier_set, curr_verifier_set, domain_separator, messages, new_verifier_set,
+        verifier_set_from_pub_keys,
     };
 
     #[test]
@@ -275,6 +341,104 @@ mod tests {
         goldie::assert!(execute_data.to_hex());
     }
 
+    #[test]
+    fn abi_bls_approve_messages_execute_data() {
+        let domain_separator = domain_separator();
+        let verifier_set = bls_verifier_set();
+        let payload = Payload::Messages(messages());
+
+        let payload_digest = assert_ok!(payload_digest(&domain_separator, &verifier_set, &payload));
+        let aggregated_signature =
+            bls_aggregated_signature(&verifier_set, &payload_digest, &[1, 3]);
+
+        let execute_data = assert_ok!(encode_aggregated_execute_data(
+            &verifier_set,
+            aggregated_signature,
+            &payload
+        ));
+
+        let call = assert_ok!(ApproveMessagesBlsCall::decode(execute_data.as_slice()));
+        assert_eq!(call.messages.len(), messages().len());
+        assert_eq!(
+            call.proof.signers,
+            WeightedBlsSigners::try_from(&verifier_set).unwrap()
+        );
+
+        // the signers are ordered by public key, which puts the signer with secret key 3 first
+        assert_eq!(call.proof.signers_bitmap.to_vec(), vec![0b011]);
+        assert_ok!(AggregatedSignature {
+            signers_bitmap: call.proof.signers_bitmap.to_vec().into(),
+            signature: call.proof.signature.to_vec().into(),
+        }
+        .verify(&verifier_set, &payload_digest));
+    }
+
+    #[test]
+    fn abi_bls_rotate_signers_execute_data() {
+        let domain_separator = domain_separator();
+        let verifier_set = bls_verifier_set();
+
+        let mut new_verifier_set = bls_verifier_set();
+        new_verifier_set.created_at = 2024;
+        let payload = Payload::VerifierSet(new_verifier_set.clone());
+
+        let payload_digest = assert_ok!(payload_digest(&domain_separator, &verifier_set, &payload));
+        let aggregated_signature =
+            bls_aggregated_signature(&verifier_set, &payload_digest, &[1, 2]);
+
+        let execute_data = assert_ok!(encode_aggregated_execute_data(
+            &verifier_set,
+            aggregated_signature,
+            &payload
+        ));
+
+        let call = assert_ok!(RotateSignersBlsCall::decode(execute_data.as_slice()));
+        assert_eq!(
+            call.new_signers,
+            WeightedBlsSigners::try_from(&new_verifier_set).unwrap()
+        );
+        assert_ok!(AggregatedSignature {
+            signers_bitmap: call.proof.signers_bitmap.to_vec().into(),
+            signature: call.proof.signature.to_vec().into(),
+        }
+        .verify(&verifier_set, &payload_digest));
+    }
+
+    #[test]
+    fn abi_bls_rotate_signers_to_non_bls_verifier_set_fails() {
+        let domain_separator = domain_separator();
+        let verifier_set = bls_verifier_set();
+        let payload = Payload::VerifierSet(new_verifier_set());
+
+        let payload_digest = assert_ok!(payload_digest(&domain_separator, &verifier_set, &payload));
+        let aggregated_signature =
+            bls_aggregated_signature(&verifier_set, &payload_digest, &[1, 2]);
+
+        let result = encode_aggregated_execute_data(&verifier_set, aggregated_signature, &payload);
+        assert_eq!(
+            result.unwrap_err().current_context(),
+            &ContractError::InvalidVerifierSet
+        );
+    }
+
+    #[test]
+    fn abi_bls_execute_data_requires_aggregated_signature() {
+        let domain_separator = domain_separator();
+        let verifier_set = bls_verifier_set();
+        let payload = Payload::Messages(messages());
+
+        let payload_digest = assert_ok!(payload_digest(&domain_separator, &verifier_set, &payload));
+        let signers_with_sigs = bls_signers_with_sigs(&verifier_set, &payload_digest, &[1, 2]);
+
+        let result = encode_execute_data(
+            &domain_separator,
+            &verifier_set,
+            signers_with_sigs,
+            &payload,
+        );
+        assert_eq!(result.unwrap_err().current_context(), &ContractError::Proof);
+    }
+
     #[test]
     fn should_convert_signature_to_recoverable() {
         let ecdsa_signature = EthersSignature::from_str("74ab5ec395cdafd861dec309c30f6cf8884fc9905eb861171e636d9797478adb60b2bfceb7db0a08769ed7a60006096d3e0f6d3783d125600ac6306180ecbc6f1b").unwrap();
@@ -304,12 +468,7 @@ mod tests {
                 .to_recoverable(
                     HexBinary::from_hex(digest).unwrap().as_slice(),
                     &multisig::key::PublicKey::Ecdsa(HexBinary::from(pub_key.to_vec())),
-                    |recovery_byte: RecoveryId| -> u8 {
-                        recovery_byte
-                            .to_byte()
-                            .checked_add(27)
-                            .expect("overflow when adding 27 to recovery byte")
-                    },
+                    recovery_byte,
                 )
                 .unwrap();
 
@@ -336,4 +495,35 @@ mod tests {
             })
             .collect()
     }
+
+    fn bls_signers_with_sigs(
+        verifier_set: &VerifierSet,
+        msg: &[u8],
+        secret_keys: &[u64],
+    ) -> Vec<SignerWithSig> {
+        secret_keys
+            .iter()
+            .map(|secret_key| {
+                let (_, sig) = bls_test_data::sign(*secret_key, msg);
+
+                verifier_set
+                    .signers
+                    .get(&format!("verifier{secret_key}"))
+                    .unwrap()
+                    .with_sig(Signature::Bls(sig))
+            })
+            .collect()
+    }
+
+    fn bls_aggregated_signature(
+        verifier_set: &VerifierSet,
+        msg: &[u8],
+        secret_keys: &[u64],
+    ) -> AggregatedSignature {
+        AggregatedSignature::new(
+            verifier_set,
+            &bls_signers_with_sigs(verifier_set, msg, secret_keys),
+        )
+        .unwrap()
+    }
 }
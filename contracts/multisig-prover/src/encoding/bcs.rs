@@ -4,14 +4,14 @@ use axelar_wasm_std::hash::Hash;
 use cosmwasm_std::HexBinary;
 use error_stack::{Result, ResultExt};
 use k256::ecdsa::RecoveryId;
-use multisig::key::Signature;
 use multisig::msg::SignerWithSig;
 use multisig::verifier_set::VerifierSet;
 use sha3::{Digest, Keccak256};
 use sui_gateway::{CommandType, ExecuteData, Message, MessageToSign, Proof, WeightedSigners};
 
+use crate::encoding::to_recoverable;
 use crate::error::ContractError;
-use crate::payload::Payload;
+use crate::payload::{arbitrary_payload_hash, Payload};
 
 fn encode_payload(payload: &Payload) -> Result<Vec<u8>, ContractError> {
     let encoded: Vec<u8> = match payload {
@@ -45,13 +45,38 @@ pub fn payload_digest(
     let data = iter::once(command_type as u8)
         .chain(encode_payload(payload)?)
         .collect::<Vec<_>>();
+
+    digest(
+        domain_separator,
+        verifier_set,
+        Keccak256::digest(data).into(),
+    )
+}
+
+pub fn arbitrary_payload_digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    payload: &[u8],
+) -> Result<Hash, ContractError> {
+    digest(
+        domain_separator,
+        verifier_set,
+        arbitrary_payload_hash(payload),
+    )
+}
+
+fn digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    data_hash: Hash,
+) -> Result<Hash, ContractError> {
     let msg = MessageToSign {
         domain_separator: (*domain_separator).into(),
         signers_hash: WeightedSigners::try_from(verifier_set.clone())
             .change_context(ContractError::InvalidVerifierSet)?
             .hash()
             .into(),
-        data_hash: <[u8; 32]>::from(Keccak256::digest(data)).into(),
+        data_hash: data_hash.into(),
     };
 
     Ok(msg.hash())
@@ -66,9 +91,10 @@ pub fn encode_execute_data(
     payload: &Payload,
 ) -> Result<HexBinary, ContractError> {
     let signatures = to_recoverable(
-        payload_digest(domain_separator, verifier_set, payload)?,
+        &payload_digest(domain_separator, verifier_set, payload)?,
         signatures,
-    );
+        RecoveryId::to_byte,
+    )?;
 
     let encoded_payload = encode_payload(payload)?;
     let encoded_proof = bcs::to_bytes(
@@ -83,27 +109,6 @@ pub fn encode_execute_data(
         .into())
 }
 
-fn to_recoverable<M>(msg: M, signers: Vec<SignerWithSig>) -> Vec<SignerWithSig>
-where
-    M: AsRef<[u8]>,
-{
-    let recovery_transform = |recovery_byte: RecoveryId| -> u8 { recovery_byte.to_byte() };
-
-    signers
-        .into_iter()
-        .map(|mut signer| {
-            if let Signature::Ecdsa(nonrecoverable) = signer.signature {
-                signer.signature = nonrecoverable
-                    .to_recoverable(msg.as_ref(), &signer.signer.pub_key, recovery_transform)
-                    .map(Signature::EcdsaRecoverable)
-                    .expect("failed to convert non-recoverable signature to recoverable");
-            }
-
-            signer
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use axelar_wasm_std::hash::Hash;
@@ -1,12 +1,17 @@
 mod abi;
 mod bcs;
+mod solana_borsh;
+mod starknet_cairo;
 mod stellar_xdr;
 
 use axelar_wasm_std::hash::Hash;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::HexBinary;
-use error_stack::Result;
+use error_stack::{report, Result, ResultExt};
+use k256::ecdsa::RecoveryId;
+use multisig::key::{KeyType, Signature};
 use multisig::msg::SignerWithSig;
+use multisig::multisig::AggregatedSignature;
 use multisig::verifier_set::VerifierSet;
 
 use crate::error::ContractError;
@@ -18,6 +23,8 @@ pub enum Encoder {
     Abi,
     Bcs,
     StellarXdr,
+    SolanaBorsh,
+    Starknet,
 }
 
 impl Encoder {
@@ -33,6 +40,49 @@ impl Encoder {
             Encoder::StellarXdr => {
                 stellar_xdr::payload_digest(domain_separator, verifier_set, payload)
             }
+            Encoder::SolanaBorsh => {
+                solana_borsh::payload_digest(domain_separator, verifier_set, payload)
+            }
+            Encoder::Starknet => {
+                starknet_cairo::payload_digest(domain_separator, verifier_set, payload)
+            }
+        }
+    }
+
+    /// Digest of an arbitrary payload signed by the verifier set on behalf of governance.
+    /// It is computed like the digest of a payload, with the hash of the arbitrary payload in place of the payload hash.
+    pub fn arbitrary_payload_digest(
+        &self,
+        domain_separator: &Hash,
+        verifier_set: &VerifierSet,
+        payload: &[u8],
+    ) -> Result<Hash, ContractError> {
+        match self {
+            Encoder::Abi => abi::arbitrary_payload_digest(domain_separator, verifier_set, payload),
+            Encoder::Bcs => bcs::arbitrary_payload_digest(domain_separator, verifier_set, payload),
+            Encoder::StellarXdr => {
+                stellar_xdr::arbitrary_payload_digest(domain_separator, verifier_set, payload)
+            }
+            Encoder::SolanaBorsh => {
+                solana_borsh::arbitrary_payload_digest(domain_separator, verifier_set, payload)
+            }
+            Encoder::Starknet => Err(report!(ContractError::ArbitraryPayloadNotSupported)),
+        }
+    }
+
+    /// Converts ECDSA signatures of the digest to the recoverable signatures the destination chain verifies.
+    /// Signatures of other key types are returned unchanged.
+    pub fn recoverable_signatures(
+        &self,
+        digest: &Hash,
+        signers: Vec<SignerWithSig>,
+    ) -> Result<Vec<SignerWithSig>, ContractError> {
+        match self {
+            Encoder::Abi => to_recoverable(digest, signers, abi::recovery_byte),
+            Encoder::Bcs | Encoder::SolanaBorsh | Encoder::Starknet => {
+                to_recoverable(digest, signers, RecoveryId::to_byte)
+            }
+            Encoder::StellarXdr => Ok(signers),
         }
     }
 
@@ -47,6 +97,61 @@ impl Encoder {
             Encoder::Abi => abi::encode_execute_data(domain_separator, verifier_set, sigs, payload),
             Encoder::Bcs => bcs::encode_execute_data(domain_separator, verifier_set, sigs, payload),
             Encoder::StellarXdr => stellar_xdr::encode_execute_data(verifier_set, sigs, payload),
+            Encoder::SolanaBorsh => {
+                solana_borsh::encode_execute_data(domain_separator, verifier_set, sigs, payload)
+            }
+            Encoder::Starknet => {
+                starknet_cairo::encode_execute_data(domain_separator, verifier_set, sigs, payload)
+            }
+        }
+    }
+
+    /// Signing sessions of BLS verifier sets are proven with an aggregated signature, which only some encoders support
+    pub fn supports_key_type(&self, key_type: KeyType) -> bool {
+        match self {
+            Encoder::Abi => true,
+            Encoder::Bcs | Encoder::StellarXdr | Encoder::SolanaBorsh | Encoder::Starknet => {
+                key_type != KeyType::Bls
+            }
+        }
+    }
+
+    /// Encodes the proof of a signing session whose signatures were aggregated by the multisig contract
+    pub fn aggregated_execute_data(
+        &self,
+        verifier_set: &VerifierSet,
+        aggregated_signature: AggregatedSignature,
+        payload: &Payload,
+    ) -> Result<HexBinary, ContractError> {
+        match self {
+            Encoder::Abi => {
+                abi::encode_aggregated_execute_data(verifier_set, aggregated_signature, payload)
+            }
+            Encoder::Bcs | Encoder::StellarXdr | Encoder::SolanaBorsh | Encoder::Starknet => {
+                Err(report!(ContractError::AggregationNotSupported))
+            }
         }
     }
 }
+
+/// Converts non-recoverable ECDSA signatures of the digest to recoverable ones.
+/// The recovery transform turns the recovery id into the recovery byte the destination gateway expects.
+fn to_recoverable(
+    digest: &Hash,
+    signers: Vec<SignerWithSig>,
+    recovery_transform: impl Fn(RecoveryId) -> u8,
+) -> Result<Vec<SignerWithSig>, ContractError> {
+    signers
+        .into_iter()
+        .map(|mut signer| {
+            if let Signature::Ecdsa(nonrecoverable) = &signer.signature {
+                signer.signature = nonrecoverable
+                    .to_recoverable(digest, &signer.signer.pub_key, &recovery_transform)
+                    .map(Signature::EcdsaRecoverable)
+                    .change_context(ContractError::Proof)?;
+            }
+
+            Ok(signer)
+        })
+        .collect()
+}
@@ -0,0 +1,322 @@
+use axelar_wasm_std::hash::Hash;
+use cosmwasm_std::HexBinary;
+use error_stack::{Result, ResultExt};
+use k256::ecdsa::RecoveryId;
+use multisig::msg::SignerWithSig;
+use multisig::verifier_set::VerifierSet;
+use sha3::{Digest, Keccak256};
+use solana_gateway::{ExecuteData, ExecutePayload, Message, Messages, Proof, WeightedSigners};
+
+use crate::encoding::to_recoverable;
+use crate::error::ContractError;
+use crate::payload::{arbitrary_payload_hash, Payload};
+
+pub fn payload_digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    payload: &Payload,
+) -> Result<Hash, ContractError> {
+    let data_hash = match payload {
+        Payload::Messages(messages) => to_messages(messages)?.messages_approval_hash(),
+        Payload::VerifierSet(verifier_set) => WeightedSigners::try_from(verifier_set)
+            .change_context(ContractError::InvalidVerifierSet)?
+            .signers_rotation_hash(),
+    };
+
+    digest(domain_separator, verifier_set, data_hash)
+}
+
+pub fn arbitrary_payload_digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    payload: &[u8],
+) -> Result<Hash, ContractError> {
+    digest(
+        domain_separator,
+        verifier_set,
+        arbitrary_payload_hash(payload),
+    )
+}
+
+fn digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    data_hash: Hash,
+) -> Result<Hash, ContractError> {
+    let signers_hash = WeightedSigners::try_from(verifier_set)
+        .change_context(ContractError::InvalidVerifierSet)?
+        .hash();
+
+    let unsigned = [
+        domain_separator,
+        signers_hash.as_slice(),
+        data_hash.as_slice(),
+    ]
+    .concat();
+
+    Ok(Keccak256::digest(unsigned).into())
+}
+
+/// `encode_execute_data` returns the Borsh encoded instruction data of the Solana gateway program.
+/// The relayer will use this data to submit the payload to the program.
+pub fn encode_execute_data(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    signatures: Vec<SignerWithSig>,
+    payload: &Payload,
+) -> Result<HexBinary, ContractError> {
+    // the secp256k1_recover syscall expects the plain recovery id, so unlike the EVM encoding no offset is added
+    let signatures = to_recoverable(
+        &payload_digest(domain_separator, verifier_set, payload)?,
+        signatures,
+        RecoveryId::to_byte,
+    )?;
+
+    let payload = match payload {
+        Payload::Messages(messages) => ExecutePayload::ApproveMessages(to_messages(messages)?),
+        Payload::VerifierSet(verifier_set) => ExecutePayload::RotateSigners(
+            WeightedSigners::try_from(verifier_set)
+                .change_context(ContractError::InvalidVerifierSet)?,
+        ),
+    };
+
+    let proof = Proof::try_from((verifier_set, signatures)).change_context(ContractError::Proof)?;
+
+    Ok(ExecuteData { payload, proof }.encode().into())
+}
+
+fn to_messages(messages: &[router_api::Message]) -> Result<Messages, ContractError> {
+    Ok(messages
+        .iter()
+        .map(Message::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(ContractError::InvalidMessage)?
+        .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::HexBinary;
+    use multisig::key::KeyType;
+
+    use crate::encoding::solana_borsh::{encode_execute_data, payload_digest};
+    use crate::error::ContractError;
+    use crate::payload::Payload;
+    use crate::test::test_data::{
+        ecdsa_signers_data, signers_with_sig, solana_message, solana_messages_payload,
+        verifier_set_from_signers_data,
+    };
+
+    #[test]
+    fn solana_messages_payload_digest() {
+        let signers_data = vec![
+            (
+                "addr_1",
+                KeyType::Ed25519,
+                "508bcac3df50837e0b093aebc549211ba72bd1e7c1830a288b816b677d62a046",
+                9u128,
+            ),
+            (
+                "addr_2",
+                KeyType::Ed25519,
+                "5c186341e6392ff06b35b2b80a05f99cdd1dd7d5b436f2eef1a6dd08c07c9463",
+                4u128,
+            ),
+            (
+                "addr_3",
+                KeyType::Ed25519,
+                "78c860cbba0b74a728bdc2ae05feef5a14c8903f59d59525ed5bea9b52027d0e",
+                3u128,
+            ),
+            (
+                "addr_4",
+                KeyType::Ed25519,
+                "ac1276368dab35ecc413c5008f184df4005e8773ea44ce3c980bc3dbe45f7521",
+                3u128,
+            ),
+            (
+                "addr_5",
+                KeyType::Ed25519,
+                "e2a6a040c4a31f8131651fb669d514066963e2fde91feb86350d494a6e02f0fa",
+                6u128,
+            ),
+        ];
+        let verifier_set = verifier_set_from_signers_data(signers_data, 16, 2024);
+
+        let domain_separator: [u8; 32] =
+            HexBinary::from_hex("2a15376c1277252b1bcce5a6ecd781bfbc2697dfd969ff58d8e2e116018b501e")
+                .unwrap()
+                .to_array()
+                .unwrap();
+
+        goldie::assert!(hex::encode(
+            payload_digest(&domain_separator, &verifier_set, &solana_messages_payload()).unwrap()
+        ));
+    }
+
+    #[test]
+    fn solana_verifier_set_payload_digest() {
+        let verifier_set = verifier_set_from_signers_data(ecdsa_signers_data(), 3, 2024);
+
+        let new_signers_data = vec![
+            (
+                "addr_1",
+                KeyType::Ed25519,
+                "5086d25f94b8c42faf7ef4325516864e179fcb2a1a9321720f0fc2b249105106",
+                5u128,
+            ),
+            (
+                "addr_2",
+                KeyType::Ed25519,
+                "57a446f70d8243b7d5e08edcd9c5774f3f0257940df7aa84bca5b1acfc0f3ba3",
+                7u128,
+            ),
+            (
+                "addr_3",
+                KeyType::Ed25519,
+                "5a3211139cca5cee83096e8009aadf6405d84f5137706bc1db68f53cbb202054",
+                9u128,
+            ),
+        ];
+        let payload =
+            Payload::VerifierSet(verifier_set_from_signers_data(new_signers_data, 14, 2025));
+
+        let domain_separator: [u8; 32] =
+            HexBinary::from_hex("6773bd037510492f863cba62a0f3c55ac846883f33cae7266aff8be5eb9681e8")
+                .unwrap()
+                .to_array()
+                .unwrap();
+
+        goldie::assert!(hex::encode(
+            payload_digest(&domain_separator, &verifier_set, &payload).unwrap()
+        ));
+    }
+
+    #[test]
+    fn solana_approve_messages_execute_data() {
+        let verifier_set = verifier_set_from_signers_data(
+            vec![
+                (
+                    "addr_1",
+                    KeyType::Ed25519,
+                    "12f7d9a9463212335914b39ee90bfa2045f90b64c1f2d7b58ed335282abac4a4",
+                    8u128,
+                ),
+                (
+                    "addr_2",
+                    KeyType::Ed25519,
+                    "4c3863e4b0252a8674c1c6ad70b3ca3002b400b49ddfae5583b21907e65c5dd8",
+                    1u128,
+                ),
+                (
+                    "addr_3",
+                    KeyType::Ed25519,
+                    "c35aa94d2038f258ecb1bb28fbc8a83ab79d2dc0a7223fd528a8f52a14c03292",
+                    7u128,
+                ),
+            ],
+            10,
+            2024,
+        );
+
+        // addr_2 did not sign, but still has to be part of the proof
+        let signers_with_sig = signers_with_sig(
+            &verifier_set,
+            vec![
+                (
+                    "addr_1",
+                    KeyType::Ed25519,
+                    "b5b3b0749aa585f866d802e32ca4a6356f82eb52e2a1b4797cbaa30f3d755462f2eb995c70d9099e436b8a48498e4d613ff2d3ca7618973a36c2fde17493180f",
+                ),
+                (
+                    "addr_3",
+                    KeyType::Ed25519,
+                    "28e2c8accfa1c2db93349c6d3f783004d6a92cdbf322b92b3555315999e0eaf5d8bdf9deb58d798168a880972e81b8513dcb942de44862317d501cf7445c660a",
+                ),
+            ],
+        );
+
+        goldie::assert!(encode_execute_data(
+            &[0; 32],
+            &verifier_set,
+            signers_with_sig,
+            &solana_messages_payload()
+        )
+        .unwrap()
+        .to_hex());
+    }
+
+    #[test]
+    fn solana_rotate_signers_execute_data() {
+        let verifier_set = verifier_set_from_signers_data(ecdsa_signers_data(), 3, 2024);
+
+        let signers_with_sig = signers_with_sig(
+            &verifier_set,
+            vec![
+                (
+                    "addr_2",
+                    KeyType::Ecdsa,
+                    "72b242d7247fc31d14ce82b32f3ea911808f6f600f362150f9904c974315942927c25f9388cecdbbb0b3723164eea92206775870cd28e1ffd8f1cb9655fb3c4a00",
+                ),
+                (
+                    "addr_3",
+                    KeyType::Ecdsa,
+                    "86909155a6ba27f173edf15d283da6a0019fb6afe6b223ca68530464813f468f356e70788faf6d1d9ff7bfcfd9021b560d72408bef4c86c66e3a94b9dee0a34a00",
+                ),
+                (
+                    "addr_4",
+                    KeyType::Ecdsa,
+                    "9b2d986652fdebe67554f1b33ae6161b205ea84e0dacb07ffde0889791bcab2e5be3b8229eae01f2c22805c87f15cb7f9642e9cba951489edcac5d12ace3993900",
+                ),
+            ],
+        );
+
+        let payload = Payload::VerifierSet(verifier_set_from_signers_data(
+            vec![
+                (
+                    "addr_1",
+                    KeyType::Ed25519,
+                    "358a2305fc783b6072049ee6f5f76fb14c3a14d7c01e36d9ef502661bf46a011",
+                    9u128,
+                ),
+                (
+                    "addr_2",
+                    KeyType::Ed25519,
+                    "3b1caf530189a9a65ae347b18cb8bf88729ba90d2aeaf7f185b600400ab49891",
+                    1u128,
+                ),
+                (
+                    "addr_3",
+                    KeyType::Ed25519,
+                    "531616448afd45c0e3e053622cbccb65d8fc99cd2f02636d728739811e72eafb",
+                    3u128,
+                ),
+            ],
+            10,
+            2025,
+        ));
+
+        goldie::assert!(
+            encode_execute_data(&[0; 32], &verifier_set, signers_with_sig, &payload)
+                .unwrap()
+                .to_hex()
+        );
+    }
+
+    #[test]
+    fn solana_invalid_destination_address_should_fail() {
+        let verifier_set = verifier_set_from_signers_data(ecdsa_signers_data(), 3, 2024);
+
+        let mut message = solana_message();
+        message.destination_address = "0xA4f10f76B86E01B98daF66A3d02a65e14adb0767"
+            .parse()
+            .unwrap();
+
+        let err =
+            payload_digest(&[0; 32], &verifier_set, &Payload::Messages(vec![message])).unwrap_err();
+        assert!(matches!(
+            err.current_context(),
+            ContractError::InvalidMessage
+        ));
+    }
+}
@@ -0,0 +1,230 @@
+use axelar_wasm_std::hash::Hash;
+use cosmwasm_std::HexBinary;
+use error_stack::{Result, ResultExt};
+use k256::ecdsa::RecoveryId;
+use multisig::msg::SignerWithSig;
+use multisig::verifier_set::VerifierSet;
+use starknet_gateway::{
+    message_hash_to_sign, ExecuteData, ExecutePayload, Message, Messages, Proof, WeightedSigners,
+};
+
+use crate::encoding::to_recoverable;
+use crate::error::ContractError;
+use crate::payload::Payload;
+
+pub fn payload_digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    payload: &Payload,
+) -> Result<Hash, ContractError> {
+    let data_hash = match payload {
+        Payload::Messages(messages) => to_messages(messages)?.messages_approval_hash(),
+        Payload::VerifierSet(verifier_set) => WeightedSigners::try_from(verifier_set)
+            .change_context(ContractError::InvalidVerifierSet)?
+            .signers_rotation_hash(),
+    };
+
+    let signers_hash = WeightedSigners::try_from(verifier_set)
+        .change_context(ContractError::InvalidVerifierSet)?
+        .hash();
+
+    Ok(message_hash_to_sign(
+        domain_separator,
+        signers_hash,
+        data_hash,
+    ))
+}
+
+/// `encode_execute_data` returns the execute data of the Starknet gateway as a felt array,
+/// with every felt encoded as 32 big endian bytes.
+/// The relayer will use this data to submit the payload to the contract.
+pub fn encode_execute_data(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    signatures: Vec<SignerWithSig>,
+    payload: &Payload,
+) -> Result<HexBinary, ContractError> {
+    // verify_eth_signature takes the y parity of the signature, which is the plain recovery id
+    let signatures = to_recoverable(
+        &payload_digest(domain_separator, verifier_set, payload)?,
+        signatures,
+        RecoveryId::to_byte,
+    )?;
+
+    let payload = match payload {
+        Payload::Messages(messages) => ExecutePayload::ApproveMessages(to_messages(messages)?),
+        Payload::VerifierSet(verifier_set) => ExecutePayload::RotateSigners(
+            WeightedSigners::try_from(verifier_set)
+                .change_context(ContractError::InvalidVerifierSet)?,
+        ),
+    };
+
+    let proof = Proof::try_from((verifier_set, signatures)).change_context(ContractError::Proof)?;
+
+    Ok(ExecuteData { payload, proof }.encode().into())
+}
+
+fn to_messages(messages: &[router_api::Message]) -> Result<Messages, ContractError> {
+    Ok(messages
+        .iter()
+        .map(Message::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(ContractError::InvalidMessage)?
+        .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::HexBinary;
+    use multisig::key::KeyType;
+    use multisig::msg::SignerWithSig;
+    use multisig::verifier_set::VerifierSet;
+
+    use crate::encoding::starknet_cairo::{encode_execute_data, payload_digest};
+    use crate::error::ContractError;
+    use crate::payload::Payload;
+    use crate::test::test_data::{
+        ecdsa_signers_data, signers_with_sig, starknet_message, starknet_messages_payload,
+        verifier_set_from_signers_data,
+    };
+
+    #[test]
+    fn starknet_messages_payload_digest() {
+        let verifier_set = verifier_set_from_signers_data(ecdsa_signers_data(), 3, 2024);
+
+        let domain_separator: [u8; 32] =
+            HexBinary::from_hex("2a15376c1277252b1bcce5a6ecd781bfbc2697dfd969ff58d8e2e116018b501e")
+                .unwrap()
+                .to_array()
+                .unwrap();
+
+        goldie::assert!(hex::encode(
+            payload_digest(
+                &domain_separator,
+                &verifier_set,
+                &starknet_messages_payload()
+            )
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    fn starknet_verifier_set_payload_digest() {
+        let verifier_set = verifier_set_from_signers_data(ecdsa_signers_data(), 3, 2024);
+        let payload =
+            Payload::VerifierSet(verifier_set_from_signers_data(new_signers_data(), 4, 2025));
+
+        let domain_separator: [u8; 32] =
+            HexBinary::from_hex("6773bd037510492f863cba62a0f3c55ac846883f33cae7266aff8be5eb9681e8")
+                .unwrap()
+                .to_array()
+                .unwrap();
+
+        goldie::assert!(hex::encode(
+            payload_digest(&domain_separator, &verifier_set, &payload).unwrap()
+        ));
+    }
+
+    #[test]
+    fn starknet_approve_messages_execute_data() {
+        let verifier_set = verifier_set_from_signers_data(ecdsa_signers_data(), 3, 2024);
+
+        goldie::assert!(encode_execute_data(
+            &[0; 32],
+            &verifier_set,
+            recoverable_signers_with_sig(&verifier_set),
+            &starknet_messages_payload()
+        )
+        .unwrap()
+        .to_hex());
+    }
+
+    #[test]
+    fn starknet_rotate_signers_execute_data() {
+        let verifier_set = verifier_set_from_signers_data(ecdsa_signers_data(), 3, 2024);
+        let payload =
+            Payload::VerifierSet(verifier_set_from_signers_data(new_signers_data(), 4, 2025));
+
+        goldie::assert!(encode_execute_data(
+            &[0; 32],
+            &verifier_set,
+            recoverable_signers_with_sig(&verifier_set),
+            &payload
+        )
+        .unwrap()
+        .to_hex());
+    }
+
+    #[test]
+    fn starknet_ed25519_verifier_set_should_fail() {
+        let verifier_set = verifier_set_from_signers_data(
+            vec![(
+                "addr_1",
+                KeyType::Ed25519,
+                "12f7d9a9463212335914b39ee90bfa2045f90b64c1f2d7b58ed335282abac4a4",
+                1u128,
+            )],
+            1,
+            2024,
+        );
+
+        let err =
+            payload_digest(&[0; 32], &verifier_set, &starknet_messages_payload()).unwrap_err();
+        assert!(matches!(
+            err.current_context(),
+            ContractError::InvalidVerifierSet
+        ));
+    }
+
+    #[test]
+    fn starknet_invalid_destination_address_should_fail() {
+        let verifier_set = verifier_set_from_signers_data(ecdsa_signers_data(), 3, 2024);
+
+        let mut message = starknet_message();
+        message.destination_address = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            .parse()
+            .unwrap();
+
+        let err =
+            payload_digest(&[0; 32], &verifier_set, &Payload::Messages(vec![message])).unwrap_err();
+        assert!(matches!(
+            err.current_context(),
+            ContractError::InvalidMessage
+        ));
+    }
+
+    fn new_signers_data() -> Vec<(&'static str, KeyType, &'static str, u128)> {
+        vec![
+            (
+                "addr_1",
+                KeyType::Ecdsa,
+                "038318535b54105d4a7aae60c08fc45f9687181b4fdfc625bd1a753fa7397fed75",
+                1u128,
+            ),
+            (
+                "addr_2",
+                KeyType::Ecdsa,
+                "02ba5734d8f7091719471e7f7ed6b9df170dc70cc661ca05e688601ad984f068b0",
+                2u128,
+            ),
+            (
+                "addr_3",
+                KeyType::Ecdsa,
+                "039d9031e97dd78ff8c15aa86939de9b1e791066a0224e331bc962a2099a7b1f04",
+                3u128,
+            ),
+        ]
+    }
+
+    // recoverable signatures skip the recovery id computation, so the encoding doesn't depend on the digest
+    fn recoverable_signers_with_sig(verifier_set: &VerifierSet) -> Vec<SignerWithSig> {
+        signers_with_sig(
+            verifier_set,
+            vec![
+                ("addr_2", KeyType::Ecdsa, "72b242d7247fc31d14ce82b32f3ea911808f6f600f362150f9904c974315942927c25f9388cecdbbb0b3723164eea92206775870cd28e1ffd8f1cb9655fb3c4a00"),
+                ("addr_3", KeyType::Ecdsa, "86909155a6ba27f173edf15d283da6a0019fb6afe6b223ca68530464813f468f356e70788faf6d1d9ff7bfcfd9021b560d72408bef4c86c66e3a94b9dee0a34a01"),
+                ("addr_4", KeyType::Ecdsa, "9b2d986652fdebe67554f1b33ae6161b205ea84e0dacb07ffde0889791bcab2e5be3b8229eae01f2c22805c87f15cb7f9642e9cba951489edcac5d12ace3993900"),
+            ],
+        )
+    }
+}
@@ -9,7 +9,7 @@ use stellar::{Message, Messages, Proof, WeightedSigners};
 use stellar_xdr::curr::{Limits, ScVal, WriteXdr};
 
 use crate::error::ContractError;
-use crate::payload::Payload;
+use crate::payload::{arbitrary_payload_hash, Payload};
 
 pub fn payload_digest(
     domain_separator: &Hash,
@@ -30,6 +30,26 @@ pub fn payload_digest(
     }
     .change_context(ContractError::SerializeData)?;
 
+    digest(domain_separator, verifier_set, data_hash)
+}
+
+pub fn arbitrary_payload_digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    payload: &[u8],
+) -> Result<Hash, ContractError> {
+    digest(
+        domain_separator,
+        verifier_set,
+        arbitrary_payload_hash(payload),
+    )
+}
+
+fn digest(
+    domain_separator: &Hash,
+    verifier_set: &VerifierSet,
+    data_hash: Hash,
+) -> Result<Hash, ContractError> {
     let signers_hash = WeightedSigners::try_from(verifier_set)
         .change_context(ContractError::InvalidVerifierSet)?
         .hash()
@@ -1,8 +1,11 @@
 use axelar_wasm_std::{nonempty, IntoContractError};
 use cosmwasm_std::StdError;
+use multisig::key::KeyType;
 use router_api::ChainName;
 use thiserror::Error;
 
+use crate::encoding::Encoder;
+
 #[derive(Error, Debug, PartialEq, IntoContractError)]
 pub enum ContractError {
     #[error(transparent)]
@@ -54,12 +57,27 @@ pub enum ContractError {
     #[error("no verifier set stored")]
     NoVerifierSet,
 
+    #[error("permissionless verifier set updates are disabled")]
+    PermissionlessVerifierSetUpdateDisabled,
+
+    #[error("the verifier set can't be updated before block {next_update_at}")]
+    VerifierSetUpdateIntervalNotElapsed { next_update_at: u64 },
+
     #[error("failed to serialize the response")]
     SerializeResponse,
 
     #[error("failed to create proof")]
     Proof,
 
+    #[error("the encoder does not support aggregated signatures")]
+    AggregationNotSupported,
+
+    #[error("the {encoder:?} encoder does not support {key_type:?} verifier sets")]
+    KeyTypeNotSupported { encoder: Encoder, key_type: KeyType },
+
+    #[error("the encoder does not support arbitrary payloads")]
+    ArbitraryPayloadNotSupported,
+
     #[error("invalid verifier set")]
     InvalidVerifierSet,
 
@@ -93,6 +111,12 @@ pub enum ContractError {
     #[error("failed to create wasm execute msg")]
     FailedToCreateWasmExecuteMsg,
 
+    #[error("the messages exceed the proof limits")]
+    ProofLimitsExceeded,
+
+    #[error("message {message_id} exceeds the proof limits by itself")]
+    MessageExceedsProofLimits { message_id: String },
+
     // Generic error to wrap cw_storage_plus errors
     // This should only be used for things that shouldn't happen, such as encountering
     // an error when loading data that should always load successfully.
@@ -1,4 +1,4 @@
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{HexBinary, Uint64};
 use router_api::{ChainName, CrossChainId};
 
 use crate::payload::PayloadId;
@@ -10,6 +10,16 @@ pub enum Event {
         multisig_session_id: Uint64,
         msg_ids: Vec<CrossChainId>,
     },
+    ArbitraryPayloadSigningStarted {
+        destination_chain: ChainName,
+        payload: HexBinary,
+        multisig_session_id: Uint64,
+    },
+    StaleProofResigned {
+        payload_id: PayloadId,
+        stale_multisig_session_id: Uint64,
+        stale_verifier_set_id: String,
+    },
 }
 
 impl From<Event> for cosmwasm_std::Event {
@@ -41,6 +51,39 @@ impl From<Event> for cosmwasm_std::Event {
                     serde_json::to_string(&msg_ids)
                         .expect("violated invariant: message_ids is not serializable"),
                 ),
+            Event::ArbitraryPayloadSigningStarted {
+                destination_chain,
+                payload,
+                multisig_session_id,
+            } => cosmwasm_std::Event::new("arbitrary_payload_signing_started")
+                .add_attribute(
+                    "destination_chain",
+                    serde_json::to_string(&destination_chain)
+                        .expect("violated invariant: destination_chain is not serializable"),
+                )
+                .add_attribute("payload", payload.to_hex())
+                .add_attribute(
+                    "multisig_session_id",
+                    serde_json::to_string(&multisig_session_id)
+                        .expect("violated invariant: multisig_session_id is not serializable"),
+                ),
+            Event::StaleProofResigned {
+                payload_id,
+                stale_multisig_session_id,
+                stale_verifier_set_id,
+            } => cosmwasm_std::Event::new("stale_proof_resigned")
+                .add_attribute(
+                    "payload_id",
+                    serde_json::to_string(&payload_id)
+                        .expect("violated invariant: payload_id is not serializable"),
+                )
+                .add_attribute(
+                    "stale_multisig_session_id",
+                    serde_json::to_string(&stale_multisig_session_id).expect(
+                        "violated invariant: stale_multisig_session_id is not serializable",
+                    ),
+                )
+                .add_attribute("stale_verifier_set_id", stale_verifier_set_id),
         }
     }
 }
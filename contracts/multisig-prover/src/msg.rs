@@ -1,9 +1,10 @@
 use axelar_wasm_std::hash::Hash;
-use axelar_wasm_std::MajorityThreshold;
+use axelar_wasm_std::{nonempty, MajorityThreshold};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{HexBinary, Uint64};
+use cosmwasm_std::{Addr, HexBinary, Uint64};
 use msgs_derive::EnsurePermissions;
 use multisig::key::KeyType;
+use multisig::msg::SignerWithSig;
 use router_api::CrossChainId;
 
 use crate::encoding::Encoder;
@@ -66,6 +67,10 @@ pub enum ExecuteMsg {
     ConstructProof(Vec<CrossChainId>),
     #[permission(Elevated)]
     UpdateVerifierSet,
+    /// Updates the verifier set if it differs enough from the registered verifiers and the minimum interval
+    /// since the creation of the current verifier set has passed. Fails if permissionless updates are disabled.
+    #[permission(Any)]
+    TriggerVerifierSetUpdate,
 
     #[permission(Any)]
     ConfirmVerifierSet,
@@ -77,6 +82,25 @@ pub enum ExecuteMsg {
     },
     #[permission(Governance)]
     UpdateAdmin { new_admin_address: String },
+    /// Limits the size of the proofs constructed by ConstructProof
+    #[permission(Governance)]
+    UpdateProofLimits { proof_limits: ProofLimits },
+    /// Sets the number of verifier set rotations after which a proof is considered stale.
+    /// This should match the number of previous verifier sets the destination gateway accepts.
+    #[permission(Governance)]
+    UpdateProofEpochWindow { epoch_window: u64 },
+    /// Sets the minimum number of blocks between the creation of the current verifier set and an update
+    /// with TriggerVerifierSetUpdate. None disables permissionless verifier set updates.
+    #[permission(Governance)]
+    UpdateVerifierSetUpdateInterval { min_interval: Option<u64> },
+    /// Starts a signing session of the current verifier set for an administrative payload of the destination chain,
+    /// e.g. an operator change. The signed digest is domain separated from message batches and verifier set rotations.
+    #[permission(Governance)]
+    SignArbitraryPayload { payload: HexBinary },
+    /// Indexes up to `limit` proofs created before the migration from v1.0.0 by message, latest proofs first.
+    /// Proofs are only found by ProofByMessage and ProofsByMessage once they are indexed.
+    #[permission(Elevated)]
+    BackfillMessageIndex { limit: u32 },
 }
 
 #[cw_serde]
@@ -92,12 +116,73 @@ pub enum QueryMsg {
     /// Returns a `VerifierSetResponse` with the next verifier set id and the verifier set itself.
     #[returns(Option<VerifierSetResponse>)]
     NextVerifierSet,
+
+    /// Returns whether the verifiers registered in the service registry differ enough from the current verifier set
+    /// for an update, and how they differ.
+    #[returns(VerifierSetUpdateStatusResponse)]
+    VerifierSetUpdateStatus,
+
+    /// Returns the latest completed proof that includes the given message, if there is one.
+    /// Only the 10 latest proofs of the message are checked, use ProofsByMessage to page through older ones.
+    #[returns(Option<ProofResponse>)]
+    ProofByMessage { message_id: CrossChainId },
+
+    /// Returns all proofs that include the given message, ordered by multisig session id.
+    /// - start_after: the multisig session id after which the page starts
+    /// - limit: the maximum number of proofs returned, 30 by default and at most 100
+    #[returns(Vec<ProofResponse>)]
+    ProofsByMessage {
+        message_id: CrossChainId,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the latest proofs of message payloads that were signed by a verifier set
+    /// that is older than the proof epoch window, ordered by multisig session id.
+    /// Calling `ConstructProof` with the message ids of a stale proof signs the payload again with the current verifier set.
+    /// Proofs created before the migration from v1.0.0 are not tracked, so they are never returned.
+    /// - min_epoch: only proofs signed in this verifier set epoch or later are returned,
+    ///   so proofs of messages that have long been executed can be skipped
+    /// - start_after: the multisig session id after which the page starts
+    /// - limit: the maximum number of proofs returned, 30 by default and at most 100
+    #[returns(Vec<StaleProofResponse>)]
+    StaleProofs {
+        min_epoch: u64,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+
+    #[returns(ProofLimits)]
+    ProofLimits,
+
+    /// Returns the signatures of a signing session started with `SignArbitraryPayload`
+    #[returns(ArbitraryPayloadProofResponse)]
+    ArbitraryPayloadProof { multisig_session_id: Uint64 },
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ProofLimits {
+    /// Maximum number of messages in a single proof
+    pub max_messages: Option<nonempty::Uint64>,
+    /// Maximum size in bytes of the execute data of a single proof, as encoded by the prover's encoder.
+    /// The size includes signatures of all signers of the current verifier set.
+    pub max_execute_data_size: Option<nonempty::Uint64>,
+    /// If set, messages that exceed the limits are split into several proofs with a signing session each.
+    /// Otherwise, constructing the proof fails.
+    pub split_oversized: bool,
 }
 
 #[cw_serde]
 pub enum ProofStatus {
     Pending,
-    Completed { execute_data: HexBinary }, // encoded data and proof sent to destination gateway
+    /// Encoded data and proof sent to destination gateway
+    Completed {
+        execute_data: HexBinary,
+    },
+    /// The signing session expired before enough signatures were submitted.
+    /// The same payload can be proven again with a new signing session.
+    Expired,
 }
 
 #[cw_serde]
@@ -106,6 +191,54 @@ pub struct ProofResponse {
     pub message_ids: Vec<CrossChainId>,
     pub payload: Payload,
     pub status: ProofStatus,
+    /// True if the proof was signed by a verifier set that is older than the proof epoch window,
+    /// so destination gateways no longer accept it. `ConstructProof` signs the payload again with the current verifier set.
+    pub stale: bool,
+}
+
+#[cw_serde]
+pub struct StaleProofResponse {
+    pub multisig_session_id: Uint64,
+    pub message_ids: Vec<CrossChainId>,
+    pub verifier_set_id: String,
+    /// Verifier set epoch in which the proof was signed
+    pub epoch: u64,
+}
+
+#[cw_serde]
+pub enum ArbitraryPayloadProofStatus {
+    Pending,
+    Completed {
+        verifier_set: multisig::verifier_set::VerifierSet,
+        signatures: Vec<SignerWithSig>,
+    },
+    Expired,
+}
+
+#[cw_serde]
+pub struct ArbitraryPayloadProofResponse {
+    pub multisig_session_id: Uint64,
+    pub payload: HexBinary,
+    /// The digest signed by the verifiers
+    pub digest: HexBinary,
+    pub status: ArbitraryPayloadProofStatus,
+}
+
+#[cw_serde]
+pub struct VerifierSetUpdateStatusResponse {
+    /// True if the registered verifiers differ from the current verifier set by more than the diff threshold,
+    /// or the signing threshold changed
+    pub update_due: bool,
+    /// True if an updated verifier set is waiting for confirmation
+    pub update_in_progress: bool,
+    pub threshold_changed: bool,
+    pub signers_added: Vec<Addr>,
+    pub signers_removed: Vec<Addr>,
+    pub weights_changed: Vec<Addr>,
+    pub pub_keys_changed: Vec<Addr>,
+    /// Block height from which TriggerVerifierSetUpdate can update the verifier set,
+    /// or None if permissionless updates are disabled
+    pub permissionless_update_at: Option<u64>,
 }
 
 #[cw_serde]
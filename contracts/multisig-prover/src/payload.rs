@@ -1,3 +1,4 @@
+use axelar_wasm_std::hash::Hash;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{from_json, HexBinary, StdResult};
 use cw_storage_plus::{Key, KeyDeserialize, PrimaryKey};
@@ -47,6 +48,19 @@ impl Payload {
     }
 }
 
+// Prefix of the digests of arbitrary payloads, so their signatures can't be mistaken
+// for signatures of the digests that the encoders compute for payloads.
+const ARBITRARY_PAYLOAD_PREFIX: &[u8] = b"axelar-amplifier-arbitrary-payload";
+
+/// Hash of an arbitrary payload, which the encoders sign in place of the hash of messages or a verifier set
+pub fn arbitrary_payload_hash(payload: &[u8]) -> Hash {
+    Keccak256::new()
+        .chain_update(ARBITRARY_PAYLOAD_PREFIX)
+        .chain_update(Keccak256::digest(payload))
+        .finalize()
+        .into()
+}
+
 #[cw_serde]
 pub struct PayloadId(HexBinary);
 
@@ -77,6 +91,9 @@ impl KeyDeserialize for PayloadId {
 
 #[cfg(test)]
 mod test {
+    use multisig::verifier_set::VerifierSet;
+
+    use crate::encoding::Encoder;
     use crate::payload::Payload;
     use crate::test::test_data;
 
@@ -93,4 +110,32 @@ mod test {
 
         goldie::assert_json!(payload.id());
     }
+
+    #[test]
+    fn arbitrary_payload_digest_is_bound_to_domain_and_verifier_set() {
+        let verifier_set = test_data::curr_verifier_set();
+        let digest = |domain_separator: &[u8; 32], verifier_set: &VerifierSet, payload: &[u8]| {
+            Encoder::Abi
+                .arbitrary_payload_digest(domain_separator, verifier_set, payload)
+                .unwrap()
+        };
+        let expected = digest(&[0; 32], &verifier_set, &[1, 2, 3]);
+
+        assert_ne!(expected, digest(&[1; 32], &verifier_set, &[1, 2, 3]));
+        assert_ne!(
+            expected,
+            digest(&[0; 32], &test_data::new_verifier_set(), &[1, 2, 3])
+        );
+        assert_ne!(expected, digest(&[0; 32], &verifier_set, &[1, 2]));
+        assert_ne!(
+            expected,
+            Encoder::Abi
+                .digest(
+                    &[0; 32],
+                    &verifier_set,
+                    &Payload::Messages(test_data::messages())
+                )
+                .unwrap()
+        );
+    }
 }
@@ -1,13 +1,14 @@
 use axelar_wasm_std::hash::Hash;
 use axelar_wasm_std::MajorityThreshold;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, HexBinary, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Deque, Item, Map};
 use multisig::key::KeyType;
 use multisig::verifier_set::VerifierSet;
-use router_api::ChainName;
+use router_api::{ChainName, ChainNameRaw, CrossChainId};
 
 use crate::encoding::Encoder;
+use crate::msg::ProofLimits;
 use crate::payload::{Payload, PayloadId};
 
 #[cw_serde]
@@ -27,10 +28,212 @@ pub struct Config {
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+pub const PROOF_LIMITS: Item<ProofLimits> = Item::new("proof_limits");
 pub const PAYLOAD: Map<&PayloadId, Payload> = Map::new("payload");
 pub const MULTISIG_SESSION_PAYLOAD: Map<u64, PayloadId> = Map::new("multisig_session_payload");
 
-pub const REPLY_TRACKER: Item<PayloadId> = Item::new("reply_tracker");
+// (source chain, message id, multisig session id) of every signing session of a proof that includes the message
+const MESSAGE_MULTISIG_SESSIONS: Map<(ChainNameRaw, String, u64), PayloadId> =
+    Map::new("message_multisig_sessions");
+
+pub fn save_message_multisig_session(
+    storage: &mut dyn Storage,
+    cc_id: &CrossChainId,
+    multisig_session_id: u64,
+    payload_id: &PayloadId,
+) -> StdResult<()> {
+    MESSAGE_MULTISIG_SESSIONS.save(
+        storage,
+        (
+            cc_id.source_chain.clone(),
+            cc_id.message_id.to_string(),
+            multisig_session_id,
+        ),
+        payload_id,
+    )
+}
+
+/// Returns the ids of the multisig sessions of all proofs that include the given message, starting after the given session id.
+pub fn message_multisig_sessions<'a>(
+    storage: &'a dyn Storage,
+    cc_id: &CrossChainId,
+    start_after: Option<u64>,
+    order: Order,
+) -> Box<dyn Iterator<Item = StdResult<u64>> + 'a> {
+    let bound = start_after.map(Bound::exclusive);
+    let (min, max) = match order {
+        Order::Ascending => (bound, None),
+        Order::Descending => (None, bound),
+    };
+
+    MESSAGE_MULTISIG_SESSIONS
+        .prefix((cc_id.source_chain.clone(), cc_id.message_id.to_string()))
+        .keys(storage, min, max, order)
+}
+
+// multisig sessions created before the message index existed with an id lower than the cursor are not indexed yet
+const MESSAGE_INDEX_BACKFILL_CURSOR: Item<u64> = Item::new("message_index_backfill_cursor");
+
+pub fn start_message_index_backfill(storage: &mut dyn Storage) -> StdResult<()> {
+    MESSAGE_INDEX_BACKFILL_CURSOR.save(storage, &u64::MAX)
+}
+
+/// Indexes up to `limit` of the not yet indexed multisig sessions by message, starting with the latest session.
+/// Returns the number of indexed sessions. Once all sessions are indexed, the backfill stops.
+pub fn backfill_message_multisig_sessions(
+    storage: &mut dyn Storage,
+    limit: usize,
+) -> StdResult<usize> {
+    let Some(cursor) = MESSAGE_INDEX_BACKFILL_CURSOR.may_load(storage)? else {
+        return Ok(0);
+    };
+
+    let sessions = MULTISIG_SESSION_PAYLOAD
+        .range(
+            storage,
+            None,
+            Some(Bound::exclusive(cursor)),
+            Order::Descending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (multisig_session_id, payload_id) in &sessions {
+        let message_ids = PAYLOAD
+            .load(storage, payload_id)?
+            .message_ids()
+            .unwrap_or_default();
+
+        for cc_id in message_ids {
+            save_message_multisig_session(storage, &cc_id, *multisig_session_id, payload_id)?;
+        }
+    }
+
+    match sessions.last() {
+        Some((multisig_session_id, _)) if sessions.len() == limit => {
+            MESSAGE_INDEX_BACKFILL_CURSOR.save(storage, multisig_session_id)?
+        }
+        _ => MESSAGE_INDEX_BACKFILL_CURSOR.remove(storage),
+    }
+
+    Ok(sessions.len())
+}
+
+// a proof can be split into several signing sessions, whose replies are handled in the order the sessions are started
+pub const REPLY_TRACKER: Deque<PayloadId> = Deque::new("reply_tracker_queue");
+
+pub const ARBITRARY_PAYLOAD_REPLY_TRACKER: Item<HexBinary> =
+    Item::new("arbitrary_payload_reply_tracker");
+pub const MULTISIG_SESSION_ARBITRARY_PAYLOAD: Map<u64, HexBinary> =
+    Map::new("multisig_session_arbitrary_payload");
 
 pub const CURRENT_VERIFIER_SET: Item<VerifierSet> = Item::new("current_verifier_set");
 pub const NEXT_VERIFIER_SET: Item<VerifierSet> = Item::new("next_verifier_set");
+
+// incremented every time the current verifier set changes
+pub const VERIFIER_SET_EPOCH: Item<u64> = Item::new("verifier_set_epoch");
+// number of verifier set epochs a proof stays usable after it was signed
+pub const PROOF_EPOCH_WINDOW: Item<u64> = Item::new("proof_epoch_window");
+// the amplifier gateways accept proofs of the 15 previous verifier sets by default
+pub const DEFAULT_PROOF_EPOCH_WINDOW: u64 = 15;
+
+pub fn verifier_set_epoch(storage: &dyn Storage) -> StdResult<u64> {
+    Ok(VERIFIER_SET_EPOCH.may_load(storage)?.unwrap_or_default())
+}
+
+pub fn proof_epoch_window(storage: &dyn Storage) -> StdResult<u64> {
+    Ok(PROOF_EPOCH_WINDOW
+        .may_load(storage)?
+        .unwrap_or(DEFAULT_PROOF_EPOCH_WINDOW))
+}
+
+// minimum number of blocks between the creation of the current verifier set and a permissionless update.
+// Permissionless updates are disabled if it is not set.
+pub const VERIFIER_SET_UPDATE_INTERVAL: Item<u64> = Item::new("verifier_set_update_interval");
+
+pub fn save_current_verifier_set(
+    storage: &mut dyn Storage,
+    verifier_set: &VerifierSet,
+) -> StdResult<()> {
+    CURRENT_VERIFIER_SET.save(storage, verifier_set)?;
+
+    VERIFIER_SET_EPOCH.save(storage, &verifier_set_epoch(storage)?.saturating_add(1))
+}
+
+#[cw_serde]
+pub struct PayloadProof {
+    pub payload_id: PayloadId,
+    pub verifier_set_id: String,
+    pub epoch: u64,
+}
+
+impl PayloadProof {
+    pub fn is_stale(&self, current_epoch: u64, epoch_window: u64) -> bool {
+        is_stale(self.epoch, current_epoch, epoch_window)
+    }
+}
+
+pub fn is_stale(epoch: u64, current_epoch: u64, epoch_window: u64) -> bool {
+    current_epoch.saturating_sub(epoch) > epoch_window
+}
+
+// verifier set epoch in which the proof of a message payload was signed, by multisig session id
+pub const MULTISIG_SESSION_EPOCH: Map<u64, u64> = Map::new("multisig_session_epoch");
+// first multisig session of a message payload that was signed in each epoch
+const EPOCH_FIRST_MULTISIG_SESSION: Map<u64, u64> = Map::new("epoch_first_multisig_session");
+
+/// Returns the first multisig session of a message payload that was signed in the given epoch or later.
+pub fn first_multisig_session_since(storage: &dyn Storage, epoch: u64) -> StdResult<Option<u64>> {
+    EPOCH_FIRST_MULTISIG_SESSION
+        .range(
+            storage,
+            Some(Bound::inclusive(epoch)),
+            None,
+            Order::Ascending,
+        )
+        .next()
+        .transpose()
+        .map(|entry| entry.map(|(_, multisig_session_id)| multisig_session_id))
+}
+
+// multisig session of the latest proof of every message payload
+const PAYLOAD_LATEST_MULTISIG_SESSION: Map<&PayloadId, u64> =
+    Map::new("payload_latest_multisig_session");
+// latest proofs of message payloads by multisig session id. Session ids and epochs both increase over time,
+// so the proofs are also ordered by the epoch in which they were signed.
+pub const LATEST_PROOFS: Map<u64, PayloadProof> = Map::new("latest_proofs");
+
+pub fn latest_proof(
+    storage: &dyn Storage,
+    payload_id: &PayloadId,
+) -> StdResult<Option<(u64, PayloadProof)>> {
+    PAYLOAD_LATEST_MULTISIG_SESSION
+        .may_load(storage, payload_id)?
+        .map(|multisig_session_id| {
+            LATEST_PROOFS
+                .load(storage, multisig_session_id)
+                .map(|proof| (multisig_session_id, proof))
+        })
+        .transpose()
+}
+
+/// Replaces the latest proof of the payload, so that only the most recent signing session of each payload is tracked.
+pub fn save_latest_proof(
+    storage: &mut dyn Storage,
+    multisig_session_id: u64,
+    proof: &PayloadProof,
+) -> StdResult<()> {
+    if let Some(previous_session_id) =
+        PAYLOAD_LATEST_MULTISIG_SESSION.may_load(storage, &proof.payload_id)?
+    {
+        LATEST_PROOFS.remove(storage, previous_session_id);
+    }
+
+    if !EPOCH_FIRST_MULTISIG_SESSION.has(storage, proof.epoch) {
+        EPOCH_FIRST_MULTISIG_SESSION.save(storage, proof.epoch, &multisig_session_id)?;
+    }
+
+    MULTISIG_SESSION_EPOCH.save(storage, multisig_session_id, &proof.epoch)?;
+    PAYLOAD_LATEST_MULTISIG_SESSION.save(storage, &proof.payload_id, &multisig_session_id)?;
+    LATEST_PROOFS.save(storage, multisig_session_id, proof)
+}
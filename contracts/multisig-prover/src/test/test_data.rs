@@ -4,10 +4,13 @@ use axelar_wasm_std::{nonempty, MajorityThreshold, Participant, Threshold};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, HexBinary, Uint128, Uint64};
 use multisig::key::{KeyType, Signature};
-use multisig::msg::Signer;
+use multisig::msg::{Signer, SignerWithSig};
+use multisig::test::common::bls_test_data;
 use multisig::verifier_set::VerifierSet;
 use router_api::{CrossChainId, Message};
 
+use crate::payload::Payload;
+
 pub fn new_verifier_set() -> VerifierSet {
     let signers = vec![
         Signer {
@@ -208,6 +211,24 @@ pub fn verifier_set_from_pub_keys(pub_keys: Vec<&str>) -> VerifierSet {
     VerifierSet::new(participants, Uint128::from(3u128), 1)
 }
 
+/// Verifier set with the BLS keys of the secret keys 1 to 3, any two of them reach the threshold
+pub fn bls_verifier_set() -> VerifierSet {
+    let participants: Vec<(_, _)> = (1..=3)
+        .map(|secret_key| {
+            let (pub_key, _) = bls_test_data::sign(secret_key, &[]);
+
+            (
+                Participant {
+                    address: Addr::unchecked(format!("verifier{secret_key}")),
+                    weight: nonempty::Uint128::one(),
+                },
+                multisig::key::PublicKey::Bls(pub_key),
+            )
+        })
+        .collect();
+    VerifierSet::new(participants, Uint128::from(2u128), 1)
+}
+
 // Domain separator matches axelar-gmp-sdk-solidity repo test data
 pub fn domain_separator() -> [u8; 32] {
     HexBinary::from_hex("3593643a7d7e917a099eef6c52d1420bb4f33eb074b16439556de5984791262b")
@@ -215,3 +236,126 @@ pub fn domain_separator() -> [u8; 32] {
         .to_array()
         .unwrap()
 }
+
+pub fn solana_message() -> Message {
+    Message {
+        cc_id: CrossChainId::new("source", "test").unwrap(),
+        source_address: "0x52444f1835Adc02086c37Cb226561605e2E1699b"
+            .parse()
+            .unwrap(),
+        destination_chain: "solana".parse().unwrap(),
+        destination_address: "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+            .parse()
+            .unwrap(),
+        payload_hash: HexBinary::from_hex(
+            "65ad329dc342a82bd1daedc42e183e6e2c272b8e2e3fd7c8f81d089736d0bc3c",
+        )
+        .unwrap()
+        .to_array()
+        .unwrap(),
+    }
+}
+
+pub fn solana_messages_payload() -> Payload {
+    Payload::Messages(vec![solana_message()])
+}
+
+pub fn starknet_message() -> Message {
+    Message {
+        cc_id: CrossChainId::new("source", "test").unwrap(),
+        source_address: "0x52444f1835Adc02086c37Cb226561605e2E1699b"
+            .parse()
+            .unwrap(),
+        destination_chain: "starknet".parse().unwrap(),
+        destination_address: "0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7"
+            .parse()
+            .unwrap(),
+        payload_hash: HexBinary::from_hex(
+            "65ad329dc342a82bd1daedc42e183e6e2c272b8e2e3fd7c8f81d089736d0bc3c",
+        )
+        .unwrap()
+        .to_array()
+        .unwrap(),
+    }
+}
+
+pub fn starknet_messages_payload() -> Payload {
+    Payload::Messages(vec![starknet_message()])
+}
+
+/// (address, key type, public key, weight) of five ECDSA signers with weight 1
+pub fn ecdsa_signers_data() -> Vec<(&'static str, KeyType, &'static str, u128)> {
+    vec![
+        (
+            "addr_1",
+            KeyType::Ecdsa,
+            "0312474390012cfbb621c91295dae42b11daaceffbcb7136045c86537a7b37042c",
+            1u128,
+        ),
+        (
+            "addr_2",
+            KeyType::Ecdsa,
+            "0315a4c9807fb3e3eb360c6b2cd09ba9edb28b566aaf986b4e107180d89895d42c",
+            1u128,
+        ),
+        (
+            "addr_3",
+            KeyType::Ecdsa,
+            "022ffb2327809de022e5aaa651508d397c10d7a2ce60c9115884a295cbab293530",
+            1u128,
+        ),
+        (
+            "addr_4",
+            KeyType::Ecdsa,
+            "028e02adae730573377cd167095c8b4c63dcc4a2095171ffc9538c7bbbaed31fb2",
+            1u128,
+        ),
+        (
+            "addr_5",
+            KeyType::Ecdsa,
+            "02d1e0cff63aa3e7988e4070242fa37871a9abc79ecf851cce9877297d1316a090",
+            1u128,
+        ),
+    ]
+}
+
+pub fn verifier_set_from_signers_data(
+    signers_data: Vec<(&str, KeyType, &str, u128)>,
+    threshold: u128,
+    created_at: u64,
+) -> VerifierSet {
+    VerifierSet {
+        signers: signers_data
+            .into_iter()
+            .map(|(addr, key_type, pub_key, weight)| {
+                (
+                    addr.to_string(),
+                    Signer {
+                        address: Addr::unchecked(addr),
+                        pub_key: (key_type, HexBinary::from_hex(pub_key).unwrap())
+                            .try_into()
+                            .unwrap(),
+                        weight: Uint128::from(weight),
+                    },
+                )
+            })
+            .collect(),
+        threshold: threshold.into(),
+        created_at,
+    }
+}
+
+/// Signers of the verifier set with the given (address, key type, signature) signatures
+pub fn signers_with_sig(
+    verifier_set: &VerifierSet,
+    signatures: Vec<(&str, KeyType, &str)>,
+) -> Vec<SignerWithSig> {
+    signatures
+        .into_iter()
+        .map(|(addr, key_type, sig)| {
+            verifier_set.signers[addr].with_sig(
+                Signature::try_from((key_type, HexBinary::from_hex(sig).unwrap())).unwrap(),
+            )
+        })
+        .collect()
+}
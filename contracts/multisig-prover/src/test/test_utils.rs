@@ -1,9 +1,11 @@
 use axelar_wasm_std::VerificationStatus;
 use cosmwasm_std::{from_json, to_json_binary, Addr, QuerierResult, Uint128, WasmQuery};
+use multisig::key::PublicKey;
 use multisig::msg::Signer;
 use multisig::multisig::Multisig;
 use multisig::types::MultisigState;
 use multisig::verifier_set::VerifierSet;
+use router_api::Message;
 use service_registry::VERIFIER_WEIGHT;
 use service_registry_api::{AuthorizationState, BondingState, Verifier, WeightedVerifier};
 
@@ -23,8 +25,8 @@ pub fn mock_querier_handler(
     verifier_set_status: VerificationStatus,
 ) -> impl Fn(&WasmQuery) -> QuerierResult {
     move |wq: &WasmQuery| match wq {
-        WasmQuery::Smart { contract_addr, .. } if contract_addr == GATEWAY_ADDRESS => {
-            gateway_mock_querier_handler()
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == GATEWAY_ADDRESS => {
+            gateway_mock_querier_handler(from_json(msg).unwrap())
         }
         WasmQuery::Smart { contract_addr, msg } if contract_addr == MULTISIG_ADDRESS => {
             multisig_mock_querier_handler(from_json(msg).unwrap(), operators.clone())
@@ -39,8 +41,24 @@ pub fn mock_querier_handler(
     }
 }
 
-fn gateway_mock_querier_handler() -> QuerierResult {
-    Ok(to_json_binary(&test_data::messages()).into()).into()
+fn gateway_mock_querier_handler(msg: gateway_api::msg::QueryMsg) -> QuerierResult {
+    let gateway_api::msg::QueryMsg::OutgoingMessages(message_ids) = msg;
+
+    // messages that are not part of the test data are copies of the first test message with the requested id
+    let messages: Vec<_> = message_ids
+        .into_iter()
+        .map(|cc_id| {
+            test_data::messages()
+                .into_iter()
+                .find(|msg| msg.cc_id == cc_id)
+                .unwrap_or_else(|| Message {
+                    cc_id,
+                    ..test_data::messages()[0].clone()
+                })
+        })
+        .collect();
+
+    Ok(to_json_binary(&messages).into()).into()
 }
 
 fn multisig_mock_querier_handler(
@@ -51,6 +69,7 @@ fn multisig_mock_querier_handler(
         multisig::msg::QueryMsg::Multisig { session_id: _ } => {
             to_json_binary(&mock_multisig(operators))
         }
+        multisig::msg::QueryMsg::PendingPublicKey { .. } => to_json_binary(&None::<PublicKey>),
         multisig::msg::QueryMsg::PublicKey {
             verifier_address,
             key_type: _,
@@ -108,6 +127,7 @@ fn mock_multisig(operators: Vec<TestOperator>) -> Multisig {
         },
         verifier_set,
         signatures,
+        aggregated_signature: None,
     }
 }
 
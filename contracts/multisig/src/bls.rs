@@ -0,0 +1,305 @@
+use ark_bls12_381::{g2, Bls12_381, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::hashing::curve_maps::wb::WBMap;
+use ark_ec::hashing::map_to_curve_hasher::MapToCurveBasedHasher;
+use ark_ec::hashing::HashToCurve;
+use ark_ec::pairing::Pairing;
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_ff::field_hashers::DefaultFieldHasher;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use cosmwasm_std::HexBinary;
+use sha2::Sha256;
+
+use crate::ContractError;
+
+pub const BLS_PUBKEY_LEN: usize = 48;
+pub const BLS_SIGNATURE_LEN: usize = 96;
+
+// Public keys are in G1 and signatures in G2 (minimal-pubkey-size variant of the IETF BLS signature draft).
+// Aggregating public keys that sign the same message is only safe against rogue key attacks
+// if every key proved possession of its secret key, which the signed sender address at registration does.
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+type G2Hasher =
+    MapToCurveBasedHasher<G2Projective, DefaultFieldHasher<Sha256, 128>, WBMap<g2::Config>>;
+
+pub fn bls_verify(msg_hash: &[u8], sig: &[u8], pub_key: &[u8]) -> Result<bool, ContractError> {
+    let pub_key =
+        deserialize_pub_key(pub_key).map_err(|err| ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        })?;
+    let sig =
+        deserialize_signature(sig).map_err(|err| ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        })?;
+
+    verify(msg_hash, sig, pub_key)
+}
+
+/// Verifies an aggregated signature of the given public keys over the same message
+pub fn bls_verify_aggregate<'a>(
+    msg_hash: &[u8],
+    aggregated_sig: &[u8],
+    pub_keys: impl IntoIterator<Item = &'a [u8]>,
+) -> Result<bool, ContractError> {
+    let pub_keys = pub_keys
+        .into_iter()
+        .map(deserialize_pub_key)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        })?;
+
+    // the sum of no public keys is the identity, which would verify the identity signature
+    if pub_keys.is_empty() {
+        return Err(ContractError::SignatureVerificationFailed {
+            reason: "no signers".into(),
+        });
+    }
+
+    let aggregated_pub_key = pub_keys
+        .into_iter()
+        .map(G1Projective::from)
+        .sum::<G1Projective>()
+        .into_affine();
+    let aggregated_sig = deserialize_signature(aggregated_sig).map_err(|err| {
+        ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        }
+    })?;
+
+    verify(msg_hash, aggregated_sig, aggregated_pub_key)
+}
+
+/// Aggregates signatures over the same message into a single signature
+pub fn aggregate_signatures<'a>(
+    sigs: impl IntoIterator<Item = &'a [u8]>,
+) -> Result<HexBinary, ContractError> {
+    let aggregated = sigs
+        .into_iter()
+        .map(deserialize_signature)
+        .sum::<Result<G2Projective, _>>()?
+        .into_affine();
+
+    Ok(serialize(aggregated).into())
+}
+
+pub fn validate_pub_key(pub_key: &[u8]) -> Result<(), ContractError> {
+    deserialize_pub_key(pub_key).map(|_| ())
+}
+
+fn verify(msg_hash: &[u8], sig: G2Affine, pub_key: G1Affine) -> Result<bool, ContractError> {
+    let msg_point = hash_to_g2(msg_hash)?;
+
+    Ok(Bls12_381::pairing(G1Affine::generator(), sig) == Bls12_381::pairing(pub_key, msg_point))
+}
+
+fn hash_to_g2(msg: &[u8]) -> Result<G2Affine, ContractError> {
+    G2Hasher::new(DST)
+        .and_then(|hasher| hasher.hash(msg))
+        .map_err(|err| ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        })
+}
+
+// the identity is rejected because it would verify any signature that is the identity as well
+fn deserialize_pub_key(pub_key: &[u8]) -> Result<G1Affine, ContractError> {
+    if pub_key.len() != BLS_PUBKEY_LEN {
+        return Err(ContractError::InvalidPublicKey);
+    }
+
+    G1Affine::deserialize_compressed(pub_key)
+        .ok()
+        .filter(|pub_key| !pub_key.is_zero())
+        .ok_or(ContractError::InvalidPublicKey)
+}
+
+fn deserialize_signature(sig: &[u8]) -> Result<G2Affine, ContractError> {
+    if sig.len() != BLS_SIGNATURE_LEN {
+        return Err(ContractError::InvalidSignatureFormat {
+            reason: format!(
+                "invalid input length {}, expected {}",
+                sig.len(),
+                BLS_SIGNATURE_LEN
+            ),
+        });
+    }
+
+    let sig = G2Affine::deserialize_compressed(sig).map_err(|err| {
+        ContractError::InvalidSignatureFormat {
+            reason: err.to_string(),
+        }
+    })?;
+
+    // same as for public keys, the identity must not be accepted as a signature
+    if sig.is_zero() {
+        return Err(ContractError::InvalidSignatureFormat {
+            reason: "signature is the identity".into(),
+        });
+    }
+
+    Ok(sig)
+}
+
+fn serialize(point: impl CanonicalSerialize) -> Vec<u8> {
+    let mut bytes = vec![];
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("serializing into a vector must not fail");
+
+    bytes
+}
+
+#[cfg(any(test, feature = "test"))]
+pub mod test_utils {
+    use ark_bls12_381::{Fr, G1Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use cosmwasm_std::HexBinary;
+
+    use super::{hash_to_g2, serialize};
+
+    /// Returns the public key and signature of the given secret key. Only meant to generate test data.
+    pub fn sign(secret_key: u64, msg: &[u8]) -> (HexBinary, HexBinary) {
+        let secret_key = Fr::from(secret_key);
+
+        let pub_key = (G1Affine::generator() * secret_key).into_affine();
+        let sig = (hash_to_g2(msg).unwrap() * secret_key).into_affine();
+
+        (serialize(pub_key).into(), serialize(sig).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::HexBinary;
+
+    use super::test_utils::sign;
+    use super::*;
+
+    const MESSAGE: [u8; 32] = [42; 32];
+
+    #[test]
+    fn should_verify_signature() {
+        let (pub_key, sig) = sign(1234, &MESSAGE);
+
+        assert_eq!(pub_key.len(), BLS_PUBKEY_LEN);
+        assert_eq!(sig.len(), BLS_SIGNATURE_LEN);
+        assert!(bls_verify(&MESSAGE, &sig, &pub_key).unwrap());
+    }
+
+    #[test]
+    fn should_not_verify_signature_of_different_message_or_key() {
+        let (pub_key, sig) = sign(1234, &MESSAGE);
+        let (other_pub_key, _) = sign(5678, &MESSAGE);
+
+        assert!(!bls_verify(&[0; 32], &sig, &pub_key).unwrap());
+        assert!(!bls_verify(&MESSAGE, &sig, &other_pub_key).unwrap());
+    }
+
+    #[test]
+    fn should_verify_aggregated_signature() {
+        let (pub_keys, sigs): (Vec<_>, Vec<_>) = [1u64, 2, 3]
+            .into_iter()
+            .map(|secret_key| sign(secret_key, &MESSAGE))
+            .unzip();
+
+        let aggregated_sig = aggregate_signatures(sigs.iter().map(HexBinary::as_slice)).unwrap();
+
+        assert_eq!(aggregated_sig.len(), BLS_SIGNATURE_LEN);
+        assert!(bls_verify_aggregate(
+            &MESSAGE,
+            &aggregated_sig,
+            pub_keys.iter().map(HexBinary::as_slice)
+        )
+        .unwrap());
+
+        // a signer that is claimed to have signed but did not must make the verification fail
+        let (missing_pub_key, _) = sign(4, &MESSAGE);
+        assert!(!bls_verify_aggregate(
+            &MESSAGE,
+            &aggregated_sig,
+            pub_keys
+                .iter()
+                .chain([&missing_pub_key])
+                .map(HexBinary::as_slice)
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn should_not_aggregate_invalid_signatures() {
+        let (_, sig) = sign(1, &MESSAGE);
+
+        assert!(matches!(
+            aggregate_signatures([sig.as_slice(), &[0; BLS_SIGNATURE_LEN]]).unwrap_err(),
+            ContractError::InvalidSignatureFormat { .. }
+        ));
+        assert!(matches!(
+            aggregate_signatures([sig.as_slice(), &sig[1..]]).unwrap_err(),
+            ContractError::InvalidSignatureFormat { .. }
+        ));
+    }
+
+    #[test]
+    fn should_reject_invalid_public_keys() {
+        // compressed encoding of the point at infinity
+        let mut identity = [0u8; BLS_PUBKEY_LEN];
+        identity[0] = 0xc0;
+        assert_eq!(
+            validate_pub_key(&identity).unwrap_err(),
+            ContractError::InvalidPublicKey
+        );
+
+        // the x coordinate is larger than the field modulus
+        let mut not_on_curve = [0xff; BLS_PUBKEY_LEN];
+        not_on_curve[0] = 0x9f;
+        assert_eq!(
+            validate_pub_key(&not_on_curve).unwrap_err(),
+            ContractError::InvalidPublicKey
+        );
+
+        let (pub_key, _) = sign(1, &MESSAGE);
+        assert_eq!(
+            validate_pub_key(&pub_key[1..]).unwrap_err(),
+            ContractError::InvalidPublicKey
+        );
+    }
+
+    #[test]
+    fn should_reject_identity_signature() {
+        let (pub_key, _) = sign(1, &MESSAGE);
+
+        // compressed encoding of the point at infinity
+        let mut identity = [0u8; BLS_SIGNATURE_LEN];
+        identity[0] = 0xc0;
+
+        assert!(matches!(
+            bls_verify(&MESSAGE, &identity, &pub_key).unwrap_err(),
+            ContractError::SignatureVerificationFailed { .. }
+        ));
+        assert!(matches!(
+            aggregate_signatures([identity.as_slice()]).unwrap_err(),
+            ContractError::InvalidSignatureFormat { .. }
+        ));
+    }
+
+    #[test]
+    fn should_not_verify_aggregated_signature_without_signers() {
+        let (_, sig) = sign(1, &MESSAGE);
+
+        assert!(matches!(
+            bls_verify_aggregate(&MESSAGE, &sig, []).unwrap_err(),
+            ContractError::SignatureVerificationFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn should_fail_sig_verification_instead_of_truncating() {
+        let (pub_key, sig) = sign(1, &MESSAGE);
+        let sig_with_extra_byte = [sig.as_slice(), &[0]].concat();
+
+        assert!(matches!(
+            bls_verify(&MESSAGE, &sig_with_extra_byte, &pub_key).unwrap_err(),
+            ContractError::SignatureVerificationFailed { .. }
+        ));
+    }
+}
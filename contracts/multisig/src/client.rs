@@ -6,8 +6,11 @@ use error_stack::{Result, ResultExt};
 use router_api::ChainName;
 
 use crate::key::{KeyType, PublicKey};
-use crate::msg::{ExecuteMsg, QueryMsg};
+use crate::msg::{
+    ExecuteMsg, PublicKeyHistory, QueryMsg, SigningSessionFilter, SigningSessionsResponse,
+};
 use crate::multisig::Multisig;
+use crate::state::SignerStats;
 use crate::verifier_set::VerifierSet;
 
 #[derive(thiserror::Error)]
@@ -25,6 +28,35 @@ pub enum Error {
         key_type: KeyType,
     },
 
+    #[error("failed to query multisig contract for pending verifier public key. verifier_address: {verifier_address}, key_type: {key_type}")]
+    PendingPublicKey {
+        verifier_address: String,
+        key_type: KeyType,
+    },
+
+    #[error("failed to query multisig contract for verifier public key history. verifier_address: {verifier_address}, key_type: {key_type}")]
+    PublicKeyHistory {
+        verifier_address: String,
+        key_type: KeyType,
+    },
+
+    #[error("failed to query multisig contract for signing sessions")]
+    SigningSessions,
+
+    #[error(
+        "failed to query multisig contract for unsigned signing sessions. verifier_address: {0}"
+    )]
+    UnsignedSigningSessions(String),
+
+    #[error("failed to query multisig contract for signer stats. verifier_address: {verifier_address}, chain_name: {chain_name}")]
+    SignerStats {
+        verifier_address: String,
+        chain_name: ChainName,
+    },
+
+    #[error("failed to query multisig contract for signer stats of chain {0}")]
+    SignerStatsByChain(ChainName),
+
     #[error("failed to query multisig contract for caller authorization. contract_address: {contract_address}, chain_name: {chain_name}")]
     IsCallerAuthorized {
         contract_address: String,
@@ -50,6 +82,24 @@ impl From<QueryMsg> for Error {
                 verifier_address,
                 key_type,
             },
+            QueryMsg::PendingPublicKey {
+                verifier_address,
+                key_type,
+            } => Error::PendingPublicKey {
+                verifier_address,
+                key_type,
+            },
+            QueryMsg::PublicKeyHistory {
+                verifier_address,
+                key_type,
+            } => Error::PublicKeyHistory {
+                verifier_address,
+                key_type,
+            },
+            QueryMsg::SigningSessions { .. } => Error::SigningSessions,
+            QueryMsg::UnsignedSigningSessions {
+                verifier_address, ..
+            } => Error::UnsignedSigningSessions(verifier_address),
             QueryMsg::IsCallerAuthorized {
                 contract_address,
                 chain_name,
@@ -57,6 +107,16 @@ impl From<QueryMsg> for Error {
                 contract_address,
                 chain_name,
             },
+            QueryMsg::SignerStats {
+                verifier_address,
+                chain_name,
+            } => Error::SignerStats {
+                verifier_address,
+                chain_name,
+            },
+            QueryMsg::SignerStatsByChain { chain_name, .. } => {
+                Error::SignerStatsByChain(chain_name)
+            }
         }
     }
 }
@@ -104,6 +164,17 @@ impl<'a> Client<'a> {
         })
     }
 
+    pub fn rotate_public_key(
+        &self,
+        public_key: PublicKey,
+        signed_sender_address: HexBinary,
+    ) -> CosmosMsg {
+        self.client.execute(&ExecuteMsg::RotatePublicKey {
+            public_key,
+            signed_sender_address,
+        })
+    }
+
     pub fn authorize_callers(&self, contracts: HashMap<String, ChainName>) -> CosmosMsg {
         self.client
             .execute(&ExecuteMsg::AuthorizeCallers { contracts })
@@ -122,6 +193,11 @@ impl<'a> Client<'a> {
         self.client.execute(&ExecuteMsg::EnableSigning)
     }
 
+    pub fn backfill_signing_session_indexes(&self, limit: u32) -> CosmosMsg {
+        self.client
+            .execute(&ExecuteMsg::BackfillSigningSessionIndexes { limit })
+    }
+
     pub fn multisig(&self, session_id: Uint64) -> Result<Multisig, Error> {
         let msg = QueryMsg::Multisig { session_id };
         self.client.query(&msg).change_context_lazy(|| msg.into())
@@ -144,6 +220,70 @@ impl<'a> Client<'a> {
         self.client.query(&msg).change_context_lazy(|| msg.into())
     }
 
+    pub fn pending_public_key(
+        &self,
+        verifier_address: String,
+        key_type: KeyType,
+    ) -> Result<Option<PublicKey>, Error> {
+        let msg = QueryMsg::PendingPublicKey {
+            verifier_address,
+            key_type,
+        };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn public_key_history(
+        &self,
+        verifier_address: String,
+        key_type: KeyType,
+    ) -> Result<PublicKeyHistory, Error> {
+        let msg = QueryMsg::PublicKeyHistory {
+            verifier_address,
+            key_type,
+        };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn signing_sessions(
+        &self,
+        filter: SigningSessionFilter,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    ) -> Result<SigningSessionsResponse, Error> {
+        let msg = QueryMsg::SigningSessions {
+            filter,
+            start_after,
+            limit,
+        };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn unsigned_signing_sessions(
+        &self,
+        verifier_address: String,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    ) -> Result<SigningSessionsResponse, Error> {
+        let msg = QueryMsg::UnsignedSigningSessions {
+            verifier_address,
+            start_after,
+            limit,
+        };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
+    pub fn signer_stats(
+        &self,
+        verifier_address: String,
+        chain_name: ChainName,
+    ) -> Result<SignerStats, Error> {
+        let msg = QueryMsg::SignerStats {
+            verifier_address,
+            chain_name,
+        };
+        self.client.query(&msg).change_context_lazy(|| msg.into())
+    }
+
     pub fn is_caller_authorized(
         &self,
         contract_address: String,
@@ -167,8 +307,13 @@ mod test {
 
     use crate::client::Client;
     use crate::key::{KeyType, PublicKey, Signature};
-    use crate::msg::QueryMsg;
+    use crate::msg::{
+        PublicKeyHistory, QueryMsg, SigningSessionFilter, SigningSessionStatus,
+        SigningSessionsResponse, VerifierSignerStats,
+    };
     use crate::multisig::Multisig;
+    use crate::signing::SigningSession;
+    use crate::state::SignerStats;
     use crate::test::common::{build_verifier_set, ecdsa_test_data};
     use crate::types::MultisigState;
 
@@ -247,6 +392,129 @@ mod test {
         goldie::assert_json!(res.unwrap());
     }
 
+    #[test]
+    fn query_pending_public_key_returns_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = Addr::unchecked("verifier").to_string();
+        let res = client.pending_public_key(verifier_address, KeyType::Ecdsa);
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_pending_public_key_returns_pending_public_key() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = Addr::unchecked("verifier").to_string();
+        let res = client.pending_public_key(verifier_address, KeyType::Ecdsa);
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_public_key_history_returns_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = Addr::unchecked("verifier").to_string();
+        let res = client.public_key_history(verifier_address, KeyType::Ecdsa);
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_public_key_history_returns_public_key_history() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = Addr::unchecked("verifier").to_string();
+        let res = client.public_key_history(verifier_address, KeyType::Ecdsa);
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_signing_sessions_returns_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let filter = SigningSessionFilter {
+            chain_name: Some("ethereum".parse().unwrap()),
+            status: Some(SigningSessionStatus::Pending),
+            ..Default::default()
+        };
+        let res = client.signing_sessions(filter, Some(1u64.into()), None);
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_signing_sessions_returns_sessions() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let res = client.signing_sessions(SigningSessionFilter::default(), None, None);
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_unsigned_signing_sessions_returns_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = Addr::unchecked("verifier").to_string();
+        let res = client.unsigned_signing_sessions(verifier_address, None, Some(10));
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_unsigned_signing_sessions_returns_sessions() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = Addr::unchecked("verifier").to_string();
+        let res = client.unsigned_signing_sessions(verifier_address, None, Some(10));
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
+    #[test]
+    fn query_signer_stats_returns_error_when_query_errors() {
+        let (querier, addr) = setup_queries_to_fail();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = Addr::unchecked("verifier").to_string();
+        let res = client.signer_stats(verifier_address, "ethereum".parse().unwrap());
+        assert!(res.is_err());
+        goldie::assert!(res.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn query_signer_stats_returns_signer_stats() {
+        let (querier, addr) = setup_queries_to_succeed();
+        let client: Client =
+            client::ContractClient::new(QuerierWrapper::new(&querier), &addr).into();
+
+        let verifier_address = Addr::unchecked("verifier").to_string();
+        let res = client.signer_stats(verifier_address, "ethereum".parse().unwrap());
+        assert!(res.is_ok());
+        goldie::assert_json!(res.unwrap());
+    }
+
     #[test]
     fn query_is_caller_authorized_returns_error_when_query_errors() {
         let (querier, addr) = setup_queries_to_fail();
@@ -315,6 +583,7 @@ mod test {
                                 )
                             })
                             .collect(),
+                        aggregated_signature: None,
                     })
                     .into())
                     .into(),
@@ -331,10 +600,69 @@ mod test {
                     )
                     .into())
                     .into(),
+                    QueryMsg::PendingPublicKey {
+                        verifier_address: _,
+                        key_type: _,
+                    } => Ok(to_json_binary(&Some(
+                        PublicKey::try_from((
+                            KeyType::Ecdsa,
+                            ecdsa_test_data::signers()[1].pub_key.clone(),
+                        ))
+                        .unwrap(),
+                    ))
+                    .into())
+                    .into(),
+                    QueryMsg::PublicKeyHistory {
+                        verifier_address: _,
+                        key_type: _,
+                    } => {
+                        let mut pub_keys = ecdsa_test_data::signers().into_iter().map(|signer| {
+                            PublicKey::try_from((KeyType::Ecdsa, signer.pub_key)).unwrap()
+                        });
+
+                        Ok(to_json_binary(&PublicKeyHistory {
+                            active: pub_keys.next().unwrap(),
+                            pending: pub_keys.next(),
+                            retired: pub_keys.take(1).collect(),
+                        })
+                        .into())
+                        .into()
+                    }
+                    QueryMsg::SigningSessions { .. } | QueryMsg::UnsignedSigningSessions { .. } => {
+                        Ok(to_json_binary(&SigningSessionsResponse {
+                            sessions: vec![SigningSession {
+                                id: 1u64.into(),
+                                verifier_set_id: "verifier_set_id".to_string(),
+                                chain_name: "ethereum".parse().unwrap(),
+                                msg: ecdsa_test_data::message().try_into().unwrap(),
+                                state: MultisigState::Pending,
+                                created_at: Some(50),
+                                expires_at: 100,
+                                sig_verifier: None,
+                            }],
+                            last_checked: Some(3u64.into()),
+                        })
+                        .into())
+                        .into()
+                    }
                     QueryMsg::IsCallerAuthorized {
                         contract_address: _,
                         chain_name: _,
                     } => Ok(to_json_binary(&true).into()).into(),
+                    QueryMsg::SignerStats {
+                        verifier_address: _,
+                        chain_name: _,
+                    } => Ok(to_json_binary(&SignerStats {
+                        sessions_eligible: 10,
+                        signed_in_time: 7,
+                        signed_late: 1,
+                        missed: 2,
+                    })
+                    .into())
+                    .into(),
+                    QueryMsg::SignerStatsByChain { .. } => {
+                        Ok(to_json_binary(&Vec::<VerifierSignerStats>::new()).into()).into()
+                    }
                 }
             }
             _ => panic!("unexpected query: {:?}", msg),
@@ -4,15 +4,15 @@ use axelar_wasm_std::{address, killswitch, permission_control, FnExt};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, HexBinary, MessageInfo, Response, StdResult,
-    Storage, Uint64,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Empty, Env, HexBinary, MessageInfo, Response,
+    StdResult, Storage, Uint64,
 };
 use error_stack::{report, Report, ResultExt};
 use itertools::Itertools;
 use router_api::ChainName;
 
 use crate::events::Event;
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrationMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
 use crate::state::{
     verifier_set, Config, CONFIG, SIGNING_SESSIONS, SIGNING_SESSION_COUNTER, VERIFIER_SETS,
 };
@@ -30,20 +30,9 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub fn migrate(
     deps: DepsMut,
     _env: Env,
-    msg: MigrationMsg,
+    _msg: Empty,
 ) -> Result<Response, axelar_wasm_std::error::ContractError> {
-    let admin = address::validate_cosmwasm_address(deps.api, &msg.admin_address)?;
-    let authorized_callers = msg
-        .authorized_callers
-        .into_iter()
-        .map(|(contract_address, chain_name)| {
-            address::validate_cosmwasm_address(deps.api, &contract_address)
-                .map(|addr| (addr, chain_name))
-        })
-        .try_collect()?;
-
-    migrations::v0_4_1::migrate(deps.storage, admin, authorized_callers)
-        .change_context(ContractError::Migration)?;
+    migrations::v1_0_0::migrate(deps.storage).change_context(ContractError::Migration)?;
 
     // this needs to be the last thing to do during migration,
     // because previous migration steps should check the old version
@@ -115,13 +104,20 @@ pub fn execute(
             session_id,
             signature,
         } => execute::submit_signature(deps, env, info, session_id, signature),
+        ExecuteMsg::ExpireSigningSession { session_id } => {
+            execute::expire_signing_session(deps, env, session_id)
+        }
         ExecuteMsg::RegisterVerifierSet { verifier_set } => {
-            execute::register_verifier_set(deps, verifier_set)
+            execute::register_verifier_set(deps, info, verifier_set)
         }
         ExecuteMsg::RegisterPublicKey {
             public_key,
             signed_sender_address,
         } => execute::register_pub_key(deps, info, public_key, signed_sender_address),
+        ExecuteMsg::RotatePublicKey {
+            public_key,
+            signed_sender_address,
+        } => execute::rotate_pub_key(deps, info, public_key, signed_sender_address),
         ExecuteMsg::AuthorizeCallers { contracts } => {
             let contracts = validate_contract_addresses(&deps, contracts)?;
             execute::authorize_callers(deps, contracts)
@@ -132,6 +128,9 @@ pub fn execute(
         }
         ExecuteMsg::DisableSigning => execute::disable_signing(deps),
         ExecuteMsg::EnableSigning => execute::enable_signing(deps),
+        ExecuteMsg::BackfillSigningSessionIndexes { limit } => {
+            execute::backfill_signing_session_indexes(deps, limit)
+        }
     }?
     .then(Ok)
 }
@@ -167,7 +166,7 @@ fn can_start_signing_session(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(
     deps: Deps,
-    _env: Env,
+    env: Env,
     msg: QueryMsg,
 ) -> Result<Binary, axelar_wasm_std::error::ContractError> {
     match msg {
@@ -183,6 +182,44 @@ pub fn query(
             address::validate_cosmwasm_address(deps.api, &verifier_address)?,
             key_type,
         )?)?,
+        QueryMsg::PendingPublicKey {
+            verifier_address,
+            key_type,
+        } => to_json_binary(&query::pending_public_key(
+            deps,
+            address::validate_cosmwasm_address(deps.api, &verifier_address)?,
+            key_type,
+        )?)?,
+        QueryMsg::PublicKeyHistory {
+            verifier_address,
+            key_type,
+        } => to_json_binary(&query::public_key_history(
+            deps,
+            address::validate_cosmwasm_address(deps.api, &verifier_address)?,
+            key_type,
+        )?)?,
+        QueryMsg::SigningSessions {
+            filter,
+            start_after,
+            limit,
+        } => to_json_binary(&query::signing_sessions(
+            deps,
+            env.block.height,
+            filter,
+            start_after,
+            limit,
+        )?)?,
+        QueryMsg::UnsignedSigningSessions {
+            verifier_address,
+            start_after,
+            limit,
+        } => to_json_binary(&query::unsigned_signing_sessions(
+            deps,
+            env.block.height,
+            address::validate_cosmwasm_address(deps.api, &verifier_address)?,
+            start_after,
+            limit,
+        )?)?,
         QueryMsg::IsCallerAuthorized {
             contract_address,
             chain_name,
@@ -191,6 +228,28 @@ pub fn query(
             address::validate_cosmwasm_address(deps.api, &contract_address)?,
             chain_name,
         )?)?,
+        QueryMsg::SignerStats {
+            verifier_address,
+            chain_name,
+        } => to_json_binary(&query::signer_stats(
+            deps,
+            env.block.height,
+            address::validate_cosmwasm_address(deps.api, &verifier_address)?,
+            chain_name,
+        )?)?,
+        QueryMsg::SignerStatsByChain {
+            chain_name,
+            start_after,
+            limit,
+        } => to_json_binary(&query::signer_stats_by_chain(
+            deps,
+            env.block.height,
+            chain_name,
+            start_after
+                .map(|addr| address::validate_cosmwasm_address(deps.api, &addr))
+                .transpose()?,
+            limit,
+        )?)?,
     }
     .then(Ok)
 }
@@ -211,9 +270,17 @@ mod tests {
 
     use super::*;
     use crate::key::{KeyType, PublicKey, Signature};
+    use crate::msg::{
+        PublicKeyHistory, SigningSessionFilter, SigningSessionStatus, SigningSessionsResponse,
+        VerifierSignerStats,
+    };
     use crate::multisig::Multisig;
-    use crate::state::load_session_signatures;
-    use crate::test::common::{build_verifier_set, ecdsa_test_data, ed25519_test_data, TestSigner};
+    use crate::signing::SigningSession;
+    use crate::state::{load_session_signatures, SignerStats};
+    use crate::test::common::{
+        bls_test_data, build_verifier_set, ecdsa_test_data, ed25519_test_data, schnorr_test_data,
+        TestSigner,
+    };
     use crate::types::MultisigState;
     use crate::verifier_set::VerifierSet;
 
@@ -249,6 +316,8 @@ mod tests {
         let signers = match key_type {
             KeyType::Ecdsa => ecdsa_test_data::signers(),
             KeyType::Ed25519 => ed25519_test_data::signers(),
+            KeyType::Schnorr => schnorr_test_data::signers(),
+            KeyType::Bls => bls_test_data::signers(),
         };
 
         let verifier_set = build_verifier_set(key_type, &signers);
@@ -278,9 +347,18 @@ mod tests {
         sender: &str,
         verifier_set_id: &str,
         chain_name: ChainName,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        do_start_signing_session_at(deps, mock_env(), sender, verifier_set_id, chain_name)
+    }
+
+    fn do_start_signing_session_at(
+        deps: DepsMut,
+        env: Env,
+        sender: &str,
+        verifier_set_id: &str,
+        chain_name: ChainName,
     ) -> Result<Response, axelar_wasm_std::error::ContractError> {
         let info = mock_info(sender, &[]);
-        let env = mock_env();
 
         let message = ecdsa_test_data::message();
         let msg = ExecuteMsg::StartSigningSession {
@@ -305,6 +383,19 @@ mod tests {
         execute(deps, env, mock_info(signer.address.as_str(), &[]), msg)
     }
 
+    fn do_expire(
+        deps: DepsMut,
+        env: Env,
+        session_id: Uint64,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        execute(
+            deps,
+            env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExpireSigningSession { session_id },
+        )
+    }
+
     fn do_register_key(
         deps: DepsMut,
         verifier: Addr,
@@ -318,6 +409,19 @@ mod tests {
         execute(deps, mock_env(), mock_info(verifier.as_str(), &[]), msg)
     }
 
+    fn do_rotate_key(
+        deps: DepsMut,
+        verifier: Addr,
+        public_key: PublicKey,
+        signed_sender_address: HexBinary,
+    ) -> Result<Response, axelar_wasm_std::error::ContractError> {
+        let msg = ExecuteMsg::RotatePublicKey {
+            public_key,
+            signed_sender_address,
+        };
+        execute(deps, mock_env(), mock_info(verifier.as_str(), &[]), msg)
+    }
+
     fn do_authorize_callers(
         deps: DepsMut,
         contracts: Vec<(Addr, ChainName)>,
@@ -388,6 +492,70 @@ mod tests {
         )
     }
 
+    fn query_pending_public_key(deps: Deps, verifier: Addr) -> Option<PublicKey> {
+        let res = query(
+            deps,
+            mock_env(),
+            QueryMsg::PendingPublicKey {
+                verifier_address: verifier.to_string(),
+                key_type: KeyType::Ecdsa,
+            },
+        );
+        from_json(res.unwrap()).unwrap()
+    }
+
+    fn query_public_key_history(deps: Deps, verifier: Addr) -> PublicKeyHistory {
+        let res = query(
+            deps,
+            mock_env(),
+            QueryMsg::PublicKeyHistory {
+                verifier_address: verifier.to_string(),
+                key_type: KeyType::Ecdsa,
+            },
+        );
+        from_json(res.unwrap()).unwrap()
+    }
+
+    fn query_signing_sessions(
+        deps: Deps,
+        env: Env,
+        filter: SigningSessionFilter,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    ) -> SigningSessionsResponse {
+        let res = query(
+            deps,
+            env,
+            QueryMsg::SigningSessions {
+                filter,
+                start_after,
+                limit,
+            },
+        );
+        from_json(res.unwrap()).unwrap()
+    }
+
+    fn query_unsigned_session_ids(deps: Deps, env: Env, verifier: &Addr) -> Vec<u64> {
+        let res = query(
+            deps,
+            env,
+            QueryMsg::UnsignedSigningSessions {
+                verifier_address: verifier.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        );
+        session_ids(
+            &from_json::<SigningSessionsResponse>(res.unwrap())
+                .unwrap()
+                .sessions,
+        )
+    }
+
+    fn session_ids(sessions: &[SigningSession]) -> Vec<u64> {
+        sessions.iter().map(|session| session.id.u64()).collect()
+    }
+
     fn setup() -> (
         OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
         String,
@@ -712,6 +880,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn submit_signature_aggregates_bls_signatures_on_completion() {
+        let (mut deps, _, _) = setup();
+        let verifier_set = generate_verifier_set(KeyType::Bls, deps.as_mut())
+            .unwrap()
+            .1;
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        do_start_signing_session(deps.as_mut(), PROVER, &verifier_set.id(), chain_name).unwrap();
+        let session_id = Uint64::one();
+        let signers = bls_test_data::signers();
+
+        let query_multisig = |deps: Deps| -> Multisig {
+            from_json(query(deps, mock_env(), QueryMsg::Multisig { session_id }).unwrap()).unwrap()
+        };
+
+        do_sign(deps.as_mut(), mock_env(), session_id, &signers[0]).unwrap();
+        assert_eq!(query_multisig(deps.as_ref()).aggregated_signature, None);
+
+        do_sign(deps.as_mut(), mock_env(), session_id, &signers[1]).unwrap();
+        let aggregated_signature = query_multisig(deps.as_ref()).aggregated_signature.unwrap();
+        assert!(aggregated_signature
+            .verify(&verifier_set, &bls_test_data::message())
+            .is_ok());
+
+        // late signatures don't change the aggregate of the completed session
+        do_sign(deps.as_mut(), mock_env(), session_id, &signers[2]).unwrap();
+        assert_eq!(
+            query_multisig(deps.as_ref()).aggregated_signature,
+            Some(aggregated_signature)
+        );
+    }
+
     #[test]
     fn submit_signature_before_expiry() {
         let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
@@ -801,7 +1007,7 @@ mod tests {
     }
 
     #[test]
-    fn submit_signature_wrong_session_id() {
+    fn expire_signing_session() {
         let (mut deps, ecdsa_subkey, _) = setup();
         let chain_name: ChainName = "mock-chain".parse().unwrap();
         do_authorize_callers(
@@ -811,71 +1017,724 @@ mod tests {
         .unwrap();
         do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone()).unwrap();
 
-        let invalid_session_id = Uint64::zero();
-        let signer = ecdsa_test_data::signers().first().unwrap().to_owned();
-        let res = do_sign(deps.as_mut(), mock_env(), invalid_session_id, &signer);
+        let session_id = Uint64::one();
+        let signers = ecdsa_test_data::signers();
+        do_sign(deps.as_mut(), mock_env(), session_id, &signers[0]).unwrap();
+
+        let expires_at = SIGNING_SESSIONS
+            .load(deps.as_ref().storage, session_id.u64())
+            .unwrap()
+            .expires_at;
 
+        let mut env = mock_env();
+        env.block.height = expires_at;
         assert_eq!(
-            res.unwrap_err().to_string(),
-            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionNotFound {
-                session_id: invalid_session_id
+            do_expire(deps.as_mut(), env.clone(), session_id)
+                .unwrap_err()
+                .to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionNotExpired {
+                session_id
             })
             .to_string()
         );
+
+        env.block.height = expires_at + 1;
+        let res = do_expire(deps.as_mut(), env.clone(), session_id).unwrap();
+
+        let event = res
+            .events
+            .iter()
+            .find(|event| event.ty == "signing_expired")
+            .unwrap();
+        assert_eq!(event_attribute(event, "session_id"), Some("1"));
+        assert_eq!(event_attribute(event, "chain"), Some("mock-chain"));
+        assert_eq!(
+            event_attribute(event, "missing_signers"),
+            Some(r#"["signer2","signer3"]"#)
+        );
+
+        let multisig: Multisig = from_json(
+            query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Multisig { session_id },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(multisig.state, MultisigState::Expired);
+        assert_eq!(
+            session_ids(
+                &query_signing_sessions(
+                    deps.as_ref(),
+                    env.clone(),
+                    SigningSessionFilter {
+                        status: Some(SigningSessionStatus::Expired),
+                        ..Default::default()
+                    },
+                    None,
+                    None
+                )
+                .sessions
+            ),
+            vec![1]
+        );
+
+        // expired sessions can neither be expired again nor be signed
+        let closed_err =
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionClosed {
+                session_id,
+            })
+            .to_string();
+        assert_eq!(
+            do_expire(deps.as_mut(), env.clone(), session_id)
+                .unwrap_err()
+                .to_string(),
+            closed_err
+        );
+        assert_eq!(
+            do_sign(deps.as_mut(), env, session_id, &signers[1])
+                .unwrap_err()
+                .to_string(),
+            closed_err
+        );
     }
 
     #[test]
-    fn query_signing_session() {
-        let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
+    fn completed_signing_session_cannot_be_expired() {
+        let (mut deps, ecdsa_subkey, _) = setup();
         let chain_name: ChainName = "mock-chain".parse().unwrap();
         do_authorize_callers(
             deps.as_mut(),
             vec![(Addr::unchecked(PROVER), chain_name.clone())],
         )
         .unwrap();
+        do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name).unwrap();
 
-        for (_key_type, subkey, signers, session_id) in
-            signature_test_data(&ecdsa_subkey, &ed25519_subkey)
-        {
-            do_start_signing_session(deps.as_mut(), PROVER, subkey, "mock-chain".parse().unwrap())
+        let session_id = Uint64::one();
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), session_id, signer).unwrap();
+        }
+
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        assert_eq!(
+            do_expire(deps.as_mut(), env, session_id)
+                .unwrap_err()
+                .to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionClosed {
+                session_id
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn signer_stats_track_participation() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        for _ in 0..2 {
+            do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone())
                 .unwrap();
+        }
 
-            do_sign(
-                deps.as_mut(),
-                mock_env(),
-                session_id,
-                signers.first().unwrap(),
+        // session 1 completes with the first two signatures, the third one is late
+        let signers = ecdsa_test_data::signers();
+        for signer in &signers {
+            do_sign(deps.as_mut(), mock_env(), Uint64::one(), signer).unwrap();
+        }
+
+        // session 2 expires with a single signature
+        do_sign(deps.as_mut(), mock_env(), Uint64::from(2u64), &signers[0]).unwrap();
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        do_expire(deps.as_mut(), env, Uint64::from(2u64)).unwrap();
+
+        let expected = [
+            SignerStats {
+                sessions_eligible: 2,
+                signed_in_time: 2,
+                signed_late: 0,
+                missed: 0,
+            },
+            SignerStats {
+                sessions_eligible: 2,
+                signed_in_time: 1,
+                signed_late: 0,
+                missed: 1,
+            },
+            SignerStats {
+                sessions_eligible: 2,
+                signed_in_time: 0,
+                signed_late: 1,
+                missed: 1,
+            },
+        ];
+
+        for (signer, expected) in signers.iter().zip(expected.iter()) {
+            let stats: SignerStats = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::SignerStats {
+                        verifier_address: signer.address.to_string(),
+                        chain_name: chain_name.clone(),
+                    },
+                )
+                .unwrap(),
             )
             .unwrap();
+            assert_eq!(stats, *expected);
+        }
 
-            let env = mock_env();
-            let expected_completed_at = env.block.height;
-            do_sign(deps.as_mut(), env, session_id, signers.get(1).unwrap()).unwrap();
+        let page: Vec<VerifierSignerStats> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::SignerStatsByChain {
+                    chain_name,
+                    start_after: Some(signers[0].address.to_string()),
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            page,
+            vec![VerifierSignerStats {
+                verifier_address: signers[1].address.clone(),
+                stats: expected[1].clone(),
+            }]
+        );
 
-            let msg = QueryMsg::Multisig { session_id };
+        let stats: SignerStats = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::SignerStats {
+                    verifier_address: signers[0].address.to_string(),
+                    chain_name: "other-chain".parse().unwrap(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(stats, SignerStats::default());
+    }
 
-            let res = query(deps.as_ref(), mock_env(), msg);
-            assert!(res.is_ok());
+    #[test]
+    fn signer_stats_count_sessions_past_expiry_as_missed() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone()).unwrap();
 
-            let query_res: Multisig = from_json(res.unwrap()).unwrap();
-            let session = SIGNING_SESSIONS
-                .load(deps.as_ref().storage, session_id.into())
-                .unwrap();
-            let verifier_set = VERIFIER_SETS
-                .load(deps.as_ref().storage, session.verifier_set_id.as_str())
-                .unwrap();
-            let signatures =
-                load_session_signatures(deps.as_ref().storage, session.id.u64()).unwrap();
+        let signers = ecdsa_test_data::signers();
+        do_sign(deps.as_mut(), mock_env(), Uint64::one(), &signers[0]).unwrap();
+
+        let query_stats = |env: Env, signer: &TestSigner| -> SignerStats {
+            from_json(
+                query(
+                    deps.as_ref(),
+                    env,
+                    QueryMsg::SignerStats {
+                        verifier_address: signer.address.to_string(),
+                        chain_name: chain_name.clone(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
 
-            assert_eq!(
-                query_res.state,
-                MultisigState::Completed {
-                    completed_at: expected_completed_at
-                }
-            );
-            assert_eq!(query_res.signatures, signatures);
-            assert_eq!(query_res.verifier_set, verifier_set);
-        }
+        // the session can still be signed
+        assert_eq!(query_stats(mock_env(), &signers[1]).missed, 0);
+
+        // nobody expires the session explicitly
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        assert_eq!(
+            query_stats(env.clone(), &signers[0]),
+            SignerStats {
+                sessions_eligible: 1,
+                signed_in_time: 1,
+                signed_late: 0,
+                missed: 0,
+            }
+        );
+        assert_eq!(
+            query_stats(env, &signers[1]),
+            SignerStats {
+                sessions_eligible: 1,
+                signed_in_time: 0,
+                signed_late: 0,
+                missed: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn signer_stats_count_open_sessions_after_block_expiry_change() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone()).unwrap();
+
+        // the newer session expires before the older one
+        let short_expiry = 10u64;
+        CONFIG
+            .update(deps.as_mut().storage, |mut config| -> StdResult<_> {
+                config.block_expiry = short_expiry.try_into().unwrap();
+                Ok(config)
+            })
+            .unwrap();
+        do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone()).unwrap();
+
+        let mut env = mock_env();
+        env.block.height = env
+            .block
+            .height
+            .saturating_add(short_expiry)
+            .saturating_add(1);
+
+        let stats: SignerStats = from_json(
+            query(
+                deps.as_ref(),
+                env,
+                QueryMsg::SignerStats {
+                    verifier_address: ecdsa_test_data::signers()[0].address.to_string(),
+                    chain_name,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            stats,
+            SignerStats {
+                sessions_eligible: 2,
+                signed_in_time: 0,
+                signed_late: 0,
+                missed: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn submit_signature_wrong_session_id() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+        do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone()).unwrap();
+
+        let invalid_session_id = Uint64::zero();
+        let signer = ecdsa_test_data::signers().first().unwrap().to_owned();
+        let res = do_sign(deps.as_mut(), mock_env(), invalid_session_id, &signer);
+
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::SigningSessionNotFound {
+                session_id: invalid_session_id
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn query_signing_session() {
+        let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        for (_key_type, subkey, signers, session_id) in
+            signature_test_data(&ecdsa_subkey, &ed25519_subkey)
+        {
+            do_start_signing_session(deps.as_mut(), PROVER, subkey, "mock-chain".parse().unwrap())
+                .unwrap();
+
+            do_sign(
+                deps.as_mut(),
+                mock_env(),
+                session_id,
+                signers.first().unwrap(),
+            )
+            .unwrap();
+
+            let env = mock_env();
+            let expected_completed_at = env.block.height;
+            do_sign(deps.as_mut(), env, session_id, signers.get(1).unwrap()).unwrap();
+
+            let msg = QueryMsg::Multisig { session_id };
+
+            let res = query(deps.as_ref(), mock_env(), msg);
+            assert!(res.is_ok());
+
+            let query_res: Multisig = from_json(res.unwrap()).unwrap();
+            let session = SIGNING_SESSIONS
+                .load(deps.as_ref().storage, session_id.into())
+                .unwrap();
+            let verifier_set = VERIFIER_SETS
+                .load(deps.as_ref().storage, session.verifier_set_id.as_str())
+                .unwrap();
+            let signatures =
+                load_session_signatures(deps.as_ref().storage, session.id.u64()).unwrap();
+
+            assert_eq!(
+                query_res.state,
+                MultisigState::Completed {
+                    completed_at: expected_completed_at
+                }
+            );
+            assert_eq!(query_res.signatures, signatures);
+            assert_eq!(query_res.verifier_set, verifier_set);
+        }
+    }
+
+    #[test]
+    fn query_signing_sessions() {
+        let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        let other_chain_name: ChainName = "other-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![
+                (Addr::unchecked(PROVER), chain_name.clone()),
+                (Addr::unchecked("other-prover"), other_chain_name.clone()),
+            ],
+        )
+        .unwrap();
+
+        do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone()).unwrap();
+        do_start_signing_session(deps.as_mut(), PROVER, &ed25519_subkey, chain_name.clone())
+            .unwrap();
+
+        let created_at = mock_env().block.height;
+        let mut next_block = mock_env();
+        next_block.block.height = created_at.saturating_add(1);
+        do_start_signing_session_at(
+            deps.as_mut(),
+            next_block,
+            "other-prover",
+            &ecdsa_subkey,
+            other_chain_name.clone(),
+        )
+        .unwrap();
+
+        // complete the first session
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), Uint64::one(), signer).unwrap();
+        }
+
+        let expires_at = SIGNING_SESSIONS
+            .load(deps.as_ref().storage, 3)
+            .unwrap()
+            .expires_at;
+        let mut after_expiry = mock_env();
+        after_expiry.block.height = expires_at.saturating_add(1);
+
+        let cases = vec![
+            (SigningSessionFilter::default(), mock_env(), vec![1, 2, 3]),
+            (
+                SigningSessionFilter {
+                    chain_name: Some(chain_name.clone()),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![1, 2],
+            ),
+            (
+                SigningSessionFilter {
+                    verifier_set_id: Some(ecdsa_subkey.clone()),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![1, 3],
+            ),
+            (
+                SigningSessionFilter {
+                    verifier_set_id: Some(ecdsa_subkey.clone()),
+                    chain_name: Some(other_chain_name),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![3],
+            ),
+            (
+                SigningSessionFilter {
+                    status: Some(SigningSessionStatus::Completed),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![1],
+            ),
+            (
+                SigningSessionFilter {
+                    status: Some(SigningSessionStatus::Pending),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![2, 3],
+            ),
+            (
+                SigningSessionFilter {
+                    status: Some(SigningSessionStatus::Pending),
+                    ..Default::default()
+                },
+                after_expiry.clone(),
+                vec![],
+            ),
+            (
+                SigningSessionFilter {
+                    chain_name: Some(chain_name),
+                    status: Some(SigningSessionStatus::Expired),
+                    ..Default::default()
+                },
+                after_expiry,
+                vec![2],
+            ),
+            (
+                SigningSessionFilter {
+                    created_at: Some((created_at, created_at)),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![1, 2],
+            ),
+            (
+                SigningSessionFilter {
+                    created_at: Some((created_at.saturating_add(1), u64::MAX)),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![3],
+            ),
+            (
+                SigningSessionFilter {
+                    created_at: Some((0, created_at.saturating_sub(1))),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![],
+            ),
+            (
+                SigningSessionFilter {
+                    verifier_set_id: Some(ecdsa_subkey),
+                    created_at: Some((created_at, created_at)),
+                    ..Default::default()
+                },
+                mock_env(),
+                vec![1],
+            ),
+        ];
+
+        for (filter, env, expected_ids) in cases {
+            let res = query_signing_sessions(deps.as_ref(), env, filter, None, None);
+            assert_eq!(session_ids(&res.sessions), expected_ids);
+            assert_eq!(res.last_checked, None);
+        }
+
+        let page = query_signing_sessions(
+            deps.as_ref(),
+            mock_env(),
+            SigningSessionFilter::default(),
+            Some(Uint64::one()),
+            Some(1),
+        );
+        assert_eq!(session_ids(&page.sessions), vec![2]);
+        assert_eq!(
+            page.sessions[0],
+            SIGNING_SESSIONS.load(deps.as_ref().storage, 2).unwrap()
+        );
+        assert_eq!(page.last_checked, Some(2u64.into()));
+
+        // the page ends with the last session started within the block range
+        let page = query_signing_sessions(
+            deps.as_ref(),
+            mock_env(),
+            SigningSessionFilter {
+                created_at: Some((created_at, created_at)),
+                ..Default::default()
+            },
+            Some(Uint64::one()),
+            Some(1),
+        );
+        assert_eq!(session_ids(&page.sessions), vec![2]);
+        assert_eq!(page.last_checked, None);
+    }
+
+    #[test]
+    fn query_signing_sessions_checks_a_bounded_number_of_sessions() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        let session_count = 1001u64;
+        for _ in 0..session_count {
+            do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone())
+                .unwrap();
+        }
+
+        // complete the last session
+        for signer in ecdsa_test_data::signers().iter().take(2) {
+            do_sign(deps.as_mut(), mock_env(), session_count.into(), signer).unwrap();
+        }
+
+        let filter = SigningSessionFilter {
+            chain_name: Some(chain_name),
+            status: Some(SigningSessionStatus::Completed),
+            ..Default::default()
+        };
+
+        let page = query_signing_sessions(deps.as_ref(), mock_env(), filter.clone(), None, None);
+        assert!(page.sessions.is_empty());
+        assert_eq!(page.last_checked, Some(1000u64.into()));
+
+        let page =
+            query_signing_sessions(deps.as_ref(), mock_env(), filter, page.last_checked, None);
+        assert_eq!(session_ids(&page.sessions), vec![session_count]);
+        assert_eq!(page.last_checked, None);
+    }
+
+    #[test]
+    fn query_unsigned_signing_sessions() {
+        let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        for subkey in [&ecdsa_subkey, &ed25519_subkey] {
+            do_start_signing_session(deps.as_mut(), PROVER, subkey, chain_name.clone()).unwrap();
+        }
+
+        let signers = ecdsa_test_data::signers();
+        do_sign(deps.as_mut(), mock_env(), Uint64::one(), &signers[0]).unwrap();
+
+        assert_eq!(
+            query_unsigned_session_ids(deps.as_ref(), mock_env(), &signers[0].address),
+            vec![2]
+        );
+        assert_eq!(
+            query_unsigned_session_ids(deps.as_ref(), mock_env(), &signers[1].address),
+            vec![1, 2]
+        );
+        assert!(query_unsigned_session_ids(
+            deps.as_ref(),
+            mock_env(),
+            &Addr::unchecked("not_a_signer")
+        )
+        .is_empty());
+
+        // completed sessions don't need any more signatures
+        do_sign(deps.as_mut(), mock_env(), Uint64::one(), &signers[1]).unwrap();
+        assert_eq!(
+            query_unsigned_session_ids(deps.as_ref(), mock_env(), &signers[2].address),
+            vec![2]
+        );
+
+        // expired sessions can't be signed anymore
+        let mut env = mock_env();
+        env.block.height += SIGNATURE_BLOCK_EXPIRY + 1;
+        assert!(query_unsigned_session_ids(deps.as_ref(), env, &signers[2].address).is_empty());
+    }
+
+    #[test]
+    fn query_unsigned_signing_sessions_skips_sessions_past_expiry() {
+        let (mut deps, ecdsa_subkey, _) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        // nobody expires these sessions explicitly
+        let expired_count = 1001u64;
+        for _ in 0..expired_count {
+            do_start_signing_session(deps.as_mut(), PROVER, &ecdsa_subkey, chain_name.clone())
+                .unwrap();
+        }
+
+        let mut env = mock_env();
+        env.block.height = env
+            .block
+            .height
+            .saturating_add(SIGNATURE_BLOCK_EXPIRY)
+            .saturating_add(1);
+        for _ in 0..3 {
+            do_start_signing_session_at(
+                deps.as_mut(),
+                env.clone(),
+                PROVER,
+                &ecdsa_subkey,
+                chain_name.clone(),
+            )
+            .unwrap();
+        }
+
+        let query_page = |start_after: Option<Uint64>| -> SigningSessionsResponse {
+            from_json(
+                query(
+                    deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::UnsignedSigningSessions {
+                        verifier_address: ecdsa_test_data::signers()[0].address.to_string(),
+                        start_after,
+                        limit: Some(2),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+
+        let page = query_page(None);
+        assert_eq!(
+            session_ids(&page.sessions),
+            vec![
+                expired_count.saturating_add(1),
+                expired_count.saturating_add(2)
+            ]
+        );
+        assert_eq!(
+            page.last_checked,
+            Some(expired_count.saturating_add(2).into())
+        );
+
+        let page = query_page(page.last_checked);
+        assert_eq!(
+            session_ids(&page.sessions),
+            vec![expired_count.saturating_add(3)]
+        );
+        assert_eq!(page.last_checked, None);
     }
 
     #[test]
@@ -929,10 +1788,34 @@ mod tests {
             assert!(res.is_ok());
         }
 
-        // Test that we can query both keys
+        // Register a Schnorr key
+        let schnorr_signers = schnorr_test_data::signers();
+        let schnorr_pub_keys = schnorr_signers
+            .iter()
+            .map(|signer| {
+                (
+                    signer.address.clone(),
+                    signer.pub_key.clone(),
+                    signer.signed_address.clone(),
+                )
+            })
+            .collect::<Vec<(Addr, HexBinary, HexBinary)>>();
+
+        for (addr, pub_key, signed_address) in &schnorr_pub_keys {
+            let res = do_register_key(
+                deps.as_mut(),
+                addr.clone(),
+                PublicKey::Schnorr(pub_key.clone()),
+                signed_address.clone(),
+            );
+            assert!(res.is_ok());
+        }
+
+        // Test that we can query all keys
         for (key_type, expected_pub_keys) in [
             (KeyType::Ecdsa, ecdsa_pub_keys),
             (KeyType::Ed25519, ed25519_pub_keys),
+            (KeyType::Schnorr, schnorr_pub_keys),
         ] {
             let mut ret_pub_keys: Vec<PublicKey> = vec![];
 
@@ -1026,6 +1909,167 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rotate_key() {
+        let (mut deps, verifier_set_id, _) = setup();
+        let chain_name: ChainName = "mock-chain".parse().unwrap();
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), chain_name.clone())],
+        )
+        .unwrap();
+
+        let mut signers = ecdsa_test_data::signers();
+        for signer in &signers {
+            do_register_key(
+                deps.as_mut(),
+                signer.address.clone(),
+                PublicKey::Ecdsa(signer.pub_key.clone()),
+                signer.signed_address.clone(),
+            )
+            .unwrap();
+        }
+
+        let old_pub_key = PublicKey::Ecdsa(signers[0].pub_key.clone());
+        let new_pub_key = PublicKey::Ecdsa(
+            HexBinary::from_hex(
+                "03a7e532333ba40803b7e5744cbc94e94e905c9ced87bbe08065e0cd36fa7e01c6",
+            )
+            .unwrap(),
+        );
+        let res = do_rotate_key(
+            deps.as_mut(),
+            signers[0].address.clone(),
+            new_pub_key.clone(),
+            HexBinary::from_hex("b8b4c7e4423e80a71171d40709a1ca3b464b09ca93c4df9e13ef98df5d6d2d3b77a2fdf22a34b2946574801ee0d7fa886d8c3b34e63ca4158b74e02fe343ca47").unwrap(),
+        );
+        assert!(res.is_ok());
+
+        // the active key stays in place until a verifier set uses the pending key
+        let res =
+            query_registered_public_key(deps.as_ref(), signers[0].address.clone(), KeyType::Ecdsa);
+        assert_eq!(from_json::<PublicKey>(res.unwrap()).unwrap(), old_pub_key);
+        assert_eq!(
+            query_pending_public_key(deps.as_ref(), signers[0].address.clone()),
+            Some(new_pub_key.clone())
+        );
+
+        let history = query_public_key_history(deps.as_ref(), signers[0].address.clone());
+        assert_eq!(history.active, old_pub_key);
+        assert_eq!(history.pending, Some(new_pub_key.clone()));
+        assert!(history.retired.is_empty());
+
+        // the old key remains valid for the current verifier set
+        do_start_signing_session(deps.as_mut(), PROVER, &verifier_set_id, chain_name).unwrap();
+        let res = do_sign(deps.as_mut(), mock_env(), Uint64::one(), &signers[0]);
+        assert!(res.is_ok());
+
+        // the pending key becomes active with the first verifier set that uses it
+        signers[0].pub_key = HexBinary::from(new_pub_key.clone());
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(PROVER, &[]),
+            ExecuteMsg::RegisterVerifierSet {
+                verifier_set: build_verifier_set(KeyType::Ecdsa, &signers),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.events.len(), 1);
+        assert_eq!(res.events[0].ty, "public_key_registered");
+
+        let history = query_public_key_history(deps.as_ref(), signers[0].address.clone());
+        assert_eq!(history.active, new_pub_key);
+        assert_eq!(history.pending, None);
+        assert_eq!(
+            query_pending_public_key(deps.as_ref(), signers[0].address.clone()),
+            None
+        );
+        assert_eq!(history.retired, vec![old_pub_key]);
+    }
+
+    #[test]
+    fn rotated_key_should_only_be_activated_by_authorized_caller() {
+        let mut deps = setup().0;
+        do_authorize_callers(
+            deps.as_mut(),
+            vec![(Addr::unchecked(PROVER), "mock-chain".parse().unwrap())],
+        )
+        .unwrap();
+
+        let mut signers = ecdsa_test_data::signers();
+        for signer in &signers {
+            do_register_key(
+                deps.as_mut(),
+                signer.address.clone(),
+                PublicKey::Ecdsa(signer.pub_key.clone()),
+                signer.signed_address.clone(),
+            )
+            .unwrap();
+        }
+
+        let old_pub_key = PublicKey::Ecdsa(signers[0].pub_key.clone());
+        let new_pub_key = PublicKey::Ecdsa(
+            HexBinary::from_hex(
+                "03a7e532333ba40803b7e5744cbc94e94e905c9ced87bbe08065e0cd36fa7e01c6",
+            )
+            .unwrap(),
+        );
+        do_rotate_key(
+            deps.as_mut(),
+            signers[0].address.clone(),
+            new_pub_key.clone(),
+            HexBinary::from_hex("b8b4c7e4423e80a71171d40709a1ca3b464b09ca93c4df9e13ef98df5d6d2d3b77a2fdf22a34b2946574801ee0d7fa886d8c3b34e63ca4158b74e02fe343ca47").unwrap(),
+        )
+        .unwrap();
+
+        signers[0].pub_key = HexBinary::from(new_pub_key.clone());
+        let msg = ExecuteMsg::RegisterVerifierSet {
+            verifier_set: build_verifier_set(KeyType::Ecdsa, &signers),
+        };
+
+        let res = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            msg.clone(),
+        )
+        .unwrap();
+        assert!(res.events.is_empty());
+
+        let history = query_public_key_history(deps.as_ref(), signers[0].address.clone());
+        assert_eq!(history.active, old_pub_key);
+        assert_eq!(history.pending, Some(new_pub_key.clone()));
+
+        let res = execute(deps.as_mut(), mock_env(), mock_info(PROVER, &[]), msg).unwrap();
+        assert_eq!(res.events.len(), 1);
+
+        let history = query_public_key_history(deps.as_ref(), signers[0].address.clone());
+        assert_eq!(history.active, new_pub_key);
+        assert_eq!(history.pending, None);
+    }
+
+    #[test]
+    fn should_fail_rotate_key_if_no_key_registered() {
+        let mut deps = mock_dependencies();
+        do_instantiate(deps.as_mut()).unwrap();
+
+        let signer = ecdsa_test_data::signers().remove(0);
+        let res = do_rotate_key(
+            deps.as_mut(),
+            signer.address,
+            PublicKey::Ecdsa(signer.pub_key),
+            signer.signed_address,
+        );
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            axelar_wasm_std::error::ContractError::from(ContractError::NoPublicKeyToRotate {
+                key_type: KeyType::Ecdsa
+            })
+            .to_string()
+        );
+    }
+
     #[test]
     fn should_fail_register_key_if_signature_invalid() {
         let mut deps = mock_dependencies();
@@ -1325,6 +2369,28 @@ mod tests {
         assert!(do_enable_signing(deps.as_mut(), GOVERNANCE).is_ok());
     }
 
+    #[test]
+    fn backfill_signing_session_indexes_should_require_elevated_permission() {
+        let mut deps = setup().0;
+        let msg = ExecuteMsg::BackfillSigningSessionIndexes { limit: 10 };
+
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            msg.clone()
+        )
+        .is_err());
+        assert!(execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(ADMIN, &[]),
+            msg.clone()
+        )
+        .is_ok());
+        assert!(execute(deps.as_mut(), mock_env(), mock_info(GOVERNANCE, &[]), msg).is_ok());
+    }
+
     #[test]
     fn start_signing_session_wrong_chain() {
         let (mut deps, ecdsa_subkey, ed25519_subkey) = setup();
@@ -6,9 +6,14 @@ use sha3::{Digest, Keccak256};
 use signature_verifier_api::client::SignatureVerifier;
 
 use super::*;
-use crate::key::{KeyTyped, PublicKey, Signature};
+use crate::key::{KeyType, KeyTyped, PublicKey, Signature};
+use crate::multisig::Multisig;
 use crate::signing::{validate_session_signature, SigningSession};
-use crate::state::{load_session_signatures, save_pub_key, save_signature, AUTHORIZED_CALLERS};
+use crate::state::{
+    self, activate_pending_pub_key, load_session_signatures, record_session_started,
+    save_pending_pub_key, save_pub_key, save_signature, update_signature_counts,
+    AGGREGATED_SIGNATURES, AUTHORIZED_CALLERS,
+};
 use crate::verifier_set::VerifierSet;
 
 pub fn start_signing_session(
@@ -55,12 +60,15 @@ pub fn start_signing_session(
         verifier_set_id.clone(),
         chain_name.clone(),
         msg.clone(),
+        env.block.height,
         expires_at,
         sig_verifier,
     );
 
     SIGNING_SESSIONS.save(deps.storage, session_id.into(), &signing_session)?;
 
+    record_session_started(deps.storage, &chain_name, &verifier_set_id, &verifier_set)?;
+
     let event = Event::SigningStarted {
         session_id,
         verifier_set_id,
@@ -130,6 +138,12 @@ pub fn submit_signature(
 
     let state_changed = old_state != session.state;
 
+    if state_changed {
+        save_aggregated_signature(deps.storage, &session, &verifier_set, signatures)?;
+    }
+
+    record_signature(deps.storage, &session, &old_state, &info.sender)?;
+
     signing_response(
         session,
         state_changed,
@@ -139,14 +153,94 @@ pub fn submit_signature(
     )
 }
 
+// BLS signatures are aggregated once when the session completes, so proofs don't need to repeat the aggregation.
+// Signatures submitted after completion are not part of the aggregate.
+fn save_aggregated_signature(
+    storage: &mut dyn Storage,
+    session: &SigningSession,
+    verifier_set: &VerifierSet,
+    signatures: HashMap<String, Signature>,
+) -> Result<(), ContractError> {
+    if verifier_set.key_type() != Some(KeyType::Bls) {
+        return Ok(());
+    }
+
+    let aggregated_signature = Multisig {
+        state: session.state.clone(),
+        verifier_set: verifier_set.clone(),
+        signatures,
+        aggregated_signature: None,
+    }
+    .aggregate_signatures()?;
+
+    AGGREGATED_SIGNATURES.save(storage, session.id.u64(), &aggregated_signature)?;
+
+    Ok(())
+}
+
+pub fn expire_signing_session(
+    deps: DepsMut,
+    env: Env,
+    session_id: Uint64,
+) -> Result<Response, ContractError> {
+    let mut session = SIGNING_SESSIONS
+        .load(deps.storage, session_id.into())
+        .map_err(|_| ContractError::SigningSessionNotFound { session_id })?;
+
+    ensure!(
+        session.state == MultisigState::Pending,
+        ContractError::SigningSessionClosed { session_id }
+    );
+    ensure!(
+        session.expires_at < env.block.height,
+        ContractError::SigningSessionNotExpired { session_id }
+    );
+
+    session.state = MultisigState::Expired;
+    SIGNING_SESSIONS.save(deps.storage, session.id.u64(), &session)?;
+
+    let verifier_set = VERIFIER_SETS.load(deps.storage, &session.verifier_set_id)?;
+    let signatures = load_session_signatures(deps.storage, session.id.u64())?;
+    let missing_signers = missing_signers(&verifier_set, &signatures);
+
+    Ok(Response::new().add_event(
+        Event::SigningExpired {
+            session_id: session.id,
+            verifier_set_id: session.verifier_set_id,
+            chain_name: session.chain_name,
+            expires_at: session.expires_at,
+            missing_signers,
+        }
+        .into(),
+    ))
+}
+
 pub fn register_verifier_set(
     deps: DepsMut,
+    info: MessageInfo,
     verifier_set: VerifierSet,
 ) -> Result<Response, ContractError> {
     let verifier_set_id = verifier_set.id();
     VERIFIER_SETS.save(deps.storage, &verifier_set_id, &verifier_set)?;
 
-    Ok(Response::default())
+    // anyone can register a verifier set, but only the provers decide which verifier sets are used
+    if !AUTHORIZED_CALLERS.has(deps.storage, &info.sender) {
+        return Ok(Response::new());
+    }
+
+    // rotated keys become active once they are part of a verifier set,
+    // the keys they replace remain valid for the sessions of verifier sets that still use them
+    let mut events = vec![];
+    for signer in verifier_set.signers.values() {
+        if activate_pending_pub_key(deps.storage, signer.address.clone(), &signer.pub_key)? {
+            events.push(Event::PublicKeyRegistered {
+                verifier: signer.address.clone(),
+                public_key: signer.pub_key.clone(),
+            });
+        }
+    }
+
+    Ok(Response::new().add_events(events.into_iter().map(cosmwasm_std::Event::from)))
 }
 
 pub fn register_pub_key(
@@ -155,16 +249,7 @@ pub fn register_pub_key(
     public_key: PublicKey,
     signed_sender_address: HexBinary,
 ) -> Result<Response, ContractError> {
-    let signed_sender_address: Signature =
-        (public_key.key_type(), signed_sender_address).try_into()?;
-
-    let address_hash = Keccak256::digest(info.sender.as_bytes());
-
-    // to prevent anyone from registering a public key that belongs to someone else,
-    // we require the sender to sign their own address using the private key
-    signed_sender_address
-        .verify(address_hash.as_slice(), &public_key)
-        .map_err(|_| ContractError::InvalidPublicKeyRegistrationSignature)?;
+    verify_key_ownership(&info.sender, &public_key, signed_sender_address)?;
 
     save_pub_key(deps.storage, info.sender.clone(), public_key.clone())?;
 
@@ -177,6 +262,42 @@ pub fn register_pub_key(
     ))
 }
 
+pub fn rotate_pub_key(
+    deps: DepsMut,
+    info: MessageInfo,
+    public_key: PublicKey,
+    signed_sender_address: HexBinary,
+) -> Result<Response, ContractError> {
+    verify_key_ownership(&info.sender, &public_key, signed_sender_address)?;
+
+    save_pending_pub_key(deps.storage, info.sender.clone(), public_key.clone())?;
+
+    Ok(Response::new().add_event(
+        Event::PublicKeyRotationStarted {
+            verifier: info.sender,
+            public_key,
+        }
+        .into(),
+    ))
+}
+
+// to prevent anyone from registering a public key that belongs to someone else,
+// we require the sender to sign their own address using the private key
+fn verify_key_ownership(
+    sender: &Addr,
+    public_key: &PublicKey,
+    signed_sender_address: HexBinary,
+) -> Result<(), ContractError> {
+    let signed_sender_address: Signature =
+        (public_key.key_type(), signed_sender_address).try_into()?;
+
+    let address_hash = Keccak256::digest(sender.as_bytes());
+
+    signed_sender_address
+        .verify(address_hash.as_slice(), public_key)
+        .map_err(|_| ContractError::InvalidPublicKeyRegistrationSignature)
+}
+
 pub fn require_authorized_caller(
     storage: &dyn Storage,
     contract_address: &Addr,
@@ -240,6 +361,45 @@ pub fn disable_signing(deps: DepsMut) -> Result<Response, ContractError> {
     killswitch::engage(deps.storage, Event::SigningDisabled).map_err(|err| err.into())
 }
 
+pub fn backfill_signing_session_indexes(
+    deps: DepsMut,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    state::backfill_signing_session_indexes(deps.storage, limit as usize)?;
+    Ok(Response::new())
+}
+
+// Verifiers without a signature are counted as missed once the session is closed, which is derived when the stats
+// are queried. A signature that arrives after completion moves the session from missed to late.
+fn record_signature(
+    storage: &mut dyn Storage,
+    session: &SigningSession,
+    old_state: &MultisigState,
+    signer: &Addr,
+) -> Result<(), ContractError> {
+    update_signature_counts(storage, &session.chain_name, signer, |counts| {
+        if *old_state == MultisigState::Pending {
+            counts.signed_in_time = counts.signed_in_time.saturating_add(1)
+        } else {
+            counts.signed_late = counts.signed_late.saturating_add(1)
+        }
+    })?;
+
+    Ok(())
+}
+
+fn missing_signers(
+    verifier_set: &VerifierSet,
+    signatures: &HashMap<String, Signature>,
+) -> Vec<Addr> {
+    verifier_set
+        .signers
+        .values()
+        .filter(|signer| !signatures.contains_key(signer.address.as_str()))
+        .map(|signer| signer.address.clone())
+        .collect()
+}
+
 fn signing_response(
     session: SigningSession,
     state_changed: bool,
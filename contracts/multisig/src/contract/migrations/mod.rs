@@ -1 +1 @@
-pub mod v0_4_1;
+pub mod v1_0_0;
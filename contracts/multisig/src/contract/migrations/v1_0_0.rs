@@ -0,0 +1,208 @@
+use cosmwasm_std::{StdError, Storage};
+use cw2::VersionError;
+
+use crate::contract::CONTRACT_NAME;
+use crate::state;
+
+const BASE_VERSION: &str = "1.0.0";
+const MIGRATION_BACKFILL_LIMIT: usize = 100;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    Std(#[from] StdError),
+    #[error(transparent)]
+    Version(#[from] VersionError),
+}
+
+pub fn migrate(storage: &mut dyn Storage) -> Result<(), Error> {
+    cw2::assert_contract_version(storage, CONTRACT_NAME, BASE_VERSION)?;
+
+    index_signing_sessions(storage)?;
+    Ok(())
+}
+
+/// Signing sessions were stored in a plain map before, so none of them are indexed yet.
+/// The latest sessions are indexed right away, older ones with ExecuteMsg::BackfillSigningSessionIndexes.
+/// The block height the sessions were started at was not stored, so it stays unknown.
+fn index_signing_sessions(storage: &mut dyn Storage) -> Result<(), Error> {
+    state::start_signing_session_index_backfill(storage)?;
+    state::backfill_signing_session_indexes(storage, MIGRATION_BACKFILL_LIMIT)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{Addr, Empty, HexBinary, Order, Storage, Uint64};
+    use cw_storage_plus::Map;
+    use router_api::ChainName;
+
+    use crate::contract::migrations::v1_0_0::{self, BASE_VERSION, MIGRATION_BACKFILL_LIMIT};
+    use crate::contract::{self, CONTRACT_NAME};
+    use crate::state::{self, state_index_key, SIGNING_SESSIONS};
+    use crate::types::{MsgToSign, MultisigState};
+
+    #[test]
+    fn migrate_checks_contract_version() {
+        let mut deps = mock_dependencies();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "something wrong").unwrap();
+
+        assert!(v1_0_0::migrate(deps.as_mut().storage).is_err());
+
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, BASE_VERSION).unwrap();
+
+        assert!(v1_0_0::migrate(deps.as_mut().storage).is_ok());
+    }
+
+    #[test]
+    fn migrate_can_only_run_once() {
+        let mut deps = mock_dependencies();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, BASE_VERSION).unwrap();
+        save_old_sessions(deps.as_mut().storage, 3);
+
+        assert!(contract::migrate(deps.as_mut(), mock_env(), Empty {}).is_ok());
+        assert!(contract::migrate(deps.as_mut(), mock_env(), Empty {}).is_err());
+    }
+
+    #[test]
+    fn migrate_indexes_latest_sessions_and_leaves_the_rest_to_the_backfill() {
+        let mut deps = mock_dependencies();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, BASE_VERSION).unwrap();
+
+        let session_count = u64::try_from(MIGRATION_BACKFILL_LIMIT)
+            .unwrap()
+            .saturating_add(5);
+        save_old_sessions(deps.as_mut().storage, session_count);
+
+        v1_0_0::migrate(deps.as_mut().storage).unwrap();
+
+        let indexed = pending_session_ids(deps.as_ref().storage);
+        assert_eq!(indexed.len(), MIGRATION_BACKFILL_LIMIT);
+        assert_eq!(indexed.first(), Some(&6));
+        assert_eq!(indexed.last(), Some(&session_count));
+
+        assert_eq!(
+            state::backfill_signing_session_indexes(deps.as_mut().storage, 3).unwrap(),
+            3
+        );
+        assert_eq!(
+            state::backfill_signing_session_indexes(deps.as_mut().storage, 10).unwrap(),
+            2
+        );
+        assert_eq!(
+            state::backfill_signing_session_indexes(deps.as_mut().storage, 10).unwrap(),
+            0
+        );
+
+        assert_eq!(
+            pending_session_ids(deps.as_ref().storage),
+            (1..=session_count).collect::<Vec<_>>()
+        );
+
+        let by_chain: Vec<_> = SIGNING_SESSIONS
+            .idx
+            .chain_name
+            .prefix("ethereum".to_string())
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(by_chain, vec![1, 2, 3, 4, 5]);
+
+        let by_verifier_set: Vec<_> = SIGNING_SESSIONS
+            .idx
+            .verifier_set_id
+            .prefix("verifier_set_1".to_string())
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(by_verifier_set, vec![1, 2, 3, 4, 5]);
+
+        let session = SIGNING_SESSIONS.load(deps.as_ref().storage, 1).unwrap();
+        assert_eq!(session.created_at, None);
+        assert_eq!(session.expires_at, 101);
+    }
+
+    #[test]
+    fn backfill_replaces_index_entries_of_sessions_updated_after_the_migration() {
+        let mut deps = mock_dependencies();
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, BASE_VERSION).unwrap();
+
+        let session_count = u64::try_from(MIGRATION_BACKFILL_LIMIT)
+            .unwrap()
+            .saturating_add(1);
+        save_old_sessions(deps.as_mut().storage, session_count);
+
+        v1_0_0::migrate(deps.as_mut().storage).unwrap();
+
+        let mut session = SIGNING_SESSIONS.load(deps.as_ref().storage, 1).unwrap();
+        session.state = MultisigState::Expired;
+        SIGNING_SESSIONS
+            .save(deps.as_mut().storage, 1, &session)
+            .unwrap();
+
+        state::backfill_signing_session_indexes(deps.as_mut().storage, 10).unwrap();
+
+        let expired: Vec<_> = SIGNING_SESSIONS
+            .idx
+            .state
+            .prefix(state_index_key(&MultisigState::Expired).to_string())
+            .keys(deps.as_ref().storage, None, None, Order::Ascending)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(expired, vec![1]);
+        assert_eq!(
+            pending_session_ids(deps.as_ref().storage),
+            (2..=session_count).collect::<Vec<_>>()
+        );
+    }
+
+    fn save_old_sessions(storage: &mut dyn Storage, count: u64) {
+        for id in 1..=count {
+            let session = SigningSession {
+                id: Uint64::from(id),
+                verifier_set_id: if id <= 5 {
+                    "verifier_set_1"
+                } else {
+                    "verifier_set_2"
+                }
+                .to_string(),
+                chain_name: if id <= 5 { "ethereum" } else { "avalanche" }
+                    .parse()
+                    .unwrap(),
+                msg: HexBinary::from([2; 32]).try_into().unwrap(),
+                state: MultisigState::Pending,
+                expires_at: id.saturating_add(100),
+                sig_verifier: None,
+            };
+
+            OLD_SIGNING_SESSIONS.save(storage, id, &session).unwrap();
+        }
+    }
+
+    fn pending_session_ids(storage: &dyn Storage) -> Vec<u64> {
+        SIGNING_SESSIONS
+            .idx
+            .state
+            .prefix(state_index_key(&MultisigState::Pending).to_string())
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    // signing sessions as they were stored by v1.0.0
+    #[cw_serde]
+    struct SigningSession {
+        pub id: Uint64,
+        pub verifier_set_id: String,
+        pub chain_name: ChainName,
+        pub msg: MsgToSign,
+        pub state: MultisigState,
+        pub expires_at: u64,
+        pub sig_verifier: Option<Addr>,
+    }
+
+    const OLD_SIGNING_SESSIONS: Map<u64, SigningSession> = Map::new("signing_sessions");
+}
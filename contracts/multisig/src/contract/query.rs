@@ -1,31 +1,413 @@
+use cosmwasm_std::Order;
+use cw_storage_plus::Bound;
+use itertools::Itertools;
 use router_api::ChainName;
 
 use super::*;
 use crate::key::{KeyType, PublicKey};
+use crate::msg::{
+    PublicKeyHistory, SigningSessionFilter, SigningSessionStatus, SigningSessionsResponse,
+    VerifierSignerStats,
+};
 use crate::multisig::Multisig;
-use crate::state::{load_pub_key, load_session_signatures, AUTHORIZED_CALLERS};
+use crate::signing::SigningSession;
+use crate::state::{
+    load_pending_pub_key, load_pub_key, load_retired_pub_keys, load_session_signatures,
+    state_index_key, SignatureCounts, SignerStats, AGGREGATED_SIGNATURES, AUTHORIZED_CALLERS,
+    SIGNATURES, SIGNATURE_COUNTS, SIGNER_VERIFIER_SETS, VERIFIER_SET_SESSION_COUNTS,
+};
 use crate::verifier_set::VerifierSet;
 
+// Pagination limits
+const DEFAULT_LIMIT: u32 = 30;
+const MAX_LIMIT: u32 = 100;
+// criteria that no index covers are checked on each session, so the number of checked sessions is bounded
+const MAX_CHECKED_SESSIONS: usize = 1000;
+
+type SessionIter<'a> = Box<dyn Iterator<Item = StdResult<(u64, SigningSession)>> + 'a>;
+
 pub fn multisig(deps: Deps, session_id: Uint64) -> StdResult<Multisig> {
     let session = SIGNING_SESSIONS.load(deps.storage, session_id.into())?;
 
     let verifier_set = VERIFIER_SETS.load(deps.storage, &session.verifier_set_id)?;
     let signatures = load_session_signatures(deps.storage, session.id.u64())?;
+    let aggregated_signature = AGGREGATED_SIGNATURES.may_load(deps.storage, session.id.u64())?;
 
     Ok(Multisig {
         state: session.state,
         verifier_set,
         signatures,
+        aggregated_signature,
+    })
+}
+
+pub fn signing_sessions(
+    deps: Deps,
+    block_height: u64,
+    filter: SigningSessionFilter,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> StdResult<SigningSessionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let session_ids = match filter.created_at {
+        Some(created_at) => match session_id_range(deps, created_at)? {
+            Some(session_ids) => Some(session_ids),
+            None => {
+                return Ok(SigningSessionsResponse {
+                    sessions: vec![],
+                    last_checked: None,
+                })
+            }
+        },
+        None => None,
+    };
+    let min = match (start_after, session_ids) {
+        (Some(start_after), Some((first, _))) if start_after.u64() < first => {
+            Some(Bound::inclusive(first))
+        }
+        (Some(start_after), _) => Some(Bound::exclusive(start_after.u64())),
+        (None, Some((first, _))) => Some(Bound::inclusive(first)),
+        (None, None) => None,
+    };
+    let max = session_ids.map(|(_, last)| Bound::inclusive(last));
+
+    // only one index can be used, the remaining criteria are checked on each session
+    let sessions: SessionIter = match (&filter.verifier_set_id, &filter.chain_name, filter.status) {
+        (Some(verifier_set_id), _, _) => Box::new(
+            SIGNING_SESSIONS
+                .idx
+                .verifier_set_id
+                .prefix(verifier_set_id.clone())
+                .range(deps.storage, min, max, Order::Ascending),
+        ),
+        (None, Some(chain_name), _) => Box::new(
+            SIGNING_SESSIONS
+                .idx
+                .chain_name
+                .prefix(chain_name.to_string())
+                .range(deps.storage, min, max, Order::Ascending),
+        ),
+        (None, None, Some(SigningSessionStatus::Completed)) => sessions_by_state(
+            deps,
+            &MultisigState::Completed { completed_at: 0 },
+            min,
+            max,
+        ),
+        (None, None, Some(SigningSessionStatus::Pending)) => {
+            sessions_by_state(deps, &MultisigState::Pending, min, max)
+        }
+        // sessions past their expiry can still be pending if nobody expired them explicitly
+        (None, None, Some(SigningSessionStatus::Expired) | None) => {
+            Box::new(SIGNING_SESSIONS.range(deps.storage, min, max, Order::Ascending))
+        }
+    };
+
+    collect_page(sessions, limit, |session| {
+        Ok(matches_filter(session, &filter, block_height))
+    })
+}
+
+pub fn unsigned_signing_sessions(
+    deps: Deps,
+    block_height: u64,
+    verifier: Addr,
+    start_after: Option<Uint64>,
+    limit: Option<u32>,
+) -> StdResult<SigningSessionsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    // the page continues after the expiry of the last checked session, unless that session is not open anymore
+    let open_from = Bound::inclusive((block_height, u64::MIN));
+    let min = match start_after {
+        Some(session_id) => {
+            let expires_at = SIGNING_SESSIONS
+                .load(deps.storage, session_id.u64())?
+                .expires_at;
+
+            if expires_at < block_height {
+                open_from
+            } else {
+                Bound::exclusive((expires_at, session_id.u64()))
+            }
+        }
+        None => open_from,
+    };
+
+    collect_page(open_sessions_by_expiry(deps, min), limit, |session| {
+        Ok(VERIFIER_SETS
+            .load(deps.storage, &session.verifier_set_id)?
+            .includes(&verifier)
+            && !SIGNATURES.has(deps.storage, (session.id.u64(), verifier.as_str())))
     })
 }
 
+/// Collects the matching sessions until the page is full or the maximum number of sessions has been checked
+fn collect_page(
+    sessions: SessionIter,
+    limit: usize,
+    mut matches: impl FnMut(&SigningSession) -> StdResult<bool>,
+) -> StdResult<SigningSessionsResponse> {
+    let mut page = vec![];
+    let mut last_checked = None;
+
+    for (checked, item) in sessions.enumerate() {
+        // there are sessions left, so the caller has to continue after the last checked one
+        if page.len() == limit || checked == MAX_CHECKED_SESSIONS {
+            return Ok(SigningSessionsResponse {
+                sessions: page,
+                last_checked,
+            });
+        }
+
+        let (session_id, session) = item?;
+        last_checked = Some(Uint64::from(session_id));
+
+        if matches(&session)? {
+            page.push(session);
+        }
+    }
+
+    Ok(SigningSessionsResponse {
+        sessions: page,
+        last_checked: None,
+    })
+}
+
+// session ids increase with the block height the sessions are started at,
+// so the sessions started within a range of block heights have consecutive ids
+fn session_id_range(deps: Deps, (min, max): (u64, u64)) -> StdResult<Option<(u64, u64)>> {
+    let first = SIGNING_SESSIONS
+        .idx
+        .created_at
+        .range(
+            deps.storage,
+            Some(Bound::inclusive((min, u64::MIN))),
+            None,
+            Order::Ascending,
+        )
+        .next()
+        .transpose()?;
+    let last = SIGNING_SESSIONS
+        .idx
+        .created_at
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive((max, u64::MAX))),
+            Order::Descending,
+        )
+        .next()
+        .transpose()?;
+
+    match (first, last) {
+        (Some((first, _)), Some((last, _))) if first <= last => Ok(Some((first, last))),
+        _ => Ok(None),
+    }
+}
+
+fn sessions_by_state<'a>(
+    deps: Deps<'a>,
+    state: &MultisigState,
+    min: Option<Bound<'a, u64>>,
+    max: Option<Bound<'a, u64>>,
+) -> SessionIter<'a> {
+    Box::new(
+        SIGNING_SESSIONS
+            .idx
+            .state
+            .prefix(state_index_key(state).to_string())
+            .range(deps.storage, min, max, Order::Ascending),
+    )
+}
+
+// sessions past their expiry can still be pending if nobody expired them explicitly, so the pending sessions are
+// ranged over by expiry height. The bound must not include heights below the current block height.
+fn open_sessions_by_expiry<'a>(deps: Deps<'a>, min: Bound<'a, (u64, u64)>) -> SessionIter<'a> {
+    Box::new(
+        SIGNING_SESSIONS
+            .idx
+            .state_expires_at
+            .sub_prefix(state_index_key(&MultisigState::Pending).to_string())
+            .range(deps.storage, Some(min), None, Order::Ascending),
+    )
+}
+
+fn matches_filter(
+    session: &SigningSession,
+    filter: &SigningSessionFilter,
+    block_height: u64,
+) -> bool {
+    filter
+        .chain_name
+        .as_ref()
+        .map_or(true, |chain_name| session.chain_name == *chain_name)
+        && filter
+            .verifier_set_id
+            .as_ref()
+            .map_or(true, |verifier_set_id| {
+                session.verifier_set_id == *verifier_set_id
+            })
+        && filter.status.map_or(true, |status_filter| {
+            status(session, block_height) == status_filter
+        })
+        && filter.created_at.map_or(true, |(min, max)| {
+            session
+                .created_at
+                .is_some_and(|created_at| (min..=max).contains(&created_at))
+        })
+}
+
+// sessions can be signed until the block height at which they expire (inclusive)
+fn status(session: &SigningSession, block_height: u64) -> SigningSessionStatus {
+    match session.state {
+        MultisigState::Completed { .. } => SigningSessionStatus::Completed,
+        MultisigState::Expired => SigningSessionStatus::Expired,
+        MultisigState::Pending if session.expires_at < block_height => {
+            SigningSessionStatus::Expired
+        }
+        MultisigState::Pending => SigningSessionStatus::Pending,
+    }
+}
+
 pub fn verifier_set(deps: Deps, verifier_set_id: String) -> StdResult<VerifierSet> {
     VERIFIER_SETS.load(deps.storage, &verifier_set_id)
 }
 
 pub fn public_key(deps: Deps, verifier: Addr, key_type: KeyType) -> StdResult<PublicKey> {
     let raw = load_pub_key(deps.storage, verifier, key_type)?;
-    Ok(PublicKey::try_from((key_type, raw)).expect("could not decode pub key"))
+    Ok(decode_pub_key(key_type, raw))
+}
+
+pub fn pending_public_key(
+    deps: Deps,
+    verifier: Addr,
+    key_type: KeyType,
+) -> StdResult<Option<PublicKey>> {
+    Ok(load_pending_pub_key(deps.storage, verifier, key_type)?
+        .map(|raw| decode_pub_key(key_type, raw)))
+}
+
+pub fn public_key_history(
+    deps: Deps,
+    verifier: Addr,
+    key_type: KeyType,
+) -> StdResult<PublicKeyHistory> {
+    let active = load_pub_key(deps.storage, verifier.clone(), key_type)?;
+    let pending = load_pending_pub_key(deps.storage, verifier.clone(), key_type)?;
+    let retired = load_retired_pub_keys(deps.storage, verifier, key_type)?;
+
+    Ok(PublicKeyHistory {
+        active: decode_pub_key(key_type, active),
+        pending: pending.map(|raw| decode_pub_key(key_type, raw)),
+        retired: retired
+            .into_iter()
+            .map(|raw| decode_pub_key(key_type, raw))
+            .collect(),
+    })
+}
+
+fn decode_pub_key(key_type: KeyType, raw: HexBinary) -> PublicKey {
+    PublicKey::try_from((key_type, raw)).expect("could not decode pub key")
+}
+
+pub fn signer_stats(
+    deps: Deps,
+    block_height: u64,
+    verifier: Addr,
+    chain_name: ChainName,
+) -> StdResult<SignerStats> {
+    let counts = SIGNATURE_COUNTS
+        .may_load(deps.storage, (chain_name.clone(), verifier.clone()))?
+        .unwrap_or_default();
+    let open_sessions = open_sessions(deps, &chain_name, block_height)?;
+
+    derive_signer_stats(deps, &chain_name, &verifier, counts, &open_sessions)
+}
+
+pub fn signer_stats_by_chain(
+    deps: Deps,
+    block_height: u64,
+    chain_name: ChainName,
+    start_after: Option<Addr>,
+    limit: Option<u32>,
+) -> StdResult<Vec<VerifierSignerStats>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let open_sessions = open_sessions(deps, &chain_name, block_height)?;
+
+    SIGNATURE_COUNTS
+        .prefix(chain_name.clone())
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            let (verifier_address, counts) = item?;
+            let stats =
+                derive_signer_stats(deps, &chain_name, &verifier_address, counts, &open_sessions)?;
+
+            Ok(VerifierSignerStats {
+                verifier_address,
+                stats,
+            })
+        })
+        .collect()
+}
+
+// Sessions that are not open anymore and have no signature of the verifier are missed,
+// this includes sessions past their expiry that nobody expired explicitly
+fn derive_signer_stats(
+    deps: Deps,
+    chain_name: &ChainName,
+    verifier: &Addr,
+    counts: SignatureCounts,
+    open_sessions: &[SigningSession],
+) -> StdResult<SignerStats> {
+    let verifier_set_ids: Vec<String> = SIGNER_VERIFIER_SETS
+        .prefix((chain_name.clone(), verifier.clone()))
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    let sessions_eligible = verifier_set_ids
+        .iter()
+        .map(|verifier_set_id| {
+            VERIFIER_SET_SESSION_COUNTS
+                .may_load(deps.storage, (chain_name.clone(), verifier_set_id))
+                .map(Option::unwrap_or_default)
+        })
+        .fold_ok(0u64, u64::saturating_add)?;
+
+    let open_unsigned = open_sessions
+        .iter()
+        .filter(|session| {
+            verifier_set_ids.contains(&session.verifier_set_id)
+                && !SIGNATURES.has(deps.storage, (session.id.u64(), verifier.as_str()))
+        })
+        .count();
+
+    Ok(SignerStats {
+        sessions_eligible,
+        signed_in_time: counts.signed_in_time,
+        signed_late: counts.signed_late,
+        missed: sessions_eligible
+            .saturating_sub(counts.signed_in_time)
+            .saturating_sub(counts.signed_late)
+            .saturating_sub(u64::try_from(open_unsigned).unwrap_or(u64::MAX)),
+    })
+}
+
+fn open_sessions(
+    deps: Deps,
+    chain_name: &ChainName,
+    block_height: u64,
+) -> StdResult<Vec<SigningSession>> {
+    open_sessions_by_expiry(deps, Bound::inclusive((block_height, u64::MIN)))
+        .map_ok(|(_, session)| session)
+        .filter_ok(|session| session.chain_name == *chain_name)
+        .collect()
 }
 
 pub fn caller_authorized(deps: Deps, address: Addr, chain_name: ChainName) -> StdResult<bool> {
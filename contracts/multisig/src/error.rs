@@ -4,6 +4,8 @@ use cw2::VersionError;
 use router_api::ChainName;
 use thiserror::Error;
 
+use crate::key::KeyType;
+
 #[derive(Error, Debug, PartialEq, IntoContractError)]
 pub enum ContractError {
     #[error(transparent)]
@@ -42,6 +44,9 @@ pub enum ContractError {
     #[error("public key is already registered")]
     DuplicatePublicKey,
 
+    #[error("no public key of type {key_type} registered that could be rotated")]
+    NoPublicKeyToRotate { key_type: KeyType },
+
     #[error("invalid message format: {reason:?}")]
     InvalidMessageFormat { reason: String },
 
@@ -51,6 +56,9 @@ pub enum ContractError {
     #[error("signing session {session_id:?} is already closed")]
     SigningSessionClosed { session_id: Uint64 },
 
+    #[error("signing session {session_id:?} has not expired yet")]
+    SigningSessionNotExpired { session_id: Uint64 },
+
     #[error("signing session {session_id:?} not found")]
     SigningSessionNotFound { session_id: Uint64 },
 
@@ -63,6 +71,12 @@ pub enum ContractError {
     #[error("key type mismatch")]
     KeyTypeMismatch,
 
+    #[error("signatures of key type {key_type} cannot be aggregated")]
+    AggregationNotSupported { key_type: KeyType },
+
+    #[error("{signer} is not a signer of the verifier set")]
+    SignerNotInVerifierSet { signer: String },
+
     #[error("caller is not authorized")]
     Unauthorized,
 
@@ -29,10 +29,23 @@ pub enum Event {
         completed_at: u64,
         chain_name: ChainName,
     },
+    // Emitted when a signing session expired without reaching the threshold
+    SigningExpired {
+        session_id: Uint64,
+        verifier_set_id: String,
+        chain_name: ChainName,
+        expires_at: u64,
+        missing_signers: Vec<Addr>,
+    },
     PublicKeyRegistered {
         verifier: Addr,
         public_key: PublicKey,
     },
+    // Emitted when a verifier registers a key that replaces its active key with the next verifier set
+    PublicKeyRotationStarted {
+        verifier: Addr,
+        public_key: PublicKey,
+    },
     CallerAuthorized {
         contract_address: Addr,
         chain_name: ChainName,
@@ -82,6 +95,21 @@ impl From<Event> for cosmwasm_std::Event {
                 .add_attribute("session_id", session_id)
                 .add_attribute("completed_at", completed_at.to_string())
                 .add_attribute("chain", chain_name),
+            Event::SigningExpired {
+                session_id,
+                verifier_set_id,
+                chain_name,
+                expires_at,
+                missing_signers,
+            } => cosmwasm_std::Event::new("signing_expired")
+                .add_attribute("session_id", session_id)
+                .add_attribute("verifier_set_id", verifier_set_id)
+                .add_attribute("chain", chain_name)
+                .add_attribute("expires_at", expires_at.to_string())
+                .add_attribute(
+                    "missing_signers",
+                    to_string(&missing_signers).expect("failed to serialize missing signers"),
+                ),
             Event::PublicKeyRegistered {
                 verifier,
                 public_key,
@@ -94,6 +122,18 @@ impl From<Event> for cosmwasm_std::Event {
                     "public_key",
                     to_string(&public_key).expect("failed to serialize public key"),
                 ),
+            Event::PublicKeyRotationStarted {
+                verifier,
+                public_key,
+            } => cosmwasm_std::Event::new("public_key_rotation_started")
+                .add_attribute(
+                    "verifier",
+                    to_string(&verifier).expect("failed to serialize verifier"),
+                )
+                .add_attribute(
+                    "public_key",
+                    to_string(&public_key).expect("failed to serialize public key"),
+                ),
             Event::CallerAuthorized {
                 contract_address,
                 chain_name,
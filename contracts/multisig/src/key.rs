@@ -7,17 +7,22 @@ use enum_display_derive::Display;
 use serde::de::Error;
 use serde::{Deserialize, Deserializer};
 
+use crate::bls::{self, bls_verify, BLS_SIGNATURE_LEN};
 use crate::ed25519::{ed25519_verify, ED25519_SIGNATURE_LEN};
+use crate::schnorr::{schnorr_verify, SCHNORR_SIGNATURE_LEN};
 use crate::secp256k1::ecdsa_verify;
 use crate::ContractError;
 
 const ECDSA_COMPRESSED_PUBKEY_LEN: usize = 33;
+const SCHNORR_PUBKEY_LEN: usize = 32;
 
 #[cw_serde]
 #[derive(Copy, Display)]
 pub enum KeyType {
     Ecdsa,
     Ed25519,
+    Schnorr,
+    Bls,
 }
 
 #[cw_serde]
@@ -26,6 +31,8 @@ pub enum Signature {
     Ecdsa(NonRecoverable),
     EcdsaRecoverable(Recoverable),
     Ed25519(HexBinary),
+    Schnorr(HexBinary),
+    Bls(HexBinary),
 }
 
 #[cw_serde]
@@ -123,6 +130,14 @@ pub enum PublicKey {
 
     #[serde(deserialize_with = "deserialize_ed25519_key")]
     Ed25519(HexBinary),
+
+    /// Schnorr public key must be in BIP-340 x-only format (32 bytes)
+    #[serde(deserialize_with = "deserialize_schnorr_key")]
+    Schnorr(HexBinary),
+
+    /// BLS12-381 public key must be a compressed G1 point (48 bytes)
+    #[serde(deserialize_with = "deserialize_bls_key")]
+    Bls(HexBinary),
 }
 
 fn deserialize_ecdsa_key<'de, D>(deserializer: D) -> Result<HexBinary, D::Error>
@@ -145,6 +160,26 @@ where
     Ok(pk)
 }
 
+fn deserialize_schnorr_key<'de, D>(deserializer: D) -> Result<HexBinary, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let pk: HexBinary = Deserialize::deserialize(deserializer)?;
+    PublicKey::try_from((KeyType::Schnorr, pk.clone()))
+        .map_err(|e| Error::custom(format!("failed to deserialize public key: {}", e)))?;
+    Ok(pk)
+}
+
+fn deserialize_bls_key<'de, D>(deserializer: D) -> Result<HexBinary, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let pk: HexBinary = Deserialize::deserialize(deserializer)?;
+    PublicKey::try_from((KeyType::Bls, pk.clone()))
+        .map_err(|e| Error::custom(format!("failed to deserialize public key: {}", e)))?;
+    Ok(pk)
+}
+
 pub trait KeyTyped {
     fn matches_type<T>(&self, other: &T) -> bool
     where
@@ -161,6 +196,8 @@ impl KeyTyped for PublicKey {
         match self {
             PublicKey::Ecdsa(_) => KeyType::Ecdsa,
             PublicKey::Ed25519(_) => KeyType::Ed25519,
+            PublicKey::Schnorr(_) => KeyType::Schnorr,
+            PublicKey::Bls(_) => KeyType::Bls,
         }
     }
 }
@@ -170,6 +207,8 @@ impl KeyTyped for Signature {
         match self {
             Signature::Ecdsa(_) | Signature::EcdsaRecoverable(_) => KeyType::Ecdsa,
             Signature::Ed25519(_) => KeyType::Ed25519,
+            Signature::Schnorr(_) => KeyType::Schnorr,
+            Signature::Bls(_) => KeyType::Bls,
         }
     }
 }
@@ -183,6 +222,8 @@ impl Signature {
         let res = match self.key_type() {
             KeyType::Ecdsa => ecdsa_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
             KeyType::Ed25519 => ed25519_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
+            KeyType::Schnorr => schnorr_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
+            KeyType::Bls => bls_verify(msg.as_ref(), self.as_ref(), pub_key.as_ref()),
         }?;
 
         if res {
@@ -195,6 +236,18 @@ impl Signature {
     }
 }
 
+impl KeyType {
+    /// Length of the longest signature encoding of the key type
+    pub fn max_signature_len(&self) -> usize {
+        match self {
+            KeyType::Ecdsa => Recoverable::LEN,
+            KeyType::Ed25519 => ED25519_SIGNATURE_LEN,
+            KeyType::Schnorr => SCHNORR_SIGNATURE_LEN,
+            KeyType::Bls => BLS_SIGNATURE_LEN,
+        }
+    }
+}
+
 impl<'a> PrimaryKey<'a> for KeyType {
     type Prefix = ();
     type SubPrefix = ();
@@ -256,9 +309,39 @@ fn validate_and_normalize_public_key(
         .map_err(|_| ContractError::InvalidPublicKey)?
         .to_bytes()
         .into()),
+        KeyType::Schnorr => Ok(normalize_schnorr_public_key(pub_key)?
+            .to_bytes()
+            .as_slice()
+            .into()),
+        // only the compressed encoding is accepted, so there is a single representation of each key
+        KeyType::Bls => {
+            bls::validate_pub_key(pub_key.as_slice())?;
+            Ok(pub_key)
+        }
     }
 }
 
+// BIP-340 public keys only encode the x coordinate, so compressed SEC1 keys are accepted as well
+// and reduced to their x coordinate. `from_bytes()` rejects x coordinates that are not on the curve.
+fn normalize_schnorr_public_key(
+    pub_key: HexBinary,
+) -> Result<k256::schnorr::VerifyingKey, ContractError> {
+    let x_only = match pub_key.len() {
+        SCHNORR_PUBKEY_LEN => pub_key.as_slice(),
+        ECDSA_COMPRESSED_PUBKEY_LEN => {
+            k256::PublicKey::from_sec1_bytes(pub_key.as_slice())
+                .map_err(|_| ContractError::InvalidPublicKey)?;
+            pub_key
+                .as_slice()
+                .get(1..)
+                .ok_or(ContractError::InvalidPublicKey)?
+        }
+        _ => return Err(ContractError::InvalidPublicKey),
+    };
+
+    k256::schnorr::VerifyingKey::from_bytes(x_only).map_err(|_| ContractError::InvalidPublicKey)
+}
+
 impl TryFrom<(KeyType, HexBinary)> for PublicKey {
     type Error = ContractError;
 
@@ -268,6 +351,8 @@ impl TryFrom<(KeyType, HexBinary)> for PublicKey {
         match key_type {
             KeyType::Ecdsa => Ok(PublicKey::Ecdsa(pub_key)),
             KeyType::Ed25519 => Ok(PublicKey::Ed25519(pub_key)),
+            KeyType::Schnorr => Ok(PublicKey::Schnorr(pub_key)),
+            KeyType::Bls => Ok(PublicKey::Bls(pub_key)),
         }
     }
 }
@@ -280,6 +365,8 @@ impl TryFrom<(KeyType, HexBinary)> for Signature {
             (KeyType::Ecdsa, Recoverable::LEN) => Ok(Signature::EcdsaRecoverable(Recoverable(sig))),
             (KeyType::Ecdsa, NonRecoverable::LEN) => Ok(Signature::Ecdsa(NonRecoverable(sig))),
             (KeyType::Ed25519, ED25519_SIGNATURE_LEN) => Ok(Signature::Ed25519(sig)),
+            (KeyType::Schnorr, SCHNORR_SIGNATURE_LEN) => Ok(Signature::Schnorr(sig)),
+            (KeyType::Bls, BLS_SIGNATURE_LEN) => Ok(Signature::Bls(sig)),
             (_, _) => Err(ContractError::InvalidSignatureFormat {
                 reason: format!(
                     "could not find a match for key type {} and signature length {}",
@@ -296,6 +383,8 @@ impl AsRef<[u8]> for PublicKey {
         match self {
             PublicKey::Ecdsa(pk) => pk.as_ref(),
             PublicKey::Ed25519(pk) => pk.as_ref(),
+            PublicKey::Schnorr(pk) => pk.as_ref(),
+            PublicKey::Bls(pk) => pk.as_ref(),
         }
     }
 }
@@ -306,6 +395,8 @@ impl AsRef<[u8]> for Signature {
             Signature::Ecdsa(sig) => sig.as_ref(),
             Signature::EcdsaRecoverable(sig) => sig.as_ref(),
             Signature::Ed25519(sig) => sig.as_ref(),
+            Signature::Schnorr(sig) => sig.as_ref(),
+            Signature::Bls(sig) => sig.as_ref(),
         }
     }
 }
@@ -315,6 +406,8 @@ impl From<PublicKey> for HexBinary {
         match original {
             PublicKey::Ecdsa(key) => key,
             PublicKey::Ed25519(key) => key,
+            PublicKey::Schnorr(key) => key,
+            PublicKey::Bls(key) => key,
         }
     }
 }
@@ -623,3 +716,180 @@ mod ed25519_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod schnorr_tests {
+    use cosmwasm_std::HexBinary;
+
+    use super::{KeyType, PublicKey};
+    use crate::key::Signature;
+    use crate::test::common::schnorr_test_data;
+    use crate::types::MsgToSign;
+    use crate::ContractError;
+
+    #[test]
+    fn deserialize_schnorr_key() {
+        let key = PublicKey::try_from((KeyType::Schnorr, schnorr_test_data::pub_key())).unwrap();
+
+        let serialized = serde_json::to_string(&key).unwrap();
+        let deserialized: Result<PublicKey, _> = serde_json::from_str(&serialized);
+        assert!(deserialized.is_ok());
+        assert_eq!(deserialized.unwrap(), key);
+    }
+
+    #[test]
+    fn deserialize_schnorr_key_fails() {
+        let key = PublicKey::Schnorr(HexBinary::from_hex("deadbeef").unwrap());
+
+        let serialized = serde_json::to_string(&key).unwrap();
+        let deserialized: Result<PublicKey, _> = serde_json::from_str(&serialized);
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    fn should_normalize_compressed_schnorr_public_key_to_x_only() {
+        let x_only = schnorr_test_data::pub_key();
+
+        for prefix in ["02", "03"] {
+            let compressed =
+                HexBinary::from_hex(&format!("{}{}", prefix, x_only.to_hex())).unwrap();
+            let pub_key = PublicKey::try_from((KeyType::Schnorr, compressed)).unwrap();
+            assert_eq!(HexBinary::from(pub_key), x_only);
+        }
+    }
+
+    #[test]
+    fn should_fail_from_hexbinary_to_schnorr_public_key_if_not_on_curve() {
+        // taken from the BIP-340 test vectors, there is no point on the curve with this x coordinate
+        let pub_key =
+            HexBinary::from_hex("eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34")
+                .unwrap();
+
+        assert_eq!(
+            PublicKey::try_from((KeyType::Schnorr, pub_key)).unwrap_err(),
+            ContractError::InvalidPublicKey
+        );
+    }
+
+    #[test]
+    fn test_try_from_hexbinary_to_signature_fails() {
+        let hex =
+            HexBinary::from_hex(&format!("{}00", schnorr_test_data::signature().to_hex())).unwrap();
+        assert_eq!(
+            Signature::try_from((KeyType::Schnorr, hex)).unwrap_err(),
+            ContractError::InvalidSignatureFormat {
+                reason: "could not find a match for key type Schnorr and signature length 65"
+                    .into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let signature =
+            Signature::try_from((KeyType::Schnorr, schnorr_test_data::signature())).unwrap();
+        let message = MsgToSign::try_from(schnorr_test_data::message()).unwrap();
+        let public_key =
+            PublicKey::try_from((KeyType::Schnorr, schnorr_test_data::pub_key())).unwrap();
+        let result = signature.verify(message, &public_key);
+        assert!(result.is_ok(), "{:?}", result)
+    }
+
+    #[test]
+    fn test_verify_signature_with_wrong_message() {
+        let signature =
+            Signature::try_from((KeyType::Schnorr, schnorr_test_data::signature())).unwrap();
+        let message = MsgToSign::try_from(HexBinary::from([0u8; 32].as_slice())).unwrap();
+        let public_key =
+            PublicKey::try_from((KeyType::Schnorr, schnorr_test_data::pub_key())).unwrap();
+        let result = signature.verify(message, &public_key);
+        assert_eq!(
+            result.unwrap_err(),
+            ContractError::SignatureVerificationFailed {
+                reason: "unable to verify signature".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_fail_sig_verification_if_key_type_mismatch() {
+        let signature =
+            Signature::try_from((KeyType::Schnorr, schnorr_test_data::signature())).unwrap();
+        let message = MsgToSign::try_from(schnorr_test_data::message()).unwrap();
+        let public_key = PublicKey::try_from((
+            KeyType::Ecdsa,
+            HexBinary::from_hex(&format!("02{}", schnorr_test_data::pub_key().to_hex())).unwrap(),
+        ))
+        .unwrap();
+
+        assert_eq!(
+            signature.verify(message, &public_key).unwrap_err(),
+            ContractError::KeyTypeMismatch
+        );
+    }
+}
+
+#[cfg(test)]
+mod bls_tests {
+    use cosmwasm_std::HexBinary;
+
+    use super::{KeyType, PublicKey};
+    use crate::key::Signature;
+    use crate::test::common::bls_test_data;
+    use crate::types::MsgToSign;
+    use crate::ContractError;
+
+    #[test]
+    fn deserialize_bls_key() {
+        let key = PublicKey::try_from((KeyType::Bls, bls_test_data::pub_key())).unwrap();
+
+        let serialized = serde_json::to_string(&key).unwrap();
+        let deserialized: Result<PublicKey, _> = serde_json::from_str(&serialized);
+        assert!(deserialized.is_ok());
+        assert_eq!(deserialized.unwrap(), key);
+    }
+
+    #[test]
+    fn deserialize_bls_key_fails() {
+        let key = PublicKey::Bls(HexBinary::from_hex("deadbeef").unwrap());
+
+        let serialized = serde_json::to_string(&key).unwrap();
+        let deserialized: Result<PublicKey, _> = serde_json::from_str(&serialized);
+        assert!(deserialized.is_err());
+    }
+
+    #[test]
+    fn test_try_from_hexbinary_to_signature_fails() {
+        let hex =
+            HexBinary::from_hex(&format!("{}00", bls_test_data::signature().to_hex())).unwrap();
+        assert_eq!(
+            Signature::try_from((KeyType::Bls, hex)).unwrap_err(),
+            ContractError::InvalidSignatureFormat {
+                reason: "could not find a match for key type Bls and signature length 97".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let signature = Signature::try_from((KeyType::Bls, bls_test_data::signature())).unwrap();
+        let message = MsgToSign::try_from(bls_test_data::message()).unwrap();
+        let public_key = PublicKey::try_from((KeyType::Bls, bls_test_data::pub_key())).unwrap();
+        let result = signature.verify(message, &public_key);
+        assert!(result.is_ok(), "{:?}", result)
+    }
+
+    #[test]
+    fn test_verify_signature_with_wrong_message() {
+        let signature = Signature::try_from((KeyType::Bls, bls_test_data::signature())).unwrap();
+        let message = MsgToSign::try_from(HexBinary::from([0u8; 32].as_slice())).unwrap();
+        let public_key = PublicKey::try_from((KeyType::Bls, bls_test_data::pub_key())).unwrap();
+        let result = signature.verify(message, &public_key);
+        assert_eq!(
+            result.unwrap_err(),
+            ContractError::SignatureVerificationFailed {
+                reason: "unable to verify signature".into(),
+            }
+        );
+    }
+}
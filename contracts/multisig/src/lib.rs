@@ -6,7 +6,7 @@ pub mod events;
 pub mod key;
 pub mod msg;
 pub mod multisig;
-mod signing;
+pub mod signing;
 mod state;
 pub mod types;
 pub mod verifier_set;
@@ -17,6 +17,12 @@ mod secp256k1;
 #[cfg(feature = "ed25519")]
 mod ed25519;
 
+#[cfg(feature = "schnorr")]
+mod schnorr;
+
+#[cfg(feature = "bls")]
+mod bls;
+
 #[cfg(any(test, feature = "test"))]
 pub mod test;
 
@@ -8,14 +8,10 @@ use router_api::ChainName;
 
 use crate::key::{KeyType, PublicKey, Signature};
 use crate::multisig::Multisig;
+use crate::signing::SigningSession;
+use crate::state::SignerStats;
 use crate::verifier_set::VerifierSet;
 
-#[cw_serde]
-pub struct MigrationMsg {
-    pub admin_address: String,
-    pub authorized_callers: HashMap<String, ChainName>,
-}
-
 #[cw_serde]
 pub struct InstantiateMsg {
     /// the governance address is allowed to modify the authorized caller list for this contract
@@ -49,6 +45,11 @@ pub enum ExecuteMsg {
         session_id: Uint64,
         signature: HexBinary,
     },
+    /// Moves a pending signing session past its expiry height to the expired state.
+    /// Reports the verifiers that did not sign, so the caller of the session can construct the proof again.
+    #[permission(Any)]
+    ExpireSigningSession { session_id: Uint64 },
+    /// Pending keys of the verifier set only become active if the sender is authorized to start signing sessions
     #[permission(Any)]
     RegisterVerifierSet { verifier_set: VerifierSet },
     #[permission(Any)]
@@ -58,6 +59,15 @@ pub enum ExecuteMsg {
         /// to sign their own address using the private key
         signed_sender_address: HexBinary,
     },
+    /// Registers a key that replaces the sender's active key of the same type once the first verifier set using it
+    /// is registered by an authorized caller. Until then, the active key keeps being used. Signing sessions of verifier sets that contain
+    /// the replaced key can still be signed with it afterwards.
+    #[permission(Any)]
+    RotatePublicKey {
+        public_key: PublicKey,
+        /// The sender's address signed with the private key of the new public key
+        signed_sender_address: HexBinary,
+    },
     /// Authorizes a set of contracts to call StartSigningSession.
     #[permission(Governance)]
     AuthorizeCallers {
@@ -76,6 +86,11 @@ pub enum ExecuteMsg {
     /// Resumes routing after an emergency shutdown
     #[permission(Elevated)]
     EnableSigning,
+
+    /// Indexes up to `limit` signing sessions started before the migration from v1.0.0, starting with the latest
+    /// session. Sessions are only found by SigningSessions and UnsignedSigningSessions once they are indexed.
+    #[permission(Elevated)]
+    BackfillSigningSessionIndexes { limit: u32 },
 }
 
 #[cw_serde]
@@ -84,6 +99,29 @@ pub enum QueryMsg {
     #[returns(Multisig)]
     Multisig { session_id: Uint64 },
 
+    /// Returns the signing sessions that match all criteria of the filter, ordered by session id.
+    /// The list is paginated by:
+    /// - start_after: the session id after which the page starts
+    /// - limit: the maximum number of sessions returned, 30 by default and at most 100
+    ///
+    /// At most 1000 sessions are checked against the filter per query, so a page can contain fewer sessions
+    /// than the limit even if more matching sessions exist. Continue from `last_checked` of the response.
+    #[returns(SigningSessionsResponse)]
+    SigningSessions {
+        filter: SigningSessionFilter,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the signing sessions that are still open and do not have a signature of the verifier yet,
+    /// ordered by the block height at which they expire. The list is paginated the same way as `SigningSessions`.
+    #[returns(SigningSessionsResponse)]
+    UnsignedSigningSessions {
+        verifier_address: String,
+        start_after: Option<Uint64>,
+        limit: Option<u32>,
+    },
+
     #[returns(VerifierSet)]
     VerifierSet { verifier_set_id: String },
 
@@ -93,11 +131,85 @@ pub enum QueryMsg {
         key_type: KeyType,
     },
 
+    /// Returns the key registered by a rotation that no verifier set uses yet, if there is one.
+    /// New verifier sets should use it instead of the active key.
+    #[returns(Option<PublicKey>)]
+    PendingPublicKey {
+        verifier_address: String,
+        key_type: KeyType,
+    },
+
+    #[returns(PublicKeyHistory)]
+    PublicKeyHistory {
+        verifier_address: String,
+        key_type: KeyType,
+    },
+
     #[returns(bool)]
     IsCallerAuthorized {
         contract_address: String,
         chain_name: ChainName,
     },
+
+    /// Returns the participation of the verifier in the signing sessions of the chain
+    #[returns(SignerStats)]
+    SignerStats {
+        verifier_address: String,
+        chain_name: ChainName,
+    },
+
+    /// Returns the participation of all verifiers in the signing sessions of the chain, ordered by verifier address
+    /// - start_after: the verifier address after which the page starts
+    /// - limit: the maximum number of verifiers returned, 30 by default and at most 100
+    #[returns(Vec<VerifierSignerStats>)]
+    SignerStatsByChain {
+        chain_name: ChainName,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct VerifierSignerStats {
+    pub verifier_address: Addr,
+    pub stats: SignerStats,
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum SigningSessionStatus {
+    /// The session can still be signed
+    Pending,
+    Completed,
+    /// The session expired before it was completed
+    Expired,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct SigningSessionFilter {
+    pub chain_name: Option<ChainName>,
+    pub verifier_set_id: Option<String>,
+    pub status: Option<SigningSessionStatus>,
+    /// Inclusive range of block heights at which the sessions were started
+    pub created_at: Option<(u64, u64)>,
+}
+
+#[cw_serde]
+pub struct SigningSessionsResponse {
+    pub sessions: Vec<SigningSession>,
+    /// Id of the last session that was checked before the page was full or the scan limit was reached.
+    /// Pass it as `start_after` to continue with the next page, it is None once all sessions have been checked.
+    pub last_checked: Option<Uint64>,
+}
+
+#[cw_serde]
+pub struct PublicKeyHistory {
+    pub active: PublicKey,
+    /// Key registered by a rotation that becomes active with the next verifier set
+    pub pending: Option<PublicKey>,
+    /// Previously active keys, oldest first
+    pub retired: Vec<PublicKey>,
 }
 
 #[cw_serde]
@@ -1,19 +1,23 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{HexBinary, Uint128};
 use itertools::Itertools;
 
-use crate::key::Signature;
+use crate::bls;
+use crate::key::{KeyType, KeyTyped, PublicKey, Signature};
 use crate::msg::{Signer, SignerWithSig};
 use crate::types::MultisigState;
 use crate::verifier_set::VerifierSet;
+use crate::ContractError;
 
 #[cw_serde]
 pub struct Multisig {
     pub state: MultisigState,
     pub verifier_set: VerifierSet,
     pub signatures: HashMap<String, Signature>,
+    /// Set when a session of a BLS verifier set is completed
+    pub aggregated_signature: Option<AggregatedSignature>,
 }
 
 impl Multisig {
@@ -37,6 +41,12 @@ impl Multisig {
             .collect()
     }
 
+    /// Aggregates the signatures returned by `optimize_signatures` into a single signature.
+    /// Only signatures of BLS keys can be aggregated.
+    pub fn aggregate_signatures(&self) -> Result<AggregatedSignature, ContractError> {
+        AggregatedSignature::new(&self.verifier_set, &self.optimize_signatures())
+    }
+
     fn signer(&self, address: &str) -> &Signer {
         self.verifier_set
             .signers
@@ -45,15 +55,104 @@ impl Multisig {
     }
 }
 
+/// A single signature that replaces the signatures of all signers marked in the bitmap
+#[cw_serde]
+pub struct AggregatedSignature {
+    /// Bit `i` (least significant bit of each byte first) is set if the `i`-th signer of the verifier set,
+    /// ordered by public key, contributed to the signature
+    pub signers_bitmap: HexBinary,
+    pub signature: HexBinary,
+}
+
+impl AggregatedSignature {
+    pub fn new(
+        verifier_set: &VerifierSet,
+        signers: &[SignerWithSig],
+    ) -> Result<Self, ContractError> {
+        if let Some(signer) = signers.iter().find(|signer| {
+            signer.signature.key_type() != KeyType::Bls
+                || !signer.signature.matches_type(&signer.signer.pub_key)
+        }) {
+            return Err(ContractError::AggregationNotSupported {
+                key_type: signer.signature.key_type(),
+            });
+        }
+
+        if let Some(signer) = signers
+            .iter()
+            .find(|signer| !verifier_set.includes(&signer.signer.address))
+        {
+            return Err(ContractError::SignerNotInVerifierSet {
+                signer: signer.signer.address.to_string(),
+            });
+        }
+
+        let signed: BTreeSet<_> = signers
+            .iter()
+            .map(|signer| &signer.signer.pub_key)
+            .collect();
+        let signers_bitmap = sorted_pub_keys(verifier_set)
+            .map(|pub_key| signed.contains(pub_key))
+            .collect::<Vec<_>>()
+            .chunks(8)
+            .map(|bits| {
+                bits.iter()
+                    .rev()
+                    .fold(0u8, |byte, signed| byte.wrapping_shl(1) | u8::from(*signed))
+            })
+            .collect::<Vec<_>>();
+
+        let signature =
+            bls::aggregate_signatures(signers.iter().map(|signer| signer.signature.as_ref()))?;
+
+        Ok(Self {
+            signers_bitmap: signers_bitmap.into(),
+            signature,
+        })
+    }
+
+    /// Verifies the aggregated signature against the public keys of the signers marked in the bitmap
+    pub fn verify(&self, verifier_set: &VerifierSet, msg: &[u8]) -> Result<(), ContractError> {
+        let bits = self
+            .signers_bitmap
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| byte.wrapping_shr(i) & 1 == 1));
+
+        let pub_keys = sorted_pub_keys(verifier_set)
+            .zip(bits)
+            .filter(|(_, signed)| *signed)
+            .map(|(pub_key, _)| pub_key.as_ref());
+
+        if bls::bls_verify_aggregate(msg, &self.signature, pub_keys)? {
+            Ok(())
+        } else {
+            Err(ContractError::SignatureVerificationFailed {
+                reason: "unable to verify signature".into(),
+            })
+        }
+    }
+}
+
+fn sorted_pub_keys(verifier_set: &VerifierSet) -> impl Iterator<Item = &PublicKey> {
+    verifier_set
+        .signers
+        .values()
+        .map(|signer| &signer.pub_key)
+        .sorted()
+}
+
 #[cfg(test)]
 mod test {
     use cosmwasm_std::{Addr, HexBinary, Uint128};
+    use itertools::Itertools;
 
-    use crate::key::{PublicKey, Signature};
+    use crate::key::{KeyType, PublicKey, Signature};
     use crate::msg::Signer;
-    use crate::multisig::Multisig;
+    use crate::multisig::{AggregatedSignature, Multisig};
+    use crate::test::common::{bls_test_data, build_verifier_set, ecdsa_test_data};
     use crate::types::MultisigState;
     use crate::verifier_set::VerifierSet;
+    use crate::ContractError;
 
     #[test]
     fn optimize_signatures() {
@@ -99,11 +198,137 @@ mod test {
             state: MultisigState::Completed { completed_at: 1 },
             verifier_set,
             signatures: sigs.into_iter().collect(),
+            aggregated_signature: None,
         };
 
         assert_eq!(multisig.optimize_signatures(), expected_optimized_signers);
     }
 
+    fn bls_multisig(signers: &[&str]) -> Multisig {
+        let verifier_set = build_verifier_set(KeyType::Bls, &bls_test_data::signers());
+
+        let signatures = bls_test_data::signers()
+            .into_iter()
+            .filter(|signer| signers.contains(&signer.address.as_str()))
+            .map(|signer| {
+                (
+                    signer.address.to_string(),
+                    Signature::try_from((KeyType::Bls, signer.signature)).unwrap(),
+                )
+            })
+            .collect();
+
+        Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            verifier_set,
+            signatures,
+            aggregated_signature: None,
+        }
+    }
+
+    #[test]
+    fn aggregate_signatures() {
+        // the threshold of the test verifier set is 2 out of 3
+        let multisig = bls_multisig(&["signer1", "signer3"]);
+
+        let aggregated = multisig.aggregate_signatures().unwrap();
+
+        let signed_positions = multisig
+            .verifier_set
+            .signers
+            .values()
+            .sorted_by(|a, b| a.pub_key.cmp(&b.pub_key))
+            .positions(|signer| signer.address == "signer1" || signer.address == "signer3")
+            .fold(0u8, |bitmap, position| {
+                bitmap | 1u8.wrapping_shl(u32::try_from(position).unwrap())
+            });
+        assert_eq!(
+            aggregated.signers_bitmap,
+            HexBinary::from(vec![signed_positions])
+        );
+
+        assert!(aggregated
+            .verify(&multisig.verifier_set, &bls_test_data::message())
+            .is_ok());
+        assert_eq!(
+            aggregated
+                .verify(&multisig.verifier_set, &[0; 32])
+                .unwrap_err(),
+            ContractError::SignatureVerificationFailed {
+                reason: "unable to verify signature".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn aggregated_signature_fails_verification_if_bitmap_is_wrong() {
+        let multisig = bls_multisig(&["signer1", "signer2"]);
+
+        let mut aggregated = multisig.aggregate_signatures().unwrap();
+        aggregated.signers_bitmap = HexBinary::from(vec![0b111]);
+
+        assert!(aggregated
+            .verify(&multisig.verifier_set, &bls_test_data::message())
+            .is_err());
+    }
+
+    #[test]
+    fn aggregated_signature_fails_verification_if_bitmap_is_empty() {
+        let multisig = bls_multisig(&["signer1", "signer2"]);
+
+        let mut aggregated = multisig.aggregate_signatures().unwrap();
+        aggregated.signers_bitmap = HexBinary::from(vec![0]);
+
+        assert!(matches!(
+            aggregated
+                .verify(&multisig.verifier_set, &bls_test_data::message())
+                .unwrap_err(),
+            ContractError::SignatureVerificationFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn aggregate_signatures_fails_for_non_bls_keys() {
+        let signers = ecdsa_test_data::signers();
+        let verifier_set = build_verifier_set(KeyType::Ecdsa, &signers);
+
+        let multisig = Multisig {
+            state: MultisigState::Completed { completed_at: 1 },
+            signatures: signers
+                .iter()
+                .map(|signer| {
+                    (
+                        signer.address.to_string(),
+                        Signature::try_from((KeyType::Ecdsa, signer.signature.clone())).unwrap(),
+                    )
+                })
+                .collect(),
+            verifier_set,
+            aggregated_signature: None,
+        };
+
+        assert_eq!(
+            multisig.aggregate_signatures().unwrap_err(),
+            ContractError::AggregationNotSupported {
+                key_type: KeyType::Ecdsa
+            }
+        );
+    }
+
+    #[test]
+    fn aggregate_signatures_fails_if_signer_not_in_verifier_set() {
+        let multisig = bls_multisig(&["signer1", "signer2"]);
+        let mut signers = multisig.optimize_signatures();
+        signers[0].signer.address = Addr::unchecked("not_a_signer");
+
+        assert_eq!(
+            AggregatedSignature::new(&multisig.verifier_set, &signers).unwrap_err(),
+            ContractError::SignerNotInVerifierSet {
+                signer: "not_a_signer".to_string()
+            }
+        );
+    }
+
     fn signer(address: &str, weight: u64) -> Signer {
         Signer {
             address: Addr::unchecked(address),
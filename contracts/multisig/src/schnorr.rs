@@ -0,0 +1,96 @@
+use k256::schnorr::{Signature, VerifyingKey};
+
+use crate::ContractError;
+
+pub const SCHNORR_SIGNATURE_LEN: usize = 64;
+
+/// Verifies a BIP-340 signature. The public key must be in x-only format (32 bytes).
+pub fn schnorr_verify(msg_hash: &[u8], sig: &[u8], pub_key: &[u8]) -> Result<bool, ContractError> {
+    let pub_key = VerifyingKey::from_bytes(pub_key).map_err(|err| {
+        ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        }
+    })?;
+    let sig =
+        Signature::try_from(sig).map_err(|err| ContractError::SignatureVerificationFailed {
+            reason: err.to_string(),
+        })?;
+
+    Ok(pub_key.verify_raw(msg_hash, &sig).is_ok())
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::HexBinary;
+
+    use super::*;
+    use crate::test::common::schnorr_test_data;
+
+    // test vectors from https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+    #[test]
+    fn should_verify_bip340_test_vectors() {
+        let vectors = [
+            (
+                "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0",
+                true,
+            ),
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a",
+                true,
+            ),
+            // R has an odd y coordinate
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a14602975563cc27944640ac607cd107ae10923d9ef7a73c643e166be5ebeafa34b1ac553e2",
+                false,
+            ),
+        ];
+
+        for (pub_key, message, signature, expected) in vectors {
+            let result = schnorr_verify(
+                &HexBinary::from_hex(message).unwrap(),
+                &HexBinary::from_hex(signature).unwrap(),
+                &HexBinary::from_hex(pub_key).unwrap(),
+            );
+
+            assert_eq!(result.unwrap(), expected, "{}", signature);
+        }
+    }
+
+    #[test]
+    fn should_fail_sig_verification_if_pub_key_not_on_curve() {
+        let pub_key =
+            HexBinary::from_hex("eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34")
+                .unwrap();
+
+        let result = schnorr_verify(
+            &schnorr_test_data::message(),
+            &schnorr_test_data::signature(),
+            &pub_key,
+        );
+        assert!(matches!(
+            result.unwrap_err(),
+            ContractError::SignatureVerificationFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn should_fail_sig_verification_instead_of_truncating() {
+        let sig_with_extra_byte = schnorr_test_data::signature().to_hex() + "00";
+
+        let signature = HexBinary::from_hex(&sig_with_extra_byte).unwrap().to_vec();
+        let message = schnorr_test_data::message().to_vec();
+        let public_key = schnorr_test_data::pub_key().to_vec();
+
+        let result = schnorr_verify(&message, &signature, &public_key);
+        assert!(matches!(
+            result.unwrap_err(),
+            ContractError::SignatureVerificationFailed { .. }
+        ));
+    }
+}
@@ -17,6 +17,8 @@ pub struct SigningSession {
     pub chain_name: ChainName,
     pub msg: MsgToSign,
     pub state: MultisigState,
+    /// Block height at which the session was started, unknown for sessions started before multisig v1.1.0
+    pub created_at: Option<u64>,
     pub expires_at: u64,
     pub sig_verifier: Option<Addr>,
 }
@@ -27,6 +29,7 @@ impl SigningSession {
         verifier_set_id: String,
         chain_name: ChainName,
         msg: MsgToSign,
+        created_at: u64,
         expires_at: u64,
         sig_verifier: Option<Addr>,
     ) -> Self {
@@ -36,6 +39,7 @@ impl SigningSession {
             chain_name,
             msg,
             state: MultisigState::Pending,
+            created_at: Some(created_at),
             expires_at,
             sig_verifier,
         }
@@ -65,7 +69,7 @@ pub fn validate_session_signature(
     block_height: u64,
     sig_verifier: Option<SignatureVerifier>,
 ) -> Result<(), ContractError> {
-    if session.expires_at < block_height {
+    if session.expires_at < block_height || session.state == MultisigState::Expired {
         return Err(ContractError::SigningSessionClosed {
             session_id: session.id,
         });
@@ -153,12 +157,14 @@ mod tests {
         let verifier_set = build_verifier_set(KeyType::Ecdsa, &signers);
 
         let message: MsgToSign = ecdsa_test_data::message().try_into().unwrap();
+        let created_at = 12245;
         let expires_at = 12345;
         let session = SigningSession::new(
             Uint64::one(),
             verifier_set_id,
             "mock-chain".parse().unwrap(),
             message.clone(),
+            created_at,
             expires_at,
             None,
         );
@@ -189,12 +195,14 @@ mod tests {
         let verifier_set = build_verifier_set(key_type, &signers);
 
         let message: MsgToSign = ed25519_test_data::message().try_into().unwrap();
+        let created_at = 12245;
         let expires_at = 12345;
         let session = SigningSession::new(
             Uint64::one(),
             verifier_set_id,
             "mock-chain".parse().unwrap(),
             message.clone(),
+            created_at,
             expires_at,
             None,
         );
@@ -375,6 +383,8 @@ mod tests {
             let sig_bytes = match config.key_type {
                 KeyType::Ecdsa =>   "a58c9543b9df54578ec45838948e19afb1c6e4c86b34d9899b10b44e619ea74e19b457611e41a047030ed233af437d7ecff84de97cb6b3c13d73d22874e03511",
                 KeyType::Ed25519 => "1fe264eb7258d48d8feedea4d237ccb20157fbe5eb412bc971d758d072b036a99b06d20853c1f23cdf82085917e08dda2fcfbb5d4d7ee17d74e4988ae81d0308",
+                KeyType::Schnorr => "fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a14602975563cc27944640ac607cd107ae10923d9ef7a73c643e166be5ebeafa34b1ac553e2",
+                KeyType::Bls =>     "c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
             };
 
             let invalid_sig: Signature = (config.key_type, HexBinary::from_hex(sig_bytes).unwrap())
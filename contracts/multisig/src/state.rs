@@ -3,11 +3,13 @@ use std::collections::HashMap;
 use axelar_wasm_std::nonempty;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, HexBinary, Order, StdResult, Storage, Uint64};
-use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, UniqueIndex};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex};
 use router_api::ChainName;
 
 use crate::key::{KeyType, KeyTyped, PublicKey, Signature};
+use crate::multisig::AggregatedSignature;
 use crate::signing::SigningSession;
+use crate::types::MultisigState;
 use crate::verifier_set::VerifierSet;
 use crate::ContractError;
 
@@ -17,17 +19,160 @@ pub struct Config {
     pub block_expiry: nonempty::Uint64, // number of blocks after which a signing session expires
 }
 
+/// Participation of a verifier in the signing sessions of a chain
+#[cw_serde]
+#[derive(Default)]
+pub struct SignerStats {
+    /// Sessions the verifier was part of the verifier set of
+    pub sessions_eligible: u64,
+    /// Signatures submitted while the session was still pending
+    pub signed_in_time: u64,
+    /// Signatures submitted after the session was completed
+    pub signed_late: u64,
+    /// Sessions that were completed or expired without a signature of the verifier.
+    /// A late signature removes the session from this count again.
+    pub missed: u64,
+}
+
+/// Signatures a verifier submitted in the signing sessions of a chain.
+/// The remaining stats are derived from the sessions of the verifier's verifier sets when they are queried.
+#[cw_serde]
+#[derive(Default)]
+pub struct SignatureCounts {
+    pub signed_in_time: u64,
+    pub signed_late: u64,
+}
+
 type VerifierSetId = str;
 
 pub const CONFIG: Item<Config> = Item::new("config");
 pub const SIGNING_SESSION_COUNTER: Item<Uint64> = Item::new("signing_session_counter");
-pub const SIGNING_SESSIONS: Map<u64, SigningSession> = Map::new("signing_sessions");
+pub const SIGNATURE_COUNTS: Map<(ChainName, Addr), SignatureCounts> = Map::new("signature_counts");
+/// Number of signing sessions started for each verifier set on a chain
+pub const VERIFIER_SET_SESSION_COUNTS: Map<(ChainName, &VerifierSetId), u64> =
+    Map::new("verifier_set_session_counts");
+/// Verifier sets of a verifier that signing sessions were started for on a chain
+pub const SIGNER_VERIFIER_SETS: Map<(ChainName, Addr, &VerifierSetId), ()> =
+    Map::new("signer_verifier_sets");
+pub const SIGNING_SESSIONS: IndexedMap<u64, SigningSession, SigningSessionIndexes> =
+    IndexedMap::new(
+        "signing_sessions",
+        SigningSessionIndexes {
+            chain_name: MultiIndex::new(
+                |_, session: &SigningSession| session.chain_name.to_string(),
+                "signing_sessions",
+                "signing_sessions__chain_name",
+            ),
+            verifier_set_id: MultiIndex::new(
+                |_, session: &SigningSession| session.verifier_set_id.clone(),
+                "signing_sessions",
+                "signing_sessions__verifier_set_id",
+            ),
+            state: MultiIndex::new(
+                |_, session: &SigningSession| state_index_key(&session.state).to_string(),
+                "signing_sessions",
+                "signing_sessions__state",
+            ),
+            // the expiry height orders the sessions of a state by how long they stay open
+            state_expires_at: MultiIndex::new(
+                |_, session: &SigningSession| {
+                    (
+                        state_index_key(&session.state).to_string(),
+                        session.expires_at,
+                    )
+                },
+                "signing_sessions",
+                "signing_sessions__state_expires_at",
+            ),
+            // sessions started before the block height was stored are indexed at height 0
+            created_at: MultiIndex::new(
+                |_, session: &SigningSession| session.created_at.unwrap_or_default(),
+                "signing_sessions",
+                "signing_sessions__created_at",
+            ),
+        },
+    );
 // The keys represent the addresses that can start a signing session.
 pub const AUTHORIZED_CALLERS: Map<&Addr, ChainName> = Map::new("authorized_callers");
 pub const VERIFIER_SETS: Map<&VerifierSetId, VerifierSet> = Map::new("verifier_sets");
 
+pub struct SigningSessionIndexes<'a> {
+    pub chain_name: MultiIndex<'a, String, SigningSession, u64>,
+    pub verifier_set_id: MultiIndex<'a, String, SigningSession, u64>,
+    pub state: MultiIndex<'a, String, SigningSession, u64>,
+    pub state_expires_at: MultiIndex<'a, (String, u64), SigningSession, u64>,
+    pub created_at: MultiIndex<'a, u64, SigningSession, u64>,
+}
+
+impl IndexList<SigningSession> for SigningSessionIndexes<'_> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<SigningSession>> + '_> {
+        let v: Vec<&dyn Index<SigningSession>> = vec![
+            &self.chain_name,
+            &self.verifier_set_id,
+            &self.state,
+            &self.state_expires_at,
+            &self.created_at,
+        ];
+        Box::new(v.into_iter())
+    }
+}
+
+// the block height of completion is not part of the key, so all completed sessions share one index entry
+pub fn state_index_key(state: &MultisigState) -> &'static str {
+    match state {
+        MultisigState::Pending => "pending",
+        MultisigState::Completed { .. } => "completed",
+        MultisigState::Expired => "expired",
+    }
+}
+
+// signing sessions started before the indexes existed with an id lower than the cursor are not indexed yet
+const SIGNING_SESSION_INDEX_BACKFILL_CURSOR: Item<u64> =
+    Item::new("signing_session_index_backfill_cursor");
+
+pub fn start_signing_session_index_backfill(storage: &mut dyn Storage) -> StdResult<()> {
+    SIGNING_SESSION_INDEX_BACKFILL_CURSOR.save(storage, &u64::MAX)
+}
+
+/// Indexes up to `limit` of the not yet indexed signing sessions, starting with the latest session.
+/// Returns the number of indexed sessions. Once all sessions are indexed, the backfill stops.
+pub fn backfill_signing_session_indexes(
+    storage: &mut dyn Storage,
+    limit: usize,
+) -> StdResult<usize> {
+    let Some(cursor) = SIGNING_SESSION_INDEX_BACKFILL_CURSOR.may_load(storage)? else {
+        return Ok(0);
+    };
+
+    let sessions = SIGNING_SESSIONS
+        .range(
+            storage,
+            None,
+            Some(Bound::exclusive(cursor)),
+            Order::Descending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // sessions that were updated since the migration are indexed already, so their entries are replaced
+    for (session_id, session) in &sessions {
+        SIGNING_SESSIONS.replace(storage, *session_id, Some(session), Some(session))?;
+    }
+
+    match sessions.last() {
+        Some((session_id, _)) if sessions.len() == limit => {
+            SIGNING_SESSION_INDEX_BACKFILL_CURSOR.save(storage, session_id)?
+        }
+        _ => SIGNING_SESSION_INDEX_BACKFILL_CURSOR.remove(storage),
+    }
+
+    Ok(sessions.len())
+}
+
 /// Signatures by session id and signer address
 pub const SIGNATURES: Map<(u64, &str), Signature> = Map::new("signatures");
+/// Aggregated signatures of completed sessions of BLS verifier sets by session id
+pub const AGGREGATED_SIGNATURES: Map<u64, AggregatedSignature> = Map::new("aggregated_signatures");
 
 pub fn load_session_signatures(
     store: &dyn Storage,
@@ -60,6 +205,52 @@ pub fn save_signature(
     )
 }
 
+/// Counts a signing session for its verifier set. The signers of the verifier set are only recorded
+/// with the first session of the verifier set on the chain, so a session doesn't need a write for each signer.
+pub fn record_session_started(
+    store: &mut dyn Storage,
+    chain_name: &ChainName,
+    verifier_set_id: &VerifierSetId,
+    verifier_set: &VerifierSet,
+) -> StdResult<()> {
+    let key = (chain_name.clone(), verifier_set_id);
+    let count = VERIFIER_SET_SESSION_COUNTS.may_load(store, key.clone())?;
+
+    if count.is_none() {
+        for signer in verifier_set.signers.values() {
+            SIGNER_VERIFIER_SETS.save(
+                store,
+                (chain_name.clone(), signer.address.clone(), verifier_set_id),
+                &(),
+            )?;
+
+            let key = (chain_name.clone(), signer.address.clone());
+            if !SIGNATURE_COUNTS.has(store, key.clone()) {
+                SIGNATURE_COUNTS.save(store, key, &SignatureCounts::default())?;
+            }
+        }
+    }
+
+    VERIFIER_SET_SESSION_COUNTS.save(store, key, &count.unwrap_or_default().saturating_add(1))
+}
+
+pub fn update_signature_counts(
+    store: &mut dyn Storage,
+    chain_name: &ChainName,
+    signer: &Addr,
+    update: impl FnOnce(&mut SignatureCounts),
+) -> StdResult<SignatureCounts> {
+    SIGNATURE_COUNTS.update(
+        store,
+        (chain_name.clone(), signer.clone()),
+        |counts| -> StdResult<SignatureCounts> {
+            let mut counts = counts.unwrap_or_default();
+            update(&mut counts);
+            Ok(counts)
+        },
+    )
+}
+
 pub fn verifier_set(
     store: &dyn Storage,
     verifier_set_id: &str,
@@ -91,25 +282,129 @@ pub fn pub_keys<'a>() -> IndexedMap<'a, (Addr, KeyType), HexBinary, PubKeysIndex
     IndexedMap::new("pub_keys", indexes)
 }
 
+// keys registered by a rotation, they replace the active key once a verifier set using them is registered
+pub fn pending_pub_keys<'a>() -> IndexedMap<'a, (Addr, KeyType), HexBinary, PubKeysIndexes<'a>> {
+    let indexes = PubKeysIndexes {
+        pub_key: UniqueIndex::new(|p| p.to_vec(), "pending_pub_key__unique"),
+    };
+
+    IndexedMap::new("pending_pub_keys", indexes)
+}
+
+/// Keys that were replaced by a newer key, oldest first
+pub const RETIRED_PUB_KEYS: Map<(Addr, KeyType), Vec<HexBinary>> = Map::new("retired_pub_keys");
+/// Owners of retired keys, so retired keys can't be registered again
+const RETIRED_PUB_KEY_OWNERS: Map<&[u8], Addr> = Map::new("retired_pub_key_owners");
+
 pub fn load_pub_key(store: &dyn Storage, signer: Addr, key_type: KeyType) -> StdResult<HexBinary> {
     pub_keys().load(store, (signer, key_type))
 }
 
+pub fn load_pending_pub_key(
+    store: &dyn Storage,
+    signer: Addr,
+    key_type: KeyType,
+) -> StdResult<Option<HexBinary>> {
+    pending_pub_keys().may_load(store, (signer, key_type))
+}
+
+pub fn load_retired_pub_keys(
+    store: &dyn Storage,
+    signer: Addr,
+    key_type: KeyType,
+) -> StdResult<Vec<HexBinary>> {
+    Ok(RETIRED_PUB_KEYS
+        .may_load(store, (signer, key_type))?
+        .unwrap_or_default())
+}
+
 pub fn save_pub_key(
     store: &mut dyn Storage,
     signer: Addr,
     pub_key: PublicKey,
 ) -> Result<(), ContractError> {
+    let key = (signer, pub_key.key_type());
+    let pub_key = HexBinary::from(pub_key);
+
+    ensure_unique_pub_key(store, &pub_key)?;
+
+    activate_pub_key(store, key, pub_key)
+}
+
+/// Saves a key that replaces the active key of the same type once a verifier set using it is registered.
+/// A previous pending key of the signer is overwritten.
+pub fn save_pending_pub_key(
+    store: &mut dyn Storage,
+    signer: Addr,
+    pub_key: PublicKey,
+) -> Result<(), ContractError> {
+    let key_type = pub_key.key_type();
+    let key = (signer, key_type);
+    let pub_key = HexBinary::from(pub_key);
+
+    if pub_keys().may_load(store, key.clone())?.is_none() {
+        return Err(ContractError::NoPublicKeyToRotate { key_type });
+    }
+
+    ensure_unique_pub_key(store, &pub_key)?;
+
+    Ok(pending_pub_keys().save(store, key, &pub_key)?)
+}
+
+/// Replaces the active key of the signer with the pending key if it matches the given key.
+/// Returns true if the key was activated.
+pub fn activate_pending_pub_key(
+    store: &mut dyn Storage,
+    signer: Addr,
+    pub_key: &PublicKey,
+) -> Result<bool, ContractError> {
+    let key = (signer, pub_key.key_type());
+
+    match pending_pub_keys().may_load(store, key.clone())? {
+        Some(pending) if pending.as_slice() == pub_key.as_ref() => {
+            pending_pub_keys().remove(store, key.clone())?;
+            activate_pub_key(store, key, pending)?;
+
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+fn activate_pub_key(
+    store: &mut dyn Storage,
+    key: (Addr, KeyType),
+    pub_key: HexBinary,
+) -> Result<(), ContractError> {
+    if let Some(previous) = pub_keys().may_load(store, key.clone())? {
+        RETIRED_PUB_KEY_OWNERS.save(store, previous.as_slice(), &key.0)?;
+        RETIRED_PUB_KEYS.update(store, key.clone(), |retired| -> StdResult<_> {
+            let mut retired = retired.unwrap_or_default();
+            retired.push(previous);
+            Ok(retired)
+        })?;
+    }
+
+    Ok(pub_keys().save(store, key, &pub_key)?)
+}
+
+fn ensure_unique_pub_key(store: &dyn Storage, pub_key: &HexBinary) -> Result<(), ContractError> {
     if pub_keys()
         .idx
         .pub_key
-        .item(store, HexBinary::from(pub_key.clone()).into())?
+        .item(store, pub_key.to_vec())?
         .is_some()
+        || pending_pub_keys()
+            .idx
+            .pub_key
+            .item(store, pub_key.to_vec())?
+            .is_some()
+        || RETIRED_PUB_KEY_OWNERS.has(store, pub_key.as_slice())
     {
         return Err(ContractError::DuplicatePublicKey);
     }
 
-    Ok(pub_keys().save(store, (signer, pub_key.key_type()), &pub_key.into())?)
+    Ok(())
 }
 
 #[cfg(test)]
@@ -158,6 +453,159 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn should_activate_pending_public_key() {
+        let mut deps = mock_dependencies();
+        let signer = Addr::unchecked("signer");
+        let pub_key: PublicKey = (KeyType::Ecdsa, ecdsa_test_data::pub_key())
+            .try_into()
+            .unwrap();
+        let new_pub_key: PublicKey = (KeyType::Ecdsa, ecdsa_test_data::signers().remove(1).pub_key)
+            .try_into()
+            .unwrap();
+
+        assert_eq!(
+            save_pending_pub_key(deps.as_mut().storage, signer.clone(), new_pub_key.clone())
+                .unwrap_err(),
+            ContractError::NoPublicKeyToRotate {
+                key_type: KeyType::Ecdsa
+            }
+        );
+
+        save_pub_key(deps.as_mut().storage, signer.clone(), pub_key.clone()).unwrap();
+        save_pending_pub_key(deps.as_mut().storage, signer.clone(), new_pub_key.clone()).unwrap();
+
+        // the active key stays in place until the pending key is used
+        assert!(
+            !activate_pending_pub_key(deps.as_mut().storage, signer.clone(), &pub_key).unwrap()
+        );
+        assert_eq!(
+            load_pub_key(deps.as_ref().storage, signer.clone(), KeyType::Ecdsa).unwrap(),
+            HexBinary::from(pub_key.clone())
+        );
+
+        assert!(
+            activate_pending_pub_key(deps.as_mut().storage, signer.clone(), &new_pub_key).unwrap()
+        );
+        assert_eq!(
+            load_pub_key(deps.as_ref().storage, signer.clone(), KeyType::Ecdsa).unwrap(),
+            HexBinary::from(new_pub_key)
+        );
+        assert_eq!(
+            load_pending_pub_key(deps.as_ref().storage, signer.clone(), KeyType::Ecdsa).unwrap(),
+            None
+        );
+        assert_eq!(
+            load_retired_pub_keys(deps.as_ref().storage, signer, KeyType::Ecdsa).unwrap(),
+            vec![HexBinary::from(pub_key)]
+        );
+    }
+
+    #[test]
+    fn should_fail_if_pending_public_key_is_duplicate() {
+        let mut deps = mock_dependencies();
+        let signers = ecdsa_test_data::signers();
+        let pub_keys: Vec<PublicKey> = signers
+            .iter()
+            .map(|signer| (KeyType::Ecdsa, signer.pub_key.clone()).try_into().unwrap())
+            .collect();
+
+        for (signer, pub_key) in signers.iter().zip(&pub_keys).take(2) {
+            save_pub_key(
+                deps.as_mut().storage,
+                signer.address.clone(),
+                pub_key.clone(),
+            )
+            .unwrap();
+        }
+
+        // an active key cannot become a pending key
+        assert_eq!(
+            save_pending_pub_key(
+                deps.as_mut().storage,
+                signers[0].address.clone(),
+                pub_keys[1].clone()
+            )
+            .unwrap_err(),
+            ContractError::DuplicatePublicKey
+        );
+
+        // a pending key cannot be registered by anyone else
+        save_pending_pub_key(
+            deps.as_mut().storage,
+            signers[0].address.clone(),
+            pub_keys[2].clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            save_pending_pub_key(
+                deps.as_mut().storage,
+                signers[1].address.clone(),
+                pub_keys[2].clone()
+            )
+            .unwrap_err(),
+            ContractError::DuplicatePublicKey
+        );
+        assert_eq!(
+            save_pub_key(
+                deps.as_mut().storage,
+                signers[2].address.clone(),
+                pub_keys[2].clone()
+            )
+            .unwrap_err(),
+            ContractError::DuplicatePublicKey
+        );
+    }
+
+    #[test]
+    fn should_fail_if_public_key_was_retired() {
+        let mut deps = mock_dependencies();
+        let signers = ecdsa_test_data::signers();
+        let pub_keys: Vec<PublicKey> = signers
+            .iter()
+            .map(|signer| (KeyType::Ecdsa, signer.pub_key.clone()).try_into().unwrap())
+            .collect();
+
+        save_pub_key(
+            deps.as_mut().storage,
+            signers[0].address.clone(),
+            pub_keys[0].clone(),
+        )
+        .unwrap();
+        save_pending_pub_key(
+            deps.as_mut().storage,
+            signers[0].address.clone(),
+            pub_keys[1].clone(),
+        )
+        .unwrap();
+        assert!(activate_pending_pub_key(
+            deps.as_mut().storage,
+            signers[0].address.clone(),
+            &pub_keys[1]
+        )
+        .unwrap());
+
+        // neither the previous owner nor anyone else can use the retired key again
+        assert_eq!(
+            save_pending_pub_key(
+                deps.as_mut().storage,
+                signers[0].address.clone(),
+                pub_keys[0].clone()
+            )
+            .unwrap_err(),
+            ContractError::DuplicatePublicKey
+        );
+        assert_eq!(
+            save_pub_key(
+                deps.as_mut().storage,
+                signers[2].address.clone(),
+                pub_keys[0].clone()
+            )
+            .unwrap_err(),
+            ContractError::DuplicatePublicKey
+        );
+    }
+
     #[test]
     fn test_save_and_load_signatures() {
         let mut deps = mock_dependencies();
@@ -126,6 +126,104 @@ pub mod ed25519_test_data {
     }
 }
 
+pub mod schnorr_test_data {
+    use super::*;
+
+    pub fn pub_key() -> HexBinary {
+        HexBinary::from_hex("76060560c05acfeae7094f64de3660a144df4857a69e9102590197635063d869")
+            .unwrap()
+    }
+
+    pub fn signature() -> HexBinary {
+        HexBinary::from_hex("11b042ddfdd178205920eaed94b7db78a34010634e68eb05ba6ecae3a8b5bf24bc6b5293cd7a9550779da29ba32b7c688b97518f605092e77d43545fca4135cf")
+            .unwrap()
+    }
+
+    pub fn message() -> HexBinary {
+        HexBinary::from_hex("fa0609efd1dfeedfdcc8ba51520fae2d5176b7621d2560f071e801b0817e1537")
+            .unwrap()
+    }
+
+    pub fn signers() -> Vec<TestSigner> {
+        vec![
+            TestSigner {
+                address: Addr::unchecked("signer1"),
+                pub_key: HexBinary::from_hex("76060560c05acfeae7094f64de3660a144df4857a69e9102590197635063d869")
+            .unwrap(),
+                signature: HexBinary::from_hex("11b042ddfdd178205920eaed94b7db78a34010634e68eb05ba6ecae3a8b5bf24bc6b5293cd7a9550779da29ba32b7c688b97518f605092e77d43545fca4135cf")
+            .unwrap(),
+                signed_address: HexBinary::from_hex(
+                    "6b8f28b3409e5c7a79598e968bd01fb7cbe01f5fed015b3dc8f7b605f780cede4dded60f43c05a5ade27c25b87e6a06ed518fa9a882371e1afb65c841e205279",
+                )
+                .unwrap(),
+            },
+            TestSigner {
+                address: Addr::unchecked("signer2"),
+                pub_key: HexBinary::from_hex("98a5d81686f6d35fccee2ef96cb0951efe98bbe5283f90d754e3c4303674ce81")
+            .unwrap(),
+                signature: HexBinary::from_hex("86baf96d8a463ce30baaca147b80786565d58f94b694d751286de2faedca5f5fba57f0077338680ab89387e6d8233c13fc47644af391d1f97ff6a429390a2bf4")
+            .unwrap(),
+                signed_address: HexBinary::from_hex(
+                    "ee014fe923d1290a647fbcbb728efcf235b12657832683e56dac8c2daa1d2c2c9a74ff13ff7ef1e81480110bea5fc4b96dcb8a6fb0e4bdaccea83114d0497c02",
+                )
+                .unwrap(),
+            },
+            TestSigner {
+                address: Addr::unchecked("signer3"),
+                pub_key: HexBinary::from_hex("059236000a2a2fe2b83026756d78e614f0432b64bb7c673801e7872c92448ee0")
+            .unwrap(),
+                signature: HexBinary::from_hex("8ee29530cbdbdc2f93608b2072228d13503222ac2cb03c37bd5399deb1318cd01c79e448cb8c03fb199d74b91517bf1c6296a461999389f08a474625f0b663c7")
+            .unwrap(),
+                signed_address: HexBinary::from_hex(
+                    "975dcc126ef78d9dda34ed247c9e29aa7c6dc966d894704a36fd73595e98afef6c23573865a41ebaa6258aacf5e9bed9ba24eef52168fcbef274bc1c0656ea4d",
+                )
+                .unwrap(),
+            },
+        ]
+    }
+}
+
+// BLS signatures are deterministic, so the test data is derived from fixed secret keys
+pub mod bls_test_data {
+    use sha3::{Digest, Keccak256};
+
+    use super::*;
+    pub use crate::bls::test_utils::sign;
+
+    const SECRET_KEYS: [(&str, u64); 3] = [("signer1", 1), ("signer2", 2), ("signer3", 3)];
+
+    pub fn pub_key() -> HexBinary {
+        signers()[0].pub_key.clone()
+    }
+
+    pub fn signature() -> HexBinary {
+        signers()[0].signature.clone()
+    }
+
+    pub fn message() -> HexBinary {
+        HexBinary::from_hex("fa0609efd1dfeedfdcc8ba51520fae2d5176b7621d2560f071e801b0817e1537")
+            .unwrap()
+    }
+
+    pub fn signers() -> Vec<TestSigner> {
+        SECRET_KEYS
+            .into_iter()
+            .map(|(address, secret_key)| {
+                let (pub_key, signature) = sign(secret_key, &message());
+                let (_, signed_address) =
+                    sign(secret_key, Keccak256::digest(address.as_bytes()).as_slice());
+
+                TestSigner {
+                    address: Addr::unchecked(address),
+                    pub_key,
+                    signature,
+                    signed_address,
+                }
+            })
+            .collect()
+    }
+}
+
 #[allow(clippy::arithmetic_side_effects)]
 pub fn build_verifier_set(key_type: KeyType, signers: &[TestSigner]) -> VerifierSet {
     let mut total_weight = Uint128::zero();
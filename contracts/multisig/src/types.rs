@@ -31,6 +31,8 @@ pub enum MultisigState {
     Completed {
         completed_at: u64, // block at which the session was completed
     },
+    // the session did not reach the threshold before it expired
+    Expired,
 }
 
 const MESSAGE_HASH_LEN: usize = 32;
@@ -4,9 +4,10 @@ use axelar_wasm_std::hash::Hash;
 use axelar_wasm_std::Participant;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, HexBinary, Uint128};
+use itertools::Itertools;
 use sha3::{Digest, Keccak256};
 
-use crate::key::PublicKey;
+use crate::key::{KeyType, KeyTyped, PublicKey};
 use crate::msg::Signer;
 
 #[cw_serde]
@@ -78,6 +79,15 @@ impl VerifierSet {
     pub fn includes(&self, signer: &Addr) -> bool {
         self.signers.contains_key(signer.as_str())
     }
+
+    /// Returns the key type of the signers if all of them share the same one
+    pub fn key_type(&self) -> Option<KeyType> {
+        self.signers
+            .values()
+            .map(|signer| signer.pub_key.key_type())
+            .all_equal_value()
+            .ok()
+    }
 }
 
 #[cfg(test)]
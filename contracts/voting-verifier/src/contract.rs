@@ -121,8 +121,8 @@ mod test {
     use assert_ok::assert_ok;
     use axelar_wasm_std::address::AddressFormat;
     use axelar_wasm_std::msg_id::{
-        Base58SolanaTxSignatureAndEventIndex, Base58TxDigestAndEventIndex, HexTxHash,
-        HexTxHashAndEventIndex, MessageIdFormat,
+        Base58SolanaTxSignatureAndEventIndex, Base58TxDigestAndEventIndex,
+        FieldElementAndEventIndex, HexTxHash, HexTxHashAndEventIndex, MessageIdFormat, XrplTxHash,
     };
     use axelar_wasm_std::voting::Vote;
     use axelar_wasm_std::{
@@ -263,6 +263,25 @@ mod test {
             .to_string()
             .parse()
             .unwrap(),
+            MessageIdFormat::FieldElementAndEventIndex => {
+                let mut tx_hash: [u8; 32] = Keccak256::digest(id.as_bytes()).into();
+                // keep the hash below 2^251 so it is a valid field element
+                tx_hash[0] &= 0x07;
+
+                FieldElementAndEventIndex {
+                    tx_hash,
+                    event_index: index,
+                }
+                .to_string()
+                .parse()
+                .unwrap()
+            }
+            MessageIdFormat::XrplTxHash => XrplTxHash {
+                tx_hash: Keccak256::digest(id.as_bytes()).into(),
+            }
+            .to_string()
+            .parse()
+            .unwrap(),
         }
     }
 
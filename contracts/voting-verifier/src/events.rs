@@ -2,8 +2,8 @@ use std::str::FromStr;
 use std::vec::Vec;
 
 use axelar_wasm_std::msg_id::{
-    Base58SolanaTxSignatureAndEventIndex, Base58TxDigestAndEventIndex, HexTxHash,
-    HexTxHashAndEventIndex, MessageIdFormat,
+    Base58SolanaTxSignatureAndEventIndex, Base58TxDigestAndEventIndex, FieldElementAndEventIndex,
+    HexTxHash, HexTxHashAndEventIndex, MessageIdFormat, XrplTxHash,
 };
 use axelar_wasm_std::voting::{PollId, Vote};
 use axelar_wasm_std::{nonempty, VerificationStatus};
@@ -170,6 +170,18 @@ fn parse_message_id(
 
             Ok((id.tx_hash_as_hex(), 0))
         }
+        MessageIdFormat::FieldElementAndEventIndex => {
+            let id = FieldElementAndEventIndex::from_str(message_id)
+                .map_err(|_| ContractError::InvalidMessageID(message_id.to_string()))?;
+
+            Ok((id.tx_hash_as_hex(), id.event_index))
+        }
+        MessageIdFormat::XrplTxHash => {
+            let id = XrplTxHash::from_str(message_id)
+                .map_err(|_| ContractError::InvalidMessageID(message_id.to_string()))?;
+
+            Ok((id.tx_hash_as_hex(), 0))
+        }
     }
 }
 
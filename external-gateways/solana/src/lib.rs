@@ -0,0 +1,503 @@
+pub mod error;
+
+use std::collections::BTreeMap;
+
+use borsh::BorshSerialize;
+use cosmwasm_std::Uint256;
+use error_stack::{bail, Report, ResultExt};
+use multisig::key::{PublicKey as MultisigPublicKey, Signature as MultisigSignature};
+use multisig::msg::SignerWithSig;
+use multisig::verifier_set::VerifierSet;
+use sha3::{Digest, Keccak256};
+
+use crate::error::Error;
+
+#[derive(BorshSerialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandType {
+    ApproveMessages,
+    RotateSigners,
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub message_id: String,
+    pub source_chain: String,
+    pub source_address: String,
+    pub destination_address: [u8; 32],
+    pub payload_hash: [u8; 32],
+}
+
+impl TryFrom<&router_api::Message> for Message {
+    type Error = Report<Error>;
+
+    fn try_from(value: &router_api::Message) -> Result<Self, Self::Error> {
+        let destination_address = bs58::decode(value.destination_address.as_str())
+            .into_vec()
+            .change_context(Error::InvalidDestinationAddress)?
+            .try_into()
+            .map_err(|_| Report::new(Error::InvalidDestinationAddress))
+            .attach_printable(value.destination_address.to_string())?;
+
+        Ok(Self {
+            message_id: value.cc_id.message_id.to_string(),
+            source_chain: value.cc_id.source_chain.to_string(),
+            source_address: value.source_address.to_string(),
+            destination_address,
+            payload_hash: value.payload_hash,
+        })
+    }
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct Messages(Vec<Message>);
+
+impl From<Vec<Message>> for Messages {
+    fn from(v: Vec<Message>) -> Self {
+        Messages(v)
+    }
+}
+
+impl Messages {
+    pub fn messages_approval_hash(&self) -> [u8; 32] {
+        keccak256(&(CommandType::ApproveMessages, self))
+    }
+}
+
+/// Public keys are tagged with their type, because the gateway program verifies each signature with the matching curve.
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PublicKey {
+    Secp256k1([u8; 33]), // compressed ECDSA public key
+    Ed25519([u8; 32]),
+}
+
+impl TryFrom<&MultisigPublicKey> for PublicKey {
+    type Error = Report<Error>;
+
+    fn try_from(value: &MultisigPublicKey) -> Result<Self, Self::Error> {
+        match value {
+            MultisigPublicKey::Ecdsa(key) => key
+                .as_slice()
+                .try_into()
+                .map(PublicKey::Secp256k1)
+                .map_err(|_| Report::new(Error::InvalidPublicKey))
+                .attach_printable(key.to_hex()),
+            MultisigPublicKey::Ed25519(key) => key
+                .as_slice()
+                .try_into()
+                .map(PublicKey::Ed25519)
+                .map_err(|_| Report::new(Error::InvalidPublicKey))
+                .attach_printable(key.to_hex()),
+            MultisigPublicKey::Schnorr(_) | MultisigPublicKey::Bls(_) => {
+                bail!(Error::UnsupportedPublicKey)
+            }
+        }
+    }
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct WeightedSigner {
+    pub signer: PublicKey,
+    pub weight: u128,
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct WeightedSigners {
+    pub signers: Vec<WeightedSigner>,
+    pub threshold: u128,
+    pub nonce: [u8; 32],
+}
+
+impl WeightedSigners {
+    pub fn hash(&self) -> [u8; 32] {
+        keccak256(self)
+    }
+
+    pub fn signers_rotation_hash(&self) -> [u8; 32] {
+        keccak256(&(CommandType::RotateSigners, self))
+    }
+}
+
+impl TryFrom<&VerifierSet> for WeightedSigners {
+    type Error = Report<Error>;
+
+    fn try_from(value: &VerifierSet) -> Result<Self, Self::Error> {
+        let mut signers = value
+            .signers
+            .values()
+            .map(|signer| {
+                Ok(WeightedSigner {
+                    signer: PublicKey::try_from(&signer.pub_key)?,
+                    weight: signer.weight.into(),
+                })
+            })
+            .collect::<Result<Vec<_>, Self::Error>>()?;
+
+        signers.sort_by(|signer1, signer2| signer1.signer.cmp(&signer2.signer));
+
+        Ok(Self {
+            signers,
+            threshold: value.threshold.into(),
+            nonce: Uint256::from(value.created_at).to_be_bytes(),
+        })
+    }
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub enum Signature {
+    EcdsaRecoverable([u8; 65]), // the last byte is the recovery id, as expected by the secp256k1_recover syscall
+    Ed25519([u8; 64]),
+}
+
+impl TryFrom<&MultisigSignature> for Signature {
+    type Error = Report<Error>;
+
+    fn try_from(value: &MultisigSignature) -> Result<Self, Self::Error> {
+        match value {
+            MultisigSignature::EcdsaRecoverable(signature) => signature
+                .as_ref()
+                .try_into()
+                .map(Signature::EcdsaRecoverable)
+                .map_err(|_| Report::new(Error::InvalidSignature)),
+            MultisigSignature::Ed25519(signature) => signature
+                .as_slice()
+                .try_into()
+                .map(Signature::Ed25519)
+                .map_err(|_| Report::new(Error::InvalidSignature)),
+            MultisigSignature::Ecdsa(_)
+            | MultisigSignature::Schnorr(_)
+            | MultisigSignature::Bls(_) => bail!(Error::UnsupportedSignature),
+        }
+    }
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub enum ProofSignature {
+    Signed(Signature),
+    Unsigned,
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct ProofSigner {
+    pub signer: WeightedSigner,
+    pub signature: ProofSignature,
+}
+
+/// The proof contains every signer of the verifier set, so the gateway can recompute the verifier set hash.
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct Proof {
+    pub signers: Vec<ProofSigner>,
+    pub threshold: u128,
+    pub nonce: [u8; 32],
+}
+
+impl TryFrom<(&VerifierSet, Vec<SignerWithSig>)> for Proof {
+    type Error = Report<Error>;
+
+    fn try_from(
+        (verifier_set, signers): (&VerifierSet, Vec<SignerWithSig>),
+    ) -> Result<Self, Self::Error> {
+        let mut signatures = signers
+            .iter()
+            .map(|signer| {
+                Ok((
+                    PublicKey::try_from(&signer.signer.pub_key)?,
+                    Signature::try_from(&signer.signature)?,
+                ))
+            })
+            .collect::<Result<BTreeMap<_, _>, Self::Error>>()?;
+
+        let WeightedSigners {
+            signers,
+            threshold,
+            nonce,
+        } = WeightedSigners::try_from(verifier_set)?;
+
+        let signers = signers
+            .into_iter()
+            .map(|signer| {
+                let signature = signatures
+                    .remove(&signer.signer)
+                    .map_or(ProofSignature::Unsigned, ProofSignature::Signed);
+
+                ProofSigner { signer, signature }
+            })
+            .collect();
+
+        Ok(Self {
+            signers,
+            threshold,
+            nonce,
+        })
+    }
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub enum ExecutePayload {
+    ApproveMessages(Messages),
+    RotateSigners(WeightedSigners),
+}
+
+#[derive(BorshSerialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExecuteData {
+    pub payload: ExecutePayload,
+    pub proof: Proof,
+}
+
+impl ExecuteData {
+    pub fn encode(&self) -> Vec<u8> {
+        borsh::to_vec(self)
+            .expect("violated invariant: borsh serialization into a vector must not fail")
+    }
+}
+
+fn keccak256(value: &impl BorshSerialize) -> [u8; 32] {
+    let encoded = borsh::to_vec(value)
+        .expect("violated invariant: borsh serialization into a vector must not fail");
+
+    Keccak256::digest(encoded).into()
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{Addr, HexBinary, Uint128};
+    use multisig::key::KeyType;
+    use multisig::msg::Signer;
+    use serde::Serialize;
+
+    use crate::{
+        CommandType, Message, Messages, Proof, ProofSignature, ProofSigner, PublicKey, Signature,
+        WeightedSigner, WeightedSigners,
+    };
+
+    #[test]
+    fn command_type_encode() {
+        #[derive(Serialize)]
+        struct Encoded {
+            approve_messages: String,
+            rotate_signers: String,
+        }
+
+        let encoded = Encoded {
+            approve_messages: hex::encode(borsh::to_vec(&CommandType::ApproveMessages).unwrap()),
+            rotate_signers: hex::encode(borsh::to_vec(&CommandType::RotateSigners).unwrap()),
+        };
+
+        goldie::assert_json!(&encoded);
+    }
+
+    #[test]
+    fn messages_approval_hash() {
+        let payload_hashes = [
+            "cfa347779c9b646ddf628c4da721976ceb998f1ab2c097b52e66a575c3975a6c",
+            "fb5eb8245e3b8eb9d44f228ee142a3378f57d49fc95fa78d437ff8aa5dd564ba",
+            "90e3761c0794fbbd8b563a0d05d83395e7f88f64f30eebb7c5533329f6653e84",
+            "60e146cb9c548ba6e614a87910d8172c9d21279a3f8f4da256ff36e15b80ea30",
+        ];
+
+        let messages: Messages = (1..=4)
+            .map(|i| Message {
+                message_id: format!("test-{}", i),
+                source_chain: format!("source-{}", i),
+                source_address: "0x52444f1835Adc02086c37Cb226561605e2E1699b".to_string(),
+                destination_address: bs58::decode("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+                    .into_vec()
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+                payload_hash: HexBinary::from_hex(payload_hashes[i - 1])
+                    .unwrap()
+                    .to_array()
+                    .unwrap(),
+            })
+            .collect::<Vec<_>>()
+            .into();
+
+        goldie::assert!(hex::encode(messages.messages_approval_hash()));
+    }
+
+    #[test]
+    fn signers_rotation_hash() {
+        let weighted_signers = WeightedSigners {
+            signers: vec![
+                WeightedSigner {
+                    signer: secp256k1_key(
+                        "02a670f57de55b8b39b4cb051e178ca8fb3fe3a78cdde7f8238baf5e6ce1893185",
+                    ),
+                    weight: 1,
+                },
+                WeightedSigner {
+                    signer: secp256k1_key(
+                        "03d123ce370b163acd576be0e32e436bb7e63262769881d35fa3573943bf6c6f81",
+                    ),
+                    weight: 2,
+                },
+                WeightedSigner {
+                    signer: ed25519_key(
+                        "12f7d9a9463212335914b39ee90bfa2045f90b64c1f2d7b58ed335282abac4a4",
+                    ),
+                    weight: 3,
+                },
+                WeightedSigner {
+                    signer: ed25519_key(
+                        "c35aa94d2038f258ecb1bb28fbc8a83ab79d2dc0a7223fd528a8f52a14c03292",
+                    ),
+                    weight: 4,
+                },
+            ],
+            threshold: 6,
+            nonce: HexBinary::from_hex(
+                "8784bf7be5a9baaeea47e12d9e8ad0dec29afcbc3617d97f771e3c24fa945dce",
+            )
+            .unwrap()
+            .to_array()
+            .unwrap(),
+        };
+
+        goldie::assert_json!(&[
+            hex::encode(weighted_signers.hash()),
+            hex::encode(weighted_signers.signers_rotation_hash())
+        ]);
+    }
+
+    #[test]
+    fn proof_encode() {
+        let proof = Proof {
+            signers: vec![
+                ProofSigner {
+                    signer: WeightedSigner {
+                        signer: secp256k1_key(
+                            "0312474390012cfbb621c91295dae42b11daaceffbcb7136045c86537a7b37042c",
+                        ),
+                        weight: 1,
+                    },
+                    signature: ProofSignature::Signed(Signature::EcdsaRecoverable(
+                        HexBinary::from_hex("72b242d7247fc31d14ce82b32f3ea911808f6f600f362150f9904c974315942927c25f9388cecdbbb0b3723164eea92206775870cd28e1ffd8f1cb9655fb3c4a00")
+                            .unwrap()
+                            .to_array()
+                            .unwrap(),
+                    )),
+                },
+                ProofSigner {
+                    signer: WeightedSigner {
+                        signer: secp256k1_key(
+                            "0315a4c9807fb3e3eb360c6b2cd09ba9edb28b566aaf986b4e107180d89895d42c",
+                        ),
+                        weight: 1,
+                    },
+                    signature: ProofSignature::Unsigned,
+                },
+                ProofSigner {
+                    signer: WeightedSigner {
+                        signer: ed25519_key(
+                            "4c3863e4b0252a8674c1c6ad70b3ca3002b400b49ddfae5583b21907e65c5dd8",
+                        ),
+                        weight: 2,
+                    },
+                    signature: ProofSignature::Signed(Signature::Ed25519(
+                        HexBinary::from_hex("cb8a1b98ec7678d5eb965d47c449b2b8396d170e53ad7b5f65a7c0fdf2aebe206b65be7cb2e81c7ddd8924acb2ffc2d463b678993227fdfbfc3ef03a8ffa030c")
+                            .unwrap()
+                            .to_array()
+                            .unwrap(),
+                    )),
+                },
+            ],
+            threshold: 3,
+            nonce: HexBinary::from_hex(
+                "00000000000000000000000000000000000000000000000000000000000007e8",
+            )
+            .unwrap()
+            .to_array()
+            .unwrap(),
+        };
+
+        goldie::assert!(hex::encode(borsh::to_vec(&proof).unwrap()));
+    }
+
+    #[test]
+    fn proof_should_contain_all_signers_sorted_by_public_key() {
+        let signers = [
+            (
+                "addr_1",
+                KeyType::Ed25519,
+                "c35aa94d2038f258ecb1bb28fbc8a83ab79d2dc0a7223fd528a8f52a14c03292",
+            ),
+            (
+                "addr_2",
+                KeyType::Ecdsa,
+                "03d123ce370b163acd576be0e32e436bb7e63262769881d35fa3573943bf6c6f81",
+            ),
+            (
+                "addr_3",
+                KeyType::Ed25519,
+                "12f7d9a9463212335914b39ee90bfa2045f90b64c1f2d7b58ed335282abac4a4",
+            ),
+        ]
+        .map(|(address, key_type, pub_key)| Signer {
+            address: Addr::unchecked(address),
+            weight: Uint128::one(),
+            pub_key: (key_type, HexBinary::from_hex(pub_key).unwrap())
+                .try_into()
+                .unwrap(),
+        });
+
+        let verifier_set = multisig::verifier_set::VerifierSet {
+            signers: signers
+                .iter()
+                .map(|signer| (signer.address.to_string(), signer.clone()))
+                .collect(),
+            threshold: Uint128::from(2u128),
+            created_at: 2024,
+        };
+
+        let signature = HexBinary::from_hex("b5b3b0749aa585f866d802e32ca4a6356f82eb52e2a1b4797cbaa30f3d755462f2eb995c70d9099e436b8a48498e4d613ff2d3ca7618973a36c2fde17493180f").unwrap();
+        let signer_with_sig =
+            signers[2].with_sig((KeyType::Ed25519, signature.clone()).try_into().unwrap());
+
+        let proof = Proof::try_from((&verifier_set, vec![signer_with_sig])).unwrap();
+
+        assert_eq!(
+            proof
+                .signers
+                .iter()
+                .map(|signer| signer.signer.signer.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                secp256k1_key("03d123ce370b163acd576be0e32e436bb7e63262769881d35fa3573943bf6c6f81"),
+                ed25519_key("12f7d9a9463212335914b39ee90bfa2045f90b64c1f2d7b58ed335282abac4a4"),
+                ed25519_key("c35aa94d2038f258ecb1bb28fbc8a83ab79d2dc0a7223fd528a8f52a14c03292"),
+            ]
+        );
+        assert_eq!(proof.signers[0].signature, ProofSignature::Unsigned);
+        assert_eq!(
+            proof.signers[1].signature,
+            ProofSignature::Signed(Signature::Ed25519(signature.to_array().unwrap()))
+        );
+        assert_eq!(proof.signers[2].signature, ProofSignature::Unsigned);
+        assert_eq!(proof.threshold, 2);
+    }
+
+    #[test]
+    fn message_with_invalid_destination_address_should_fail() {
+        let message = router_api::Message {
+            cc_id: router_api::CrossChainId::new("source", "test").unwrap(),
+            source_address: "0x52444f1835Adc02086c37Cb226561605e2E1699b"
+                .parse()
+                .unwrap(),
+            destination_chain: "solana".parse().unwrap(),
+            destination_address: "0x52444f1835Adc02086c37Cb226561605e2E1699b"
+                .parse()
+                .unwrap(),
+            payload_hash: [0; 32],
+        };
+
+        assert!(Message::try_from(&message).is_err());
+    }
+
+    fn secp256k1_key(key: &str) -> PublicKey {
+        PublicKey::Secp256k1(HexBinary::from_hex(key).unwrap().to_array().unwrap())
+    }
+
+    fn ed25519_key(key: &str) -> PublicKey {
+        PublicKey::Ed25519(HexBinary::from_hex(key).unwrap().to_array().unwrap())
+    }
+}
@@ -0,0 +1,607 @@
+pub mod error;
+
+use axelar_wasm_std::hash::Hash;
+use axelar_wasm_std::msg_id::STARKNET_PRIME;
+use cosmwasm_std::{HexBinary, Uint256};
+use error_stack::{bail, ensure, Report, ResultExt};
+use k256::ecdsa::VerifyingKey;
+use multisig::key::{PublicKey, Signature as MultisigSignature};
+use multisig::msg::SignerWithSig;
+use multisig::verifier_set::VerifierSet;
+use sha3::{Digest, Keccak256};
+use starknet_crypto::{poseidon_hash_many, Felt};
+
+use crate::error::Error;
+
+// number of bytes packed into each full word of a Cairo ByteArray
+const BYTES31_LEN: usize = 31;
+
+/// Serializes values into felts the same way the Serde implementations of the corresponding Cairo types do,
+/// so the gateway can deserialize them and recompute their hashes.
+pub trait CairoSerialize {
+    fn serialize(&self, output: &mut Vec<Felt>);
+
+    fn to_felts(&self) -> Vec<Felt> {
+        let mut output = vec![];
+        self.serialize(&mut output);
+        output
+    }
+}
+
+impl CairoSerialize for u128 {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        output.push(Felt::from(*self));
+    }
+}
+
+impl CairoSerialize for bool {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        output.push(Felt::from(u8::from(*self)));
+    }
+}
+
+/// 32 byte big endian values are serialized as Cairo u256, i.e. the low 128 bits followed by the high 128 bits.
+impl CairoSerialize for [u8; 32] {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        let (high, low) = self.split_at(16);
+        output.push(Felt::from_bytes_be_slice(low));
+        output.push(Felt::from_bytes_be_slice(high));
+    }
+}
+
+/// Strings are serialized as Cairo ByteArray: the full 31 byte words, followed by the remaining bytes and their count.
+impl CairoSerialize for String {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        let mut words = self.as_bytes().chunks(BYTES31_LEN).collect::<Vec<_>>();
+        let pending_word = if words.last().is_some_and(|word| word.len() < BYTES31_LEN) {
+            words.pop()
+        } else {
+            None
+        }
+        .unwrap_or_default();
+
+        output.push(Felt::from(words.len()));
+        output.extend(words.into_iter().map(Felt::from_bytes_be_slice));
+        output.push(Felt::from_bytes_be_slice(pending_word));
+        output.push(Felt::from(pending_word.len()));
+    }
+}
+
+impl<T: CairoSerialize> CairoSerialize for Vec<T> {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        output.push(Felt::from(self.len()));
+        self.iter().for_each(|item| item.serialize(output));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandType {
+    ApproveMessages,
+    RotateSigners,
+}
+
+impl CairoSerialize for CommandType {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        let variant: u8 = match self {
+            CommandType::ApproveMessages => 0,
+            CommandType::RotateSigners => 1,
+        };
+        output.push(Felt::from(variant));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub source_chain: String,
+    pub message_id: String,
+    pub source_address: String,
+    pub contract_address: Felt,
+    pub payload_hash: [u8; 32],
+}
+
+impl CairoSerialize for Message {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        self.source_chain.serialize(output);
+        self.message_id.serialize(output);
+        self.source_address.serialize(output);
+        output.push(self.contract_address);
+        self.payload_hash.serialize(output);
+    }
+}
+
+impl TryFrom<&router_api::Message> for Message {
+    type Error = Report<Error>;
+
+    fn try_from(value: &router_api::Message) -> Result<Self, Self::Error> {
+        Ok(Self {
+            source_chain: value.cc_id.source_chain.to_string(),
+            message_id: value.cc_id.message_id.to_string(),
+            source_address: value.source_address.to_string(),
+            contract_address: contract_address(value.destination_address.as_str())
+                .attach_printable(value.destination_address.to_string())?,
+            payload_hash: value.payload_hash,
+        })
+    }
+}
+
+/// Parses a `0x` prefixed hex contract address, which may omit leading zeros but must be a valid felt.
+fn contract_address(address: &str) -> Result<Felt, Report<Error>> {
+    let hex = address
+        .strip_prefix("0x")
+        .ok_or(Error::InvalidDestinationAddress)?;
+    ensure!(
+        !hex.is_empty() && hex.len() <= 64,
+        Error::InvalidDestinationAddress
+    );
+
+    let address: [u8; 32] = HexBinary::from_hex(&format!("{:0>64}", hex))
+        .and_then(|address| address.to_array())
+        .change_context(Error::InvalidDestinationAddress)?;
+
+    // both are big endian, so the lexicographic order is the numeric order
+    ensure!(address < STARKNET_PRIME, Error::InvalidDestinationAddress);
+
+    Ok(Felt::from_bytes_be(&address))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Messages(Vec<Message>);
+
+impl From<Vec<Message>> for Messages {
+    fn from(v: Vec<Message>) -> Self {
+        Messages(v)
+    }
+}
+
+impl CairoSerialize for Messages {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        self.0.serialize(output);
+    }
+}
+
+impl Messages {
+    pub fn messages_approval_hash(&self) -> Felt {
+        hash_with_command_type(CommandType::ApproveMessages, self)
+    }
+}
+
+/// Signers are identified by the Ethereum address of their secp256k1 public key,
+/// which is what the gateway passes to `verify_eth_signature`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedSigner {
+    pub signer: [u8; 20],
+    pub weight: u128,
+}
+
+impl CairoSerialize for WeightedSigner {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        output.push(Felt::from_bytes_be_slice(&self.signer));
+        self.weight.serialize(output);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeightedSigners {
+    pub signers: Vec<WeightedSigner>,
+    pub threshold: u128,
+    pub nonce: [u8; 32],
+}
+
+impl CairoSerialize for WeightedSigners {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        self.signers.serialize(output);
+        self.threshold.serialize(output);
+        self.nonce.serialize(output);
+    }
+}
+
+impl WeightedSigners {
+    pub fn hash(&self) -> Felt {
+        poseidon_hash_many(&self.to_felts())
+    }
+
+    pub fn signers_rotation_hash(&self) -> Felt {
+        hash_with_command_type(CommandType::RotateSigners, self)
+    }
+}
+
+impl TryFrom<&VerifierSet> for WeightedSigners {
+    type Error = Report<Error>;
+
+    fn try_from(value: &VerifierSet) -> Result<Self, Self::Error> {
+        let mut signers = value
+            .signers
+            .values()
+            .map(|signer| {
+                Ok(WeightedSigner {
+                    signer: eth_address(&signer.pub_key)?,
+                    weight: signer.weight.into(),
+                })
+            })
+            .collect::<Result<Vec<_>, Self::Error>>()?;
+
+        signers.sort_by(|signer1, signer2| signer1.signer.cmp(&signer2.signer));
+
+        Ok(Self {
+            signers,
+            threshold: value.threshold.into(),
+            nonce: Uint256::from(value.created_at).to_be_bytes(),
+        })
+    }
+}
+
+pub fn eth_address(pub_key: &PublicKey) -> Result<[u8; 20], Report<Error>> {
+    match pub_key {
+        PublicKey::Ecdsa(pub_key) => {
+            let pub_key = VerifyingKey::from_sec1_bytes(pub_key)
+                .change_context(Error::InvalidPublicKey)
+                .attach_printable(pub_key.to_hex())?
+                .to_encoded_point(false);
+
+            // the address is the last 20 bytes of the hash of the uncompressed public key without its 0x04 prefix
+            let hash: [u8; 32] = Keccak256::digest(&pub_key.as_bytes()[1..]).into();
+            Ok(hash[12..]
+                .try_into()
+                .expect("violated invariant: the address is 20 bytes long"))
+        }
+        PublicKey::Ed25519(_) | PublicKey::Schnorr(_) | PublicKey::Bls(_) => {
+            bail!(Error::UnsupportedPublicKey)
+        }
+    }
+}
+
+/// Signature in the form expected by `verify_eth_signature`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub y_parity: bool,
+}
+
+impl CairoSerialize for Signature {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        self.r.serialize(output);
+        self.s.serialize(output);
+        self.y_parity.serialize(output);
+    }
+}
+
+impl TryFrom<&MultisigSignature> for Signature {
+    type Error = Report<Error>;
+
+    fn try_from(value: &MultisigSignature) -> Result<Self, Self::Error> {
+        match value {
+            MultisigSignature::EcdsaRecoverable(signature) => {
+                let (r, rest) = signature.as_ref().split_at(32);
+                let (s, recovery_id) = rest.split_at(32);
+
+                let y_parity = match recovery_id {
+                    [0] => false,
+                    [1] => true,
+                    _ => bail!(Error::InvalidSignature),
+                };
+
+                Ok(Self {
+                    r: r.try_into().change_context(Error::InvalidSignature)?,
+                    s: s.try_into().change_context(Error::InvalidSignature)?,
+                    y_parity,
+                })
+            }
+            MultisigSignature::Ecdsa(_)
+            | MultisigSignature::Ed25519(_)
+            | MultisigSignature::Schnorr(_)
+            | MultisigSignature::Bls(_) => bail!(Error::UnsupportedSignature),
+        }
+    }
+}
+
+/// The proof contains the whole verifier set, so the gateway can recompute its hash,
+/// and the signatures ordered by the address of their signer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proof {
+    pub signers: WeightedSigners,
+    pub signatures: Vec<Signature>,
+}
+
+impl CairoSerialize for Proof {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        self.signers.serialize(output);
+        self.signatures.serialize(output);
+    }
+}
+
+impl TryFrom<(&VerifierSet, Vec<SignerWithSig>)> for Proof {
+    type Error = Report<Error>;
+
+    fn try_from(
+        (verifier_set, signers): (&VerifierSet, Vec<SignerWithSig>),
+    ) -> Result<Self, Self::Error> {
+        let mut signatures = signers
+            .iter()
+            .map(|signer| {
+                Ok((
+                    eth_address(&signer.signer.pub_key)?,
+                    Signature::try_from(&signer.signature)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, Self::Error>>()?;
+
+        signatures.sort_by(|(signer1, _), (signer2, _)| signer1.cmp(signer2));
+
+        Ok(Self {
+            signers: WeightedSigners::try_from(verifier_set)?,
+            signatures: signatures
+                .into_iter()
+                .map(|(_, signature)| signature)
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutePayload {
+    ApproveMessages(Messages),
+    RotateSigners(WeightedSigners),
+}
+
+impl CairoSerialize for ExecutePayload {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        match self {
+            ExecutePayload::ApproveMessages(messages) => {
+                CommandType::ApproveMessages.serialize(output);
+                messages.serialize(output);
+            }
+            ExecutePayload::RotateSigners(signers) => {
+                CommandType::RotateSigners.serialize(output);
+                signers.serialize(output);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecuteData {
+    pub payload: ExecutePayload,
+    pub proof: Proof,
+}
+
+impl CairoSerialize for ExecuteData {
+    fn serialize(&self, output: &mut Vec<Felt>) {
+        self.payload.serialize(output);
+        self.proof.serialize(output);
+    }
+}
+
+impl ExecuteData {
+    /// Encodes the execute data as a felt array, with every felt taking up 32 big endian bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        self.to_felts()
+            .iter()
+            .flat_map(|felt| felt.to_bytes_be())
+            .collect()
+    }
+}
+
+/// The hash the verifiers sign, which binds the payload to the gateway's domain and the signing verifier set.
+pub fn message_hash_to_sign(domain_separator: &Hash, signers_hash: Felt, data_hash: Felt) -> Hash {
+    let mut felts = domain_separator.to_felts();
+    felts.extend([signers_hash, data_hash]);
+
+    poseidon_hash_many(&felts).to_bytes_be()
+}
+
+fn hash_with_command_type(command_type: CommandType, value: &impl CairoSerialize) -> Felt {
+    let mut felts = command_type.to_felts();
+    value.serialize(&mut felts);
+
+    poseidon_hash_many(&felts)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::HexBinary;
+    use multisig::key::{KeyType, PublicKey};
+    use router_api::{CrossChainId, Message as RouterMessage};
+
+    use crate::error::Error;
+    use crate::{
+        eth_address, CairoSerialize, ExecuteData, ExecutePayload, Message, Messages, Proof,
+        Signature, WeightedSigner, WeightedSigners,
+    };
+
+    #[test]
+    fn string_serialize() {
+        let strings = [
+            "",
+            "hello",
+            "0123456789012345678901234567890",
+            "0x52444f1835Adc02086c37Cb226561605e2E1699b",
+        ];
+
+        goldie::assert_json!(&strings
+            .iter()
+            .map(|string| felts_to_hex(&string.to_string().to_felts()))
+            .collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn messages_approval_hash() {
+        let payload_hashes = [
+            "cfa347779c9b646ddf628c4da721976ceb998f1ab2c097b52e66a575c3975a6c",
+            "fb5eb8245e3b8eb9d44f228ee142a3378f57d49fc95fa78d437ff8aa5dd564ba",
+        ];
+        let destination_addresses = [
+            "0x049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7",
+            "0x1234",
+        ];
+
+        let messages: Messages = (1..=2)
+            .map(|i| {
+                Message::try_from(&RouterMessage {
+                    cc_id: CrossChainId::new(format!("source-{}", i), format!("test-{}", i))
+                        .unwrap(),
+                    source_address: "0x52444f1835Adc02086c37Cb226561605e2E1699b"
+                        .parse()
+                        .unwrap(),
+                    destination_chain: "starknet".parse().unwrap(),
+                    destination_address: destination_addresses[i - 1].parse().unwrap(),
+                    payload_hash: HexBinary::from_hex(payload_hashes[i - 1])
+                        .unwrap()
+                        .to_array()
+                        .unwrap(),
+                })
+                .unwrap()
+            })
+            .collect::<Vec<_>>()
+            .into();
+
+        goldie::assert!(hex::encode(messages.messages_approval_hash().to_bytes_be()));
+    }
+
+    #[test]
+    fn signers_rotation_hash() {
+        let weighted_signers = weighted_signers();
+
+        goldie::assert_json!(&[
+            hex::encode(weighted_signers.hash().to_bytes_be()),
+            hex::encode(weighted_signers.signers_rotation_hash().to_bytes_be())
+        ]);
+    }
+
+    #[test]
+    fn execute_data_encode() {
+        let execute_data = ExecuteData {
+            payload: ExecutePayload::RotateSigners(WeightedSigners {
+                signers: vec![WeightedSigner {
+                    signer: address("7e5f4552091a69125d5dfcb7b8c2659029395bdf"),
+                    weight: 1,
+                }],
+                threshold: 1,
+                nonce: [0; 32],
+            }),
+            proof: Proof {
+                signers: weighted_signers(),
+                signatures: vec![
+                    Signature {
+                        r: bytes32(
+                            "72b242d7247fc31d14ce82b32f3ea911808f6f600f362150f9904c9743159429",
+                        ),
+                        s: bytes32(
+                            "27c25f9388cecdbbb0b3723164eea92206775870cd28e1ffd8f1cb9655fb3c4a",
+                        ),
+                        y_parity: false,
+                    },
+                    Signature {
+                        r: bytes32(
+                            "86909155a6ba27f173edf15d283da6a0019fb6afe6b223ca68530464813f468f",
+                        ),
+                        s: bytes32(
+                            "356e70788faf6d1d9ff7bfcfd9021b560d72408bef4c86c66e3a94b9dee0a34a",
+                        ),
+                        y_parity: true,
+                    },
+                ],
+            },
+        };
+
+        goldie::assert!(hex::encode(execute_data.encode()));
+    }
+
+    #[test]
+    fn eth_address_should_be_derived_from_public_key() {
+        let pub_key: PublicKey = (
+            KeyType::Ecdsa,
+            HexBinary::from_hex(
+                "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            )
+            .unwrap(),
+        )
+            .try_into()
+            .unwrap();
+
+        assert_eq!(
+            eth_address(&pub_key).unwrap(),
+            address("7e5f4552091a69125d5dfcb7b8c2659029395bdf")
+        );
+    }
+
+    #[test]
+    fn eth_address_of_ed25519_key_should_fail() {
+        let pub_key: PublicKey = (
+            KeyType::Ed25519,
+            HexBinary::from_hex("12f7d9a9463212335914b39ee90bfa2045f90b64c1f2d7b58ed335282abac4a4")
+                .unwrap(),
+        )
+            .try_into()
+            .unwrap();
+
+        assert!(matches!(
+            eth_address(&pub_key).unwrap_err().current_context(),
+            Error::UnsupportedPublicKey
+        ));
+    }
+
+    #[test]
+    fn message_with_invalid_destination_address_should_fail() {
+        let invalid_addresses = [
+            "049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7",
+            "0x",
+            "0xzz",
+            "0x0049d36570d4e46f48e99674bd3fcc84644ddd6b96f7c741b1562b82f9e004dc7",
+            "0x0800000000000011000000000000000000000000000000000000000000000001",
+        ];
+
+        for destination_address in invalid_addresses {
+            let result = Message::try_from(&RouterMessage {
+                cc_id: CrossChainId::new("source", "test").unwrap(),
+                source_address: "0x52444f1835Adc02086c37Cb226561605e2E1699b"
+                    .parse()
+                    .unwrap(),
+                destination_chain: "starknet".parse().unwrap(),
+                destination_address: destination_address.parse().unwrap(),
+                payload_hash: [0; 32],
+            });
+
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                Error::InvalidDestinationAddress
+            ));
+        }
+    }
+
+    fn weighted_signers() -> WeightedSigners {
+        WeightedSigners {
+            signers: vec![
+                WeightedSigner {
+                    signer: address("3ef8e4b6f2a2a0e81b1b2d6c6d4f5a1e8e5c1b29"),
+                    weight: 1,
+                },
+                WeightedSigner {
+                    signer: address("7e5f4552091a69125d5dfcb7b8c2659029395bdf"),
+                    weight: 2,
+                },
+                WeightedSigner {
+                    signer: address("a4f10f76b86e01b98daf66a3d02a65e14adb0767"),
+                    weight: 3,
+                },
+            ],
+            threshold: 4,
+            nonce: bytes32("8784bf7be5a9baaeea47e12d9e8ad0dec29afcbc3617d97f771e3c24fa945dce"),
+        }
+    }
+
+    fn address(hex: &str) -> [u8; 20] {
+        HexBinary::from_hex(hex).unwrap().to_array().unwrap()
+    }
+
+    fn bytes32(hex: &str) -> [u8; 32] {
+        HexBinary::from_hex(hex).unwrap().to_array().unwrap()
+    }
+
+    fn felts_to_hex(felts: &[starknet_crypto::Felt]) -> Vec<String> {
+        felts
+            .iter()
+            .map(|felt| hex::encode(felt.to_bytes_be()))
+            .collect()
+    }
+}
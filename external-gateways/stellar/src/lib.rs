@@ -212,7 +212,9 @@ impl TryFrom<&VerifierSet> for WeightedSigners {
                         .attach_printable(key.to_hex())?,
                     weight: signer.weight.into(),
                 }),
-                PublicKey::Ecdsa(_) => Err(Report::new(Error::UnsupportedPublicKey)),
+                PublicKey::Ecdsa(_) | PublicKey::Schnorr(_) | PublicKey::Bls(_) => {
+                    Err(Report::new(Error::UnsupportedPublicKey))
+                }
             })
             .collect::<Result<Vec<_>, _>>()?;
 
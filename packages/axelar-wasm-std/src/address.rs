@@ -4,6 +4,8 @@ use alloy_primitives::Address;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Api};
 use error_stack::{bail, Result, ResultExt};
+use lazy_static::lazy_static;
+use regex::Regex;
 use stellar_xdr::curr::ScAddress;
 use sui_types::SuiAddress;
 
@@ -14,11 +16,25 @@ pub enum Error {
     InvalidAddress(String),
 }
 
+// contract addresses are felts below 2^251, zero-padded to 64 lowercase hex characters
+const STARKNET_ADDRESS_PATTERN: &str = "^0x[0-7][0-9a-f]{63}$";
+lazy_static! {
+    static ref STARKNET_ADDRESS_REGEX: Regex =
+        Regex::new(STARKNET_ADDRESS_PATTERN).expect("invalid regex");
+}
+
+// classic XRPL addresses are the base58check encoding of the account id with version byte 0
+const XRPL_ACCOUNT_ID_VERSION: u8 = 0;
+const XRPL_ACCOUNT_ID_LEN: usize = 20;
+
 #[cw_serde]
 pub enum AddressFormat {
     Eip55,
     Sui,
     Stellar,
+    Bech32 { prefix: String },
+    Starknet,
+    Xrpl,
 }
 
 pub fn validate_address(address: &str, format: &AddressFormat) -> Result<(), Error> {
@@ -38,6 +54,32 @@ pub fn validate_address(address: &str, format: &AddressFormat) -> Result<(), Err
             ScAddress::from_str(address)
                 .change_context(Error::InvalidAddress(address.to_string()))?;
         }
+        AddressFormat::Bech32 { prefix } => {
+            if address != address.to_lowercase() {
+                bail!(Error::InvalidAddress(address.to_string()))
+            }
+            let (hrp, _) = bech32::decode(address)
+                .change_context(Error::InvalidAddress(address.to_string()))?;
+            if hrp.as_str() != prefix {
+                bail!(Error::InvalidAddress(address.to_string()))
+            }
+        }
+        AddressFormat::Starknet => {
+            if !STARKNET_ADDRESS_REGEX.is_match(address) {
+                bail!(Error::InvalidAddress(address.to_string()))
+            }
+        }
+        AddressFormat::Xrpl => {
+            // the decoded address still contains the version byte, followed by the 20-byte account id
+            let decoded = bs58::decode(address)
+                .with_alphabet(bs58::Alphabet::RIPPLE)
+                .with_check(Some(XRPL_ACCOUNT_ID_VERSION))
+                .into_vec()
+                .change_context(Error::InvalidAddress(address.to_string()))?;
+            if decoded.len() != XRPL_ACCOUNT_ID_LEN.saturating_add(1) {
+                bail!(Error::InvalidAddress(address.to_string()))
+            }
+        }
     }
 
     Ok(())
@@ -187,4 +229,108 @@ mod tests {
             address::Error::InvalidAddress(..)
         );
     }
+
+    #[test]
+    fn validate_bech32_address() {
+        let addr = "osmo1x46rqay4d3cssq8gxxvqz8xt6nwlz4tdyslpn7";
+        let format = address::AddressFormat::Bech32 {
+            prefix: "osmo".to_string(),
+        };
+        assert_ok!(address::validate_address(addr, &format));
+
+        let upper_case = addr.to_uppercase();
+        assert_err_contains!(
+            address::validate_address(&upper_case, &format),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+
+        let other_prefix = "axelar1x46rqay4d3cssq8gxxvqz8xt6nwlz4tdg96ewd";
+        assert_err_contains!(
+            address::validate_address(other_prefix, &format),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+
+        let invalid_checksum = "osmo1x46rqay4d3cssq8gxxvqz8xt6nwlz4tdyslpnq";
+        assert_err_contains!(
+            address::validate_address(invalid_checksum, &format),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+    }
+
+    #[test]
+    fn validate_starknet_address() {
+        let addr = "0x0282b4492e08d8b6bbec8dfe7412e42e897eef9c080c5b97be1537433e583bdc";
+        assert_ok!(address::validate_address(
+            addr,
+            &address::AddressFormat::Starknet
+        ));
+
+        let without_padding = "0x282b4492e08d8b6bbec8dfe7412e42e897eef9c080c5b97be1537433e583bdc";
+        assert_err_contains!(
+            address::validate_address(without_padding, &address::AddressFormat::Starknet),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+
+        let without_prefix = addr.strip_prefix("0x").unwrap();
+        assert_err_contains!(
+            address::validate_address(without_prefix, &address::AddressFormat::Starknet),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+
+        let upper_case = format!("0x{}", without_prefix.to_uppercase());
+        assert_err_contains!(
+            address::validate_address(&upper_case, &address::AddressFormat::Starknet),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+
+        let too_large = "0x0800000000000000000000000000000000000000000000000000000000000000";
+        assert_err_contains!(
+            address::validate_address(too_large, &address::AddressFormat::Starknet),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+    }
+
+    #[test]
+    fn validate_xrpl_address() {
+        let addr = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+        assert_ok!(address::validate_address(
+            addr,
+            &address::AddressFormat::Xrpl
+        ));
+
+        let account_zero = "rrrrrrrrrrrrrrrrrrrrrhoLvTp";
+        assert_ok!(address::validate_address(
+            account_zero,
+            &address::AddressFormat::Xrpl
+        ));
+
+        let invalid_checksum = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYf";
+        assert_err_contains!(
+            address::validate_address(invalid_checksum, &address::AddressFormat::Xrpl),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+
+        // '0' is not part of the ripple base58 alphabet
+        let invalid_character = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAY0";
+        assert_err_contains!(
+            address::validate_address(invalid_character, &address::AddressFormat::Xrpl),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+
+        let evm_address = "0x8cc8d18733a4bf98de8f861d356e2191918733e3";
+        assert_err_contains!(
+            address::validate_address(evm_address, &address::AddressFormat::Xrpl),
+            address::Error,
+            address::Error::InvalidAddress(..)
+        );
+    }
 }
@@ -0,0 +1,181 @@
+use core::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use cosmwasm_std::HexBinary;
+use error_stack::{bail, Report, ResultExt};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::Error;
+use crate::hash::Hash;
+use crate::nonempty;
+
+/// The prime of the field that Starknet felts are elements of, i.e. 2^251 + 17 * 2^192 + 1
+pub const STARKNET_PRIME: Hash = [
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+];
+
+/// A transaction hash encoded as a field element (felt), as used by Starknet.
+/// The hash is always zero-padded to 64 hex characters so every felt has exactly one representation.
+pub struct FieldElementAndEventIndex {
+    pub tx_hash: Hash,
+    pub event_index: u32,
+}
+
+impl FieldElementAndEventIndex {
+    pub fn tx_hash_as_hex(&self) -> nonempty::String {
+        format!("0x{}", HexBinary::from(self.tx_hash).to_hex())
+            .try_into()
+            .expect("failed to convert tx hash to non-empty string")
+    }
+
+    pub fn new(tx_id: impl Into<[u8; 32]>, event_index: impl Into<u32>) -> Self {
+        Self {
+            tx_hash: tx_id.into(),
+            event_index: event_index.into(),
+        }
+    }
+}
+
+const PATTERN: &str = "^(0x[0-9a-f]{64})-(0|[1-9][0-9]*)$";
+lazy_static! {
+    static ref REGEX: Regex = Regex::new(PATTERN).expect("invalid regex");
+}
+
+impl FromStr for FieldElementAndEventIndex {
+    type Err = Report<Error>;
+
+    fn from_str(message_id: &str) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        // the PATTERN has exactly two capture groups, so the groups can be extracted safely
+        let (_, [tx_id, event_index]) = REGEX
+            .captures(message_id)
+            .ok_or(Error::InvalidMessageID {
+                id: message_id.to_string(),
+                expected_format: PATTERN.to_string(),
+            })?
+            .extract();
+
+        let tx_hash: Hash = HexBinary::from_hex(&tx_id[2..])
+            .change_context(Error::InvalidTxHash(message_id.to_string()))?
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::InvalidTxHash(message_id.to_string()))?;
+
+        // both are big endian, so the lexicographic order is the numeric order
+        if tx_hash >= STARKNET_PRIME {
+            bail!(Error::InvalidTxHash(message_id.to_string()));
+        }
+
+        Ok(FieldElementAndEventIndex {
+            tx_hash,
+            event_index: event_index
+                .parse()
+                .map_err(|_| Error::EventIndexOverflow(message_id.to_string()))?,
+        })
+    }
+}
+
+impl Display for FieldElementAndEventIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "0x{}-{}",
+            HexBinary::from(self.tx_hash).to_hex(),
+            self.event_index
+        )
+    }
+}
+
+impl From<FieldElementAndEventIndex> for nonempty::String {
+    fn from(msg_id: FieldElementAndEventIndex) -> Self {
+        msg_id
+            .to_string()
+            .try_into()
+            .expect("failed to convert msg id to non-empty string")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn random_felt() -> String {
+        let mut bytes: Vec<u8> = (0..32).map(|_| rand::random()).collect();
+        // keep the value below 2^251 so it is a valid felt
+        bytes[0] &= 0x07;
+        format!("0x{}", HexBinary::from(bytes).to_hex())
+    }
+
+    #[test]
+    fn should_parse_msg_id() {
+        let res = FieldElementAndEventIndex::from_str(
+            "0x0670d1dd42a19cb229bb4378b58b9c3e76aa43edaaea46845cd8c456c1224d89-0",
+        );
+        assert!(res.is_ok());
+
+        for _ in 0..1000 {
+            let tx_hash = random_felt();
+            let event_index: u32 = rand::random();
+            let msg_id = format!("{}-{}", tx_hash, event_index);
+
+            let parsed = FieldElementAndEventIndex::from_str(&msg_id).unwrap();
+            assert_eq!(parsed.event_index, event_index);
+            assert_eq!(parsed.tx_hash_as_hex(), tx_hash.try_into().unwrap());
+            assert_eq!(parsed.to_string(), msg_id);
+        }
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_with_felt_overflow() {
+        let prime = HexBinary::from(STARKNET_PRIME).to_hex();
+        let res = FieldElementAndEventIndex::from_str(&format!("0x{}-1", prime));
+        assert!(res.is_err());
+
+        let max = "f".repeat(64);
+        let res = FieldElementAndEventIndex::from_str(&format!("0x{}-1", max));
+        assert!(res.is_err());
+
+        let below_prime = "0800000000000011000000000000000000000000000000000000000000000000";
+        let res = FieldElementAndEventIndex::from_str(&format!("0x{}-1", below_prime));
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_without_padding() {
+        let res = FieldElementAndEventIndex::from_str(
+            "0x670d1dd42a19cb229bb4378b58b9c3e76aa43edaaea46845cd8c456c1224d89-0",
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_with_uppercase_tx_hash() {
+        let tx_hash = &random_felt()[2..];
+        let res =
+            FieldElementAndEventIndex::from_str(&format!("0x{}-{}", tx_hash.to_uppercase(), 1));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_without_0x() {
+        let tx_hash = &random_felt()[2..];
+        let res = FieldElementAndEventIndex::from_str(&format!("{}-1", tx_hash));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_with_invalid_event_index() {
+        let tx_hash = random_felt();
+
+        assert!(FieldElementAndEventIndex::from_str(&tx_hash).is_err());
+        assert!(FieldElementAndEventIndex::from_str(&format!("{}-01", tx_hash)).is_err());
+        assert!(FieldElementAndEventIndex::from_str(&format!("{}-{}", tx_hash, u64::MAX)).is_err());
+        assert!(FieldElementAndEventIndex::from_str(&format!("{}:1", tx_hash)).is_err());
+    }
+}
@@ -6,13 +6,17 @@ use error_stack::Report;
 
 pub use self::base_58_event_index::Base58TxDigestAndEventIndex;
 pub use self::base_58_solana_event_index::Base58SolanaTxSignatureAndEventIndex;
+pub use self::field_element_event_index::{FieldElementAndEventIndex, STARKNET_PRIME};
 pub use self::tx_hash::HexTxHash;
 pub use self::tx_hash_event_index::HexTxHashAndEventIndex;
+pub use self::xrpl_tx_hash::XrplTxHash;
 
 mod base_58_event_index;
 mod base_58_solana_event_index;
+mod field_element_event_index;
 mod tx_hash;
 mod tx_hash_event_index;
+mod xrpl_tx_hash;
 
 #[derive(thiserror::Error)]
 #[cw_serde]
@@ -45,6 +49,8 @@ pub enum MessageIdFormat {
     Base58TxDigestAndEventIndex,
     Base58SolanaTxSignatureAndEventIndex,
     HexTxHash,
+    FieldElementAndEventIndex,
+    XrplTxHash,
 }
 
 // function the router calls to verify msg ids
@@ -60,6 +66,10 @@ pub fn verify_msg_id(message_id: &str, format: &MessageIdFormat) -> Result<(), R
             Base58SolanaTxSignatureAndEventIndex::from_str(message_id).map(|_| ())
         }
         MessageIdFormat::HexTxHash => HexTxHash::from_str(message_id).map(|_| ()),
+        MessageIdFormat::FieldElementAndEventIndex => {
+            FieldElementAndEventIndex::from_str(message_id).map(|_| ())
+        }
+        MessageIdFormat::XrplTxHash => XrplTxHash::from_str(message_id).map(|_| ()),
     }
 }
 
@@ -67,7 +77,9 @@ pub fn verify_msg_id(message_id: &str, format: &MessageIdFormat) -> Result<(), R
 mod test {
     use super::tx_hash_event_index::HexTxHashAndEventIndex;
     use crate::msg_id::base_58_event_index::Base58TxDigestAndEventIndex;
-    use crate::msg_id::{verify_msg_id, MessageIdFormat};
+    use crate::msg_id::{
+        verify_msg_id, FieldElementAndEventIndex, HexTxHash, MessageIdFormat, XrplTxHash,
+    };
 
     #[test]
     fn should_verify_hex_tx_hash_event_index_msg_id() {
@@ -89,6 +101,32 @@ mod test {
         assert!(verify_msg_id(&msg_id, &MessageIdFormat::Base58TxDigestAndEventIndex).is_ok());
     }
 
+    #[test]
+    fn should_verify_field_element_event_index_msg_id() {
+        let msg_id = FieldElementAndEventIndex {
+            tx_hash: [1; 32],
+            event_index: 0,
+        }
+        .to_string();
+        assert!(verify_msg_id(&msg_id, &MessageIdFormat::FieldElementAndEventIndex).is_ok());
+
+        let msg_id = HexTxHashAndEventIndex {
+            tx_hash: [u8::MAX; 32],
+            event_index: 0,
+        }
+        .to_string();
+        assert!(verify_msg_id(&msg_id, &MessageIdFormat::FieldElementAndEventIndex).is_err());
+    }
+
+    #[test]
+    fn should_verify_xrpl_tx_hash_msg_id() {
+        let msg_id = XrplTxHash { tx_hash: [1; 32] }.to_string();
+        assert!(verify_msg_id(&msg_id, &MessageIdFormat::XrplTxHash).is_ok());
+
+        let msg_id = HexTxHash { tx_hash: [1; 32] }.to_string();
+        assert!(verify_msg_id(&msg_id, &MessageIdFormat::XrplTxHash).is_err());
+    }
+
     #[test]
     fn should_not_verify_invalid_msg_id() {
         let msg_id = "foobar";
@@ -0,0 +1,134 @@
+use core::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use cosmwasm_std::HexBinary;
+use error_stack::{ensure, Report, ResultExt};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::Error;
+use crate::hash::Hash;
+use crate::nonempty;
+
+/// XRPL payments cannot emit events, so a message is identified by the hash of the transaction that sent it.
+/// The hash is encoded as uppercase hex without a prefix, the same way the XRPL encodes it.
+pub struct XrplTxHash {
+    pub tx_hash: Hash,
+}
+
+impl XrplTxHash {
+    pub fn tx_hash_as_hex(&self) -> nonempty::String {
+        HexBinary::from(self.tx_hash)
+            .to_hex()
+            .to_uppercase()
+            .try_into()
+            .expect("failed to convert tx hash to non-empty string")
+    }
+
+    pub fn new(tx_id: impl Into<[u8; 32]>) -> Self {
+        Self {
+            tx_hash: tx_id.into(),
+        }
+    }
+}
+
+const PATTERN: &str = "^[0-9A-F]{64}$";
+lazy_static! {
+    static ref REGEX: Regex = Regex::new(PATTERN).expect("invalid regex");
+}
+
+impl FromStr for XrplTxHash {
+    type Err = Report<Error>;
+
+    fn from_str(message_id: &str) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        ensure!(
+            REGEX.is_match(message_id),
+            Error::InvalidMessageID {
+                id: message_id.to_string(),
+                expected_format: PATTERN.to_string(),
+            }
+        );
+        Ok(XrplTxHash {
+            tx_hash: HexBinary::from_hex(message_id)
+                .change_context(Error::InvalidTxHash(message_id.to_string()))?
+                .as_slice()
+                .try_into()
+                .map_err(|_| Error::InvalidTxHash(message_id.to_string()))?,
+        })
+    }
+}
+
+impl Display for XrplTxHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tx_hash_as_hex())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn random_hash() -> String {
+        let mut bytes = vec![];
+        for _ in 0..32 {
+            let byte: u8 = rand::random();
+            bytes.push(byte)
+        }
+        HexBinary::from(bytes).to_hex().to_uppercase()
+    }
+
+    #[test]
+    fn should_parse_msg_id() {
+        let res = XrplTxHash::from_str(
+            "C53ECF838647FA5A4C780377025FEC7999AB4182590510CA461444B207AB74A9",
+        );
+        assert!(res.is_ok());
+
+        for _ in 0..1000 {
+            let msg_id = random_hash();
+
+            let res = XrplTxHash::from_str(&msg_id);
+            let parsed = res.unwrap();
+            assert_eq!(parsed.tx_hash_as_hex(), msg_id.clone().try_into().unwrap());
+            assert_eq!(parsed.to_string(), msg_id);
+        }
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_with_wrong_length_tx_hash() {
+        let tx_hash = random_hash();
+        // too long
+        let res = XrplTxHash::from_str(&format!("{}FF", tx_hash));
+        assert!(res.is_err());
+
+        // too short
+        let res = XrplTxHash::from_str(&tx_hash[..tx_hash.len() - 2]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_with_lowercase_tx_hash() {
+        let tx_hash = random_hash();
+        let res = XrplTxHash::from_str(&tx_hash.to_lowercase());
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_with_0x() {
+        let tx_hash = random_hash();
+        let res = XrplTxHash::from_str(&format!("0x{}", tx_hash));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn should_not_parse_msg_id_with_event_index() {
+        let tx_hash = random_hash();
+        let res = XrplTxHash::from_str(&format!("{}-1", tx_hash));
+        assert!(res.is_err());
+    }
+}
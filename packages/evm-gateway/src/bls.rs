@@ -0,0 +1,184 @@
+use axelar_wasm_std::hash::Hash;
+use cosmwasm_std::Uint256;
+use error_stack::{Report, ResultExt};
+use ethers_contract::{EthAbiCodec, EthAbiType, EthCall};
+use ethers_core::abi::Token::Tuple;
+use ethers_core::abi::{encode, Tokenize};
+use ethers_core::types::Bytes;
+use multisig::key::PublicKey;
+use multisig::msg::Signer;
+use multisig::multisig::AggregatedSignature;
+use multisig::verifier_set::VerifierSet;
+use sha3::{Digest, Keccak256};
+
+use crate::error::Error;
+use crate::Message;
+
+// Counterparts of the gateway structs for verifier sets with BLS keys.
+// Signers are identified by their public key instead of an address, and a proof carries a single
+// aggregated signature together with a bitmap of the signers (ordered by public key) that contributed to it.
+
+#[derive(Clone, EthAbiType, EthAbiCodec, Default, Debug, PartialEq, Eq)]
+pub struct WeightedBlsSigner {
+    pub pub_key: Bytes,
+    pub weight: u128,
+}
+
+#[derive(Clone, EthAbiType, EthAbiCodec, Default, Debug, PartialEq, Eq)]
+pub struct WeightedBlsSigners {
+    pub signers: Vec<WeightedBlsSigner>,
+    pub threshold: u128,
+    pub nonce: [u8; 32],
+}
+
+#[derive(Clone, EthAbiType, EthAbiCodec, Default, Debug, PartialEq, Eq)]
+pub struct AggregatedProof {
+    pub signers: WeightedBlsSigners,
+    pub signers_bitmap: Bytes,
+    pub signature: Bytes,
+}
+
+#[derive(Clone, EthCall, Default, Debug, PartialEq, Eq)]
+#[ethcall(
+    name = "approveMessages",
+    abi = "approveMessages((string,string,string,address,bytes32)[],(((bytes,uint128)[],uint128,bytes32),bytes,bytes))"
+)]
+pub struct ApproveMessagesBlsCall {
+    pub messages: Vec<Message>,
+    pub proof: AggregatedProof,
+}
+
+#[derive(Clone, EthCall, Default, Debug, PartialEq, Eq)]
+#[ethcall(
+    name = "rotateSigners",
+    abi = "rotateSigners(((bytes,uint128)[],uint128,bytes32),(((bytes,uint128)[],uint128,bytes32),bytes,bytes))"
+)]
+pub struct RotateSignersBlsCall {
+    pub new_signers: WeightedBlsSigners,
+    pub proof: AggregatedProof,
+}
+
+impl TryFrom<&VerifierSet> for WeightedBlsSigners {
+    type Error = Report<Error>;
+
+    fn try_from(verifier_set: &VerifierSet) -> Result<Self, Self::Error> {
+        let mut signers: Vec<_> = verifier_set
+            .signers
+            .values()
+            .map(WeightedBlsSigner::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        signers.sort_by(|signer1, signer2| signer1.pub_key.cmp(&signer2.pub_key));
+
+        Ok(WeightedBlsSigners {
+            signers,
+            threshold: verifier_set.threshold.u128(),
+            nonce: Uint256::from(verifier_set.created_at).to_be_bytes(),
+        })
+    }
+}
+
+impl TryFrom<&Signer> for WeightedBlsSigner {
+    type Error = Report<Error>;
+
+    fn try_from(signer: &Signer) -> Result<Self, Self::Error> {
+        match &signer.pub_key {
+            PublicKey::Bls(pub_key) => Ok(WeightedBlsSigner {
+                pub_key: Bytes::from(pub_key.to_vec()),
+                weight: signer.weight.u128(),
+            }),
+            _ => Err(Error::InvalidPublicKey).attach_printable("expect BLS public key"),
+        }
+    }
+}
+
+impl WeightedBlsSigners {
+    pub fn abi_encode(&self) -> Vec<u8> {
+        let tokens = self.clone().into_tokens();
+
+        encode(&[Tuple(tokens)])
+    }
+
+    pub fn hash(&self) -> Hash {
+        Keccak256::digest(self.abi_encode()).into()
+    }
+}
+
+impl AggregatedProof {
+    pub fn new(
+        verifier_set: &VerifierSet,
+        aggregated_signature: AggregatedSignature,
+    ) -> Result<Self, Report<Error>> {
+        Ok(AggregatedProof {
+            signers: WeightedBlsSigners::try_from(verifier_set)?,
+            signers_bitmap: Bytes::from(aggregated_signature.signers_bitmap.to_vec()),
+            signature: Bytes::from(aggregated_signature.signature.to_vec()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use axelar_wasm_std::nonempty;
+    use axelar_wasm_std::snapshot::Participant;
+    use cosmwasm_std::{Addr, HexBinary, Uint128};
+    use ethers_contract::EthCall;
+    use multisig::key::PublicKey;
+    use multisig::verifier_set::VerifierSet;
+
+    use crate::bls::{ApproveMessagesBlsCall, RotateSignersBlsCall, WeightedBlsSigners};
+    use crate::test::curr_verifier_set;
+
+    // compressed G1 points of the secret keys 1, 2 and 3
+    const PUB_KEYS: [&str; 3] = [
+        "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+        "a572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e",
+        "89ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e5224",
+    ];
+
+    #[test]
+    fn bls_signers_are_sorted_by_pub_key() {
+        let signers = WeightedBlsSigners::try_from(&bls_verifier_set()).unwrap();
+
+        let pub_keys: Vec<_> = signers
+            .signers
+            .iter()
+            .map(|signer| HexBinary::from(signer.pub_key.to_vec()).to_hex())
+            .collect();
+        assert_eq!(pub_keys, vec![PUB_KEYS[2], PUB_KEYS[0], PUB_KEYS[1]]);
+        assert_eq!(signers.threshold, 2);
+    }
+
+    #[test]
+    fn bls_signers_require_bls_keys() {
+        assert!(WeightedBlsSigners::try_from(&curr_verifier_set()).is_err());
+    }
+
+    #[test]
+    fn bls_calls_use_gateway_function_names() {
+        assert_eq!(ApproveMessagesBlsCall::function_name(), "approveMessages");
+        assert_eq!(RotateSignersBlsCall::function_name(), "rotateSigners");
+        assert_ne!(
+            ApproveMessagesBlsCall::selector(),
+            crate::ApproveMessagesCall::selector()
+        );
+    }
+
+    fn bls_verifier_set() -> VerifierSet {
+        let participants = PUB_KEYS
+            .iter()
+            .enumerate()
+            .map(|(i, pub_key)| {
+                (
+                    Participant {
+                        address: Addr::unchecked(format!("verifier{i}")),
+                        weight: nonempty::Uint128::one(),
+                    },
+                    PublicKey::Bls(HexBinary::from_hex(pub_key).unwrap()),
+                )
+            })
+            .collect();
+
+        VerifierSet::new(participants, Uint128::from(2u128), 0)
+    }
+}
@@ -18,6 +18,7 @@ use sha3::{Digest, Keccak256};
 
 use crate::error::Error;
 
+pub mod bls;
 pub mod error;
 
 // Generates the bindings for the Axelar Amplifier Gateway contract.